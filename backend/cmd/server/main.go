@@ -8,6 +8,9 @@ import (
 	"musicclubbot/backend/internal/app"
 	"musicclubbot/backend/internal/config"
 	"musicclubbot/backend/internal/db"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/internal/linkcheck"
+	"musicclubbot/backend/internal/logging"
 
 	"os"
 
@@ -19,11 +22,29 @@ func main() {
 	defer stop()
 
 	cfg := config.Load()
-	logger.SetDefaultFormat("%{time} %{lvl} %{message}")
-	log, _ := logger.New("", 1, os.Stdout)
+	var log *logger.Logger
+	if cfg.LogFormat == "json" {
+		logger.SetDefaultFormat(logging.JSONFormat)
+		log, _ = logger.New("", 0, logging.NewJSONWriter(os.Stdout))
+	} else {
+		logger.SetDefaultFormat("%{time} %{lvl} %{message}")
+		log, _ = logger.New("", 1, os.Stdout)
+	}
+	if err := cfg.Validate(); err != nil {
+		log.Fatalf("invalid configuration: %v", err)
+	}
+	for _, warning := range cfg.ClampTokenTTLs() {
+		log.Warning(warning)
+	}
 	ctx = context.WithValue(ctx, "log", log)
 	ctx = context.WithValue(ctx, "cfg", cfg)
-	ctx = context.WithValue(ctx, "db", db.MustInitDb(ctx, cfg.DbUrl))
+	conn := db.MustInitDb(ctx, cfg.DbUrl)
+	ctx = context.WithValue(ctx, "db", conn)
+	ctx = context.WithValue(ctx, "cache", helpers.NewDetailsCache(cfg.CacheTTL))
+
+	linkChecks := linkcheck.NewQueue(cfg.LinkCheckQueueSize)
+	go linkChecks.Run(ctx, conn, log)
+	ctx = context.WithValue(ctx, "linkcheck", linkChecks)
 
 	if err := app.Run(ctx); err != nil {
 		log.Fatalf("backend exited with error: %v", err)
@@ -10,6 +10,7 @@ require (
 	github.com/lib/pq v1.10.9
 	golang.org/x/crypto v0.46.0
 	golang.org/x/net v0.48.0
+	google.golang.org/genproto/googleapis/rpc v0.0.0-20251213004720-97cd9d5aeac2
 	google.golang.org/grpc v1.77.0
 	google.golang.org/protobuf v1.36.11
 )
@@ -21,6 +22,5 @@ require (
 	github.com/rs/cors v1.7.0 // indirect
 	golang.org/x/sys v0.39.0 // indirect
 	golang.org/x/text v0.32.0 // indirect
-	google.golang.org/genproto/googleapis/rpc v0.0.0-20251213004720-97cd9d5aeac2 // indirect
 	nhooyr.io/websocket v1.8.6 // indirect
 )
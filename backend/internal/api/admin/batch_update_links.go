@@ -0,0 +1,91 @@
+package admin
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) BatchUpdateLinks(ctx context.Context, req *proto.BatchUpdateLinksRequest) (*proto.BatchUpdateLinksResponse, error) {
+	if len(req.GetUpdates()) == 0 {
+		return nil, status.Error(codes.InvalidArgument, "updates must not be empty")
+	}
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	results := make([]*proto.LinkUpdateResult, 0, len(req.GetUpdates()))
+	updated := []string{}
+	for _, u := range req.GetUpdates() {
+		result := &proto.LinkUpdateResult{SongId: u.GetSongId()}
+		results = append(results, result)
+
+		linkKind, err := helpers.MapSongLinkKindToDB(u.GetLink().GetKind())
+		_, idErr := uuid.Parse(u.GetSongId())
+		switch {
+		case u.GetSongId() == "":
+			result.Status, result.Error = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID, "song_id must not be empty"
+			continue
+		case idErr != nil:
+			result.Status, result.Error = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID, "song_id must be a uuid"
+			continue
+		case err != nil:
+			result.Status, result.Error = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID, err.Error()
+			continue
+		case !helpers.ValidLinkURL(u.GetLink().GetUrl()):
+			result.Status, result.Error = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID, "link url must be an absolute http(s) url"
+			continue
+		}
+
+		linkURL := helpers.CanonicalizeLinkURL(linkKind, u.GetLink().GetUrl())
+		var oldKind, oldURL, thumbnail string
+		err = tx.QueryRowContext(ctx, `
+			SELECT link_kind, link_url, COALESCE(thumbnail_url, '') FROM song WHERE id = $1 FOR UPDATE
+		`, u.GetSongId()).Scan(&oldKind, &oldURL, &thumbnail)
+		if errors.Is(err, sql.ErrNoRows) {
+			result.Status = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_NOT_FOUND
+			continue
+		}
+		if err != nil {
+			return nil, status.Errorf(codes.Internal, "load song %s: %v", u.GetSongId(), err)
+		}
+
+		// Thumbnails derived from the old link follow the new one; custom ones are kept.
+		if thumbnail == helpers.ExtractThumbnailURL(oldKind, oldURL) {
+			thumbnail = ""
+		}
+		thumbnail = helpers.NormalizeThumbnailURL(thumbnail, linkKind, linkURL)
+
+		if _, err := tx.ExecContext(ctx, `
+			UPDATE song SET link_kind = $1, link_url = $2, thumbnail_url = $3, updated_at = NOW()
+			WHERE id = $4
+		`, linkKind, linkURL, thumbnail, u.GetSongId()); err != nil {
+			return nil, status.Errorf(codes.Internal, "update song %s: %v", u.GetSongId(), err)
+		}
+		result.Status = proto.LinkUpdateStatus_LINK_UPDATE_STATUS_UPDATED
+		updated = append(updated, u.GetSongId())
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+	for _, id := range updated {
+		helpers.InvalidateSong(ctx, id)
+	}
+
+	return &proto.BatchUpdateLinksResponse{Results: results}, nil
+}
@@ -0,0 +1,52 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+)
+
+func TestBatchUpdateLinksReportsEachUpdate(t *testing.T) {
+	const (
+		songID    = "11111111-1111-1111-1111-111111111111"
+		missingID = "99999999-9999-9999-9999-999999999999"
+	)
+	db, mock := dbtest.New(t)
+	mock.ExpectBegin()
+	mock.ExpectQuery("SELECT link_kind, link_url").WithArgs(songID).
+		WillReturnRows([]string{"link_kind", "link_url", "thumbnail_url"}, []any{"youtube", "https://youtu.be/old", ""})
+	mock.ExpectExec("UPDATE song SET link_kind = $1").WillReturnResult(1)
+	mock.ExpectQuery("SELECT link_kind, link_url").WithArgs(missingID)
+	mock.ExpectCommit()
+
+	youtube := func(url string) *proto.SongLink {
+		return &proto.SongLink{Kind: proto.SongLinkType_SONG_LINK_TYPE_YOUTUBE, Url: url}
+	}
+	resp, err := (&AdminService{}).BatchUpdateLinks(context.WithValue(context.Background(), "db", db), &proto.BatchUpdateLinksRequest{
+		Updates: []*proto.LinkUpdate{
+			{SongId: songID, Link: youtube("https://youtu.be/dQw4w9WgXcQ")},
+			{SongId: songID, Link: youtube("not a url")},
+			{SongId: "not-a-uuid", Link: youtube("https://youtu.be/dQw4w9WgXcQ")},
+			{SongId: missingID, Link: youtube("https://youtu.be/dQw4w9WgXcQ")},
+		},
+	})
+	if err != nil {
+		t.Fatalf("BatchUpdateLinks: %v", err)
+	}
+
+	want := []proto.LinkUpdateStatus{
+		proto.LinkUpdateStatus_LINK_UPDATE_STATUS_UPDATED,
+		proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID,
+		proto.LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID,
+		proto.LinkUpdateStatus_LINK_UPDATE_STATUS_NOT_FOUND,
+	}
+	if len(resp.GetResults()) != len(want) {
+		t.Fatalf("got %d results, want %d", len(resp.GetResults()), len(want))
+	}
+	for i, r := range resp.GetResults() {
+		if r.GetStatus() != want[i] {
+			t.Errorf("results[%d] = %v (%q), want %v", i, r.GetStatus(), r.GetError(), want[i])
+		}
+	}
+}
@@ -0,0 +1,81 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+type explainQuery struct {
+	sql  string
+	args []any
+}
+
+// explainWhitelist holds the only queries ExplainQuery is allowed to run. They
+// are built from the SQL the handlers use, with filters left out, and events
+// are listed as members see them. Parameters are fixed sample values so the
+// planner sees realistic shapes.
+var explainWhitelist = map[proto.ExplainTarget]explainQuery{
+	proto.ExplainTarget_EXPLAIN_TARGET_LIST_SONGS: {
+		sql: `
+			SELECT ` + helpers.SongColumns + `
+			FROM song
+			ORDER BY ` + helpers.SongListOrder + `
+			LIMIT $1 OFFSET $2`,
+		args: []any{helpers.DefaultPageSize + 1, 0},
+	},
+	proto.ExplainTarget_EXPLAIN_TARGET_SEARCH_SONGS: {
+		sql: `
+			SELECT ` + helpers.SongColumns + `
+			FROM song
+			WHERE ` + helpers.SongSearchFilter + `
+			ORDER BY ` + helpers.SongListOrder + `
+			LIMIT $2 OFFSET $3`,
+		args: []any{"%a%", helpers.DefaultPageSize + 1, 0},
+	},
+	proto.ExplainTarget_EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG: {
+		sql:  helpers.SongAssignmentsQuery,
+		args: []any{"00000000-0000-0000-0000-000000000000"},
+	},
+	proto.ExplainTarget_EXPLAIN_TARGET_LIST_EVENTS: {
+		sql: `
+			SELECT ` + helpers.EventColumns + `
+			FROM event
+			WHERE deleted_at IS NULL AND ` + helpers.EventVisibilityFilter(nil, "status") + `
+			ORDER BY ` + helpers.EventListOrder + `
+			LIMIT $1`,
+		args: []any{51},
+	},
+}
+
+func (s *AdminService) ExplainQuery(ctx context.Context, req *proto.ExplainQueryRequest) (*proto.ExplainQueryResponse, error) {
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionIsAdmin(perms) {
+		return nil, status.Error(codes.PermissionDenied, "admin rights required")
+	}
+
+	q, ok := explainWhitelist[req.GetTarget()]
+	if !ok {
+		return nil, status.Error(codes.InvalidArgument, "query is not explainable")
+	}
+
+	var plan string
+	if err := db.QueryRowContext(ctx, "EXPLAIN (FORMAT JSON) "+q.sql, q.args...).Scan(&plan); err != nil {
+		return nil, status.Errorf(codes.Internal, "explain query: %v", err)
+	}
+	return &proto.ExplainQueryResponse{PlanJson: plan}, nil
+}
@@ -0,0 +1,39 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) GrantAdmin(ctx context.Context, req *proto.AdminUserRequest) (*proto.PermissionSet, error) {
+	userID, err := uuid.Parse(req.GetUserId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "user_id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM app_user WHERE id = $1)`, userID).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load user: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "user not found")
+	}
+
+	if err := helpers.GrantAdmin(ctx, db, userID.String()); err != nil {
+		return nil, status.Errorf(codes.Internal, "grant admin: %v", err)
+	}
+	perms, err := helpers.GetUserPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	return perms, nil
+}
@@ -0,0 +1,101 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) MergeSongs(ctx context.Context, req *proto.MergeSongsRequest) (*proto.MergeSongsResponse, error) {
+	if req.GetSourceId() == "" || req.GetTargetId() == "" {
+		return nil, status.Error(codes.InvalidArgument, "source_id and target_id are required")
+	}
+	source, err := uuid.Parse(req.GetSourceId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "source_id must be a uuid")
+	}
+	target, err := uuid.Parse(req.GetTargetId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "target_id must be a uuid")
+	}
+	// Compare parsed ids so differently cased spellings of one id are caught too.
+	if source == target {
+		return nil, status.Error(codes.InvalidArgument, "cannot merge a song into itself")
+	}
+	sourceID, targetID := source.String(), target.String()
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var found int
+	if err := tx.QueryRowContext(ctx, `
+		SELECT COUNT(*) FROM (SELECT id FROM song WHERE id IN ($1, $2) FOR UPDATE) s
+	`, sourceID, targetID).Scan(&found); err != nil {
+		return nil, status.Errorf(codes.Internal, "load songs: %v", err)
+	}
+	if found != 2 {
+		return nil, status.Error(codes.NotFound, "song not found")
+	}
+
+	var total int32
+	if err := tx.QueryRowContext(ctx, `
+		SELECT COUNT(*) FROM song_role_assignment WHERE song_id = $1
+	`, sourceID).Scan(&total); err != nil {
+		return nil, status.Errorf(codes.Internal, "count participations: %v", err)
+	}
+
+	// Assignments reference (song_id, role), so the target must offer every source role.
+	if _, err := tx.ExecContext(ctx, `
+		INSERT INTO song_role (song_id, role)
+		SELECT $2, role FROM song_role WHERE song_id = $1
+		ON CONFLICT DO NOTHING
+	`, sourceID, targetID); err != nil {
+		return nil, status.Errorf(codes.Internal, "merge roles: %v", err)
+	}
+
+	res, err := tx.ExecContext(ctx, `
+		INSERT INTO song_role_assignment (song_id, role, user_id, joined_at, status)
+		SELECT $2, role, user_id, joined_at, status FROM song_role_assignment WHERE song_id = $1
+		ON CONFLICT (song_id, role, user_id) DO NOTHING
+	`, sourceID, targetID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "move participations: %v", err)
+	}
+	moved, err := res.RowsAffected()
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "move participations: %v", err)
+	}
+
+	if _, err := tx.ExecContext(ctx, `
+		UPDATE event_track_item SET song_id = $2 WHERE song_id = $1
+	`, sourceID, targetID); err != nil {
+		return nil, status.Errorf(codes.Internal, "move tracklist items: %v", err)
+	}
+
+	if _, err := tx.ExecContext(ctx, `DELETE FROM song WHERE id = $1`, sourceID); err != nil {
+		return nil, status.Errorf(codes.Internal, "delete song: %v", err)
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+
+	helpers.InvalidateSong(ctx, sourceID)
+	helpers.InvalidateSong(ctx, targetID)
+	return &proto.MergeSongsResponse{
+		Moved:   int32(moved),
+		Skipped: total - int32(moved),
+	}, nil
+}
@@ -0,0 +1,88 @@
+package admin
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"strings"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) RenameRole(ctx context.Context, req *proto.RenameRoleRequest) (*proto.RenameRoleResponse, error) {
+	from, to := req.GetFrom(), strings.TrimSpace(req.GetTo())
+	v := &helpers.Violations{}
+	v.Check(from != "", "from", "must not be empty")
+	v.CheckRole("to", to)
+	v.Check(from != to, "to", "must differ from from")
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var total, moved int64
+	var songIDs []string
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		if err := tx.QueryRowContext(ctx, `
+			SELECT COUNT(*) FROM song_role_assignment WHERE role = $1
+		`, from).Scan(&total); err != nil {
+			return status.Errorf(codes.Internal, "count participations: %v", err)
+		}
+
+		// Assignments reference (song_id, role), so the new role must exist first.
+		if _, err := tx.ExecContext(ctx, `
+			INSERT INTO song_role (song_id, role)
+			SELECT song_id, $2 FROM song_role WHERE role = $1
+			ON CONFLICT DO NOTHING
+		`, from, to); err != nil {
+			return status.Errorf(codes.Internal, "add role: %v", err)
+		}
+
+		res, err := tx.ExecContext(ctx, `
+			INSERT INTO song_role_assignment (song_id, role, user_id, joined_at, status)
+			SELECT song_id, $2, user_id, joined_at, status FROM song_role_assignment WHERE role = $1
+			ON CONFLICT (song_id, role, user_id) DO NOTHING
+		`, from, to)
+		if err != nil {
+			return status.Errorf(codes.Internal, "move participations: %v", err)
+		}
+		if moved, err = res.RowsAffected(); err != nil {
+			return status.Errorf(codes.Internal, "move participations: %v", err)
+		}
+
+		// Dropping the old role cascades to the original assignments.
+		rows, err := tx.QueryContext(ctx, `DELETE FROM song_role WHERE role = $1 RETURNING song_id`, from)
+		if err != nil {
+			return status.Errorf(codes.Internal, "delete old role: %v", err)
+		}
+		defer rows.Close()
+		for rows.Next() {
+			var id string
+			if err := rows.Scan(&id); err != nil {
+				return status.Errorf(codes.Internal, "scan song id: %v", err)
+			}
+			songIDs = append(songIDs, id)
+		}
+		if err := rows.Err(); err != nil {
+			return status.Errorf(codes.Internal, "delete old role: %v", err)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	for _, id := range songIDs {
+		helpers.InvalidateSong(ctx, id)
+	}
+	return &proto.RenameRoleResponse{
+		Moved:   int32(moved),
+		Skipped: int32(total - moved),
+	}, nil
+}
@@ -0,0 +1,39 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) RestoreEvent(ctx context.Context, req *proto.EventId) (*proto.EventDetails, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	res, err := db.ExecContext(ctx, `
+		UPDATE event SET deleted_at = NULL, updated_at = NOW()
+		WHERE id = $1 AND deleted_at IS NOT NULL
+	`, req.GetId())
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "restore event: %v", err)
+	}
+	if n, err := res.RowsAffected(); err == nil && n == 0 {
+		return nil, status.Error(codes.NotFound, "deleted event not found")
+	}
+
+	helpers.InvalidateEvent(ctx, req.GetId())
+	return helpers.LoadEventDetails(ctx, db, req.GetId(), userID)
+}
@@ -0,0 +1,54 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const (
+	testEventID = "44444444-4444-4444-4444-444444444444"
+	testUserID  = "22222222-2222-2222-2222-222222222222"
+)
+
+func adminCtx(t *testing.T) (context.Context, *dbtest.Mock) {
+	db, mock := dbtest.New(t)
+	ctx := context.WithValue(context.Background(), "db", db)
+	return context.WithValue(ctx, "user_id", testUserID), mock
+}
+
+func TestRestoreEventRejectsMalformedID(t *testing.T) {
+	ctx, _ := adminCtx(t)
+	_, err := (&AdminService{}).RestoreEvent(ctx, &proto.EventId{Id: "not-a-uuid"})
+	if status.Code(err) != codes.InvalidArgument {
+		t.Fatalf("RestoreEvent = %v, want InvalidArgument", err)
+	}
+}
+
+func TestRestoreEventRevivesDeletedEvent(t *testing.T) {
+	ctx, mock := adminCtx(t)
+	mock.ExpectExec("UPDATE event SET deleted_at = NULL").WithArgs(testEventID).WillReturnResult(1)
+	mock.ExpectEventDetails([]any{testEventID, "Gig", nil, "", false, false, testUserID, "published"}, testUserID, dbtest.Admin)
+
+	details, err := (&AdminService{}).RestoreEvent(ctx, &proto.EventId{Id: testEventID})
+	if err != nil {
+		t.Fatalf("RestoreEvent: %v", err)
+	}
+	if details.GetEvent().GetId() != testEventID {
+		t.Errorf("restored event %q, want %q", details.GetEvent().GetId(), testEventID)
+	}
+}
+
+func TestRestoreEventOfLiveEvent(t *testing.T) {
+	ctx, mock := adminCtx(t)
+	mock.ExpectExec("UPDATE event SET deleted_at = NULL").WithArgs(testEventID).WillReturnResult(0)
+
+	_, err := (&AdminService{}).RestoreEvent(ctx, &proto.EventId{Id: testEventID})
+	if status.Code(err) != codes.NotFound {
+		t.Fatalf("RestoreEvent = %v, want NotFound", err)
+	}
+}
@@ -0,0 +1,57 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) RevokeAdmin(ctx context.Context, req *proto.AdminUserRequest) (*proto.PermissionSet, error) {
+	userID, err := uuid.Parse(req.GetUserId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "user_id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var exists bool
+	if err := tx.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM app_user WHERE id = $1)`, userID).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load user: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "user not found")
+	}
+
+	if err := helpers.RevokeAdmin(ctx, tx, userID.String()); err != nil {
+		return nil, status.Errorf(codes.Internal, "revoke admin: %v", err)
+	}
+
+	remaining, err := helpers.CountAdmins(ctx, tx)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "count admins: %v", err)
+	}
+	if remaining == 0 {
+		return nil, status.Error(codes.FailedPrecondition, "cannot revoke the last admin")
+	}
+
+	perms, err := helpers.GetUserPermissions(ctx, tx, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+	return perms, nil
+}
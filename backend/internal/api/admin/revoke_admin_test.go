@@ -0,0 +1,24 @@
+package admin
+
+import (
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestRevokeAdminKeepsTheLastAdmin(t *testing.T) {
+	ctx, mock := adminCtx(t)
+	mock.ExpectBegin()
+	mock.ExpectQuery("SELECT EXISTS (SELECT 1 FROM app_user WHERE id = $1)").WillReturnRows([]string{"exists"}, []any{true})
+	mock.ExpectExec("DELETE FROM app_admin WHERE user_id = $1").WithArgs(testUserID).WillReturnResult(1)
+	mock.ExpectQuery("SELECT COUNT(*) FROM app_admin").WillReturnRows([]string{"count"}, []any{0})
+	mock.ExpectRollback()
+
+	_, err := (&AdminService{}).RevokeAdmin(ctx, &proto.AdminUserRequest{UserId: testUserID})
+	if status.Code(err) != codes.FailedPrecondition {
+		t.Fatalf("RevokeAdmin = %v, want FailedPrecondition", err)
+	}
+}
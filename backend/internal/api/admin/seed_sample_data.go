@@ -0,0 +1,88 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+type sampleSong struct {
+	title, artist, linkKind, linkURL string
+	roles                            []string
+}
+
+// sampleSongs is the fixed demo catalog; the caller joins the first role of each song.
+var sampleSongs = []sampleSong{
+	{"Smells Like Teen Spirit", "Nirvana", "youtube", "https://www.youtube.com/watch?v=hTWKbfoikeg", []string{"vocals", "guitar", "bass", "drums"}},
+	{"Seven Nation Army", "The White Stripes", "youtube", "https://www.youtube.com/watch?v=0J2QdDbelmY", []string{"guitar", "vocals", "drums"}},
+	{"Группа крови", "Кино", "yandex_music", "https://music.yandex.ru/album/4433/track/3906", []string{"vocals", "guitar", "bass", "drums", "keys"}},
+}
+
+const sampleEventTitle = "Demo concert"
+
+func (s *AdminService) SeedSampleData(ctx context.Context, req *proto.SeedSampleDataRequest) (*proto.SeedSampleDataResponse, error) {
+	cfg := ctx.Value("cfg").(config.Config)
+	if !cfg.DevMode {
+		return nil, status.Error(codes.FailedPrecondition, "sample data can only be seeded with DEV_MODE=true")
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	resp := &proto.SeedSampleDataResponse{}
+	tracklist := &proto.Tracklist{}
+	for i, song := range sampleSongs {
+		var songID string
+		if err := tx.QueryRowContext(ctx, `
+			INSERT INTO song (title, artist, description, link_kind, link_url, created_by, thumbnail_url)
+			VALUES ($1, $2, '', $3, $4, $5, $6)
+			RETURNING id
+		`, song.title, song.artist, song.linkKind, song.linkURL, userID,
+			helpers.ExtractThumbnailURL(song.linkKind, song.linkURL)).Scan(&songID); err != nil {
+			return nil, status.Errorf(codes.Internal, "insert song: %v", err)
+		}
+		for _, role := range song.roles {
+			if _, err := tx.ExecContext(ctx, `INSERT INTO song_role (song_id, role) VALUES ($1, $2)`, songID, role); err != nil {
+				return nil, status.Errorf(codes.Internal, "insert role: %v", err)
+			}
+		}
+		if _, err := tx.ExecContext(ctx, `
+			INSERT INTO song_role_assignment (song_id, role, user_id) VALUES ($1, $2, $3)
+		`, songID, song.roles[0], userID); err != nil {
+			return nil, status.Errorf(codes.Internal, "insert participation: %v", err)
+		}
+		resp.SongIds = append(resp.SongIds, songID)
+		resp.ParticipationsCreated++
+		tracklist.Items = append(tracklist.Items, &proto.TrackItem{Order: uint32(i + 1), SongId: songID})
+	}
+
+	if err := tx.QueryRowContext(ctx, `
+		INSERT INTO event (title, created_by, status) VALUES ($1, $2, 'published') RETURNING id
+	`, sampleEventTitle, userID).Scan(&resp.EventId); err != nil {
+		return nil, status.Errorf(codes.Internal, "insert event: %v", err)
+	}
+	if err := helpers.ReplaceTracklist(ctx, tx, resp.EventId, tracklist); err != nil {
+		return nil, status.Errorf(codes.Internal, "set tracklist: %v", err)
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+	return resp, nil
+}
@@ -0,0 +1,10 @@
+package admin
+
+import (
+	"musicclubbot/backend/proto"
+)
+
+// AdminService implements maintenance endpoints for administrators.
+type AdminService struct {
+	proto.UnimplementedAdminServiceServer
+}
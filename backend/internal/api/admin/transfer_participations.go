@@ -0,0 +1,92 @@
+package admin
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *AdminService) TransferParticipations(ctx context.Context, req *proto.TransferParticipationsRequest) (*proto.TransferParticipationsResponse, error) {
+	fromID, err := uuid.Parse(req.GetFromUserId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "from_user_id must be a uuid")
+	}
+	toID, err := uuid.Parse(req.GetToUserId())
+	if err != nil {
+		return nil, status.Error(codes.InvalidArgument, "to_user_id must be a uuid")
+	}
+	if fromID == toID {
+		return nil, status.Error(codes.InvalidArgument, "cannot transfer participations to the same user")
+	}
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var found int
+	if err := tx.QueryRowContext(ctx, `
+		SELECT COUNT(*) FROM app_user WHERE id IN ($1, $2)
+	`, fromID, toID).Scan(&found); err != nil {
+		return nil, status.Errorf(codes.Internal, "load users: %v", err)
+	}
+	if found != 2 {
+		return nil, status.Error(codes.NotFound, "user not found")
+	}
+
+	res, err := tx.ExecContext(ctx, `
+		INSERT INTO song_role_assignment (song_id, role, user_id, joined_at, status)
+		SELECT song_id, role, $2, joined_at, status FROM song_role_assignment WHERE user_id = $1
+		ON CONFLICT (song_id, role, user_id) DO NOTHING
+	`, fromID, toID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "move participations: %v", err)
+	}
+	moved, err := res.RowsAffected()
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "move participations: %v", err)
+	}
+
+	// Drop the originals, including the ones the target already had.
+	rows, err := tx.QueryContext(ctx, `
+		DELETE FROM song_role_assignment WHERE user_id = $1 RETURNING song_id
+	`, fromID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "delete old participations: %v", err)
+	}
+	var songIDs []string
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			rows.Close()
+			return nil, status.Errorf(codes.Internal, "scan song id: %v", err)
+		}
+		songIDs = append(songIDs, id)
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "delete old participations: %v", err)
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+
+	for _, id := range songIDs {
+		helpers.InvalidateSong(ctx, id)
+	}
+	return &proto.TransferParticipationsResponse{
+		Moved:   int32(moved),
+		Skipped: int32(len(songIDs)) - int32(moved),
+	}, nil
+}
@@ -14,8 +14,32 @@ import (
 
 // Authentication middleware
 func AuthInterceptor(ctx context.Context, req interface{}, info *grpc.UnaryServerInfo, handler grpc.UnaryHandler) (interface{}, error) {
-	if helpers.PublicMethods[info.FullMethod] {
-		return handler(ctx, req)
+	ctx, err := authorize(ctx, info.FullMethod)
+	if err != nil {
+		return nil, err
+	}
+	return handler(ctx, req)
+}
+
+// AuthStreamInterceptor applies the same access rules to streaming calls.
+func AuthStreamInterceptor(srv interface{}, ss grpc.ServerStream, info *grpc.StreamServerInfo, handler grpc.StreamHandler) error {
+	ctx, err := authorize(ss.Context(), info.FullMethod)
+	if err != nil {
+		return err
+	}
+	return handler(srv, helpers.WithStreamContext(ss, ctx))
+}
+
+// authorize checks the caller against the method's access level and returns
+// ctx carrying the caller's claims and user id.
+func authorize(ctx context.Context, method string) (context.Context, error) {
+	access, registered := helpers.MethodAccess[method]
+	if !registered {
+		// Default-deny: methods missing from the registry are never served.
+		return nil, status.Error(codes.PermissionDenied, "method is not allowed")
+	}
+	if access == helpers.AccessPublic {
+		return ctx, nil
 	}
 
 	md, ok := metadata.FromIncomingContext(ctx)
@@ -56,8 +80,21 @@ func AuthInterceptor(ctx context.Context, req interface{}, info *grpc.UnaryServe
 		}
 	}
 
+	if access.RequiresPermissions() {
+		if db == nil {
+			return nil, status.Error(codes.Internal, "database connection not available in context")
+		}
+		perms, err := helpers.LoadPermissions(ctx, db, claims.UserID)
+		if err != nil {
+			return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+		}
+		if !access.Allows(perms) {
+			return nil, status.Error(codes.PermissionDenied, "insufficient permissions")
+		}
+	}
+
 	ctx = context.WithValue(ctx, "user_claims", claims)
 	ctx = context.WithValue(ctx, "user_id", claims.UserID)
 
-	return handler(ctx, req)
+	return ctx, nil
 }
@@ -6,6 +6,7 @@ import (
 	"encoding/base64"
 	"fmt"
 	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/helpers"
 	"time"
 
 	"github.com/golang-jwt/jwt/v5"
@@ -14,7 +15,6 @@ import (
 
 // JWT configuration
 const (
-	AccessTokenExp   = 15 * time.Minute   // 15 minutes
 	RefreshTokenExp  = 7 * 24 * time.Hour // 7 days
 	RefreshTokenSize = 32                 // bytes for refresh token
 )
@@ -34,9 +34,23 @@ type RefreshToken struct {
 	CreatedAt time.Time `db:"created_at"`
 }
 
-func GenerateAccessToken(ctx context.Context, userID uuid.UUID, username string) (string, error) {
+// GenerateAccessToken signs an access token and returns it with its expiry.
+// Admins get JWT_ADMIN_TTL_SECONDS, everyone else JWT_TTL_SECONDS.
+func GenerateAccessToken(ctx context.Context, userID uuid.UUID, username string) (string, time.Time, error) {
 	cfg := ctx.Value("cfg").(config.Config)
-	expirationTime := time.Now().Add(AccessTokenExp)
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return "", time.Time{}, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID.String())
+	if err != nil {
+		return "", time.Time{}, fmt.Errorf("load permissions: %w", err)
+	}
+	ttl := cfg.AccessTokenTTL
+	if helpers.PermissionIsAdmin(perms) {
+		ttl = cfg.AdminAccessTokenTTL
+	}
+	expirationTime := time.Now().Add(ttl)
 
 	claims := &JWTClaims{
 		UserID:   userID.String(),
@@ -49,8 +63,9 @@ func GenerateAccessToken(ctx context.Context, userID uuid.UUID, username string)
 		},
 	}
 
-	token := jwt.NewWithClaims(jwt.SigningMethodHS256, claims)
-	return token.SignedString(cfg.JwtSecretKey)
+	token := jwt.NewWithClaims(jwt.GetSigningMethod(cfg.JwtAlg), claims)
+	signed, err := token.SignedString(cfg.JwtSecretKey)
+	return signed, expirationTime, err
 }
 
 func GenerateRefreshToken() (string, error) {
@@ -65,7 +80,7 @@ func GenerateRefreshToken() (string, error) {
 func VerifyToken(ctx context.Context, tokenString string) (*JWTClaims, error) {
 	cfg := ctx.Value("cfg").(config.Config)
 	token, err := jwt.ParseWithClaims(tokenString, &JWTClaims{}, func(token *jwt.Token) (interface{}, error) {
-		if _, ok := token.Method.(*jwt.SigningMethodHMAC); !ok {
+		if _, ok := token.Method.(*jwt.SigningMethodHMAC); !ok || token.Method.Alg() != cfg.JwtAlg {
 			return nil, fmt.Errorf("unexpected signing method: %v", token.Header["alg"])
 		}
 		return cfg.JwtSecretKey, nil
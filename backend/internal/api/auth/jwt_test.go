@@ -0,0 +1,46 @@
+package auth
+
+import (
+	"context"
+	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/dbtest"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+func TestAdminAccessTokensExpireSooner(t *testing.T) {
+	db, mock := dbtest.New(t)
+	cfg := config.Config{
+		JwtAlg:              "HS256",
+		JwtSecretKey:        []byte(strings.Repeat("s", 32)),
+		AccessTokenTTL:      2 * time.Hour,
+		AdminAccessTokenTTL: 15 * time.Minute,
+	}
+	ctx := context.WithValue(context.WithValue(context.Background(), "cfg", cfg), "db", db)
+	member, admin := uuid.New(), uuid.New()
+	mock.ExpectPermissions(member.String(), dbtest.Member)
+	mock.ExpectPermissions(admin.String(), dbtest.Admin)
+
+	start := time.Now()
+	_, memberExp, err := GenerateAccessToken(ctx, member, "member")
+	if err != nil {
+		t.Fatalf("member token: %v", err)
+	}
+	_, adminExp, err := GenerateAccessToken(ctx, admin, "admin")
+	if err != nil {
+		t.Fatalf("admin token: %v", err)
+	}
+
+	if d := memberExp.Sub(start); d < cfg.AccessTokenTTL-time.Minute || d > cfg.AccessTokenTTL+time.Minute {
+		t.Errorf("member token lives %s, want about %s", d, cfg.AccessTokenTTL)
+	}
+	if d := adminExp.Sub(start); d < cfg.AdminAccessTokenTTL-time.Minute || d > cfg.AdminAccessTokenTTL+time.Minute {
+		t.Errorf("admin token lives %s, want about %s", d, cfg.AdminAccessTokenTTL)
+	}
+	if !adminExp.Before(memberExp) {
+		t.Errorf("admin token expires at %s, not before the member token at %s", adminExp, memberExp)
+	}
+}
@@ -53,7 +53,7 @@ func (s *AuthService) Login(ctx context.Context, req *proto.Credentials) (*proto
 	}
 
 	// Generate new tokens
-	accessToken, err := GenerateAccessToken(ctx, userID, username)
+	accessToken, accessExp, err := GenerateAccessToken(ctx, userID, username)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "generate access token: %v", err)
 	}
@@ -118,7 +118,7 @@ func (s *AuthService) Login(ctx context.Context, req *proto.Credentials) (*proto
 			RefreshToken: refreshToken,
 		},
 		Iat:            uint64(time.Now().Unix()),
-		Exp:            uint64(time.Now().Add(AccessTokenExp).Unix()),
+		Exp:            uint64(accessExp.Unix()),
 		IsChatMember:   isChatMember,
 		JoinRequestUrl: "https://t.me/your_musicclub_bot?start=join", // TODO start link generation
 		Profile:        profile,
@@ -53,7 +53,7 @@ func (s *AuthService) Refresh(ctx context.Context, req *proto.RefreshRequest) (*
 	}
 
 	// Generate new tokens
-	newAccessToken, err := GenerateAccessToken(ctx, userID, username)
+	newAccessToken, _, err := GenerateAccessToken(ctx, userID, username)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "generate access token: %v", err)
 	}
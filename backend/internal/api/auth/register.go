@@ -95,7 +95,7 @@ func (s *AuthService) Register(ctx context.Context, req *proto.RegisterUserReque
 	}
 
 	// Generate JWT tokens
-	accessToken, err := GenerateAccessToken(ctx, userID, username)
+	accessToken, accessExp, err := GenerateAccessToken(ctx, userID, username)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "generate access token: %v", err)
 	}
@@ -153,7 +153,7 @@ func (s *AuthService) Register(ctx context.Context, req *proto.RegisterUserReque
 			RefreshToken: refreshToken,
 		},
 		Iat:            uint64(time.Now().Unix()),
-		Exp:            uint64(time.Now().Add(AccessTokenExp).Unix()),
+		Exp:            uint64(accessExp.Unix()),
 		IsChatMember:   isChatMember,
 		JoinRequestUrl: "https://t.me/your_musicclub_bot?start=join", // Replace with your bot
 		Profile:        profileResp,
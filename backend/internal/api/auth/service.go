@@ -7,6 +7,7 @@ import (
 // AuthService implements auth-related gRPC endpoints.
 type AuthService struct {
 	proto.UnimplementedAuthServiceServer
+	throttle loginThrottle
 	// You might want to add dependencies like a Telegram bot client here
 	// telegramBot *tgbotapi.BotAPI
 }
@@ -15,6 +15,7 @@ import (
 	"musicclubbot/backend/proto"
 	"net/http"
 	"net/url"
+	"slices"
 	"sort"
 	"strings"
 	"time"
@@ -52,6 +53,13 @@ func (s *AuthService) TelegramWebAppAuth(ctx context.Context, req *proto.Telegra
 		log.Printf("[ERROR] Failed to verify Telegram WebApp data: %v, initData: %s", err, req.InitData)
 		return nil, status.Error(codes.Unauthenticated, "invalid Telegram data")
 	}
+	if !helpers.ValidTelegramUserID(user.ID) {
+		return nil, status.Error(codes.InvalidArgument, "telegram user id out of range")
+	}
+	if !s.throttle.allow(user.ID, cfg.LoginAttemptsPerMinute, time.Now()) {
+		log.Printf("[WARN] Too many login attempts for user %d", user.ID)
+		return nil, status.Error(codes.ResourceExhausted, "too many login attempts, try again in a minute")
+	}
 
 	// 2. Check chat membership
 	isMember := true // Default to true if check is skipped
@@ -153,8 +161,22 @@ func (s *AuthService) TelegramWebAppAuth(ctx context.Context, req *proto.Telegra
 		}
 	}
 
+	// ADMIN_IDS only bootstraps the first admin; once one exists, admins are
+	// managed via AdminService so a revoke is not undone at the next login.
+	if slices.Contains(cfg.AdminTelegramIDs, user.ID) {
+		admins, err := helpers.CountAdmins(ctx, db)
+		if err != nil {
+			return nil, status.Errorf(codes.Internal, "count admins: %v", err)
+		}
+		if admins == 0 {
+			if err := helpers.GrantAdmin(ctx, db, userID.String()); err != nil {
+				return nil, status.Errorf(codes.Internal, "grant admin: %v", err)
+			}
+		}
+	}
+
 	// 4. Generate JWT tokens
-	accessToken, err := GenerateAccessToken(ctx, userID, username)
+	accessToken, accessExp, err := GenerateAccessToken(ctx, userID, username)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "generate access token: %v", err)
 	}
@@ -212,7 +234,7 @@ func (s *AuthService) TelegramWebAppAuth(ctx context.Context, req *proto.Telegra
 			RefreshToken: refreshToken,
 		},
 		Iat:          uint64(time.Now().Unix()),
-		Exp:          uint64(time.Now().Add(AccessTokenExp).Unix()),
+		Exp:          uint64(accessExp.Unix()),
 		IsChatMember: isMember,
 		Profile:      profile,
 		Permissions:  permissions,
@@ -0,0 +1,51 @@
+package auth
+
+import (
+	"sync"
+	"time"
+)
+
+const loginThrottleWindow = time.Minute
+
+// loginThrottle counts Telegram login attempts per user in a sliding
+// one-minute window. The zero value is ready to use.
+type loginThrottle struct {
+	mu        sync.Mutex
+	attempts  map[int64][]time.Time
+	lastSweep time.Time
+}
+
+// allow records an attempt by tgID at now and reports whether it is within
+// limit. A non-positive limit disables throttling.
+func (t *loginThrottle) allow(tgID int64, limit int, now time.Time) bool {
+	if limit <= 0 {
+		return true
+	}
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	if t.attempts == nil {
+		t.attempts = map[int64][]time.Time{}
+	}
+	cutoff := now.Add(-loginThrottleWindow)
+	// Drop users without recent attempts once per window so the map does not grow forever.
+	if now.Sub(t.lastSweep) >= loginThrottleWindow {
+		for id, times := range t.attempts {
+			if len(times) == 0 || !times[len(times)-1].After(cutoff) {
+				delete(t.attempts, id)
+			}
+		}
+		t.lastSweep = now
+	}
+
+	recent := t.attempts[tgID]
+	for len(recent) > 0 && !recent[0].After(cutoff) {
+		recent = recent[1:]
+	}
+	if len(recent) >= limit {
+		t.attempts[tgID] = recent
+		return false
+	}
+	t.attempts[tgID] = append(recent, now)
+	return true
+}
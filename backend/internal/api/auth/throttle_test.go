@@ -0,0 +1,60 @@
+package auth
+
+import (
+	"testing"
+	"time"
+)
+
+func TestLoginThrottleAllow(t *testing.T) {
+	start := time.Date(2025, 1, 1, 12, 0, 0, 0, time.UTC)
+	tests := []struct {
+		name  string
+		limit int
+		// offsets of consecutive attempts by the same user from start
+		attempts []time.Duration
+		want     []bool
+	}{
+		{"disabled", 0, []time.Duration{0, 0, 0}, []bool{true, true, true}},
+		{"within limit", 3, []time.Duration{0, time.Second, 2 * time.Second}, []bool{true, true, true}},
+		{"over limit", 2, []time.Duration{0, time.Second, 2 * time.Second}, []bool{true, true, false}},
+		{"window slides", 2, []time.Duration{0, time.Second, time.Minute, time.Minute + time.Second}, []bool{true, true, true, true}},
+		{"rejections are not counted", 1, []time.Duration{0, 30 * time.Second, time.Minute}, []bool{true, false, true}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			var th loginThrottle
+			for i, offset := range tt.attempts {
+				if got := th.allow(42, tt.limit, start.Add(offset)); got != tt.want[i] {
+					t.Errorf("attempt %d at +%s: allow = %v, want %v", i, offset, got, tt.want[i])
+				}
+			}
+		})
+	}
+}
+
+func TestLoginThrottlePerUser(t *testing.T) {
+	var th loginThrottle
+	now := time.Date(2025, 1, 1, 12, 0, 0, 0, time.UTC)
+	if !th.allow(1, 1, now) {
+		t.Fatal("first attempt by user 1 rejected")
+	}
+	if th.allow(1, 1, now) {
+		t.Error("second attempt by user 1 allowed")
+	}
+	if !th.allow(2, 1, now) {
+		t.Error("user 2 throttled by user 1's attempts")
+	}
+}
+
+func TestLoginThrottleSweepsIdleUsers(t *testing.T) {
+	var th loginThrottle
+	now := time.Date(2025, 1, 1, 12, 0, 0, 0, time.UTC)
+	th.allow(1, 5, now)
+	th.allow(2, 5, now.Add(2*time.Minute))
+	if _, ok := th.attempts[1]; ok {
+		t.Error("idle user 1 was not swept")
+	}
+	if len(th.attempts[2]) != 1 {
+		t.Errorf("user 2 has %d attempts recorded, want 1", len(th.attempts[2]))
+	}
+}
@@ -0,0 +1,75 @@
+package event
+
+import (
+	"context"
+	"fmt"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"github.com/lib/pq"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) BatchGetEvents(ctx context.Context, req *proto.BatchGetEventsRequest) (*proto.BatchGetEventsResponse, error) {
+	v := &helpers.Violations{}
+	var ids []string
+	seen := map[string]bool{}
+	for i, raw := range req.GetIds() {
+		parsed, err := uuid.Parse(raw)
+		if err != nil {
+			v.Add(fmt.Sprintf("ids[%d]", i), "must be a uuid")
+			continue
+		}
+		// Ids are deduplicated and reported in canonical form.
+		if id := parsed.String(); !seen[id] {
+			seen[id] = true
+			ids = append(ids, id)
+		}
+	}
+	v.Check(len(req.GetIds()) > 0, "ids", "must not be empty")
+	v.Check(len(ids) <= helpers.MaxPageSize, "ids", "too many ids")
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT `+helpers.EventColumns+`
+		FROM event
+		WHERE id = ANY($1::uuid[]) AND deleted_at IS NULL AND `+helpers.EventVisibilityFilter(perms, "status")+`
+	`, pq.Array(ids))
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "batch get events: %v", err)
+	}
+	defer rows.Close()
+
+	found, err := scanEvents(rows)
+	if err != nil {
+		return nil, err
+	}
+	byID := make(map[string]*proto.Event, len(found))
+	for _, e := range found {
+		byID[e.GetId()] = e
+	}
+
+	resp := &proto.BatchGetEventsResponse{}
+	for _, id := range ids {
+		if e, ok := byID[id]; ok {
+			resp.Events = append(resp.Events, e)
+		} else {
+			resp.MissingIds = append(resp.MissingIds, id)
+		}
+	}
+	return resp, nil
+}
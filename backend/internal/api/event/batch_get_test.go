@@ -0,0 +1,38 @@
+package event
+
+import (
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"github.com/lib/pq"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestBatchGetEventsRejectsMalformedIDs(t *testing.T) {
+	for _, ids := range [][]string{nil, {""}, {"44444444-4444-4444-4444-444444444444", "not-a-uuid"}} {
+		db, _ := dbtest.New(t)
+		_, err := (&EventService{}).BatchGetEvents(testCtx(db, testUserID), &proto.BatchGetEventsRequest{Ids: ids})
+		if status.Code(err) != codes.InvalidArgument {
+			t.Errorf("BatchGetEvents(%q) = %v, want InvalidArgument", ids, err)
+		}
+	}
+}
+
+func TestBatchGetEventsReportsCanonicalMissingIDs(t *testing.T) {
+	const id = "4444aaaa-4444-4444-4444-444444444444"
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("WHERE id = ANY($1::uuid[])").WithArgs(pq.Array([]string{id}))
+
+	resp, err := (&EventService{}).BatchGetEvents(testCtx(db, testUserID), &proto.BatchGetEventsRequest{
+		Ids: []string{"4444AAAA-4444-4444-4444-444444444444", id},
+	})
+	if err != nil {
+		t.Fatalf("BatchGetEvents: %v", err)
+	}
+	if len(resp.GetMissingIds()) != 1 || resp.GetMissingIds()[0] != id {
+		t.Errorf("missing_ids = %q, want [%q]", resp.GetMissingIds(), id)
+	}
+}
@@ -0,0 +1,94 @@
+package event
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) CloneEvent(ctx context.Context, req *proto.CloneEventRequest) (*proto.EventDetails, error) {
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionAllowsEventEdit(perms) {
+		return nil, status.Error(codes.PermissionDenied, "no rights to create events")
+	}
+
+	v := &helpers.Violations{}
+	_, err = uuid.Parse(req.GetSourceEventId())
+	v.Check(err == nil, "source_event_id", "must be a uuid")
+	validateEventTitle(v, req.GetTitle())
+	validateTimestamp(v, "start_at", req.GetStartAt())
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	var startAt sql.NullTime
+	if ts := req.GetStartAt(); ts != nil {
+		startAt = sql.NullTime{Valid: true, Time: ts.AsTime()}
+	}
+
+	var eventID string
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		// Location and reminder settings carry over; the clone starts as a draft.
+		err := tx.QueryRowContext(ctx, `
+			INSERT INTO event (title, start_at, location, notify_day_before, notify_hour_before, created_by)
+			SELECT $2, $3, location, notify_day_before, notify_hour_before, $4
+			FROM event
+			WHERE id = $1 AND deleted_at IS NULL
+			RETURNING id
+		`, req.GetSourceEventId(), req.GetTitle(), startAt, userID).Scan(&eventID)
+		if err == sql.ErrNoRows {
+			return status.Error(codes.NotFound, "event not found")
+		}
+		if err != nil {
+			return status.Errorf(codes.Internal, "insert event: %v", err)
+		}
+		if startAt.Valid {
+			if _, err := tx.ExecContext(ctx, `INSERT INTO event_date (event_id, start_at) VALUES ($1, $2)`, eventID, startAt.Time); err != nil {
+				return status.Errorf(codes.Internal, "insert event date: %v", err)
+			}
+		}
+
+		if _, err := tx.ExecContext(ctx, `
+			INSERT INTO event_track_item (event_id, position, song_id, custom_title, custom_artist)
+			SELECT $2, position, song_id, custom_title, custom_artist
+			FROM event_track_item
+			WHERE event_id = $1
+		`, req.GetSourceEventId(), eventID); err != nil {
+			return status.Errorf(codes.Internal, "copy tracklist: %v", err)
+		}
+
+		if req.GetCopyParticipants() {
+			// Participants tied to a track item are copied as event-wide ones.
+			if _, err := tx.ExecContext(ctx, `
+				INSERT INTO event_participant (event_id, user_id, role)
+				SELECT DISTINCT $2::uuid, user_id, role
+				FROM event_participant
+				WHERE event_id = $1
+			`, req.GetSourceEventId(), eventID); err != nil {
+				return status.Errorf(codes.Internal, "copy participants: %v", err)
+			}
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return helpers.LoadEventDetails(ctx, db, eventID, userID)
+}
@@ -27,12 +27,39 @@ func (s *EventService) CreateEvent(ctx context.Context, req *proto.CreateEventRe
 		return nil, status.Error(codes.PermissionDenied, "no rights to create events")
 	}
 
+	v := &helpers.Violations{}
+	validateEventTitle(v, req.GetTitle())
+	validateTimestamp(v, "start_at", req.GetStartAt())
+	v.CheckText("location", req.GetLocation(), false)
+	validateTracklist(v, "tracklist", req.GetTracklist())
+	eventStatus := "draft"
+	if req.GetStatus() != proto.EventStatus_EVENT_STATUS_UNSPECIFIED {
+		eventStatus, err = helpers.MapEventStatusToDB(req.GetStatus())
+		if err != nil {
+			v.Add("status", err.Error())
+		}
+		v.Check(eventStatus != "cancelled", "status", "new events cannot be cancelled")
+	}
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+	if err := checkTracklistOrders(req.GetTracklist()); err != nil {
+		return nil, err
+	}
+
 	tx, err := db.BeginTx(ctx, nil)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
 	}
 	defer tx.Rollback()
 
+	if err := checkTracklistSongs(ctx, tx, v, "tracklist", req.GetTracklist()); err != nil {
+		return nil, err
+	}
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
 	var eventID string
 	var startAt sql.NullTime
 	if ts := req.GetStartAt(); ts != nil {
@@ -40,13 +67,18 @@ func (s *EventService) CreateEvent(ctx context.Context, req *proto.CreateEventRe
 	}
 
 	err = tx.QueryRowContext(ctx, `
-		INSERT INTO event (title, start_at, location, notify_day_before, notify_hour_before, created_by)
-		VALUES ($1, $2, $3, $4, $5, $6)
+		INSERT INTO event (title, start_at, location, notify_day_before, notify_hour_before, created_by, status)
+		VALUES ($1, $2, $3, $4, $5, $6, $7)
 		RETURNING id
-	`, req.GetTitle(), startAt, nullIfEmpty(req.GetLocation()), req.GetNotifyDayBefore(), req.GetNotifyHourBefore(), userID).Scan(&eventID)
+	`, req.GetTitle(), startAt, nullIfEmpty(req.GetLocation()), req.GetNotifyDayBefore(), req.GetNotifyHourBefore(), userID, eventStatus).Scan(&eventID)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "insert event: %v", err)
 	}
+	if startAt.Valid {
+		if _, err := tx.ExecContext(ctx, `INSERT INTO event_date (event_id, start_at) VALUES ($1, $2)`, eventID, startAt.Time); err != nil {
+			return nil, status.Errorf(codes.Internal, "insert event date: %v", err)
+		}
+	}
 
 	if err := helpers.ReplaceTracklist(ctx, tx, eventID, req.GetTracklist()); err != nil {
 		return nil, status.Errorf(codes.Internal, "set tracklist: %v", err)
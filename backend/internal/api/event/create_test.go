@@ -0,0 +1,74 @@
+package event
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const testUserID = "22222222-2222-2222-2222-222222222222"
+
+func testCtx(db *sql.DB, userID string) context.Context {
+	ctx := context.WithValue(context.Background(), "db", db)
+	return context.WithValue(ctx, "user_id", userID)
+}
+
+func TestCreateEventRejectsCancelledStatus(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+
+	_, err := (&EventService{}).CreateEvent(testCtx(db, testUserID), &proto.CreateEventRequest{
+		Title:  "Gig",
+		Status: proto.EventStatus_EVENT_STATUS_CANCELLED,
+	})
+	if status.Code(err) != codes.InvalidArgument {
+		t.Fatalf("CreateEvent = %v, want InvalidArgument", err)
+	}
+}
+
+func TestCreateEventStoresRequestedStatus(t *testing.T) {
+	tests := []struct {
+		name string
+		in   proto.EventStatus
+		want string
+	}{
+		{"default", proto.EventStatus_EVENT_STATUS_UNSPECIFIED, "draft"},
+		{"published", proto.EventStatus_EVENT_STATUS_PUBLISHED, "published"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			db, mock := dbtest.New(t)
+			mock.ExpectPermissions(testUserID, dbtest.Admin)
+			mock.ExpectBegin()
+			mock.ExpectQuery("INSERT INTO event").
+				WithArgs("Gig", nil, nil, false, false, testUserID, tt.want).WillReturnError(sql.ErrConnDone)
+			mock.ExpectRollback()
+
+			_, err := (&EventService{}).CreateEvent(testCtx(db, testUserID), &proto.CreateEventRequest{Title: "Gig", Status: tt.in})
+			if status.Code(err) != codes.Internal {
+				t.Fatalf("CreateEvent = %v, want the scripted insert failure", err)
+			}
+		})
+	}
+}
+
+func TestCreateEventRejectsTakenPosition(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+
+	_, err := (&EventService{}).CreateEvent(testCtx(db, testUserID), &proto.CreateEventRequest{
+		Title: "Gig",
+		Tracklist: &proto.Tracklist{Items: []*proto.TrackItem{
+			{Order: 1, CustomTitle: "Opener"},
+			{Order: 1, CustomTitle: "Encore"},
+		}},
+	})
+	if status.Code(err) != codes.FailedPrecondition {
+		t.Fatalf("CreateEvent = %v, want FailedPrecondition", err)
+	}
+}
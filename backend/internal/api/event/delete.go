@@ -10,7 +10,7 @@ import (
 	"google.golang.org/protobuf/types/known/emptypb"
 )
 
-func (s *EventService) DeleteEvent(ctx context.Context, req *proto.EventId) (*emptypb.Empty, error) {
+func (s *EventService) DeleteEvent(ctx context.Context, req *proto.DeleteEventRequest) (*emptypb.Empty, error) {
 	userID, err := helpers.UserIDFromCtx(ctx)
 	if err != nil {
 		return nil, err
@@ -27,13 +27,18 @@ func (s *EventService) DeleteEvent(ctx context.Context, req *proto.EventId) (*em
 		return nil, status.Error(codes.PermissionDenied, "no rights to delete events")
 	}
 
-	res, err := db.ExecContext(ctx, `DELETE FROM event WHERE id = $1`, req.GetId())
+	query := `UPDATE event SET deleted_at = NOW(), updated_at = NOW() WHERE id = $1 AND deleted_at IS NULL`
+	if req.GetForce() {
+		query = `DELETE FROM event WHERE id = $1`
+	}
+	res, err := db.ExecContext(ctx, query, req.GetId())
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "delete event: %v", err)
 	}
 	affected, _ := res.RowsAffected()
-	if affected == 0 {
+	if affected == 0 && !req.GetAllowMissing() {
 		return nil, status.Error(codes.NotFound, "event not found")
 	}
+	helpers.InvalidateEvent(ctx, req.GetId())
 	return &emptypb.Empty{}, nil
 }
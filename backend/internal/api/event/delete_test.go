@@ -0,0 +1,50 @@
+package event
+
+import (
+	"context"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"testing"
+	"time"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const testEventID = "44444444-4444-4444-4444-444444444444"
+
+func testEventRow() []any {
+	return []any{testEventID, "Gig", nil, "", false, false, testUserID, "published"}
+}
+
+func TestDeleteEventHidesEvent(t *testing.T) {
+	db, mock := dbtest.New(t)
+	ctx := context.WithValue(testCtx(db, testUserID), "cache", helpers.NewDetailsCache(time.Minute))
+	mock.ExpectEventDetails(testEventRow(), testUserID, dbtest.Admin)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+	mock.ExpectExec("UPDATE event SET deleted_at = NOW()").WithArgs(testEventID).WillReturnResult(1)
+	mock.ExpectQuery("FROM event WHERE id = $1 AND deleted_at IS NULL").WithArgs(testEventID)
+
+	svc := &EventService{}
+	if _, err := svc.GetEvent(ctx, &proto.EventId{Id: testEventID}); err != nil {
+		t.Fatalf("GetEvent before delete: %v", err)
+	}
+	if _, err := svc.DeleteEvent(ctx, &proto.DeleteEventRequest{Id: testEventID}); err != nil {
+		t.Fatalf("DeleteEvent: %v", err)
+	}
+	// The cached details must not outlive the delete.
+	if _, err := svc.GetEvent(ctx, &proto.EventId{Id: testEventID}); status.Code(err) != codes.NotFound {
+		t.Errorf("GetEvent after delete = %v, want NotFound", err)
+	}
+}
+
+func TestDeleteEventForceRemovesEvent(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+	mock.ExpectExec("DELETE FROM event WHERE id = $1").WithArgs(testEventID).WillReturnResult(1)
+
+	if _, err := (&EventService{}).DeleteEvent(testCtx(db, testUserID), &proto.DeleteEventRequest{Id: testEventID, Force: true}); err != nil {
+		t.Fatalf("DeleteEvent: %v", err)
+	}
+}
@@ -6,17 +6,21 @@ import (
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
+	"github.com/google/uuid"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 )
 
 func (s *EventService) GetEvent(ctx context.Context, req *proto.EventId) (*proto.EventDetails, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
 	db, err := helpers.DbFromCtx(ctx)
 	if err != nil {
 		return nil, err
 	}
 	currentUserID, _ := helpers.UserIDFromCtx(ctx)
-	details, err := helpers.LoadEventDetails(ctx, db, req.GetId(), currentUserID)
+	details, err := helpers.CachedEventDetails(ctx, db, req.GetId(), currentUserID)
 	if err != nil {
 		if err == sql.ErrNoRows {
 			return nil, status.Error(codes.NotFound, "event not found")
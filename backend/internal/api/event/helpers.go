@@ -1,6 +1,23 @@
 package event
 
-import "database/sql"
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"fmt"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"strings"
+	"unicode/utf8"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+// maxEventTitleLength matches the event_title_length check constraint.
+const maxEventTitleLength = 200
 
 func nullIfEmpty(s string) interface{} {
 	if s == "" {
@@ -8,3 +25,106 @@ func nullIfEmpty(s string) interface{} {
 	}
 	return s
 }
+
+// validateEventTitle rejects empty titles and titles the schema would refuse.
+func validateEventTitle(v *helpers.Violations, title string) {
+	v.Check(strings.TrimSpace(title) != "", "title", "must not be empty")
+	v.CheckText("title", title, false)
+	v.Check(utf8.RuneCountInString(title) <= maxEventTitleLength, "title",
+		fmt.Sprintf("must be at most %d characters", maxEventTitleLength))
+}
+
+// validateTimestamp rejects nanos outside [0, 1e9), which AsTime would
+// otherwise silently carry into the seconds.
+func validateTimestamp(v *helpers.Violations, field string, ts *timestamppb.Timestamp) {
+	if ts == nil {
+		return
+	}
+	v.Check(ts.GetNanos() >= 0 && ts.GetNanos() < 1e9, field, "invalid timestamp nanos")
+}
+
+// validateTracklist checks that every item references a song or has a custom title.
+func validateTracklist(v *helpers.Violations, field string, tracklist *proto.Tracklist) {
+	for i, item := range tracklist.GetItems() {
+		v.Check(item.GetSongId() != "" || strings.TrimSpace(item.GetCustomTitle()) != "",
+			fmt.Sprintf("%s.items[%d]", field, i), "needs song_id or custom_title")
+		v.CheckText(fmt.Sprintf("%s.items[%d].custom_title", field, i), item.GetCustomTitle(), false)
+		v.CheckText(fmt.Sprintf("%s.items[%d].custom_artist", field, i), item.GetCustomArtist(), false)
+	}
+}
+
+// checkTracklistOrders rejects a tracklist in which two items share an order.
+func checkTracklistOrders(tracklist *proto.Tracklist) error {
+	orders := map[uint32]bool{}
+	for _, item := range tracklist.GetItems() {
+		if orders[item.GetOrder()] {
+			return status.Errorf(codes.FailedPrecondition, "position already taken: %d", item.GetOrder())
+		}
+		orders[item.GetOrder()] = true
+	}
+	return nil
+}
+
+// checkTracklistSongs records a violation for every item referencing a song
+// that does not exist. It runs in the transaction that writes the tracklist and
+// key-share locks the songs it finds, so none can be deleted before the commit.
+func checkTracklistSongs(ctx context.Context, tx *sql.Tx, v *helpers.Violations, field string, tracklist *proto.Tracklist) error {
+	for i, item := range tracklist.GetItems() {
+		if item.GetSongId() == "" {
+			continue
+		}
+		songField := fmt.Sprintf("%s.items[%d].song_id", field, i)
+		if _, err := uuid.Parse(item.GetSongId()); err != nil {
+			v.Add(songField, "must be a uuid")
+			continue
+		}
+		var id string
+		err := tx.QueryRowContext(ctx, `SELECT id FROM song WHERE id = $1::uuid FOR KEY SHARE`, item.GetSongId()).Scan(&id)
+		if err != nil && !errors.Is(err, sql.ErrNoRows) {
+			return status.Errorf(codes.Internal, "check tracklist songs: %v", err)
+		}
+		v.Check(err == nil, songField, "song not found")
+	}
+	return nil
+}
+
+// checkEventStatusTransition rejects reopening a cancelled event.
+func checkEventStatusTransition(from, to string) error {
+	if from == "cancelled" && to != "cancelled" {
+		return status.Error(codes.FailedPrecondition, "cancelled events cannot be reopened")
+	}
+	return nil
+}
+
+// syncEventStart keeps event.start_at equal to the earliest performance date.
+func syncEventStart(ctx context.Context, tx *sql.Tx, eventID string) error {
+	_, err := tx.ExecContext(ctx, `
+		UPDATE event
+		SET start_at = (SELECT MIN(start_at) FROM event_date WHERE event_id = $1), updated_at = NOW()
+		WHERE id = $1
+	`, eventID)
+	return err
+}
+
+// scanEvents reads rows selecting id, title, start_at, location,
+// notify_day_before, notify_hour_before, created_by and status.
+func scanEvents(rows *sql.Rows) ([]*proto.Event, error) {
+	var events []*proto.Event
+	for rows.Next() {
+		var ev proto.Event
+		var start sql.NullTime
+		var eventStatus string
+		if err := rows.Scan(&ev.Id, &ev.Title, &start, &ev.Location, &ev.NotifyDayBefore, &ev.NotifyHourBefore, &ev.CreatedBy, &eventStatus); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan event: %v", err)
+		}
+		ev.Status = helpers.MapEventStatus(eventStatus)
+		if start.Valid {
+			ev.StartAt = timestamppb.New(start.Time)
+		}
+		events = append(events, &ev)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate events: %v", err)
+	}
+	return events, nil
+}
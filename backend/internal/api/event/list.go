@@ -2,7 +2,6 @@ package event
 
 import (
 	"context"
-	"database/sql"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 	"strconv"
@@ -11,17 +10,25 @@ import (
 
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
-	"google.golang.org/protobuf/types/known/timestamppb"
 )
 
+// tracklistPreviewLength caps the items returned per event by include_tracklist.
+const tracklistPreviewLength = 5
+
 func (s *EventService) ListEvents(ctx context.Context, req *proto.ListEventsRequest) (*proto.ListEventsResponse, error) {
 	db, err := helpers.DbFromCtx(ctx)
 	if err != nil {
 		return nil, err
 	}
 
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
 	args := []any{}
-	clauses := []string{}
+	clauses := []string{"deleted_at IS NULL", helpers.EventVisibilityFilter(perms, "status")}
 	if req.GetFrom() != nil {
 		clauses = append(clauses, "start_at >= $"+strconv.Itoa(len(args)+1))
 		args = append(args, time.Unix(req.GetFrom().Seconds, int64(req.GetFrom().Nanos)))
@@ -30,43 +37,48 @@ func (s *EventService) ListEvents(ctx context.Context, req *proto.ListEventsRequ
 		clauses = append(clauses, "start_at <= $"+strconv.Itoa(len(args)+1))
 		args = append(args, time.Unix(req.GetTo().Seconds, int64(req.GetTo().Nanos)))
 	}
-	where := ""
-	if len(clauses) > 0 {
-		where = "WHERE " + strings.Join(clauses, " AND ")
-	}
+	where := "WHERE " + strings.Join(clauses, " AND ")
 
 	limit := req.GetLimit()
 	if limit == 0 || limit > 200 {
 		limit = 50
 	}
-	args = append(args, limit)
+	args = append(args, limit+1)
 
 	rows, err := db.QueryContext(ctx, `
-		SELECT id, title, start_at, location, notify_day_before, notify_hour_before
+		SELECT `+helpers.EventColumns+`
 		FROM event
 	`+where+`
-		ORDER BY start_at NULLS LAST
+		ORDER BY `+helpers.EventListOrder+`
 		LIMIT $`+strconv.Itoa(len(args)), args...)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "list events: %v", err)
 	}
 	defer rows.Close()
 
-	var events []*proto.Event
-	for rows.Next() {
-		var ev proto.Event
-		var start sql.NullTime
-		if err := rows.Scan(&ev.Id, &ev.Title, &start, &ev.Location, &ev.NotifyDayBefore, &ev.NotifyHourBefore); err != nil {
-			return nil, status.Errorf(codes.Internal, "scan event: %v", err)
-		}
-		if start.Valid {
-			ev.StartAt = timestamppb.New(start.Time)
-		}
-		events = append(events, &ev)
+	events, err := scanEvents(rows)
+	if err != nil {
+		return nil, err
 	}
-	if err := rows.Err(); err != nil {
-		return nil, status.Errorf(codes.Internal, "iterate events: %v", err)
+
+	hasMore := len(events) > int(limit)
+	if hasMore {
+		events = events[:limit]
 	}
 
-	return &proto.ListEventsResponse{Events: events}, nil
+	if req.GetIncludeTracklist() && len(events) > 0 {
+		ids := make([]string, len(events))
+		for i, e := range events {
+			ids[i] = e.GetId()
+		}
+		previews, counts, err := helpers.LoadTracklistPreviews(ctx, db, ids, tracklistPreviewLength)
+		if err != nil {
+			return nil, status.Errorf(codes.Internal, "load tracklists: %v", err)
+		}
+		for _, e := range events {
+			e.TracklistPreview = previews[e.GetId()]
+			e.SongCount = counts[e.GetId()]
+		}
+	}
+	return &proto.ListEventsResponse{Events: events, HasMore: hasMore}, nil
 }
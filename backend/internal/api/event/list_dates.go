@@ -0,0 +1,40 @@
+package event
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) ListEventDates(ctx context.Context, req *proto.EventId) (*proto.EventDates, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM event WHERE id = $1 AND deleted_at IS NULL AND `+helpers.EventVisibilityFilter(perms, "status")+`)`, req.GetId()).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load event: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "event not found")
+	}
+
+	dates, err := helpers.LoadEventDates(ctx, db, req.GetId())
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list event dates: %v", err)
+	}
+	return &proto.EventDates{Dates: dates}, nil
+}
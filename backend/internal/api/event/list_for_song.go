@@ -0,0 +1,52 @@
+package event
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) ListEventsForSong(ctx context.Context, req *proto.SongId) (*proto.ListEventsResponse, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM song WHERE id = $1)`, req.GetId()).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load song: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "song not found")
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT e.id, e.title, e.start_at, e.location, e.notify_day_before, e.notify_hour_before, COALESCE(e.created_by::text, ''), e.status
+		FROM event e
+		WHERE e.deleted_at IS NULL AND `+helpers.EventVisibilityFilter(perms, "e.status")+`
+		  AND EXISTS (SELECT 1 FROM event_track_item eti WHERE eti.event_id = e.id AND eti.song_id = $1)
+		ORDER BY e.start_at NULLS LAST, e.id
+	`, req.GetId())
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list events for song: %v", err)
+	}
+	defer rows.Close()
+
+	events, err := scanEvents(rows)
+	if err != nil {
+		return nil, err
+	}
+	return &proto.ListEventsResponse{Events: events}, nil
+}
@@ -0,0 +1,63 @@
+package event
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) ListEventPeople(ctx context.Context, req *proto.EventId) (*proto.EventPeople, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM event WHERE id = $1 AND deleted_at IS NULL AND `+helpers.EventVisibilityFilter(perms, "status")+`)`, req.GetId()).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load event: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "event not found")
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT au.id, au.display_name, COALESCE(au.username, ''), COALESCE(au.avatar_url, '')
+		FROM app_user au
+		WHERE EXISTS (
+			SELECT 1
+			FROM event_track_item eti
+			JOIN song_role_assignment sra ON sra.song_id = eti.song_id
+			WHERE eti.event_id = $1 AND sra.user_id = au.id
+		)
+		ORDER BY au.display_name, au.id
+	`, req.GetId())
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list event people: %v", err)
+	}
+	defer rows.Close()
+
+	resp := &proto.EventPeople{}
+	for rows.Next() {
+		var u proto.User
+		if err := rows.Scan(&u.Id, &u.DisplayName, &u.Username, &u.AvatarUrl); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan person: %v", err)
+		}
+		resp.People = append(resp.People, &u)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate people: %v", err)
+	}
+	return resp, nil
+}
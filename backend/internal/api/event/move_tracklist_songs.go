@@ -0,0 +1,133 @@
+package event
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) MoveTracklistSongs(ctx context.Context, req *proto.MoveTracklistSongsRequest) (*proto.EventDetails, error) {
+	v := &helpers.Violations{}
+	sourceID, sourceErr := uuid.Parse(req.GetSourceEventId())
+	v.Check(sourceErr == nil, "source_event_id", "must be a uuid")
+	targetID, targetErr := uuid.Parse(req.GetTargetEventId())
+	v.Check(targetErr == nil, "target_event_id", "must be a uuid")
+	v.Check(sourceErr != nil || targetErr != nil || sourceID != targetID, "target_event_id", "must differ from source_event_id")
+	v.Check(len(req.GetSongIds()) > 0, "song_ids", "must not be empty")
+	seen := map[uuid.UUID]bool{}
+	for _, id := range req.GetSongIds() {
+		songID, err := uuid.Parse(id)
+		v.Check(err == nil, "song_ids", "must contain only uuids")
+		v.Check(err != nil || !seen[songID], "song_ids", "must not contain duplicates")
+		seen[songID] = true
+	}
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionAllowsTracklistEdit(perms) {
+		return nil, status.Error(codes.PermissionDenied, "no rights to edit tracklists")
+	}
+
+	source, target := sourceID.String(), targetID.String()
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		// Lock both events in a fixed order so opposite moves cannot deadlock.
+		rows, err := tx.QueryContext(ctx, `
+			SELECT id::text FROM event WHERE id IN ($1, $2) AND deleted_at IS NULL ORDER BY id FOR UPDATE
+		`, source, target)
+		if err != nil {
+			return status.Errorf(codes.Internal, "load events: %v", err)
+		}
+		found := map[string]bool{}
+		for rows.Next() {
+			var id string
+			if err := rows.Scan(&id); err != nil {
+				rows.Close()
+				return status.Errorf(codes.Internal, "scan event: %v", err)
+			}
+			found[id] = true
+		}
+		rows.Close()
+		if err := rows.Err(); err != nil {
+			return status.Errorf(codes.Internal, "iterate events: %v", err)
+		}
+		if !found[source] {
+			return status.Error(codes.NotFound, "source event not found")
+		}
+		if !found[target] {
+			return status.Error(codes.NotFound, "target event not found")
+		}
+
+		for _, id := range req.GetSongIds() {
+			if _, err := trackPosition(ctx, tx, source, id, "song_ids"); err != nil {
+				return err
+			}
+		}
+
+		var next int64
+		if err := tx.QueryRowContext(ctx, `
+			SELECT COALESCE(MAX(position) + 1, 0) FROM event_track_item WHERE event_id = $1
+		`, target).Scan(&next); err != nil {
+			return status.Errorf(codes.Internal, "load target tracklist: %v", err)
+		}
+		// Items are re-created rather than re-parented, like SetTracklist does.
+		for _, id := range req.GetSongIds() {
+			if _, err := tx.ExecContext(ctx, `
+				WITH moved AS (
+					DELETE FROM event_track_item WHERE event_id = $1 AND song_id = $3
+					RETURNING song_id, custom_title, custom_artist
+				)
+				INSERT INTO event_track_item (event_id, position, song_id, custom_title, custom_artist)
+				SELECT $2, $4, song_id, custom_title, custom_artist FROM moved
+			`, source, target, id, next); err != nil {
+				if helpers.IsUniqueViolation(err) {
+					return status.Error(codes.FailedPrecondition, "position already taken")
+				}
+				return status.Errorf(codes.Internal, "move song %s: %v", id, err)
+			}
+			next++
+		}
+
+		// Close the gaps left in the source. Positions pass through negative
+		// values because the unique constraint is checked row by row.
+		for _, query := range []string{`
+			UPDATE event_track_item eti SET position = -1 - ranked.rn
+			FROM (
+				SELECT id, ROW_NUMBER() OVER (ORDER BY position) - 1 AS rn
+				FROM event_track_item WHERE event_id = $1
+			) ranked
+			WHERE eti.id = ranked.id
+		`, `
+			UPDATE event_track_item SET position = -1 - position WHERE event_id = $1
+		`} {
+			if _, err := tx.ExecContext(ctx, query, source); err != nil {
+				return status.Errorf(codes.Internal, "renumber source tracklist: %v", err)
+			}
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	helpers.InvalidateEvent(ctx, source)
+	helpers.InvalidateEvent(ctx, target)
+	return helpers.LoadEventDetails(ctx, db, target, userID)
+}
@@ -0,0 +1,63 @@
+package event
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) RemoveEventDate(ctx context.Context, req *proto.EventDateRequest) (*proto.EventDetails, error) {
+	if req.GetDate() == nil {
+		return nil, status.Error(codes.InvalidArgument, "date is required")
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionAllowsEventEdit(perms) {
+		return nil, status.Error(codes.PermissionDenied, "no rights to update events")
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var id string
+	if err := tx.QueryRowContext(ctx, `SELECT id FROM event WHERE id = $1 AND deleted_at IS NULL FOR UPDATE`, req.GetEventId()).Scan(&id); err != nil {
+		if err == sql.ErrNoRows {
+			return nil, status.Error(codes.NotFound, "event not found")
+		}
+		return nil, status.Errorf(codes.Internal, "load event: %v", err)
+	}
+
+	if _, err := tx.ExecContext(ctx, `
+		DELETE FROM event_date WHERE event_id = $1 AND start_at = $2
+	`, req.GetEventId(), req.GetDate().AsTime()); err != nil {
+		return nil, status.Errorf(codes.Internal, "remove event date: %v", err)
+	}
+	if err := syncEventStart(ctx, tx, req.GetEventId()); err != nil {
+		return nil, status.Errorf(codes.Internal, "sync start date: %v", err)
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+
+	helpers.InvalidateEvent(ctx, req.GetEventId())
+	return helpers.LoadEventDetails(ctx, db, req.GetEventId(), userID)
+}
@@ -2,6 +2,8 @@ package event
 
 import (
 	"context"
+	"database/sql"
+	"errors"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
@@ -26,19 +28,39 @@ func (s *EventService) SetTracklist(ctx context.Context, req *proto.SetTracklist
 		return nil, status.Error(codes.PermissionDenied, "no rights to edit tracklists")
 	}
 
-	tx, err := db.BeginTx(ctx, nil)
-	if err != nil {
-		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	v := &helpers.Violations{}
+	validateTracklist(v, "tracklist", req.GetTracklist())
+	if err := v.Err(); err != nil {
+		return nil, err
 	}
-	defer tx.Rollback()
-
-	if err := helpers.ReplaceTracklist(ctx, tx, req.GetEventId(), req.GetTracklist()); err != nil {
-		return nil, status.Errorf(codes.Internal, "set tracklist: %v", err)
+	if err := checkTracklistOrders(req.GetTracklist()); err != nil {
+		return nil, err
 	}
 
-	if err := tx.Commit(); err != nil {
-		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		var id string
+		err := tx.QueryRowContext(ctx, `SELECT id FROM event WHERE id = $1 AND deleted_at IS NULL FOR UPDATE`, req.GetEventId()).Scan(&id)
+		if errors.Is(err, sql.ErrNoRows) {
+			return status.Error(codes.NotFound, "event not found")
+		}
+		if err != nil {
+			return status.Errorf(codes.Internal, "load event: %v", err)
+		}
+		if err := checkTracklistSongs(ctx, tx, v, "tracklist", req.GetTracklist()); err != nil {
+			return err
+		}
+		if err := v.Err(); err != nil {
+			return err
+		}
+		if err := helpers.ReplaceTracklist(ctx, tx, req.GetEventId(), req.GetTracklist()); err != nil {
+			return status.Errorf(codes.Internal, "set tracklist: %v", err)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
 	}
 
+	helpers.InvalidateEvent(ctx, req.GetEventId())
 	return helpers.LoadEventDetails(ctx, db, req.GetEventId(), userID)
 }
@@ -0,0 +1,112 @@
+package event
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *EventService) SwapTracklistSongs(ctx context.Context, req *proto.SwapTracklistSongsRequest) (*proto.EventDetails, error) {
+	v := &helpers.Violations{}
+	eventID := v.CheckUUID("event_id", req.GetEventId()).String()
+	firstID := v.CheckUUID("first_song_id", req.GetFirstSongId())
+	secondID := v.CheckUUID("second_song_id", req.GetSecondSongId())
+	v.Check(firstID == uuid.Nil || firstID != secondID, "second_song_id", "must differ from first_song_id")
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionAllowsTracklistEdit(perms) {
+		return nil, status.Error(codes.PermissionDenied, "no rights to edit tracklists")
+	}
+
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		var id string
+		err := tx.QueryRowContext(ctx, `SELECT id FROM event WHERE id = $1 AND deleted_at IS NULL FOR UPDATE`, eventID).Scan(&id)
+		if errors.Is(err, sql.ErrNoRows) {
+			return status.Error(codes.NotFound, "event not found")
+		}
+		if err != nil {
+			return status.Errorf(codes.Internal, "load event: %v", err)
+		}
+
+		first, err := trackPosition(ctx, tx, eventID, firstID.String(), "first_song_id")
+		if err != nil {
+			return err
+		}
+		second, err := trackPosition(ctx, tx, eventID, secondID.String(), "second_song_id")
+		if err != nil {
+			return err
+		}
+
+		// Positions are unique per event, so one item parks at -1 during the swap.
+		for _, move := range [][2]int64{{first, -1}, {second, first}, {-1, second}} {
+			if _, err := tx.ExecContext(ctx, `
+				UPDATE event_track_item SET position = $3 WHERE event_id = $1 AND position = $2
+			`, eventID, move[0], move[1]); err != nil {
+				if helpers.IsUniqueViolation(err) {
+					return status.Error(codes.FailedPrecondition, "position already taken")
+				}
+				return status.Errorf(codes.Internal, "swap positions: %v", err)
+			}
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	helpers.InvalidateEvent(ctx, eventID)
+	return helpers.LoadEventDetails(ctx, db, eventID, userID)
+}
+
+// trackPosition returns the position of songID in the event's tracklist,
+// which must contain it exactly once.
+func trackPosition(ctx context.Context, tx *sql.Tx, eventID, songID, field string) (int64, error) {
+	rows, err := tx.QueryContext(ctx, `
+		SELECT position FROM event_track_item WHERE event_id = $1 AND song_id = $2
+	`, eventID, songID)
+	if err != nil {
+		return 0, status.Errorf(codes.Internal, "load track item: %v", err)
+	}
+	defer rows.Close()
+
+	var positions []int64
+	for rows.Next() {
+		var p int64
+		if err := rows.Scan(&p); err != nil {
+			return 0, status.Errorf(codes.Internal, "scan track item: %v", err)
+		}
+		positions = append(positions, p)
+	}
+	if err := rows.Err(); err != nil {
+		return 0, status.Errorf(codes.Internal, "iterate track items: %v", err)
+	}
+
+	switch len(positions) {
+	case 0:
+		return 0, status.Errorf(codes.FailedPrecondition, "%s is not in the tracklist", field)
+	case 1:
+		return positions[0], nil
+	default:
+		return 0, status.Errorf(codes.FailedPrecondition, "%s appears more than once in the tracklist", field)
+	}
+}
@@ -0,0 +1,34 @@
+package event
+
+import (
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestSwapTracklistSongsValidatesIDs(t *testing.T) {
+	const (
+		eventID = "44444444-4444-4444-4444-444444444444"
+		songID  = "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa"
+	)
+	tests := []struct {
+		name string
+		req  *proto.SwapTracklistSongsRequest
+	}{
+		{"malformed event", &proto.SwapTracklistSongsRequest{EventId: "nope", FirstSongId: songID, SecondSongId: "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb"}},
+		{"malformed song", &proto.SwapTracklistSongsRequest{EventId: eventID, FirstSongId: songID, SecondSongId: "nope"}},
+		{"same song spelled differently", &proto.SwapTracklistSongsRequest{EventId: eventID, FirstSongId: songID, SecondSongId: "AAAAAAAA-AAAA-AAAA-AAAA-AAAAAAAAAAAA"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			db, _ := dbtest.New(t)
+			_, err := (&EventService{}).SwapTracklistSongs(testCtx(db, testUserID), tt.req)
+			if status.Code(err) != codes.InvalidArgument {
+				t.Fatalf("SwapTracklistSongs = %v, want InvalidArgument", err)
+			}
+		})
+	}
+}
@@ -27,22 +27,75 @@ func (s *EventService) UpdateEvent(ctx context.Context, req *proto.UpdateEventRe
 		return nil, status.Error(codes.PermissionDenied, "no rights to update events")
 	}
 
+	v := &helpers.Violations{}
+	v.Check(req.GetId() != "", "id", "must not be empty")
+	validateEventTitle(v, req.GetTitle())
+	validateTimestamp(v, "start_at", req.GetStartAt())
+	v.CheckText("location", req.GetLocation(), false)
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
 	var startAt sql.NullTime
 	if ts := req.GetStartAt(); ts != nil {
 		startAt = sql.NullTime{Valid: true, Time: ts.AsTime()}
 	}
 
-	res, err := db.ExecContext(ctx, `
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var prevStartAt sql.NullTime
+	var prevStatus string
+	if err := tx.QueryRowContext(ctx, `SELECT start_at, status FROM event WHERE id = $1 AND deleted_at IS NULL FOR UPDATE`, req.GetId()).Scan(&prevStartAt, &prevStatus); err != nil {
+		if err == sql.ErrNoRows {
+			return nil, status.Error(codes.NotFound, "event not found")
+		}
+		return nil, status.Errorf(codes.Internal, "load event: %v", err)
+	}
+
+	newStatus := prevStatus
+	if req.GetStatus() != proto.EventStatus_EVENT_STATUS_UNSPECIFIED {
+		newStatus, err = helpers.MapEventStatusToDB(req.GetStatus())
+		if err != nil {
+			return nil, status.Errorf(codes.InvalidArgument, "status: %v", err)
+		}
+		if err := checkEventStatusTransition(prevStatus, newStatus); err != nil {
+			return nil, err
+		}
+	}
+
+	if _, err := tx.ExecContext(ctx, `
 		UPDATE event
-		SET title = $1, start_at = $2, location = $3, notify_day_before = $4, notify_hour_before = $5, updated_at = NOW()
+		SET title = $1, start_at = $2, location = $3, notify_day_before = $4, notify_hour_before = $5, status = $7, updated_at = NOW()
 		WHERE id = $6
-	`, req.GetTitle(), startAt, nullIfEmpty(req.GetLocation()), req.GetNotifyDayBefore(), req.GetNotifyHourBefore(), req.GetId())
-	if err != nil {
+	`, req.GetTitle(), startAt, nullIfEmpty(req.GetLocation()), req.GetNotifyDayBefore(), req.GetNotifyHourBefore(), req.GetId(), newStatus); err != nil {
 		return nil, status.Errorf(codes.Internal, "update event: %v", err)
 	}
-	affected, _ := res.RowsAffected()
-	if affected == 0 {
-		return nil, status.Error(codes.NotFound, "event not found")
+
+	// start_at replaces the earliest performance date; later nights are kept.
+	if prevStartAt.Valid {
+		if _, err := tx.ExecContext(ctx, `DELETE FROM event_date WHERE event_id = $1 AND start_at = $2`, req.GetId(), prevStartAt.Time); err != nil {
+			return nil, status.Errorf(codes.Internal, "update event date: %v", err)
+		}
+	}
+	if startAt.Valid {
+		if _, err := tx.ExecContext(ctx, `
+			INSERT INTO event_date (event_id, start_at) VALUES ($1, $2) ON CONFLICT DO NOTHING
+		`, req.GetId(), startAt.Time); err != nil {
+			return nil, status.Errorf(codes.Internal, "update event date: %v", err)
+		}
 	}
+	if err := syncEventStart(ctx, tx, req.GetId()); err != nil {
+		return nil, status.Errorf(codes.Internal, "sync start date: %v", err)
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+
+	helpers.InvalidateEvent(ctx, req.GetId())
 	return helpers.LoadEventDetails(ctx, db, req.GetId(), userID)
 }
@@ -0,0 +1,25 @@
+package meta
+
+import (
+	"context"
+	"musicclubbot/backend/internal/api/auth"
+	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/protobuf/types/known/emptypb"
+)
+
+func (s *MetaService) GetServerConfig(ctx context.Context, _ *emptypb.Empty) (*proto.ServerConfig, error) {
+	cfg := ctx.Value("cfg").(config.Config)
+	return &proto.ServerConfig{
+		DefaultPageSize:            helpers.DefaultPageSize,
+		MaxPageSize:                helpers.MaxPageSize,
+		AccessTokenTtlSeconds:      int64(cfg.AccessTokenTTL.Seconds()),
+		AdminAccessTokenTtlSeconds: int64(cfg.AdminAccessTokenTTL.Seconds()),
+		RefreshTokenTtlSeconds:     int64(auth.RefreshTokenExp.Seconds()),
+		MinApiVersion:              int32(cfg.MinAPIVersion),
+		MaxRolesPerEvent:           int32(cfg.MaxRolesPerEvent),
+		CompressResponses:          cfg.CompressResponses,
+	}, nil
+}
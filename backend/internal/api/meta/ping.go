@@ -0,0 +1,15 @@
+package meta
+
+import (
+	"context"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+func (s *MetaService) Ping(ctx context.Context, req *proto.PingRequest) (*proto.PingResponse, error) {
+	return &proto.PingResponse{
+		Nonce:      req.GetNonce(),
+		ServerTime: timestamppb.Now(),
+	}, nil
+}
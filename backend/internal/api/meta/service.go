@@ -0,0 +1,10 @@
+package meta
+
+import (
+	"musicclubbot/backend/proto"
+)
+
+// MetaService implements connection-level helper endpoints.
+type MetaService struct {
+	proto.UnimplementedMetaServiceServer
+}
@@ -1,14 +1,18 @@
 package api
 
 import (
+	"musicclubbot/backend/internal/api/admin"
 	"musicclubbot/backend/internal/api/auth"
 	"musicclubbot/backend/internal/api/event"
+	"musicclubbot/backend/internal/api/meta"
 	"musicclubbot/backend/internal/api/song"
 
 	"google.golang.org/grpc"
 
+	adminpb "musicclubbot/backend/proto"
 	authpb "musicclubbot/backend/proto"
 	eventpb "musicclubbot/backend/proto"
+	metapb "musicclubbot/backend/proto"
 	songpb "musicclubbot/backend/proto"
 )
 
@@ -17,4 +21,6 @@ func Register(server *grpc.Server) {
 	authpb.RegisterAuthServiceServer(server, &auth.AuthService{})
 	songpb.RegisterSongServiceServer(server, &song.SongService{})
 	eventpb.RegisterEventServiceServer(server, &event.EventService{})
+	adminpb.RegisterAdminServiceServer(server, &admin.AdminService{})
+	metapb.RegisterMetaServiceServer(server, &meta.MetaService{})
 }
@@ -0,0 +1,62 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) BatchTagSongs(ctx context.Context, req *proto.BatchTagSongsRequest) (*proto.BatchTagSongsResponse, error) {
+	tag := normalizeTag(req.GetTag())
+	v := &helpers.Violations{}
+	validateTag(v, tag)
+	v.Check(len(req.GetSongIds()) > 0, "song_ids", "must not be empty")
+	for _, id := range req.GetSongIds() {
+		v.Check(id != "", "song_ids", "must not contain empty ids")
+	}
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	for _, id := range req.GetSongIds() {
+		if err := authorizeSongEdit(ctx, db, perms, id, userID, "tag"); err != nil {
+			return nil, err
+		}
+	}
+
+	var tagged int32
+	err = helpers.WithTx(ctx, db, func(tx *sql.Tx) error {
+		for _, id := range req.GetSongIds() {
+			res, err := tx.ExecContext(ctx, `
+				INSERT INTO song_tag (song_id, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING
+			`, id, tag)
+			if err != nil {
+				return status.Errorf(codes.Internal, "tag song %s: %v", id, err)
+			}
+			if n, err := res.RowsAffected(); err == nil {
+				tagged += int32(n)
+			}
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+	return &proto.BatchTagSongsResponse{Tag: tag, TaggedCount: tagged}, nil
+}
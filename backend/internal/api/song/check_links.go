@@ -0,0 +1,30 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/emptypb"
+)
+
+func (s *SongService) CheckSongLinks(ctx context.Context, req *proto.CheckSongLinksRequest) (*emptypb.Empty, error) {
+	v := &helpers.Violations{}
+	v.Check(len(req.GetSongIds()) > 0, "song_ids", "must not be empty")
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	queue, err := helpers.LinkCheckQueueFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	for i, id := range req.GetSongIds() {
+		if !queue.Enqueue(id) {
+			return nil, status.Errorf(codes.ResourceExhausted, "link check queue is full, queued %d of %d songs", i, len(req.GetSongIds()))
+		}
+	}
+	return &emptypb.Empty{}, nil
+}
@@ -0,0 +1,41 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) CountParticipationsByRole(ctx context.Context, req *proto.CountParticipationsByRoleRequest) (*proto.CountParticipationsByRoleResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT role, COUNT(*) AS cnt
+		FROM song_role_assignment
+		GROUP BY role
+		ORDER BY cnt DESC, role
+	`)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "count participations: %v", err)
+	}
+	defer rows.Close()
+
+	resp := &proto.CountParticipationsByRoleResponse{}
+	for rows.Next() {
+		var rc proto.RoleCount
+		if err := rows.Scan(&rc.Role, &rc.Count); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan role count: %v", err)
+		}
+		resp.Counts = append(resp.Counts, &rc)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate role counts: %v", err)
+	}
+	return resp, nil
+}
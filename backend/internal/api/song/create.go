@@ -26,13 +26,21 @@ func (s *SongService) CreateSong(ctx context.Context, req *proto.CreateSongReque
 		return nil, status.Error(codes.PermissionDenied, "no rights to create songs")
 	}
 
-	linkKind, err := helpers.MapSongLinkKindToDB(req.GetLink().GetKind())
-	if err != nil {
-		return nil, status.Error(codes.InvalidArgument, err.Error())
+	if req.GetNotes() != "" && !helpers.PermissionIsAdmin(perms) {
+		return nil, status.Error(codes.PermissionDenied, "only admins may set notes")
+	}
+
+	v := &helpers.Violations{}
+	validateSongFields(v, req.GetTitle(), req.GetArtist(), req.GetDescription(), req.GetLink(), req.GetAvailableRoles(), req.GetDurationSeconds())
+	v.CheckText("notes", req.GetNotes(), true)
+	if err := v.Err(); err != nil {
+		return nil, err
 	}
+	linkKind, _ := helpers.MapSongLinkKindToDB(req.GetLink().GetKind())
+	linkURL := helpers.CanonicalizeLinkURL(linkKind, req.GetLink().GetUrl())
 
 	// Auto-extract or use custom thumbnail URL
-	thumbnailURL := helpers.NormalizeThumbnailURL(req.GetThumbnailUrl(), linkKind, req.GetLink().GetUrl())
+	thumbnailURL := helpers.NormalizeThumbnailURL(req.GetThumbnailUrl(), linkKind, linkURL)
 
 	var songID string
 	tx, err := db.BeginTx(ctx, nil)
@@ -42,10 +50,10 @@ func (s *SongService) CreateSong(ctx context.Context, req *proto.CreateSongReque
 	defer tx.Rollback()
 
 	err = tx.QueryRowContext(ctx, `
-		INSERT INTO song (title, artist, description, link_kind, link_url, created_by, thumbnail_url)
-		VALUES ($1, $2, $3, $4, $5, $6, $7)
+		INSERT INTO song (title, artist, description, link_kind, link_url, created_by, thumbnail_url, duration_seconds, notes)
+		VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NULLIF($9, ''))
 		RETURNING id
-	`, req.GetTitle(), req.GetArtist(), req.GetDescription(), linkKind, req.GetLink().GetUrl(), userID, thumbnailURL).Scan(&songID)
+	`, req.GetTitle(), req.GetArtist(), req.GetDescription(), linkKind, linkURL, userID, thumbnailURL, nullIfZero(req.GetDurationSeconds()), req.GetNotes()).Scan(&songID)
 	if err != nil {
 		return nil, status.Errorf(codes.Internal, "insert song: %v", err)
 	}
@@ -2,7 +2,6 @@ package song
 
 import (
 	"context"
-	"database/sql"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
@@ -11,7 +10,7 @@ import (
 	"google.golang.org/protobuf/types/known/emptypb"
 )
 
-func (s *SongService) DeleteSong(ctx context.Context, req *proto.SongId) (*emptypb.Empty, error) {
+func (s *SongService) DeleteSong(ctx context.Context, req *proto.DeleteSongRequest) (*emptypb.Empty, error) {
 	userID, err := helpers.UserIDFromCtx(ctx)
 	if err != nil {
 		return nil, err
@@ -25,20 +24,16 @@ func (s *SongService) DeleteSong(ctx context.Context, req *proto.SongId) (*empty
 		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
 	}
 
-	var creatorID sql.NullString
-	row := db.QueryRowContext(ctx, `SELECT COALESCE(created_by, NULL) FROM song WHERE id = $1`, req.GetId())
-	if err := row.Scan(&creatorID); err != nil {
-		if err == sql.ErrNoRows {
-			return nil, status.Error(codes.NotFound, "song not found")
+	if err := authorizeSongEdit(ctx, db, perms, req.GetId(), userID, "delete"); err != nil {
+		if req.GetAllowMissing() && status.Code(err) == codes.NotFound {
+			return &emptypb.Empty{}, nil
 		}
-		return nil, status.Errorf(codes.Internal, "load song: %v", err)
-	}
-	if !helpers.PermissionAllowsSongEdit(perms, creatorID, userID) {
-		return nil, status.Error(codes.PermissionDenied, "no rights to delete song")
+		return nil, err
 	}
 
 	if _, err := db.ExecContext(ctx, `DELETE FROM song WHERE id = $1`, req.GetId()); err != nil {
 		return nil, status.Errorf(codes.Internal, "delete song: %v", err)
 	}
+	helpers.InvalidateSong(ctx, req.GetId())
 	return &emptypb.Empty{}, nil
 }
@@ -6,22 +6,30 @@ import (
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
+	"github.com/google/uuid"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 )
 
-func (s *SongService) GetSong(ctx context.Context, req *proto.SongId) (*proto.SongDetails, error) {
+func (s *SongService) GetSong(ctx context.Context, req *proto.GetSongRequest) (*proto.SongDetails, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	if err := helpers.ValidateReadMask(&proto.Song{}, req.GetReadMask()); err != nil {
+		return nil, err
+	}
 	db, err := helpers.DbFromCtx(ctx)
 	if err != nil {
 		return nil, err
 	}
 	currentUserID, _ := helpers.UserIDFromCtx(ctx)
-	details, err := helpers.LoadSongDetails(ctx, db, req.GetId(), currentUserID)
+	details, err := helpers.CachedSongDetails(ctx, db, req.GetId(), currentUserID)
 	if err != nil {
 		if err == sql.ErrNoRows {
 			return nil, status.Error(codes.NotFound, "song not found")
 		}
 		return nil, status.Errorf(codes.Internal, "get song: %v", err)
 	}
+	helpers.ApplyReadMask(details.Song, req.GetReadMask())
 	return details, nil
 }
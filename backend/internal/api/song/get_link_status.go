@@ -0,0 +1,59 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/internal/linkcheck"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+var linkHealthFromDB = map[string]proto.LinkHealth{
+	linkcheck.HealthOK:          proto.LinkHealth_LINK_HEALTH_OK,
+	linkcheck.HealthBroken:      proto.LinkHealth_LINK_HEALTH_BROKEN,
+	linkcheck.HealthUnreachable: proto.LinkHealth_LINK_HEALTH_UNREACHABLE,
+	linkcheck.HealthMissing:     proto.LinkHealth_LINK_HEALTH_MISSING,
+}
+
+func (s *SongService) GetLinkStatus(ctx context.Context, req *proto.SongId) (*proto.LinkStatus, error) {
+	if _, err := uuid.Parse(req.GetId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var health sql.NullString
+	var httpStatus sql.NullInt32
+	var checkErr sql.NullString
+	var checkedAt sql.NullTime
+	err = db.QueryRowContext(ctx, `
+		SELECT ls.status, ls.http_status, ls.error, ls.checked_at
+		FROM song s
+		LEFT JOIN link_status ls ON ls.song_id = s.id
+		WHERE s.id = $1
+	`, req.GetId()).Scan(&health, &httpStatus, &checkErr, &checkedAt)
+	if err == sql.ErrNoRows {
+		return nil, status.Error(codes.NotFound, "song not found")
+	}
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load link status: %v", err)
+	}
+
+	resp := &proto.LinkStatus{
+		SongId:     req.GetId(),
+		Health:     linkHealthFromDB[health.String],
+		HttpStatus: uint32(httpStatus.Int32),
+		Error:      checkErr.String,
+	}
+	if checkedAt.Valid {
+		resp.CheckedAt = timestamppb.New(checkedAt.Time)
+	}
+	return resp, nil
+}
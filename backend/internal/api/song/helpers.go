@@ -3,8 +3,81 @@ package song
 import (
 	"context"
 	"database/sql"
+	"fmt"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"strings"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
 )
 
+// authorizeSongEdit allows the song's creator (with edit_own_songs) or anyone
+// with edit_any_songs to modify the song; everybody else gets PermissionDenied.
+func authorizeSongEdit(ctx context.Context, db *sql.DB, perms *proto.PermissionSet, songID, userID, action string) error {
+	var creatorID sql.NullString
+	row := db.QueryRowContext(ctx, `SELECT created_by FROM song WHERE id = $1`, songID)
+	if err := row.Scan(&creatorID); err != nil {
+		if err == sql.ErrNoRows {
+			return status.Error(codes.NotFound, "song not found")
+		}
+		return status.Errorf(codes.Internal, "load song: %v", err)
+	}
+	if !helpers.PermissionAllowsSongEdit(perms, creatorID, userID) {
+		return status.Errorf(codes.PermissionDenied, "no rights to %s song", action)
+	}
+	return nil
+}
+
+// nullIfZero stores an unknown (zero) duration as NULL.
+func nullIfZero(n int32) sql.NullInt32 {
+	return sql.NullInt32{Int32: n, Valid: n != 0}
+}
+
+// validateSongFields checks the fields shared by CreateSong and UpdateSong.
+func validateSongFields(v *helpers.Violations, title, artist, description string, link *proto.SongLink, roles []string, duration int32) {
+	v.Check(strings.TrimSpace(title) != "", "title", "must not be empty")
+	v.CheckText("title", title, false)
+	v.Check(strings.TrimSpace(artist) != "", "artist", "must not be empty")
+	v.CheckText("artist", artist, false)
+	v.CheckText("description", description, true)
+	if _, err := helpers.MapSongLinkKindToDB(link.GetKind()); err != nil {
+		v.Add("link.kind", err.Error())
+	}
+	v.Check(link.GetUrl() == "" || helpers.ValidLinkURL(link.GetUrl()), "link.url", "must be an absolute http(s) url")
+	v.Check(duration >= 0, "duration_seconds", "must not be negative")
+	for i, r := range roles {
+		v.CheckRole(fmt.Sprintf("available_roles[%d]", i), r)
+	}
+}
+
+// checkEventRoleLimit rejects joining a role when the user already holds
+// maxRoles roles within the tracklist of any event featuring the song.
+// A non-positive maxRoles disables the check.
+func checkEventRoleLimit(ctx context.Context, db *sql.DB, maxRoles int, songID, role, userID string) error {
+	if maxRoles <= 0 {
+		return nil
+	}
+	var held int
+	if err := db.QueryRowContext(ctx, `
+		SELECT COALESCE(MAX(cnt), 0) FROM (
+			SELECT eti.event_id, COUNT(DISTINCT (sra.song_id, sra.role)) AS cnt
+			FROM event_track_item eti
+			JOIN event e ON e.id = eti.event_id AND e.deleted_at IS NULL
+			JOIN event_track_item other ON other.event_id = eti.event_id
+			JOIN song_role_assignment sra ON sra.song_id = other.song_id AND sra.user_id = $3
+			WHERE eti.song_id = $1 AND NOT (sra.song_id = $1 AND sra.role = $2)
+			GROUP BY eti.event_id
+		) per_event
+	`, songID, role, userID).Scan(&held); err != nil {
+		return status.Errorf(codes.Internal, "count event roles: %v", err)
+	}
+	if held >= maxRoles {
+		return status.Errorf(codes.FailedPrecondition, "at most %d roles per event are allowed", maxRoles)
+	}
+	return nil
+}
+
 func replaceSongRoles(ctx context.Context, tx *sql.Tx, songID string, roles []string) error {
 	if _, err := tx.ExecContext(ctx, `DELETE FROM song_role WHERE song_id = $1`, songID); err != nil {
 		return err
@@ -16,3 +89,70 @@ func replaceSongRoles(ctx context.Context, tx *sql.Tx, songID string, roles []st
 	}
 	return nil
 }
+
+func loadRoleSet(ctx context.Context, tx *sql.Tx, songID string) (map[string]bool, error) {
+	rows, err := tx.QueryContext(ctx, `SELECT role FROM song_role WHERE song_id = $1`, songID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	roles := make(map[string]bool)
+	for rows.Next() {
+		var r string
+		if err := rows.Scan(&r); err != nil {
+			return nil, err
+		}
+		roles[r] = true
+	}
+	return roles, rows.Err()
+}
+
+// scanSongs reads song rows selected as helpers.SongColumns and fills in roles,
+// assignment counts and editability for the current user. Notes are kept for
+// admins only.
+func scanSongs(ctx context.Context, db *sql.DB, rows *sql.Rows, currentUserID string) ([]*proto.Song, error) {
+	perms, _ := helpers.LoadPermissions(ctx, db, currentUserID)
+
+	var songs []*proto.Song
+	for rows.Next() {
+		var sng proto.Song
+		var linkKind, linkURL, thumbnailURL, notes string
+		var creatorID sql.NullString
+		var duration sql.NullInt32
+		if err := rows.Scan(&sng.Id, &sng.Title, &sng.Artist, &sng.Description, &linkKind, &linkURL, &creatorID, &thumbnailURL, &duration, &notes); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan song: %v", err)
+		}
+		sng.Link = &proto.SongLink{Kind: helpers.MapSongLinkType(linkKind), Url: linkURL}
+		sng.ThumbnailUrl = thumbnailURL
+		sng.CreatedBy = creatorID.String
+		sng.DurationSeconds = duration.Int32
+		roles, err := helpers.LoadSongRoles(ctx, db, sng.Id)
+		if err != nil {
+			return nil, status.Errorf(codes.Internal, "load roles: %v", err)
+		}
+		sng.AvailableRoles = roles
+		sng.EditableByMe = helpers.PermissionAllowsSongEdit(perms, creatorID, currentUserID)
+
+		// Count participants assigned to this song
+		var assignmentCount int32
+		countQuery := `SELECT COUNT(*) FROM song_role_assignment WHERE song_id = $1`
+		if err := db.QueryRowContext(ctx, countQuery, sng.Id).Scan(&assignmentCount); err != nil {
+			return nil, status.Errorf(codes.Internal, "count assignments: %v", err)
+		}
+		sng.AssignmentCount = assignmentCount
+
+		if sng.VoteCount, err = helpers.CountSongVotes(ctx, db, sng.Id); err != nil {
+			return nil, status.Errorf(codes.Internal, "count votes: %v", err)
+		}
+
+		if helpers.PermissionIsAdmin(perms) {
+			sng.Notes = notes
+		}
+
+		songs = append(songs, &sng)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate songs: %v", err)
+	}
+	return songs, nil
+}
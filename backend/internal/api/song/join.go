@@ -2,6 +2,7 @@ package song
 
 import (
 	"context"
+	"musicclubbot/backend/internal/config"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
@@ -10,6 +11,14 @@ import (
 )
 
 func (s *SongService) JoinRole(ctx context.Context, req *proto.JoinRoleRequest) (*proto.SongDetails, error) {
+	assignmentStatus := "confirmed"
+	if req.GetStatus() != proto.ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED {
+		var err error
+		if assignmentStatus, err = helpers.MapParticipationStatusToDB(req.GetStatus()); err != nil {
+			return nil, status.Errorf(codes.InvalidArgument, "status: %v", err)
+		}
+	}
+
 	userID, err := helpers.UserIDFromCtx(ctx)
 	if err != nil {
 		return nil, err
@@ -26,13 +35,19 @@ func (s *SongService) JoinRole(ctx context.Context, req *proto.JoinRoleRequest)
 		return nil, status.Error(codes.PermissionDenied, "no rights to join roles")
 	}
 
+	cfg := ctx.Value("cfg").(config.Config)
+	if err := checkEventRoleLimit(ctx, db, cfg.MaxRolesPerEvent, req.GetSongId(), req.GetRole(), userID); err != nil {
+		return nil, err
+	}
+
 	if _, err := db.ExecContext(ctx, `
-		INSERT INTO song_role_assignment (song_id, role, user_id)
-		VALUES ($1, $2, $3)
-		ON CONFLICT (song_id, role, user_id) DO NOTHING
-	`, req.GetSongId(), req.GetRole(), userID); err != nil {
+		INSERT INTO song_role_assignment (song_id, role, user_id, status)
+		VALUES ($1, $2, $3, $4)
+		ON CONFLICT (song_id, role, user_id) DO UPDATE SET status = EXCLUDED.status, updated_at = NOW()
+	`, req.GetSongId(), req.GetRole(), userID, assignmentStatus); err != nil {
 		return nil, status.Errorf(codes.Internal, "join role: %v", err)
 	}
 
+	helpers.InvalidateSong(ctx, req.GetSongId())
 	return helpers.LoadSongDetails(ctx, db, req.GetSongId(), userID)
 }
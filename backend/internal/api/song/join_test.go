@@ -0,0 +1,27 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+)
+
+func TestJoinRoleAgainUpdatesStatus(t *testing.T) {
+	db, mock := dbtest.New(t)
+	ctx := context.WithValue(testCtx(db, testUserID), "cfg", config.Config{})
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectExec("ON CONFLICT (song_id, role, user_id) DO UPDATE SET status = EXCLUDED.status").
+		WithArgs(testSongID, "guitar", testUserID, "tentative").WillReturnResult(1)
+	mock.ExpectSongDetails(testSongRow(), testUserID, dbtest.Member)
+
+	_, err := (&SongService{}).JoinRole(ctx, &proto.JoinRoleRequest{
+		SongId: testSongID,
+		Role:   "guitar",
+		Status: proto.ParticipationStatus_PARTICIPATION_STATUS_TENTATIVE,
+	})
+	if err != nil {
+		t.Fatalf("JoinRole: %v", err)
+	}
+}
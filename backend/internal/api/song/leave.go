@@ -32,5 +32,6 @@ func (s *SongService) LeaveRole(ctx context.Context, req *proto.LeaveRoleRequest
 		return nil, status.Errorf(codes.Internal, "leave role: %v", err)
 	}
 
+	helpers.InvalidateSong(ctx, req.GetSongId())
 	return helpers.LoadSongDetails(ctx, db, req.GetSongId(), userID)
 }
@@ -2,16 +2,21 @@ package song
 
 import (
 	"context"
-	"database/sql"
+	"fmt"
+	"hash/fnv"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 	"strconv"
+	"strings"
 
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 )
 
 func (s *SongService) ListSongs(ctx context.Context, req *proto.ListSongsRequest) (*proto.ListSongsResponse, error) {
+	if err := helpers.ValidateReadMask(&proto.Song{}, req.GetReadMask()); err != nil {
+		return nil, err
+	}
 	db, err := helpers.DbFromCtx(ctx)
 	if err != nil {
 		return nil, err
@@ -19,32 +24,45 @@ func (s *SongService) ListSongs(ctx context.Context, req *proto.ListSongsRequest
 
 	currentUserID, _ := helpers.UserIDFromCtx(ctx) // best effort; anonymous users just see editable=false
 
-	limit := int(req.GetPageSize())
-	if limit <= 0 || limit > 100 {
-		limit = 20
-	}
-	offset := 0
-	if tok := req.GetPageToken(); tok != "" {
-		if v, err := strconv.Atoi(tok); err == nil && v >= 0 {
-			offset = v
-		}
+	limit := helpers.PageSize(req.GetPageSize())
+	cursorKind := songsCursorKind(req)
+	offset, err := helpers.DecodePageToken(ctx, cursorKind, req.GetPageToken())
+	if err != nil {
+		return nil, err
 	}
 
 	args := []any{}
-	where := ""
+	clauses := []string{}
 	if q := req.GetQuery(); q != "" {
-		where = "WHERE title ILIKE $1 OR artist ILIKE $1"
 		args = append(args, "%"+q+"%")
+		clauses = append(clauses, helpers.SongSearchFilter)
+	}
+	if req.GetParticipantId() != "" {
+		args = append(args, req.GetParticipantId())
+		clauses = append(clauses, "EXISTS (SELECT 1 FROM song_role_assignment sra WHERE sra.song_id = song.id AND sra.user_id = $"+strconv.Itoa(len(args))+")")
+	}
+	if tag := normalizeTag(req.GetTag()); tag != "" {
+		args = append(args, tag)
+		clauses = append(clauses, "EXISTS (SELECT 1 FROM song_tag st WHERE st.song_id = song.id AND st.tag = $"+strconv.Itoa(len(args))+")")
+	}
+	orderBy := helpers.SongListOrder
+	if req.GetMissingLink() {
+		clauses = append(clauses, "link_url = ''")
+		orderBy = "id"
+	}
+	where := ""
+	if len(clauses) > 0 {
+		where = "WHERE " + strings.Join(clauses, " AND ")
 	}
 
 	query := `
-		SELECT id, title, artist, description, link_kind, link_url, COALESCE(created_by, NULL), COALESCE(thumbnail_url, '')
+		SELECT ` + helpers.SongColumns + `
 		FROM song
 	` + where + `
-		ORDER BY created_at DESC
+		ORDER BY ` + orderBy + `
 		LIMIT $` + strconv.Itoa(len(args)+1) + `
 		OFFSET $` + strconv.Itoa(len(args)+2)
-	args = append(args, limit, offset)
+	args = append(args, limit+1, offset)
 
 	rows, err := db.QueryContext(ctx, query, args...)
 	if err != nil {
@@ -52,46 +70,36 @@ func (s *SongService) ListSongs(ctx context.Context, req *proto.ListSongsRequest
 	}
 	defer rows.Close()
 
-	perms, _ := helpers.LoadPermissions(ctx, db, currentUserID)
-
-	var songs []*proto.Song
-	for rows.Next() {
-		var sng proto.Song
-		var linkKind, linkURL, thumbnailURL string
-		var creatorID sql.NullString
-		if err := rows.Scan(&sng.Id, &sng.Title, &sng.Artist, &sng.Description, &linkKind, &linkURL, &creatorID, &thumbnailURL); err != nil {
-			return nil, status.Errorf(codes.Internal, "scan song: %v", err)
-		}
-		sng.Link = &proto.SongLink{Kind: helpers.MapSongLinkType(linkKind), Url: linkURL}
-		sng.ThumbnailUrl = thumbnailURL
-		roles, err := helpers.LoadSongRoles(ctx, db, sng.Id)
-		if err != nil {
-			return nil, status.Errorf(codes.Internal, "load roles: %v", err)
-		}
-		sng.AvailableRoles = roles
-		sng.EditableByMe = helpers.PermissionAllowsSongEdit(perms, creatorID, currentUserID)
-
-		// Count participants assigned to this song
-		var assignmentCount int32
-		countQuery := `SELECT COUNT(*) FROM song_role_assignment WHERE song_id = $1`
-		if err := db.QueryRowContext(ctx, countQuery, sng.Id).Scan(&assignmentCount); err != nil {
-			return nil, status.Errorf(codes.Internal, "count assignments: %v", err)
-		}
-		sng.AssignmentCount = assignmentCount
-
-		songs = append(songs, &sng)
-	}
-	if err := rows.Err(); err != nil {
-		return nil, status.Errorf(codes.Internal, "iterate songs: %v", err)
+	songs, err := scanSongs(ctx, db, rows, currentUserID)
+	if err != nil {
+		return nil, err
 	}
 
+	// One extra row is fetched to learn whether another page exists.
 	nextToken := ""
-	if len(songs) == limit {
-		nextToken = strconv.Itoa(offset + limit)
+	hasMore := len(songs) > limit
+	if hasMore {
+		songs = songs[:limit]
+		nextToken = helpers.EncodePageToken(ctx, cursorKind, offset+limit)
+	}
+	for _, sng := range songs {
+		helpers.ApplyReadMask(sng, req.GetReadMask())
 	}
 
 	return &proto.ListSongsResponse{
 		Songs:         songs,
 		NextPageToken: nextToken,
+		HasMore:       hasMore,
 	}, nil
 }
+
+// songsCursorKind ties page tokens to the filters they were issued for, so a
+// cursor from one search cannot be replayed against another.
+func songsCursorKind(req *proto.ListSongsRequest) string {
+	h := fnv.New32a()
+	for _, part := range []string{req.GetQuery(), req.GetParticipantId(), normalizeTag(req.GetTag()), strconv.FormatBool(req.GetMissingLink())} {
+		h.Write([]byte(part))
+		h.Write([]byte{0})
+	}
+	return fmt.Sprintf("songs/%08x", h.Sum32())
+}
@@ -0,0 +1,55 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) ListSongsByVotes(ctx context.Context, req *proto.ListSongsByVotesRequest) (*proto.ListSongsResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+
+	limit := helpers.PageSize(req.GetPageSize())
+	offset, err := helpers.DecodePageToken(ctx, "songs_by_votes", req.GetPageToken())
+	if err != nil {
+		return nil, err
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT `+helpers.SongColumns+`
+		FROM song
+		ORDER BY (SELECT COUNT(*) FROM song_vote sv WHERE sv.song_id = song.id) DESC, id
+		LIMIT $1
+		OFFSET $2
+	`, limit+1, offset)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list songs by votes: %v", err)
+	}
+	defer rows.Close()
+
+	songs, err := scanSongs(ctx, db, rows, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+
+	nextToken := ""
+	hasMore := len(songs) > limit
+	if hasMore {
+		songs = songs[:limit]
+		nextToken = helpers.EncodePageToken(ctx, "songs_by_votes", offset+limit)
+	}
+
+	return &proto.ListSongsResponse{
+		Songs:         songs,
+		NextPageToken: nextToken,
+		HasMore:       hasMore,
+	}, nil
+}
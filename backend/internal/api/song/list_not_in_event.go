@@ -0,0 +1,77 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) ListSongsNotInEvent(ctx context.Context, req *proto.ListSongsNotInEventRequest) (*proto.ListSongsResponse, error) {
+	v := &helpers.Violations{}
+	v.Check(req.GetEventId() != "", "event_id", "must not be empty")
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+	perms, err := helpers.LoadPermissions(ctx, db, currentUserID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+
+	limit := helpers.PageSize(req.GetPageSize())
+	offset, err := helpers.DecodePageToken(ctx, "songs_not_in_event", req.GetPageToken())
+	if err != nil {
+		return nil, err
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM event WHERE id = $1 AND deleted_at IS NULL AND `+helpers.EventVisibilityFilter(perms, "status")+`)`, req.GetEventId()).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load event: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "event not found")
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT `+helpers.SongColumns+`
+		FROM song
+		WHERE NOT EXISTS (
+			SELECT 1 FROM event_track_item eti
+			WHERE eti.event_id = $1 AND eti.song_id = song.id
+		)
+		ORDER BY title, id
+		LIMIT $2
+		OFFSET $3
+	`, req.GetEventId(), limit+1, offset)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list songs not in event: %v", err)
+	}
+	defer rows.Close()
+
+	songs, err := scanSongs(ctx, db, rows, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+
+	nextToken := ""
+	hasMore := len(songs) > limit
+	if hasMore {
+		songs = songs[:limit]
+		nextToken = helpers.EncodePageToken(ctx, "songs_not_in_event", offset+limit)
+	}
+
+	return &proto.ListSongsResponse{
+		Songs:         songs,
+		NextPageToken: nextToken,
+		HasMore:       hasMore,
+	}, nil
+}
@@ -0,0 +1,123 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"strconv"
+	"strings"
+	"time"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+// participationSelect is the shared projection read by scanParticipations.
+const participationSelect = `
+	SELECT s.id, s.title, s.artist, sra.role,
+	       au.id, au.display_name, COALESCE(au.username, ''), COALESCE(au.avatar_url, ''),
+	       sra.joined_at, sra.updated_at, sra.status, sra.id::text
+	FROM song_role_assignment sra
+	JOIN song s ON sra.song_id = s.id
+	JOIN app_user au ON sra.user_id = au.id
+`
+
+func (s *SongService) ListParticipations(ctx context.Context, req *proto.ListParticipationsRequest) (*proto.ListParticipationsResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	args := []any{}
+	clauses := []string{}
+	if req.GetUserId() != "" {
+		args = append(args, req.GetUserId())
+		clauses = append(clauses, "sra.user_id = $"+strconv.Itoa(len(args)))
+	}
+	if req.GetSongId() != "" {
+		args = append(args, req.GetSongId())
+		clauses = append(clauses, "sra.song_id = $"+strconv.Itoa(len(args)))
+	}
+	if req.GetRole() != "" {
+		args = append(args, req.GetRole())
+		clauses = append(clauses, "LOWER(sra.role) = LOWER($"+strconv.Itoa(len(args))+")")
+	}
+	if req.GetStatus() != proto.ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED {
+		dbStatus, err := helpers.MapParticipationStatusToDB(req.GetStatus())
+		if err != nil {
+			return nil, status.Errorf(codes.InvalidArgument, "status: %v", err)
+		}
+		args = append(args, dbStatus)
+		clauses = append(clauses, "sra.status = $"+strconv.Itoa(len(args)))
+	}
+	where := ""
+	if len(clauses) > 0 {
+		where = "WHERE " + strings.Join(clauses, " AND ")
+	}
+
+	offset, err := helpers.DecodePageToken(ctx, "participations", req.GetPageToken())
+	if err != nil {
+		return nil, err
+	}
+	limit := helpers.PageSize(req.GetPageSize())
+	args = append(args, limit+1, offset)
+
+	rows, err := db.QueryContext(ctx, participationSelect+where+`
+		ORDER BY s.title, s.id, sra.joined_at, sra.role, sra.id
+		LIMIT $`+strconv.Itoa(len(args)-1)+` OFFSET $`+strconv.Itoa(len(args)), args...)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list participations: %v", err)
+	}
+	defer rows.Close()
+
+	items, _, err := scanParticipations(rows)
+	if err != nil {
+		return nil, err
+	}
+	nextToken := ""
+	hasMore := len(items) > limit
+	if hasMore {
+		items = items[:limit]
+		nextToken = helpers.EncodePageToken(ctx, "participations", offset+limit)
+	}
+	return &proto.ListParticipationsResponse{
+		Participations: items,
+		NextPageToken:  nextToken,
+		HasMore:        hasMore,
+	}, nil
+}
+
+// scanParticipations also returns the assignment ids, parallel to the items,
+// for lists that page by key.
+func scanParticipations(rows *sql.Rows) ([]*proto.Participation, []string, error) {
+	var items []*proto.Participation
+	var ids []string
+	for rows.Next() {
+		var p proto.Participation
+		var role, uid, display, username, avatar, assignmentStatus, id string
+		var joined, updated time.Time
+		if err := rows.Scan(&p.SongId, &p.SongTitle, &p.SongArtist, &role, &uid, &display, &username, &avatar, &joined, &updated, &assignmentStatus, &id); err != nil {
+			return nil, nil, status.Errorf(codes.Internal, "scan participation: %v", err)
+		}
+		p.Assignment = &proto.RoleAssignment{
+			Role: role,
+			User: &proto.User{
+				Id:          uid,
+				DisplayName: display,
+				Username:    username,
+				AvatarUrl:   avatar,
+			},
+			JoinedAt: timestamppb.New(joined),
+			Status:   helpers.MapParticipationStatus(assignmentStatus),
+		}
+		p.UpdatedAt = timestamppb.New(updated)
+		items = append(items, &p)
+		ids = append(ids, id)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, nil, status.Errorf(codes.Internal, "iterate participations: %v", err)
+	}
+	return items, ids, nil
+}
@@ -0,0 +1,163 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"slices"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+func (s *SongService) ListParticipationsSince(ctx context.Context, req *proto.ListParticipationsSinceRequest) (*proto.ListParticipationsSinceResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	since := time.Unix(0, 0)
+	if ts := req.GetSince(); ts != nil {
+		since = ts.AsTime()
+	}
+	limit := helpers.PageSize(req.GetPageSize())
+	// The cursor is the (updated_at, id) of the last row sent, so rows updated
+	// between calls move behind the cursor instead of shifting later pages.
+	kind := sinceCursorKind(since)
+	cursor, err := helpers.DecodeCursor(ctx, kind, req.GetPageToken())
+	if err != nil {
+		return nil, err
+	}
+	afterAt, afterID, err := decodeSinceCursor(cursor, since)
+	if err != nil {
+		return nil, err
+	}
+
+	rows, err := db.QueryContext(ctx, participationSelect+`
+		WHERE sra.updated_at > $1 AND (sra.updated_at, sra.id) > ($2, $3)
+		ORDER BY sra.updated_at, sra.id
+		LIMIT $4
+	`, since, afterAt, afterID, limit+1)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list participations: %v", err)
+	}
+	defer rows.Close()
+
+	items, ids, err := scanParticipations(rows)
+	if err != nil {
+		return nil, err
+	}
+	changes := make([]sinceChange, 0, len(items))
+	for i, item := range items {
+		changes = append(changes, sinceChange{at: item.GetUpdatedAt().AsTime(), id: ids[i], item: item})
+	}
+	removals, err := listRemovalsSince(ctx, db, since, afterAt, afterID, limit+1)
+	if err != nil {
+		return nil, err
+	}
+	changes = append(changes, removals...)
+	// Each list holds its first limit+1 changes past the cursor, so the first
+	// limit+1 of the merged list are the next changes overall.
+	slices.SortFunc(changes, func(a, b sinceChange) int {
+		if c := a.at.Compare(b.at); c != 0 {
+			return c
+		}
+		return strings.Compare(a.id, b.id)
+	})
+
+	nextToken := ""
+	hasMore := len(changes) > limit
+	if hasMore {
+		changes = changes[:limit]
+		last := changes[limit-1]
+		nextToken = helpers.EncodeCursor(ctx, kind, encodeSinceCursor(last.at, last.id))
+	}
+	resp := &proto.ListParticipationsSinceResponse{
+		NextPageToken: nextToken,
+		HasMore:       hasMore,
+	}
+	for _, c := range changes {
+		if c.removal != nil {
+			resp.Removals = append(resp.Removals, c.removal)
+		} else {
+			resp.Participations = append(resp.Participations, c.item)
+		}
+	}
+	return resp, nil
+}
+
+// sinceChange is a participation or a removal, keyed by (time, assignment id)
+// for paging.
+type sinceChange struct {
+	at      time.Time
+	id      string
+	item    *proto.Participation
+	removal *proto.RemovedParticipation
+}
+
+// listRemovalsSince reads up to limit assignment tombstones past the cursor.
+func listRemovalsSince(ctx context.Context, db *sql.DB, since, afterAt time.Time, afterID string, limit int) ([]sinceChange, error) {
+	rows, err := db.QueryContext(ctx, `
+		SELECT id::text, song_id::text, role, user_id::text, removed_at
+		FROM song_role_assignment_removal
+		WHERE removed_at > $1 AND (removed_at, id) > ($2, $3)
+		ORDER BY removed_at, id
+		LIMIT $4
+	`, since, afterAt, afterID, limit)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list removals: %v", err)
+	}
+	defer rows.Close()
+
+	var changes []sinceChange
+	for rows.Next() {
+		var c sinceChange
+		var r proto.RemovedParticipation
+		if err := rows.Scan(&c.id, &r.SongId, &r.Role, &r.UserId, &c.at); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan removal: %v", err)
+		}
+		r.RemovedAt = timestamppb.New(c.at)
+		c.removal = &r
+		changes = append(changes, c)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate removals: %v", err)
+	}
+	return changes, nil
+}
+
+// sinceCursorKind binds cursors to the watermark they were issued for.
+func sinceCursorKind(since time.Time) string {
+	return fmt.Sprintf("participations_since/%d", since.UnixMicro())
+}
+
+func encodeSinceCursor(updatedAt time.Time, id string) string {
+	return strconv.FormatInt(updatedAt.UnixMicro(), 10) + "," + id
+}
+
+// decodeSinceCursor parses a cursor value; an empty one starts at since.
+func decodeSinceCursor(value string, since time.Time) (time.Time, string, error) {
+	if value == "" {
+		return since, uuid.Nil.String(), nil
+	}
+	invalid := status.Error(codes.InvalidArgument, "invalid page token")
+	rawAt, id, ok := strings.Cut(value, ",")
+	if !ok {
+		return time.Time{}, "", invalid
+	}
+	micros, err := strconv.ParseInt(rawAt, 10, 64)
+	if err != nil {
+		return time.Time{}, "", invalid
+	}
+	if _, err := uuid.Parse(id); err != nil {
+		return time.Time{}, "", invalid
+	}
+	return time.UnixMicro(micros).UTC(), id, nil
+}
@@ -0,0 +1,97 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"testing"
+	"time"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestSinceCursorRoundTrip(t *testing.T) {
+	ctx := context.WithValue(context.Background(), "cfg", config.Config{PageTokenKey: []byte("test-key")})
+	since := time.Date(2025, 3, 1, 12, 0, 0, 0, time.UTC)
+	updatedAt := time.Date(2025, 3, 2, 8, 30, 15, 123456000, time.UTC)
+	id := "5f0c6a8e-4b1d-4a55-9a3e-2f7c1d0b9e11"
+
+	token := helpers.EncodeCursor(ctx, sinceCursorKind(since), encodeSinceCursor(updatedAt, id))
+	value, err := helpers.DecodeCursor(ctx, sinceCursorKind(since), token)
+	if err != nil {
+		t.Fatalf("DecodeCursor: %v", err)
+	}
+	gotAt, gotID, err := decodeSinceCursor(value, since)
+	if err != nil {
+		t.Fatalf("decodeSinceCursor: %v", err)
+	}
+	if !gotAt.Equal(updatedAt) || gotID != id {
+		t.Errorf("got (%s, %s), want (%s, %s)", gotAt, gotID, updatedAt, id)
+	}
+
+	// A cursor issued for one watermark is rejected for another.
+	_, err = helpers.DecodeCursor(ctx, sinceCursorKind(since.Add(time.Second)), token)
+	if status.Code(err) != codes.InvalidArgument {
+		t.Errorf("cursor for another watermark: got %v, want InvalidArgument", err)
+	}
+}
+
+func TestDecodeSinceCursor(t *testing.T) {
+	since := time.Date(2025, 3, 1, 0, 0, 0, 0, time.UTC)
+
+	at, id, err := decodeSinceCursor("", since)
+	if err != nil || !at.Equal(since) || id != "00000000-0000-0000-0000-000000000000" {
+		t.Errorf("empty cursor = (%s, %s, %v), want the watermark and the nil uuid", at, id, err)
+	}
+
+	for _, value := range []string{"no-comma", "abc,5f0c6a8e-4b1d-4a55-9a3e-2f7c1d0b9e11", "1700000000000000,not-a-uuid"} {
+		if _, _, err := decodeSinceCursor(value, since); status.Code(err) != codes.InvalidArgument {
+			t.Errorf("decodeSinceCursor(%q) = %v, want InvalidArgument", value, err)
+		}
+	}
+}
+
+func TestListParticipationsSinceMergesRemovals(t *testing.T) {
+	db, mock := dbtest.New(t)
+	ctx := context.WithValue(testCtx(db, testUserID), "cfg", config.Config{PageTokenKey: []byte("test-key")})
+	t0 := time.Date(2025, 3, 1, 10, 0, 0, 0, time.UTC)
+	removedID := "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa"
+	laterRemovedID := "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb"
+	assignmentID := "cccccccc-cccc-cccc-cccc-cccccccccccc"
+
+	mock.ExpectQuery("FROM song_role_assignment sra").WillReturnRows(
+		[]string{"song_id", "title", "artist", "role", "user_id", "display_name", "username", "avatar_url", "joined_at", "updated_at", "status", "id"},
+		[]any{testSongID, "Song", "Artist", "bass", testUserID, "User", "", "", t0, t0.Add(2 * time.Minute), "confirmed", assignmentID},
+	)
+	mock.ExpectQuery("FROM song_role_assignment_removal").WillReturnRows(
+		[]string{"id", "song_id", "role", "user_id", "removed_at"},
+		[]any{removedID, testSongID, "guitar", testUserID, t0.Add(time.Minute)},
+		[]any{laterRemovedID, testSongID, "drums", testUserID, t0.Add(3 * time.Minute)},
+	)
+
+	resp, err := (&SongService{}).ListParticipationsSince(ctx, &proto.ListParticipationsSinceRequest{PageSize: 2})
+	if err != nil {
+		t.Fatalf("ListParticipationsSince: %v", err)
+	}
+	if len(resp.GetRemovals()) != 1 || resp.GetRemovals()[0].GetRole() != "guitar" {
+		t.Errorf("removals = %v, want only the guitar removal", resp.GetRemovals())
+	}
+	if len(resp.GetParticipations()) != 1 || resp.GetParticipations()[0].GetAssignment().GetRole() != "bass" {
+		t.Errorf("participations = %v, want only the bass assignment", resp.GetParticipations())
+	}
+	if !resp.GetHasMore() {
+		t.Fatal("has_more = false, want the drums removal on the next page")
+	}
+
+	value, err := helpers.DecodeCursor(ctx, sinceCursorKind(time.Unix(0, 0)), resp.GetNextPageToken())
+	if err != nil {
+		t.Fatalf("DecodeCursor: %v", err)
+	}
+	at, id, err := decodeSinceCursor(value, time.Unix(0, 0))
+	if err != nil || !at.Equal(t0.Add(2*time.Minute)) || id != assignmentID {
+		t.Errorf("cursor = (%s, %s, %v), want the bass assignment", at, id, err)
+	}
+}
@@ -0,0 +1,45 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) ListRecentSongs(ctx context.Context, req *proto.ListRecentSongsRequest) (*proto.ListSongsResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	currentUserID, _ := helpers.UserIDFromCtx(ctx)
+
+	limit := req.GetLimit()
+	if limit == 0 || limit > 50 {
+		limit = 10
+	}
+
+	rows, err := db.QueryContext(ctx, `
+		SELECT `+helpers.SongColumns+`
+		FROM song
+		ORDER BY created_at DESC, id DESC
+		LIMIT $1
+	`, limit+1)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list recent songs: %v", err)
+	}
+	defer rows.Close()
+
+	songs, err := scanSongs(ctx, db, rows, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+	hasMore := len(songs) > int(limit)
+	if hasMore {
+		songs = songs[:limit]
+	}
+	return &proto.ListSongsResponse{Songs: songs, HasMore: hasMore}, nil
+}
@@ -0,0 +1,42 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) ListRecentParticipations(ctx context.Context, req *proto.ListRecentParticipationsRequest) (*proto.ListParticipationsResponse, error) {
+	if _, err := uuid.Parse(req.GetUserId()); err != nil {
+		return nil, status.Error(codes.InvalidArgument, "user_id must be a uuid")
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	limit := req.GetLimit()
+	if limit == 0 || limit > 50 {
+		limit = 10
+	}
+
+	rows, err := db.QueryContext(ctx, participationSelect+`
+		WHERE sra.user_id = $1
+		ORDER BY sra.joined_at DESC, sra.id DESC
+		LIMIT $2
+	`, req.GetUserId(), limit)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list recent participations: %v", err)
+	}
+	defer rows.Close()
+
+	items, _, err := scanParticipations(rows)
+	if err != nil {
+		return nil, err
+	}
+	return &proto.ListParticipationsResponse{Participations: items}, nil
+}
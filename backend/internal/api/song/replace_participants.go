@@ -0,0 +1,128 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"github.com/google/uuid"
+	"github.com/lib/pq"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+type participantSlot struct {
+	role   string
+	userID string
+}
+
+func (s *SongService) ReplaceParticipants(ctx context.Context, req *proto.ReplaceParticipantsRequest) (*proto.SongDetails, error) {
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	desired := make(map[participantSlot]bool, len(req.GetParticipants()))
+	users := map[string]bool{}
+	for _, p := range req.GetParticipants() {
+		if p.GetRole() == "" || p.GetUserId() == "" {
+			return nil, status.Error(codes.InvalidArgument, "every participant needs role and user_id")
+		}
+		participantID, err := uuid.Parse(p.GetUserId())
+		if err != nil {
+			return nil, status.Errorf(codes.InvalidArgument, "participant user_id %q must be a uuid", p.GetUserId())
+		}
+		// Canonical form so slots compare equal to the ids scanned back from the table.
+		desired[participantSlot{role: p.GetRole(), userID: participantID.String()}] = true
+		users[participantID.String()] = true
+	}
+
+	tx, err := db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "begin tx: %v", err)
+	}
+	defer tx.Rollback()
+
+	var songID string
+	if err := tx.QueryRowContext(ctx, `SELECT id FROM song WHERE id = $1 FOR UPDATE`, req.GetSongId()).Scan(&songID); err != nil {
+		if err == sql.ErrNoRows {
+			return nil, status.Error(codes.NotFound, "song not found")
+		}
+		return nil, status.Errorf(codes.Internal, "load song: %v", err)
+	}
+
+	roles, err := loadRoleSet(ctx, tx, songID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load roles: %v", err)
+	}
+	for slot := range desired {
+		if !roles[slot.role] {
+			return nil, status.Errorf(codes.InvalidArgument, "role %q is not available for this song", slot.role)
+		}
+	}
+
+	userIDs := make([]string, 0, len(users))
+	for id := range users {
+		userIDs = append(userIDs, id)
+	}
+	var found int
+	if err := tx.QueryRowContext(ctx, `SELECT COUNT(*) FROM app_user WHERE id = ANY($1::uuid[])`, pq.Array(userIDs)).Scan(&found); err != nil {
+		return nil, status.Errorf(codes.Internal, "load users: %v", err)
+	}
+	if found != len(userIDs) {
+		return nil, status.Error(codes.NotFound, "participant user not found")
+	}
+
+	rows, err := tx.QueryContext(ctx, `SELECT role, user_id FROM song_role_assignment WHERE song_id = $1`, songID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load participants: %v", err)
+	}
+	var stale []participantSlot
+	existing := make(map[participantSlot]bool)
+	for rows.Next() {
+		var slot participantSlot
+		if err := rows.Scan(&slot.role, &slot.userID); err != nil {
+			rows.Close()
+			return nil, status.Errorf(codes.Internal, "scan participant: %v", err)
+		}
+		existing[slot] = true
+		if !desired[slot] {
+			stale = append(stale, slot)
+		}
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate participants: %v", err)
+	}
+
+	for _, slot := range stale {
+		if _, err := tx.ExecContext(ctx, `
+			DELETE FROM song_role_assignment WHERE song_id = $1 AND role = $2 AND user_id = $3
+		`, songID, slot.role, slot.userID); err != nil {
+			return nil, status.Errorf(codes.Internal, "remove participant: %v", err)
+		}
+	}
+	// Kept assignments are left untouched so their joined_at survives.
+	for slot := range desired {
+		if existing[slot] {
+			continue
+		}
+		if _, err := tx.ExecContext(ctx, `
+			INSERT INTO song_role_assignment (song_id, role, user_id) VALUES ($1, $2, $3)
+		`, songID, slot.role, slot.userID); err != nil {
+			return nil, status.Errorf(codes.Internal, "add participant: %v", err)
+		}
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, status.Errorf(codes.Internal, "commit: %v", err)
+	}
+
+	helpers.InvalidateSong(ctx, songID)
+	return helpers.LoadSongDetails(ctx, db, songID, userID)
+}
@@ -0,0 +1,49 @@
+package song
+
+import (
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) StreamSearchSongs(req *proto.StreamSearchSongsRequest, stream grpc.ServerStreamingServer[proto.Song]) error {
+	ctx := stream.Context()
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return err
+	}
+
+	// Same matching and order as ListSongs, read row by row instead of by page.
+	rows, err := db.QueryContext(ctx, `
+		SELECT id, title, artist, description, link_kind, link_url, COALESCE(created_by::text, ''), COALESCE(thumbnail_url, ''), duration_seconds
+		FROM song
+		WHERE $1 = '' OR title ILIKE '%' || $1 || '%' OR artist ILIKE '%' || $1 || '%'
+		ORDER BY created_at DESC, id DESC
+	`, req.GetQuery())
+	if err != nil {
+		return status.Errorf(codes.Internal, "search songs: %v", err)
+	}
+	defer rows.Close()
+
+	for rows.Next() {
+		var sng proto.Song
+		var linkKind, linkURL string
+		var duration sql.NullInt32
+		if err := rows.Scan(&sng.Id, &sng.Title, &sng.Artist, &sng.Description, &linkKind, &linkURL, &sng.CreatedBy, &sng.ThumbnailUrl, &duration); err != nil {
+			return status.Errorf(codes.Internal, "scan song: %v", err)
+		}
+		sng.Link = &proto.SongLink{Kind: helpers.MapSongLinkType(linkKind), Url: linkURL}
+		sng.DurationSeconds = duration.Int32
+		if err := stream.Send(&sng); err != nil {
+			return err
+		}
+	}
+	if err := rows.Err(); err != nil {
+		return status.Errorf(codes.Internal, "iterate songs: %v", err)
+	}
+	return nil
+}
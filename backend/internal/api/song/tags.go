@@ -0,0 +1,116 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+	"strings"
+	"unicode/utf8"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const maxTagLength = 50
+
+func normalizeTag(tag string) string {
+	return strings.ToLower(strings.TrimSpace(tag))
+}
+
+func (s *SongService) ListSongTags(ctx context.Context, req *proto.SongId) (*proto.SongTags, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM song WHERE id = $1)`, req.GetId()).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load song: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "song not found")
+	}
+	return loadSongTags(ctx, db, req.GetId())
+}
+
+func (s *SongService) AddSongTag(ctx context.Context, req *proto.SongTagRequest) (*proto.SongTags, error) {
+	tag, db, err := prepareTagEdit(ctx, req)
+	if err != nil {
+		return nil, err
+	}
+	if _, err := db.ExecContext(ctx, `
+		INSERT INTO song_tag (song_id, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING
+	`, req.GetSongId(), tag); err != nil {
+		return nil, status.Errorf(codes.Internal, "add tag: %v", err)
+	}
+	return loadSongTags(ctx, db, req.GetSongId())
+}
+
+func (s *SongService) RemoveSongTag(ctx context.Context, req *proto.SongTagRequest) (*proto.SongTags, error) {
+	tag, db, err := prepareTagEdit(ctx, req)
+	if err != nil {
+		return nil, err
+	}
+	if _, err := db.ExecContext(ctx, `
+		DELETE FROM song_tag WHERE song_id = $1 AND tag = $2
+	`, req.GetSongId(), tag); err != nil {
+		return nil, status.Errorf(codes.Internal, "remove tag: %v", err)
+	}
+	return loadSongTags(ctx, db, req.GetSongId())
+}
+
+// validateTag checks a tag that has already gone through normalizeTag.
+func validateTag(v *helpers.Violations, tag string) {
+	v.Check(tag != "", "tag", "must not be empty")
+	v.Check(utf8.RuneCountInString(tag) <= maxTagLength, "tag", "must be at most 50 characters")
+	v.CheckText("tag", tag, false)
+}
+
+// prepareTagEdit validates the tag and checks that the caller may edit the song.
+func prepareTagEdit(ctx context.Context, req *proto.SongTagRequest) (string, *sql.DB, error) {
+	tag := normalizeTag(req.GetTag())
+	v := &helpers.Violations{}
+	v.Check(req.GetSongId() != "", "song_id", "must not be empty")
+	validateTag(v, tag)
+	if err := v.Err(); err != nil {
+		return "", nil, err
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return "", nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return "", nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return "", nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if err := authorizeSongEdit(ctx, db, perms, req.GetSongId(), userID, "tag"); err != nil {
+		return "", nil, err
+	}
+	return tag, db, nil
+}
+
+func loadSongTags(ctx context.Context, db *sql.DB, songID string) (*proto.SongTags, error) {
+	rows, err := db.QueryContext(ctx, `SELECT tag FROM song_tag WHERE song_id = $1 ORDER BY tag`, songID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "list tags: %v", err)
+	}
+	defer rows.Close()
+
+	resp := &proto.SongTags{SongId: songID}
+	for rows.Next() {
+		var tag string
+		if err := rows.Scan(&tag); err != nil {
+			return nil, status.Errorf(codes.Internal, "scan tag: %v", err)
+		}
+		resp.Tags = append(resp.Tags, tag)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, status.Errorf(codes.Internal, "iterate tags: %v", err)
+	}
+	return resp, nil
+}
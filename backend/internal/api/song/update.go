@@ -2,7 +2,6 @@ package song
 
 import (
 	"context"
-	"database/sql"
 	"musicclubbot/backend/internal/helpers"
 	"musicclubbot/backend/proto"
 
@@ -11,6 +10,12 @@ import (
 )
 
 func (s *SongService) UpdateSong(ctx context.Context, req *proto.UpdateSongRequest) (*proto.SongDetails, error) {
+	v := &helpers.Violations{}
+	v.CheckUUID("id", req.GetId())
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+
 	userID, err := helpers.UserIDFromCtx(ctx)
 	if err != nil {
 		return nil, err
@@ -24,25 +29,31 @@ func (s *SongService) UpdateSong(ctx context.Context, req *proto.UpdateSongReque
 		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
 	}
 
-	var creatorID sql.NullString
-	row := db.QueryRowContext(ctx, `SELECT COALESCE(created_by, NULL) FROM song WHERE id = $1`, req.GetId())
-	if err := row.Scan(&creatorID); err != nil {
-		if err == sql.ErrNoRows {
-			return nil, status.Error(codes.NotFound, "song not found")
+	if err := authorizeSongEdit(ctx, db, perms, req.GetId(), userID, "edit"); err != nil {
+		return nil, err
+	}
+
+	setNotes := false
+	for _, path := range req.GetUpdateMask().GetPaths() {
+		if path != "notes" {
+			return nil, status.Errorf(codes.InvalidArgument, "update_mask: unsupported path %q", path)
 		}
-		return nil, status.Errorf(codes.Internal, "load song: %v", err)
+		setNotes = true
 	}
-	if !helpers.PermissionAllowsSongEdit(perms, creatorID, userID) {
-		return nil, status.Error(codes.PermissionDenied, "no rights to edit song")
+	if setNotes && !helpers.PermissionIsAdmin(perms) {
+		return nil, status.Error(codes.PermissionDenied, "only admins may set notes")
 	}
 
-	linkKind, err := helpers.MapSongLinkKindToDB(req.GetLink().GetKind())
-	if err != nil {
-		return nil, status.Error(codes.InvalidArgument, err.Error())
+	validateSongFields(v, req.GetTitle(), req.GetArtist(), req.GetDescription(), req.GetLink(), req.GetAvailableRoles(), req.GetDurationSeconds())
+	v.CheckText("notes", req.GetNotes(), true)
+	if err := v.Err(); err != nil {
+		return nil, err
 	}
+	linkKind, _ := helpers.MapSongLinkKindToDB(req.GetLink().GetKind())
+	linkURL := helpers.CanonicalizeLinkURL(linkKind, req.GetLink().GetUrl())
 
 	// Auto-extract or use custom thumbnail URL
-	thumbnailURL := helpers.NormalizeThumbnailURL(req.GetThumbnailUrl(), linkKind, req.GetLink().GetUrl())
+	thumbnailURL := helpers.NormalizeThumbnailURL(req.GetThumbnailUrl(), linkKind, linkURL)
 
 	tx, err := db.BeginTx(ctx, nil)
 	if err != nil {
@@ -52,9 +63,10 @@ func (s *SongService) UpdateSong(ctx context.Context, req *proto.UpdateSongReque
 
 	if _, err := tx.ExecContext(ctx, `
 		UPDATE song
-		SET title = $1, artist = $2, description = $3, link_kind = $4, link_url = $5, thumbnail_url = $6, updated_at = NOW()
-		WHERE id = $7
-	`, req.GetTitle(), req.GetArtist(), req.GetDescription(), linkKind, req.GetLink().GetUrl(), thumbnailURL, req.GetId()); err != nil {
+		SET title = $1, artist = $2, description = $3, link_kind = $4, link_url = $5, thumbnail_url = $6, duration_seconds = $7,
+		    notes = CASE WHEN $9 THEN NULLIF($10, '') ELSE notes END, updated_at = NOW()
+		WHERE id = $8
+	`, req.GetTitle(), req.GetArtist(), req.GetDescription(), linkKind, linkURL, thumbnailURL, nullIfZero(req.GetDurationSeconds()), req.GetId(), setNotes, req.GetNotes()); err != nil {
 		return nil, status.Errorf(codes.Internal, "update song: %v", err)
 	}
 
@@ -66,5 +78,6 @@ func (s *SongService) UpdateSong(ctx context.Context, req *proto.UpdateSongReque
 		return nil, status.Errorf(codes.Internal, "commit: %v", err)
 	}
 
+	helpers.InvalidateSong(ctx, req.GetId())
 	return helpers.LoadSongDetails(ctx, db, req.GetId(), userID)
 }
@@ -0,0 +1,82 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) UpdateRole(ctx context.Context, req *proto.UpdateRoleRequest) (*proto.SongDetails, error) {
+	key := req.GetKey()
+	v := &helpers.Violations{}
+	v.Check(key.GetSongId() != "", "key.song_id", "must not be empty")
+	v.Check(key.GetRole() != "", "key.role", "must not be empty")
+	v.Check(key.GetUserId() != "", "key.user_id", "must not be empty")
+	hasNewStatus := req.GetNewStatus() != proto.ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+	v.Check(req.GetNewRole() != "" || hasNewStatus, "new_role", "must not be empty unless new_status is set")
+	if req.GetNewRole() != "" {
+		v.CheckRole("new_role", req.GetNewRole())
+	}
+	var newStatus sql.NullString
+	if hasNewStatus {
+		dbStatus, err := helpers.MapParticipationStatusToDB(req.GetNewStatus())
+		if err != nil {
+			v.Add("new_status", err.Error())
+		}
+		newStatus = sql.NullString{String: dbStatus, Valid: err == nil}
+	}
+	if err := v.Err(); err != nil {
+		return nil, err
+	}
+	newRole := req.GetNewRole()
+	if newRole == "" {
+		newRole = key.GetRole()
+	}
+
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := helpers.LoadPermissions(ctx, db, userID)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "load permissions: %v", err)
+	}
+	if !helpers.PermissionAllowsJoinEdit(perms, key.GetUserId(), userID) {
+		return nil, status.Error(codes.PermissionDenied, "no rights to update role")
+	}
+
+	var roleExists bool
+	if err := db.QueryRowContext(ctx, `
+		SELECT EXISTS (SELECT 1 FROM song_role WHERE song_id = $1 AND role = $2)
+	`, key.GetSongId(), newRole).Scan(&roleExists); err != nil {
+		return nil, status.Errorf(codes.Internal, "check role: %v", err)
+	}
+	if !roleExists {
+		return nil, status.Error(codes.InvalidArgument, "role is not available for this song")
+	}
+
+	res, err := db.ExecContext(ctx, `
+		UPDATE song_role_assignment SET role = $1, status = COALESCE($5, status), updated_at = NOW()
+		WHERE song_id = $2 AND role = $3 AND user_id = $4
+	`, newRole, key.GetSongId(), key.GetRole(), key.GetUserId(), newStatus)
+	if err != nil {
+		if helpers.IsUniqueViolation(err) {
+			return nil, status.Error(codes.AlreadyExists, "user already holds this role")
+		}
+		return nil, status.Errorf(codes.Internal, "update role: %v", err)
+	}
+	if n, err := res.RowsAffected(); err == nil && n == 0 {
+		return nil, status.Error(codes.NotFound, "role assignment not found")
+	}
+
+	helpers.InvalidateSong(ctx, key.GetSongId())
+	return helpers.LoadSongDetails(ctx, db, key.GetSongId(), userID)
+}
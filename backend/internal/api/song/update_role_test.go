@@ -0,0 +1,138 @@
+package song
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"github.com/lib/pq"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const (
+	testSongID  = "11111111-1111-1111-1111-111111111111"
+	testUserID  = "22222222-2222-2222-2222-222222222222"
+	otherUserID = "33333333-3333-3333-3333-333333333333"
+)
+
+func testCtx(db *sql.DB, userID string) context.Context {
+	ctx := context.WithValue(context.Background(), "db", db)
+	return context.WithValue(ctx, "user_id", userID)
+}
+
+func testSongRow() []any {
+	return []any{testSongID, "Song", "Artist", "", "youtube", "", testUserID, "", nil, ""}
+}
+
+func roleRequest(newRole string) *proto.UpdateRoleRequest {
+	return &proto.UpdateRoleRequest{
+		Key:     &proto.RoleAssignmentKey{SongId: testSongID, Role: "guitar", UserId: testUserID},
+		NewRole: newRole,
+	}
+}
+
+func TestUpdateRoleRejectsIncompleteRequests(t *testing.T) {
+	tests := []struct {
+		name string
+		req  *proto.UpdateRoleRequest
+	}{
+		{"no key", &proto.UpdateRoleRequest{NewRole: "bass"}},
+		{"no song", &proto.UpdateRoleRequest{Key: &proto.RoleAssignmentKey{Role: "guitar", UserId: testUserID}, NewRole: "bass"}},
+		{"no role", &proto.UpdateRoleRequest{Key: &proto.RoleAssignmentKey{SongId: testSongID, UserId: testUserID}, NewRole: "bass"}},
+		{"no new values", roleRequest("")},
+		{"invisible new role", roleRequest("ba\u200bss")},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			db, _ := dbtest.New(t)
+			_, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), tt.req)
+			if status.Code(err) != codes.InvalidArgument {
+				t.Fatalf("UpdateRole = %v, want InvalidArgument", err)
+			}
+		})
+	}
+}
+
+func TestUpdateRoleOfAnotherMemberIsDenied(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(otherUserID, dbtest.Member)
+
+	_, err := (&SongService{}).UpdateRole(testCtx(db, otherUserID), roleRequest("bass"))
+	if status.Code(err) != codes.PermissionDenied {
+		t.Fatalf("UpdateRole = %v, want PermissionDenied", err)
+	}
+}
+
+func TestUpdateRoleToUnknownRole(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("FROM song_role WHERE song_id = $1 AND role = $2").WithArgs(testSongID, "theremin").
+		WillReturnRows([]string{"exists"}, []any{false})
+
+	_, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), roleRequest("theremin"))
+	if status.Code(err) != codes.InvalidArgument {
+		t.Fatalf("UpdateRole = %v, want InvalidArgument", err)
+	}
+}
+
+func TestUpdateRoleOntoHeldRole(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("FROM song_role WHERE song_id = $1 AND role = $2").WillReturnRows([]string{"exists"}, []any{true})
+	mock.ExpectExec("UPDATE song_role_assignment SET role = $1").WillReturnError(&pq.Error{Code: "23505"})
+
+	_, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), roleRequest("bass"))
+	if status.Code(err) != codes.AlreadyExists {
+		t.Fatalf("UpdateRole = %v, want AlreadyExists", err)
+	}
+}
+
+func TestUpdateRoleOfMissingAssignment(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("FROM song_role WHERE song_id = $1 AND role = $2").WillReturnRows([]string{"exists"}, []any{true})
+	mock.ExpectExec("UPDATE song_role_assignment SET role = $1").WillReturnResult(0)
+
+	_, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), roleRequest("bass"))
+	if status.Code(err) != codes.NotFound {
+		t.Fatalf("UpdateRole = %v, want NotFound", err)
+	}
+}
+
+func TestUpdateRoleMovesAssignment(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("FROM song_role WHERE song_id = $1 AND role = $2").WithArgs(testSongID, "bass").
+		WillReturnRows([]string{"exists"}, []any{true})
+	// The key names the existing record; the new values travel separately.
+	mock.ExpectExec("UPDATE song_role_assignment SET role = $1").
+		WithArgs("bass", testSongID, "guitar", testUserID, nil).WillReturnResult(1)
+	mock.ExpectSongDetails(testSongRow(), testUserID, dbtest.Member)
+
+	details, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), roleRequest("bass"))
+	if err != nil {
+		t.Fatalf("UpdateRole: %v", err)
+	}
+	if details.GetSong().GetId() != testSongID {
+		t.Errorf("returned song %q, want %q", details.GetSong().GetId(), testSongID)
+	}
+}
+
+func TestUpdateRoleChangesOnlyStatus(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("FROM song_role WHERE song_id = $1 AND role = $2").WithArgs(testSongID, "guitar").
+		WillReturnRows([]string{"exists"}, []any{true})
+	mock.ExpectExec("UPDATE song_role_assignment SET role = $1").
+		WithArgs("guitar", testSongID, "guitar", testUserID, "tentative").WillReturnResult(1)
+	mock.ExpectSongDetails(testSongRow(), testUserID, dbtest.Member)
+
+	req := roleRequest("")
+	req.NewStatus = proto.ParticipationStatus_PARTICIPATION_STATUS_TENTATIVE
+	if _, err := (&SongService{}).UpdateRole(testCtx(db, testUserID), req); err != nil {
+		t.Fatalf("UpdateRole: %v", err)
+	}
+}
@@ -0,0 +1,94 @@
+package song
+
+import (
+	"musicclubbot/backend/internal/dbtest"
+	"musicclubbot/backend/proto"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/fieldmaskpb"
+)
+
+func songUpdate() *proto.UpdateSongRequest {
+	return &proto.UpdateSongRequest{
+		Id:     testSongID,
+		Title:  "Song",
+		Artist: "Artist",
+		Link:   &proto.SongLink{Kind: proto.SongLinkType_SONG_LINK_TYPE_YOUTUBE},
+	}
+}
+
+// expectSongUpdate expects the UPDATE of songUpdate with the given notes arguments.
+func expectSongUpdate(mock *dbtest.Mock, setNotes bool, notes string) {
+	mock.ExpectBegin()
+	mock.ExpectExec("UPDATE song").
+		WithArgs("Song", "Artist", "", "youtube", "", "", nil, testSongID, setNotes, notes).WillReturnResult(1)
+	mock.ExpectExec("DELETE FROM song_role WHERE song_id = $1").WithArgs(testSongID).WillReturnResult(0)
+	mock.ExpectCommit()
+}
+
+func TestUpdateSongLeavesNotesUnlessMasked(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+	mock.ExpectQuery("SELECT created_by FROM song WHERE id = $1").WillReturnRows([]string{"created_by"}, []any{testUserID})
+	expectSongUpdate(mock, false, "")
+	mock.ExpectSongDetails(testSongRow(), testUserID, dbtest.Admin)
+
+	if _, err := (&SongService{}).UpdateSong(testCtx(db, testUserID), songUpdate()); err != nil {
+		t.Fatalf("UpdateSong: %v", err)
+	}
+}
+
+func TestUpdateSongWritesMaskedNotes(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+	mock.ExpectQuery("SELECT created_by FROM song WHERE id = $1").WillReturnRows([]string{"created_by"}, []any{testUserID})
+	expectSongUpdate(mock, true, "bring a capo")
+	mock.ExpectSongDetails(testSongRow(), testUserID, dbtest.Admin)
+
+	req := songUpdate()
+	req.Notes = "bring a capo"
+	req.UpdateMask = &fieldmaskpb.FieldMask{Paths: []string{"notes"}}
+	if _, err := (&SongService{}).UpdateSong(testCtx(db, testUserID), req); err != nil {
+		t.Fatalf("UpdateSong: %v", err)
+	}
+}
+
+func TestUpdateSongNotesNeedAdmin(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Member)
+	mock.ExpectQuery("SELECT created_by FROM song WHERE id = $1").WillReturnRows([]string{"created_by"}, []any{testUserID})
+
+	req := songUpdate()
+	req.UpdateMask = &fieldmaskpb.FieldMask{Paths: []string{"notes"}}
+	_, err := (&SongService{}).UpdateSong(testCtx(db, testUserID), req)
+	if status.Code(err) != codes.PermissionDenied {
+		t.Fatalf("UpdateSong = %v, want PermissionDenied", err)
+	}
+}
+
+func TestUpdateSongRejectsUnknownMaskPath(t *testing.T) {
+	db, mock := dbtest.New(t)
+	mock.ExpectPermissions(testUserID, dbtest.Admin)
+	mock.ExpectQuery("SELECT created_by FROM song WHERE id = $1").WillReturnRows([]string{"created_by"}, []any{testUserID})
+
+	req := songUpdate()
+	req.UpdateMask = &fieldmaskpb.FieldMask{Paths: []string{"title"}}
+	_, err := (&SongService{}).UpdateSong(testCtx(db, testUserID), req)
+	if status.Code(err) != codes.InvalidArgument {
+		t.Fatalf("UpdateSong = %v, want InvalidArgument", err)
+	}
+}
+
+func TestUpdateSongValidatesIDFirst(t *testing.T) {
+	for _, id := range []string{"", "not-a-uuid"} {
+		db, _ := dbtest.New(t)
+		req := songUpdate()
+		req.Id = id
+		_, err := (&SongService{}).UpdateSong(testCtx(db, testUserID), req)
+		if status.Code(err) != codes.InvalidArgument {
+			t.Errorf("UpdateSong(id %q) = %v, want InvalidArgument", id, err)
+		}
+	}
+}
@@ -0,0 +1,36 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) ValidateSong(ctx context.Context, req *proto.CreateSongRequest) (*proto.ValidateSongResponse, error) {
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	v := &helpers.Violations{}
+	validateSongFields(v, req.GetTitle(), req.GetArtist(), req.GetDescription(), req.GetLink(), req.GetAvailableRoles(), req.GetDurationSeconds())
+	v.CheckText("notes", req.GetNotes(), true)
+
+	// CreateSong accepts duplicates, but a form should warn about them.
+	var duplicate bool
+	if err := db.QueryRowContext(ctx, `
+		SELECT EXISTS (SELECT 1 FROM song WHERE LOWER(title) = LOWER($1) AND LOWER(artist) = LOWER($2))
+	`, req.GetTitle(), req.GetArtist()).Scan(&duplicate); err != nil {
+		return nil, status.Errorf(codes.Internal, "check duplicates: %v", err)
+	}
+	v.Check(!duplicate, "title", "a song with this title and artist already exists")
+
+	resp := &proto.ValidateSongResponse{}
+	for _, f := range v.Fields() {
+		resp.Violations = append(resp.Violations, &proto.FieldViolation{Field: f.GetField(), Description: f.GetDescription()})
+	}
+	return resp, nil
+}
@@ -0,0 +1,48 @@
+package song
+
+import (
+	"context"
+	"musicclubbot/backend/internal/helpers"
+	"musicclubbot/backend/proto"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (s *SongService) VoteSong(ctx context.Context, req *proto.SongId) (*proto.SongDetails, error) {
+	return s.setVote(ctx, req.GetId(), `
+		INSERT INTO song_vote (song_id, user_id) VALUES ($1, $2)
+		ON CONFLICT (song_id, user_id) DO NOTHING
+	`)
+}
+
+func (s *SongService) UnvoteSong(ctx context.Context, req *proto.SongId) (*proto.SongDetails, error) {
+	return s.setVote(ctx, req.GetId(), `DELETE FROM song_vote WHERE song_id = $1 AND user_id = $2`)
+}
+
+// setVote runs query with the song and the current user and returns the updated song.
+func (s *SongService) setVote(ctx context.Context, songID, query string) (*proto.SongDetails, error) {
+	userID, err := helpers.UserIDFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+	db, err := helpers.DbFromCtx(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var exists bool
+	if err := db.QueryRowContext(ctx, `SELECT EXISTS (SELECT 1 FROM song WHERE id = $1)`, songID).Scan(&exists); err != nil {
+		return nil, status.Errorf(codes.Internal, "load song: %v", err)
+	}
+	if !exists {
+		return nil, status.Error(codes.NotFound, "song not found")
+	}
+
+	if _, err := db.ExecContext(ctx, query, songID, userID); err != nil {
+		return nil, status.Errorf(codes.Internal, "vote: %v", err)
+	}
+
+	helpers.InvalidateSong(ctx, songID)
+	return helpers.LoadSongDetails(ctx, db, songID, userID)
+}
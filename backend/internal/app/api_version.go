@@ -0,0 +1,71 @@
+package app
+
+import (
+	"context"
+	"strconv"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+const apiVersionHeader = "x-api-version"
+
+// newAPIVersionInterceptor rejects clients whose x-api-version is below minVersion.
+// Requests without the header are treated as legacy clients and only rejected
+// when requireHeader is set. A non-positive minVersion disables the check.
+func newAPIVersionInterceptor(minVersion int, requireHeader bool) grpc.UnaryServerInterceptor {
+	return func(
+		ctx context.Context,
+		req any,
+		_ *grpc.UnaryServerInfo,
+		handler grpc.UnaryHandler,
+	) (any, error) {
+		if err := checkAPIVersion(ctx, minVersion, requireHeader); err != nil {
+			return nil, err
+		}
+		return handler(ctx, req)
+	}
+}
+
+// newAPIVersionStreamInterceptor is the streaming counterpart of newAPIVersionInterceptor.
+func newAPIVersionStreamInterceptor(minVersion int, requireHeader bool) grpc.StreamServerInterceptor {
+	return func(
+		srv any,
+		ss grpc.ServerStream,
+		_ *grpc.StreamServerInfo,
+		handler grpc.StreamHandler,
+	) error {
+		if err := checkAPIVersion(ss.Context(), minVersion, requireHeader); err != nil {
+			return err
+		}
+		return handler(srv, ss)
+	}
+}
+
+func checkAPIVersion(ctx context.Context, minVersion int, requireHeader bool) error {
+	if minVersion <= 0 {
+		return nil
+	}
+
+	var values []string
+	if md, ok := metadata.FromIncomingContext(ctx); ok {
+		values = md.Get(apiVersionHeader)
+	}
+	if len(values) == 0 {
+		if requireHeader {
+			return status.Errorf(codes.FailedPrecondition, "missing %s header, minimum supported version is %d", apiVersionHeader, minVersion)
+		}
+		return nil
+	}
+
+	version, err := strconv.Atoi(values[0])
+	if err != nil {
+		return status.Errorf(codes.InvalidArgument, "malformed %s header %q", apiVersionHeader, values[0])
+	}
+	if version < minVersion {
+		return status.Errorf(codes.FailedPrecondition, "client api version %d is no longer supported, minimum is %d", version, minVersion)
+	}
+	return nil
+}
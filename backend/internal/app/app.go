@@ -16,9 +16,10 @@ import (
 	"musicclubbot/backend/internal/api"
 	"musicclubbot/backend/internal/api/auth"
 	"musicclubbot/backend/internal/config"
+	"musicclubbot/backend/internal/helpers"
 )
 
-var propagatedCtxKeys = []string{"cfg", "log", "db"}
+var propagatedCtxKeys = []string{"cfg", "log", "db", "cache", "linkcheck"}
 
 func Run(ctx context.Context) error {
 	cfg := mustCfg(ctx)
@@ -29,45 +30,70 @@ func Run(ctx context.Context) error {
 		return fmt.Errorf("listen on %s: %w", cfg.GRPCAddr(), err)
 	}
 
-	grpcServer := newGrpcServer(ctx)
+	inFlight := &inFlightCounter{}
+	grpcServer := newGrpcServer(ctx, inFlight)
 	api.Register(grpcServer)
+	registerHealth(ctx, grpcServer)
+	if err := helpers.CheckMethodAccess(grpcServer.GetServiceInfo()); err != nil {
+		return err
+	}
 	reflection.Register(grpcServer)
 
 	httpServer := &http.Server{
-		Handler: newHTTPHandler(grpcServer),
+		Handler: newHTTPHandler(grpcServer, newCORSPolicy(cfg)),
 	}
 
-	go gracefulShutdown(ctx, grpcServer, httpServer)
+	drained := make(chan struct{})
+	go func() {
+		gracefulShutdown(ctx, grpcServer, httpServer, cfg.ShutdownGrace, inFlight)
+		close(drained)
+	}()
 
 	log.Infof("Starting gRPC server on %s", cfg.GRPCAddr())
 	if err := httpServer.Serve(lis); err != nil && err != http.ErrServerClosed {
 		return fmt.Errorf("serve gRPC/gRPC-Web: %w", err)
 	}
 
+	// Serve returns as soon as shutdown starts; wait for in-flight calls to drain.
+	<-drained
 	return nil
 }
 
 /* -------------------- helpers -------------------- */
 
-func newGrpcServer(baseCtx context.Context) *grpc.Server {
+func newGrpcServer(baseCtx context.Context, inFlight *inFlightCounter) *grpc.Server {
 	return grpc.NewServer(
 		grpc.ChainUnaryInterceptor(
+			inFlight.interceptor,
 			withBaseContext(baseCtx),
-			loggingInterceptor,
+			newRequestIDInterceptor(),
+			newLoggingInterceptor(mustCfg(baseCtx).RequestLogLevel, mustCfg(baseCtx).TrustedProxies),
+			newAPIVersionInterceptor(mustCfg(baseCtx).MinAPIVersion, mustCfg(baseCtx).RequireAPIVersion),
+			newConcurrencyInterceptor(mustCfg(baseCtx).MethodConcurrency),
+			newCompressionInterceptor(mustCfg(baseCtx).CompressResponses),
 			auth.AuthInterceptor,
 		),
+		grpc.ChainStreamInterceptor(
+			inFlight.streamInterceptor,
+			withBaseStreamContext(baseCtx),
+			newRequestIDStreamInterceptor(),
+			newLoggingStreamInterceptor(mustCfg(baseCtx).RequestLogLevel, mustCfg(baseCtx).TrustedProxies),
+			newAPIVersionStreamInterceptor(mustCfg(baseCtx).MinAPIVersion, mustCfg(baseCtx).RequireAPIVersion),
+			newConcurrencyStreamInterceptor(mustCfg(baseCtx).MethodConcurrency),
+			auth.AuthStreamInterceptor,
+		),
 	)
 }
 
-func newHTTPHandler(grpcServer *grpc.Server) http.Handler {
+func newHTTPHandler(grpcServer *grpc.Server, cors corsPolicy) http.Handler {
 	grpcWeb := grpcweb.WrapServer(
 		grpcServer,
-		grpcweb.WithOriginFunc(func(string) bool { return true }),
+		grpcweb.WithOriginFunc(cors.allowsOrigin),
 	)
 
 	return h2c.NewHandler(
 		http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
-			if handlePreflight(w, r) {
+			if cors.handlePreflight(w, r) {
 				return
 			}
 
@@ -82,12 +108,6 @@ func newHTTPHandler(grpcServer *grpc.Server) http.Handler {
 	)
 }
 
-func gracefulShutdown(ctx context.Context, grpcServer *grpc.Server, httpServer *http.Server) {
-	<-ctx.Done()
-	grpcServer.GracefulStop()
-	_ = httpServer.Shutdown(context.Background())
-}
-
 func withBaseContext(base context.Context) grpc.UnaryServerInterceptor {
 	return func(
 		ctx context.Context,
@@ -105,6 +125,23 @@ func withBaseContext(base context.Context) grpc.UnaryServerInterceptor {
 
 }
 
+func withBaseStreamContext(base context.Context) grpc.StreamServerInterceptor {
+	return func(
+		srv interface{},
+		ss grpc.ServerStream,
+		_ *grpc.StreamServerInfo,
+		handler grpc.StreamHandler,
+	) error {
+		ctx := ss.Context()
+		for _, key := range propagatedCtxKeys {
+			if v := base.Value(key); v != nil {
+				ctx = context.WithValue(ctx, key, v)
+			}
+		}
+		return handler(srv, helpers.WithStreamContext(ss, ctx))
+	}
+}
+
 func mustCfg(ctx context.Context) config.Config {
 	return ctx.Value("cfg").(config.Config)
 }
@@ -113,21 +150,6 @@ func mustLog(ctx context.Context) *logger.Logger {
 	return ctx.Value("log").(*logger.Logger)
 }
 
-func handlePreflight(w http.ResponseWriter, r *http.Request) bool {
-	if r.Method != http.MethodOptions {
-		return false
-	}
-
-	w.Header().Set("Access-Control-Allow-Origin", "*")
-	w.Header().Set("Access-Control-Allow-Methods", "POST, OPTIONS")
-	w.Header().Set(
-		"Access-Control-Allow-Headers",
-		"Content-Type, X-Grpc-Web, X-User-Agent, Authorization",
-	)
-	w.WriteHeader(http.StatusNoContent)
-	return true
-}
-
 func isGrpcWebRequest(gw *grpcweb.WrappedGrpcServer, r *http.Request) bool {
 	return gw.IsGrpcWebRequest(r) ||
 		gw.IsGrpcWebSocketRequest(r) ||
@@ -0,0 +1,67 @@
+package app
+
+import (
+	"context"
+	"net"
+	"strings"
+
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/peer"
+)
+
+// clientIP returns the address of the caller. Forwarding headers are only
+// believed when the socket peer is one of the trusted proxies; otherwise a
+// client could claim any address by setting them itself.
+func clientIP(ctx context.Context, trusted []*net.IPNet) string {
+	socketIP := peerIP(ctx)
+	if socketIP == nil {
+		return ""
+	}
+	if !isTrusted(socketIP, trusted) {
+		return socketIP.String()
+	}
+
+	md, _ := metadata.FromIncomingContext(ctx)
+	// X-Forwarded-For lists hops left to right; the rightmost entry that is
+	// not one of our proxies is the first address we did not add ourselves.
+	var hops []string
+	for _, v := range md.Get("x-forwarded-for") {
+		hops = append(hops, strings.Split(v, ",")...)
+	}
+	for i := len(hops) - 1; i >= 0; i-- {
+		ip := net.ParseIP(strings.TrimSpace(hops[i]))
+		if ip == nil {
+			break
+		}
+		if !isTrusted(ip, trusted) || i == 0 {
+			return ip.String()
+		}
+	}
+	if values := md.Get("x-real-ip"); len(values) > 0 {
+		if ip := net.ParseIP(strings.TrimSpace(values[0])); ip != nil {
+			return ip.String()
+		}
+	}
+	return socketIP.String()
+}
+
+func peerIP(ctx context.Context) net.IP {
+	p, ok := peer.FromContext(ctx)
+	if !ok || p.Addr == nil {
+		return nil
+	}
+	host, _, err := net.SplitHostPort(p.Addr.String())
+	if err != nil {
+		host = p.Addr.String()
+	}
+	return net.ParseIP(host)
+}
+
+func isTrusted(ip net.IP, trusted []*net.IPNet) bool {
+	for _, n := range trusted {
+		if n.Contains(ip) {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,52 @@
+package app
+
+import (
+	"context"
+	"net"
+	"testing"
+
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/peer"
+)
+
+func TestClientIP(t *testing.T) {
+	_, loopback, _ := net.ParseCIDR("127.0.0.0/8")
+	_, docker, _ := net.ParseCIDR("172.16.0.0/12")
+	trusted := []*net.IPNet{loopback, docker}
+
+	tests := []struct {
+		name string
+		peer string
+		md   []string
+		want string
+	}{
+		{"direct client", "203.0.113.7:5000", nil, "203.0.113.7"},
+		{"untrusted peer cannot spoof", "203.0.113.7:5000", []string{"x-forwarded-for", "198.51.100.1", "x-real-ip", "198.51.100.2"}, "203.0.113.7"},
+		{"trusted proxy without headers", "127.0.0.1:5000", nil, "127.0.0.1"},
+		{"trusted proxy forwards", "172.17.0.2:5000", []string{"x-forwarded-for", "198.51.100.1"}, "198.51.100.1"},
+		{"rightmost untrusted hop wins", "172.17.0.2:5000", []string{"x-forwarded-for", "1.2.3.4, 198.51.100.1, 172.17.0.5"}, "198.51.100.1"},
+		{"only proxies in chain", "127.0.0.1:5000", []string{"x-forwarded-for", "172.17.0.9, 127.0.0.1"}, "172.17.0.9"},
+		{"malformed hop falls back to x-real-ip", "127.0.0.1:5000", []string{"x-forwarded-for", "garbage", "x-real-ip", "198.51.100.2"}, "198.51.100.2"},
+		{"x-real-ip from trusted proxy", "127.0.0.1:5000", []string{"x-real-ip", "198.51.100.2"}, "198.51.100.2"},
+		{"ipv6 peer", "[2001:db8::1]:5000", nil, "2001:db8::1"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			addr, err := net.ResolveTCPAddr("tcp", tt.peer)
+			if err != nil {
+				t.Fatal(err)
+			}
+			ctx := peer.NewContext(context.Background(), &peer.Peer{Addr: addr})
+			if tt.md != nil {
+				ctx = metadata.NewIncomingContext(ctx, metadata.Pairs(tt.md...))
+			}
+			if got := clientIP(ctx, trusted); got != tt.want {
+				t.Errorf("clientIP = %q, want %q", got, tt.want)
+			}
+		})
+	}
+
+	if got := clientIP(context.Background(), trusted); got != "" {
+		t.Errorf("clientIP without a peer = %q, want empty", got)
+	}
+}
@@ -0,0 +1,27 @@
+package app
+
+import (
+	"context"
+	"slices"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/encoding/gzip"
+)
+
+// newCompressionInterceptor gzips responses for clients that advertise gzip
+// in grpc-accept-encoding. Clients that do not ask for it get plain responses.
+func newCompressionInterceptor(enabled bool) grpc.UnaryServerInterceptor {
+	return func(
+		ctx context.Context,
+		req any,
+		_ *grpc.UnaryServerInfo,
+		handler grpc.UnaryHandler,
+	) (any, error) {
+		if enabled {
+			if accepted, err := grpc.ClientSupportedCompressors(ctx); err == nil && slices.Contains(accepted, gzip.Name) {
+				_ = grpc.SetSendCompressor(ctx, gzip.Name)
+			}
+		}
+		return handler(ctx, req)
+	}
+}
@@ -0,0 +1,75 @@
+package app
+
+import (
+	"context"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// newConcurrencyInterceptor caps the number of in-flight calls per method.
+// Calls over the limit fail fast with ResourceExhausted instead of queueing;
+// methods without a configured limit are not restricted.
+func newConcurrencyInterceptor(limits map[string]int) grpc.UnaryServerInterceptor {
+	semaphores := newSemaphores(limits)
+
+	return func(
+		ctx context.Context,
+		req any,
+		info *grpc.UnaryServerInfo,
+		handler grpc.UnaryHandler,
+	) (any, error) {
+		release, err := acquire(semaphores, info.FullMethod)
+		if err != nil {
+			return nil, err
+		}
+		defer release()
+
+		return handler(ctx, req)
+	}
+}
+
+// newConcurrencyStreamInterceptor is the streaming counterpart of
+// newConcurrencyInterceptor; a slot is held until the stream ends.
+func newConcurrencyStreamInterceptor(limits map[string]int) grpc.StreamServerInterceptor {
+	semaphores := newSemaphores(limits)
+
+	return func(
+		srv any,
+		ss grpc.ServerStream,
+		info *grpc.StreamServerInfo,
+		handler grpc.StreamHandler,
+	) error {
+		release, err := acquire(semaphores, info.FullMethod)
+		if err != nil {
+			return err
+		}
+		defer release()
+
+		return handler(srv, ss)
+	}
+}
+
+func newSemaphores(limits map[string]int) map[string]chan struct{} {
+	semaphores := make(map[string]chan struct{}, len(limits))
+	for method, n := range limits {
+		semaphores[method] = make(chan struct{}, n)
+	}
+	return semaphores
+}
+
+// acquire takes a slot for method, returning the func that gives it back.
+func acquire(semaphores map[string]chan struct{}, method string) (func(), error) {
+	sem, ok := semaphores[method]
+	if !ok {
+		return func() {}, nil
+	}
+
+	select {
+	case sem <- struct{}{}:
+		return func() { <-sem }, nil
+	default:
+		return nil, status.Errorf(codes.ResourceExhausted, "too many concurrent %s calls", method)
+	}
+}
@@ -0,0 +1,60 @@
+package app
+
+import (
+	"context"
+	"testing"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+type fakeStream struct {
+	grpc.ServerStream
+	ctx context.Context
+}
+
+func (s fakeStream) Context() context.Context { return s.ctx }
+
+func TestConcurrencyStreamInterceptorLimitsStreams(t *testing.T) {
+	const method = "/musicclub.song.SongService/StreamSearchSongs"
+	intercept := newConcurrencyStreamInterceptor(map[string]int{method: 1})
+	info := &grpc.StreamServerInfo{FullMethod: method}
+	ss := fakeStream{ctx: context.Background()}
+
+	var nested error
+	err := intercept(nil, ss, info, func(any, grpc.ServerStream) error {
+		nested = intercept(nil, ss, info, func(any, grpc.ServerStream) error { return nil })
+		return nil
+	})
+	if err != nil {
+		t.Fatalf("first stream: %v", err)
+	}
+	if status.Code(nested) != codes.ResourceExhausted {
+		t.Errorf("second stream = %v, want ResourceExhausted", nested)
+	}
+
+	// The slot is released once the stream ends.
+	if err := intercept(nil, ss, info, func(any, grpc.ServerStream) error { return nil }); err != nil {
+		t.Errorf("stream after release: %v", err)
+	}
+}
+
+func TestAPIVersionStreamInterceptor(t *testing.T) {
+	intercept := newAPIVersionStreamInterceptor(2, false)
+	info := &grpc.StreamServerInfo{FullMethod: "/musicclub.song.SongService/StreamSearchSongs"}
+	handler := func(any, grpc.ServerStream) error { return nil }
+
+	old := metadata.NewIncomingContext(context.Background(), metadata.Pairs(apiVersionHeader, "1"))
+	if err := intercept(nil, fakeStream{ctx: old}, info, handler); status.Code(err) != codes.FailedPrecondition {
+		t.Errorf("version 1 = %v, want FailedPrecondition", err)
+	}
+	current := metadata.NewIncomingContext(context.Background(), metadata.Pairs(apiVersionHeader, "2"))
+	if err := intercept(nil, fakeStream{ctx: current}, info, handler); err != nil {
+		t.Errorf("version 2: %v", err)
+	}
+	if err := intercept(nil, fakeStream{ctx: context.Background()}, info, handler); err != nil {
+		t.Errorf("no header: %v", err)
+	}
+}
@@ -0,0 +1,56 @@
+package app
+
+import (
+	"net/http"
+	"slices"
+	"strings"
+
+	"musicclubbot/backend/internal/config"
+)
+
+// corsPolicy answers browser preflights and decides which origins may call
+// the gRPC-Web endpoint.
+type corsPolicy struct {
+	origins []string
+	methods string
+	headers string
+}
+
+func newCORSPolicy(cfg config.Config) corsPolicy {
+	return corsPolicy{
+		origins: cfg.CORSAllowedOrigins,
+		methods: strings.Join(cfg.CORSAllowedMethods, ", "),
+		headers: strings.Join(cfg.CORSAllowedHeaders, ", "),
+	}
+}
+
+func (p corsPolicy) allowsAnyOrigin() bool {
+	return slices.Contains(p.origins, "*")
+}
+
+func (p corsPolicy) allowsOrigin(origin string) bool {
+	return p.allowsAnyOrigin() || slices.Contains(p.origins, origin)
+}
+
+func (p corsPolicy) handlePreflight(w http.ResponseWriter, r *http.Request) bool {
+	if r.Method != http.MethodOptions {
+		return false
+	}
+
+	origin := r.Header.Get("Origin")
+	switch {
+	case p.allowsAnyOrigin():
+		w.Header().Set("Access-Control-Allow-Origin", "*")
+	case origin != "" && p.allowsOrigin(origin):
+		w.Header().Set("Access-Control-Allow-Origin", origin)
+		w.Header().Add("Vary", "Origin")
+	default:
+		// Unknown origins get no CORS headers, so the browser blocks the call.
+		w.WriteHeader(http.StatusNoContent)
+		return true
+	}
+	w.Header().Set("Access-Control-Allow-Methods", p.methods)
+	w.Header().Set("Access-Control-Allow-Headers", p.headers)
+	w.WriteHeader(http.StatusNoContent)
+	return true
+}
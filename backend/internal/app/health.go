@@ -0,0 +1,68 @@
+package app
+
+import (
+	"context"
+	"database/sql"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/health"
+	healthpb "google.golang.org/grpc/health/grpc_health_v1"
+
+	"musicclubbot/backend/internal/db"
+)
+
+// dbHealth turns individual ping results into a serving state. It starts
+// out not serving, needs threshold consecutive failures to stop serving and
+// a single success to serve again.
+type dbHealth struct {
+	threshold int
+	failures  int
+	serving   bool
+}
+
+// observe records a ping result and reports whether the serving state changed.
+func (h *dbHealth) observe(err error) bool {
+	if err == nil {
+		h.failures = 0
+		if h.serving {
+			return false
+		}
+		h.serving = true
+		return true
+	}
+	h.failures++
+	if !h.serving || h.failures < h.threshold {
+		return false
+	}
+	h.serving = false
+	return true
+}
+
+// registerHealth exposes grpc.health.v1.Health. The server reports
+// NOT_SERVING until the database answers and again after
+// HEALTH_FAILURE_THRESHOLD pings in a row have failed.
+func registerHealth(ctx context.Context, grpcServer *grpc.Server) {
+	healthServer := health.NewServer()
+	healthServer.SetServingStatus("", healthpb.HealthCheckResponse_NOT_SERVING)
+	healthpb.RegisterHealthServer(grpcServer, healthServer)
+
+	log := mustLog(ctx)
+	cfg := mustCfg(ctx)
+	conn := ctx.Value("db").(*sql.DB)
+	state := &dbHealth{threshold: cfg.HealthFailureThreshold}
+	go db.MonitorDbConnection(ctx, conn, cfg.HealthPingInterval, func(err error) {
+		if err != nil && state.serving {
+			log.Warningf("Database ping failed (%d/%d): %v", state.failures+1, state.threshold, err)
+		}
+		if !state.observe(err) {
+			return
+		}
+		if state.serving {
+			log.Infof("Database is reachable, serving")
+			healthServer.SetServingStatus("", healthpb.HealthCheckResponse_SERVING)
+		} else {
+			log.Errorf("Database became unreachable: %v", err)
+			healthServer.SetServingStatus("", healthpb.HealthCheckResponse_NOT_SERVING)
+		}
+	})
+}
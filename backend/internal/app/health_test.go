@@ -0,0 +1,55 @@
+package app
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestDBHealthObserve(t *testing.T) {
+	fail := errors.New("connection refused")
+	tests := []struct {
+		name      string
+		threshold int
+		pings     []error
+		// serving state and whether it changed after each ping
+		wantServing []bool
+		wantChanged []bool
+	}{
+		{
+			"starts not serving until a ping succeeds", 3,
+			[]error{fail, nil},
+			[]bool{false, true},
+			[]bool{false, true},
+		},
+		{
+			"stops serving after threshold failures in a row", 3,
+			[]error{nil, fail, fail, fail, fail},
+			[]bool{true, true, true, false, false},
+			[]bool{true, false, false, true, false},
+		},
+		{
+			"a success resets the failure count", 2,
+			[]error{nil, fail, nil, fail, nil},
+			[]bool{true, true, true, true, true},
+			[]bool{true, false, false, false, false},
+		},
+		{
+			"one success serves again", 1,
+			[]error{nil, fail, nil, nil},
+			[]bool{true, false, true, true},
+			[]bool{true, true, true, false},
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			h := &dbHealth{threshold: tt.threshold}
+			for i, err := range tt.pings {
+				changed := h.observe(err)
+				if h.serving != tt.wantServing[i] || changed != tt.wantChanged[i] {
+					t.Errorf("ping %d (%v): serving=%v changed=%v, want serving=%v changed=%v",
+						i, err, h.serving, changed, tt.wantServing[i], tt.wantChanged[i])
+				}
+			}
+		})
+	}
+}
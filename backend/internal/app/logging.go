@@ -2,55 +2,79 @@ package app
 
 import (
 	"context"
+	"net"
+	"strings"
 	"time"
 
 	"github.com/apsdehal/go-logger"
 	"google.golang.org/grpc"
-	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
 )
 
-func loggingInterceptor(
-	ctx context.Context,
-	req any,
-	info *grpc.UnaryServerInfo,
-	handler grpc.UnaryHandler,
-) (any, error) {
-	log := ctx.Value("log").(*logger.Logger)
-
-	start := time.Now()
-	resp, err := handler(ctx, req)
-	duration := time.Since(start)
-
-	ip := realIPFromContext(ctx)
+type logFunc func(format string, a ...interface{})
 
-	if err != nil {
-		if ip != "" {
-			log.Errorf("[%s] %s failed: %v", ip, info.FullMethod, err)
-		} else {
-			log.Errorf("%s failed: %v", info.FullMethod, err)
-		}
+// newLoggingInterceptor logs one line per request with the method, resulting
+// status code and latency. Successful calls are logged at the given level,
+// failures always at error level. Lines are prefixed with the client address
+// and request id. Request and response bodies are never logged.
+func newLoggingInterceptor(level string, trustedProxies []*net.IPNet) grpc.UnaryServerInterceptor {
+	return func(
+		ctx context.Context,
+		req any,
+		info *grpc.UnaryServerInfo,
+		handler grpc.UnaryHandler,
+	) (any, error) {
+		start := time.Now()
+		resp, err := handler(ctx, req)
+		logCall(ctx, level, trustedProxies, info.FullMethod, time.Since(start), err)
 		return resp, err
 	}
+}
 
-	if ip != "" {
-		log.Infof("[%s] %s handled in %s", ip, info.FullMethod, duration)
-	} else {
-		log.Infof("%s handled in %s", info.FullMethod, duration)
+// newLoggingStreamInterceptor logs streaming calls like newLoggingInterceptor,
+// once the stream has ended.
+func newLoggingStreamInterceptor(level string, trustedProxies []*net.IPNet) grpc.StreamServerInterceptor {
+	return func(
+		srv any,
+		ss grpc.ServerStream,
+		info *grpc.StreamServerInfo,
+		handler grpc.StreamHandler,
+	) error {
+		start := time.Now()
+		err := handler(srv, ss)
+		logCall(ss.Context(), level, trustedProxies, info.FullMethod, time.Since(start), err)
+		return err
 	}
-
-	return resp, nil
 }
 
-func realIPFromContext(ctx context.Context) string {
-	md, ok := metadata.FromIncomingContext(ctx)
-	if !ok {
-		return ""
+func logCall(ctx context.Context, level string, trustedProxies []*net.IPNet, method string, duration time.Duration, err error) {
+	log := ctx.Value("log").(*logger.Logger)
+
+	code := status.Code(err)
+	prefix := ""
+	if ip := clientIP(ctx, trustedProxies); ip != "" {
+		prefix = "[" + ip + "] "
+	}
+	if id := requestIDFromCtx(ctx); id != "" {
+		prefix += "[" + id + "] "
 	}
 
-	// gRPC metadata keys are lowercase
-	if values := md.Get("x-real-ip"); len(values) > 0 {
-		return values[0]
+	if err != nil {
+		log.Errorf("%s%s %s in %s: %v", prefix, method, code, duration, err)
+		return
 	}
+	levelFunc(log, level)("%s%s %s in %s", prefix, method, code, duration)
+}
 
-	return ""
+func levelFunc(log *logger.Logger, level string) logFunc {
+	switch strings.ToLower(level) {
+	case "debug":
+		return log.Debugf
+	case "notice":
+		return log.Noticef
+	case "warning", "warn":
+		return log.Warningf
+	default:
+		return log.Infof
+	}
 }
@@ -0,0 +1,79 @@
+package app
+
+import (
+	"context"
+	"unicode"
+
+	"github.com/google/uuid"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/metadata"
+
+	"musicclubbot/backend/internal/helpers"
+)
+
+const (
+	requestIDHeader    = "x-request-id"
+	maxRequestIDLength = 128
+)
+
+// newRequestIDInterceptor tags every call with a request id, stored in the
+// context under "request_id" and echoed in the x-request-id response header.
+// A well-formed incoming x-request-id is reused so traces span services;
+// otherwise a random UUID is generated.
+func newRequestIDInterceptor() grpc.UnaryServerInterceptor {
+	return func(
+		ctx context.Context,
+		req any,
+		_ *grpc.UnaryServerInfo,
+		handler grpc.UnaryHandler,
+	) (any, error) {
+		ctx = withRequestID(ctx)
+		_ = grpc.SetHeader(ctx, metadata.Pairs(requestIDHeader, requestIDFromCtx(ctx)))
+		return handler(ctx, req)
+	}
+}
+
+// newRequestIDStreamInterceptor is the streaming counterpart of newRequestIDInterceptor.
+func newRequestIDStreamInterceptor() grpc.StreamServerInterceptor {
+	return func(
+		srv any,
+		ss grpc.ServerStream,
+		_ *grpc.StreamServerInfo,
+		handler grpc.StreamHandler,
+	) error {
+		ctx := withRequestID(ss.Context())
+		_ = ss.SetHeader(metadata.Pairs(requestIDHeader, requestIDFromCtx(ctx)))
+		return handler(srv, helpers.WithStreamContext(ss, ctx))
+	}
+}
+
+func withRequestID(ctx context.Context) context.Context {
+	id := ""
+	if md, ok := metadata.FromIncomingContext(ctx); ok {
+		if values := md.Get(requestIDHeader); len(values) > 0 && validRequestID(values[0]) {
+			id = values[0]
+		}
+	}
+	if id == "" {
+		id = uuid.NewString()
+	}
+	return context.WithValue(ctx, "request_id", id)
+}
+
+// validRequestID accepts short printable ASCII ids so they are safe to log.
+func validRequestID(id string) bool {
+	if id == "" || len(id) > maxRequestIDLength {
+		return false
+	}
+	for _, r := range id {
+		if r > unicode.MaxASCII || !unicode.IsPrint(r) {
+			return false
+		}
+	}
+	return true
+}
+
+func requestIDFromCtx(ctx context.Context) string {
+	id, _ := ctx.Value("request_id").(string)
+	return id
+}
@@ -0,0 +1,64 @@
+package app
+
+import (
+	"context"
+	"net/http"
+	"sync/atomic"
+	"time"
+
+	"google.golang.org/grpc"
+)
+
+// inFlightCounter tracks calls that have not returned yet, so shutdown
+// can report how many it had to abandon.
+type inFlightCounter struct {
+	n atomic.Int64
+}
+
+func (c *inFlightCounter) interceptor(
+	ctx context.Context,
+	req any,
+	_ *grpc.UnaryServerInfo,
+	handler grpc.UnaryHandler,
+) (any, error) {
+	c.n.Add(1)
+	defer c.n.Add(-1)
+	return handler(ctx, req)
+}
+
+func (c *inFlightCounter) streamInterceptor(
+	srv any,
+	ss grpc.ServerStream,
+	_ *grpc.StreamServerInfo,
+	handler grpc.StreamHandler,
+) error {
+	c.n.Add(1)
+	defer c.n.Add(-1)
+	return handler(srv, ss)
+}
+
+// gracefulShutdown waits for ctx to be cancelled, then lets in-flight calls
+// finish for up to grace before closing the remaining connections.
+func gracefulShutdown(ctx context.Context, grpcServer *grpc.Server, httpServer *http.Server, grace time.Duration, inFlight *inFlightCounter) {
+	<-ctx.Done()
+	log := mustLog(ctx)
+	log.Infof("Shutting down, waiting up to %s for %d in-flight requests", grace, inFlight.n.Load())
+
+	drainCtx, cancel := context.WithTimeout(context.Background(), grace)
+	defer cancel()
+
+	stopped := make(chan struct{})
+	go func() {
+		grpcServer.GracefulStop()
+		close(stopped)
+	}()
+	_ = httpServer.Shutdown(drainCtx)
+
+	select {
+	case <-stopped:
+	case <-drainCtx.Done():
+		log.Warningf("Shutdown grace period elapsed, dropping %d in-flight requests", inFlight.n.Load())
+		grpcServer.Stop()
+		_ = httpServer.Close()
+	}
+}
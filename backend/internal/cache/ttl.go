@@ -0,0 +1,73 @@
+package cache
+
+import (
+	"sync"
+	"time"
+)
+
+type entry[V any] struct {
+	value   V
+	expires time.Time
+}
+
+// TTL is a small in-memory cache whose entries expire after a fixed duration.
+// A nil *TTL is a valid, always-empty cache, which is how caching is disabled.
+type TTL[V any] struct {
+	mu    sync.Mutex
+	ttl   time.Duration
+	items map[string]entry[V]
+}
+
+// NewTTL returns a cache keeping entries for ttl, or nil when ttl is not positive.
+func NewTTL[V any](ttl time.Duration) *TTL[V] {
+	if ttl <= 0 {
+		return nil
+	}
+	return &TTL[V]{ttl: ttl, items: make(map[string]entry[V])}
+}
+
+func (c *TTL[V]) Get(key string) (V, bool) {
+	var zero V
+	if c == nil {
+		return zero, false
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	e, ok := c.items[key]
+	if !ok {
+		return zero, false
+	}
+	if time.Now().After(e.expires) {
+		delete(c.items, key)
+		return zero, false
+	}
+	return e.value, true
+}
+
+func (c *TTL[V]) Set(key string, value V) {
+	if c == nil {
+		return
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.items[key] = entry[V]{value: value, expires: time.Now().Add(c.ttl)}
+}
+
+func (c *TTL[V]) Delete(key string) {
+	if c == nil {
+		return
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	delete(c.items, key)
+}
+
+// Purge drops every entry.
+func (c *TTL[V]) Purge() {
+	if c == nil {
+		return
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	clear(c.items)
+}
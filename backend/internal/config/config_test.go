@@ -0,0 +1,180 @@
+package config
+
+import (
+	"net"
+	"strings"
+	"testing"
+	"time"
+)
+
+func validConfig() Config {
+	secret := []byte(strings.Repeat("s", 32))
+	return Config{
+		JwtSecretKey:           secret,
+		JwtAlg:                 "HS256",
+		AccessTokenTTL:         time.Hour,
+		AdminAccessTokenTTL:    15 * time.Minute,
+		MaxAccessTokenTTL:      DefaultMaxAccessTokenTTL,
+		PageTokenKey:           secret,
+		LogFormat:              "text",
+		HealthPingInterval:     time.Minute,
+		HealthFailureThreshold: 3,
+		MinSecretLength:        32,
+	}
+}
+
+type validateCase struct {
+	name    string
+	mutate  func(c *Config)
+	wantErr string
+}
+
+func runValidateCases(t *testing.T, tests []validateCase) {
+	t.Helper()
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			c := validConfig()
+			tt.mutate(&c)
+			err := c.Validate()
+			if tt.wantErr == "" {
+				if err != nil {
+					t.Fatalf("Validate() = %v, want nil", err)
+				}
+				return
+			}
+			if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+				t.Fatalf("Validate() = %v, want an error mentioning %q", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestValidateJwtAlg(t *testing.T) {
+	runValidateCases(t, []validateCase{
+		{"HS256", func(c *Config) {}, ""},
+		{"HS512", func(c *Config) { c.JwtAlg = "HS512" }, ""},
+		{"typo", func(c *Config) { c.JwtAlg = "HS526" }, "unsupported JWT_ALG \"HS526\""},
+		{"asymmetric", func(c *Config) { c.JwtAlg = "RS256" }, "JWT_ALG"},
+		{"json logs", func(c *Config) { c.LogFormat = "json" }, ""},
+		{"unsupported log format", func(c *Config) { c.LogFormat = "xml" }, "LOG_FORMAT"},
+	})
+}
+
+func TestValidateTokenTTLs(t *testing.T) {
+	runValidateCases(t, []validateCase{
+		{"shorter admin ttl", func(c *Config) { c.AdminAccessTokenTTL = time.Minute }, ""},
+		{"zero access ttl", func(c *Config) { c.AccessTokenTTL = 0 }, "JWT_TTL_SECONDS"},
+		{"negative admin ttl", func(c *Config) { c.AdminAccessTokenTTL = -time.Second }, "JWT_ADMIN_TTL_SECONDS"},
+	})
+}
+
+func TestClampTokenTTLs(t *testing.T) {
+	tests := []struct {
+		name                 string
+		access, admin, limit time.Duration
+		wantAccess           time.Duration
+		wantAdmin            time.Duration
+		wantWarnings         int
+	}{
+		{"within ceiling", time.Hour, time.Minute, 24 * time.Hour, time.Hour, time.Minute, 0},
+		{"equal to ceiling", 24 * time.Hour, time.Minute, 24 * time.Hour, 24 * time.Hour, time.Minute, 0},
+		{"access above ceiling", 48 * time.Hour, time.Minute, 24 * time.Hour, 24 * time.Hour, time.Minute, 1},
+		{"both above ceiling", 48 * time.Hour, 30 * time.Hour, 24 * time.Hour, 24 * time.Hour, 24 * time.Hour, 2},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			c := Config{AccessTokenTTL: tt.access, AdminAccessTokenTTL: tt.admin, MaxAccessTokenTTL: tt.limit}
+			warnings := c.ClampTokenTTLs()
+			if len(warnings) != tt.wantWarnings {
+				t.Errorf("got %d warnings %q, want %d", len(warnings), warnings, tt.wantWarnings)
+			}
+			if c.AccessTokenTTL != tt.wantAccess || c.AdminAccessTokenTTL != tt.wantAdmin {
+				t.Errorf("got TTLs %s/%s, want %s/%s", c.AccessTokenTTL, c.AdminAccessTokenTTL, tt.wantAccess, tt.wantAdmin)
+			}
+		})
+	}
+}
+
+func TestValidateTTLCeiling(t *testing.T) {
+	runValidateCases(t, []validateCase{
+		{"zero ttl ceiling", func(c *Config) { c.MaxAccessTokenTTL = 0 }, "JWT_MAX_TTL_SECONDS"},
+	})
+}
+
+func TestValidateHealthSettings(t *testing.T) {
+	runValidateCases(t, []validateCase{
+		{"zero ping interval", func(c *Config) { c.HealthPingInterval = 0 }, "HEALTH_PING_INTERVAL_SECONDS"},
+		{"zero failure threshold", func(c *Config) { c.HealthFailureThreshold = 0 }, "HEALTH_FAILURE_THRESHOLD"},
+	})
+}
+
+func TestParseCIDRList(t *testing.T) {
+	tests := []struct {
+		input    string
+		want     []string
+		contains string
+		excludes string
+	}{
+		{"", nil, "", "127.0.0.1"},
+		{"127.0.0.0/8, ::1/128", []string{"127.0.0.0/8", "::1/128"}, "127.1.2.3", "10.0.0.1"},
+		{"192.168.1.5", []string{"192.168.1.5/32"}, "192.168.1.5", "192.168.1.6"},
+		{"2001:db8::1", []string{"2001:db8::1/128"}, "2001:db8::1", "2001:db8::2"},
+		{"10.0.0.0/8,not-an-ip,300.1.1.1,10.0.0.0/99,,", []string{"10.0.0.0/8"}, "10.9.8.7", "11.0.0.1"},
+	}
+	for _, tt := range tests {
+		nets := parseCIDRList(tt.input)
+		var got []string
+		for _, n := range nets {
+			got = append(got, n.String())
+		}
+		if strings.Join(got, ",") != strings.Join(tt.want, ",") {
+			t.Errorf("parseCIDRList(%q) = %v, want %v", tt.input, got, tt.want)
+		}
+		if tt.contains != "" && !anyContains(nets, tt.contains) {
+			t.Errorf("parseCIDRList(%q) does not contain %s", tt.input, tt.contains)
+		}
+		if tt.excludes != "" && anyContains(nets, tt.excludes) {
+			t.Errorf("parseCIDRList(%q) contains %s", tt.input, tt.excludes)
+		}
+	}
+}
+
+func anyContains(nets []*net.IPNet, ip string) bool {
+	for _, n := range nets {
+		if n.Contains(net.ParseIP(ip)) {
+			return true
+		}
+	}
+	return false
+}
+
+func TestCheckSecretLength(t *testing.T) {
+	tests := []struct {
+		secret  string
+		min     int
+		wantErr bool
+	}{
+		{"", 0, false},
+		{"", 1, true},
+		{"weak", 32, true},
+		{strings.Repeat("x", 31), 32, true},
+		{strings.Repeat("x", 32), 32, false},
+		{strings.Repeat("x", 64), 32, false},
+	}
+	for _, tt := range tests {
+		err := CheckSecretLength("JWT_SECRET", []byte(tt.secret), tt.min)
+		if (err != nil) != tt.wantErr {
+			t.Errorf("CheckSecretLength(%d bytes, %d) = %v, want error %v", len(tt.secret), tt.min, err, tt.wantErr)
+		}
+	}
+}
+
+func TestValidateSecrets(t *testing.T) {
+	runValidateCases(t, []validateCase{
+		{"missing jwt secret", func(c *Config) { c.JwtSecretKey = nil }, "JWT_SECRET must be set"},
+		{"missing jwt secret with weak secrets allowed", func(c *Config) { c.JwtSecretKey, c.AllowWeakSecrets = nil, true }, "JWT_SECRET must be set"},
+		{"short jwt secret", func(c *Config) { c.JwtSecretKey = []byte("short") }, "JWT_SECRET is 5 bytes"},
+		{"short page token secret", func(c *Config) { c.PageTokenKey = []byte("short") }, "PAGE_TOKEN_SECRET is 5 bytes"},
+		{"short secrets allowed", func(c *Config) { c.JwtSecretKey, c.PageTokenKey, c.AllowWeakSecrets = []byte("a"), []byte("b"), true }, ""},
+	})
+}
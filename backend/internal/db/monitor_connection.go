@@ -1,24 +1,30 @@
 package db
 
 import (
+	"context"
 	"database/sql"
-	"log"
 	"time"
 )
 
-// MonitorDbConnection pings database every delay and fails the program when a ping fails
-func MonitorDbConnection(db *sql.DB, delay time.Duration) {
-	ticker := time.NewTicker(delay)
-	defer ticker.Stop()
+// retryDelay is used instead of the regular delay while the database is unreachable.
+const retryDelay = 5 * time.Second
 
-	TestDbConnection(db)
-	for range ticker.C {
-		TestDbConnection(db)
-	}
-}
+// MonitorDbConnection pings the database every delay and passes each result
+// to report. It never stops the program, so a database outage during deploy
+// only makes the server unhealthy until Postgres is back.
+func MonitorDbConnection(ctx context.Context, db *sql.DB, delay time.Duration, report func(error)) {
+	for {
+		err := db.PingContext(ctx)
+		report(err)
 
-func TestDbConnection(db *sql.DB) {
-	if err := db.Ping(); err != nil {
-		log.Fatalf("Failed to ping database: %v", err)
+		wait := delay
+		if err != nil {
+			wait = retryDelay
+		}
+		select {
+		case <-ctx.Done():
+			return
+		case <-time.After(wait):
+		}
 	}
 }
@@ -0,0 +1,286 @@
+// Package dbtest provides a scripted database/sql driver for handler tests.
+//
+// A test lists the statements it expects the code under test to run, in
+// order, together with the rows or results each one returns:
+//
+//	db, mock := dbtest.New(t)
+//	mock.ExpectQuery("FROM user_permissions").WithArgs(userID).WillReturnRows([]string{"edit"}, []any{true})
+//	mock.ExpectBegin()
+//	mock.ExpectExec("UPDATE song").WillReturnResult(1)
+//	mock.ExpectCommit()
+//
+// A statement matches when its text, with runs of whitespace collapsed,
+// contains the expected fragment. Anything unexpected fails the test, and so
+// does any expectation still pending when the test ends.
+package dbtest
+
+import (
+	"context"
+	"database/sql"
+	"database/sql/driver"
+	"errors"
+	"fmt"
+	"io"
+	"reflect"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+)
+
+type kind int
+
+const (
+	kindQuery kind = iota
+	kindExec
+	kindBegin
+	kindCommit
+	kindRollback
+)
+
+func (k kind) String() string {
+	return [...]string{"query", "exec", "begin", "commit", "rollback"}[k]
+}
+
+// Mock holds the statements a test still expects.
+type Mock struct {
+	t        testing.TB
+	mu       sync.Mutex
+	expected []*Expectation
+}
+
+// Expectation is one expected statement and what it returns.
+type Expectation struct {
+	kind      kind
+	fragment  string
+	args      []driver.Value
+	checkArgs bool
+	columns   []string
+	rows      [][]driver.Value
+	affected  int64
+	err       error
+}
+
+// New returns a database handle backed by a fresh Mock. Pending
+// expectations are reported when the test finishes.
+func New(t testing.TB) (*sql.DB, *Mock) {
+	m := &Mock{t: t}
+	db := sql.OpenDB(connector{m})
+	t.Cleanup(func() {
+		db.Close()
+		m.mu.Lock()
+		defer m.mu.Unlock()
+		for _, e := range m.expected {
+			t.Errorf("dbtest: expected %s %q was not run", e.kind, e.fragment)
+		}
+	})
+	return db, m
+}
+
+func (m *Mock) expect(k kind, fragment string) *Expectation {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	e := &Expectation{kind: k, fragment: normalize(fragment)}
+	m.expected = append(m.expected, e)
+	return e
+}
+
+// ExpectQuery expects a query whose text contains fragment.
+func (m *Mock) ExpectQuery(fragment string) *Expectation { return m.expect(kindQuery, fragment) }
+
+// ExpectExec expects a statement run with Exec whose text contains fragment.
+func (m *Mock) ExpectExec(fragment string) *Expectation { return m.expect(kindExec, fragment) }
+
+// ExpectBegin expects a transaction to start.
+func (m *Mock) ExpectBegin() *Expectation { return m.expect(kindBegin, "") }
+
+// ExpectCommit expects the open transaction to commit.
+func (m *Mock) ExpectCommit() *Expectation { return m.expect(kindCommit, "") }
+
+// ExpectRollback expects the open transaction to roll back.
+func (m *Mock) ExpectRollback() *Expectation { return m.expect(kindRollback, "") }
+
+// WithArgs requires the statement to be run with exactly these arguments.
+func (e *Expectation) WithArgs(args ...any) *Expectation {
+	e.checkArgs = true
+	e.args = convertAll(args)
+	return e
+}
+
+// WillReturnRows makes a query return the given rows.
+func (e *Expectation) WillReturnRows(columns []string, rows ...[]any) *Expectation {
+	e.columns = columns
+	for _, r := range rows {
+		e.rows = append(e.rows, convertAll(r))
+	}
+	return e
+}
+
+// WillReturnResult makes an exec report affected rows.
+func (e *Expectation) WillReturnResult(affected int64) *Expectation {
+	e.affected = affected
+	return e
+}
+
+// WillReturnError makes the statement fail with err.
+func (e *Expectation) WillReturnError(err error) *Expectation {
+	e.err = err
+	return e
+}
+
+func convertAll(values []any) []driver.Value {
+	out := make([]driver.Value, len(values))
+	for i, v := range values {
+		cv, err := driver.DefaultParameterConverter.ConvertValue(v)
+		if err != nil {
+			panic(fmt.Sprintf("dbtest: cannot convert %#v: %v", v, err))
+		}
+		out[i] = cv
+	}
+	return out
+}
+
+func normalize(s string) string {
+	return strings.Join(strings.Fields(s), " ")
+}
+
+var errUnexpected = errors.New("dbtest: unexpected statement")
+
+// next pops the expectation for a statement and checks that it matches.
+func (m *Mock) next(k kind, query string, args []driver.NamedValue) (*Expectation, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	query = normalize(query)
+	if len(m.expected) == 0 {
+		m.t.Errorf("dbtest: unexpected %s %q, nothing more was expected", k, query)
+		return nil, errUnexpected
+	}
+	e := m.expected[0]
+	if e.kind != k || !strings.Contains(query, e.fragment) {
+		m.t.Errorf("dbtest: got %s %q, want %s containing %q", k, query, e.kind, e.fragment)
+		return nil, errUnexpected
+	}
+	m.expected = m.expected[1:]
+	if e.checkArgs && !argsEqual(e.args, args) {
+		got := make([]driver.Value, len(args))
+		for i, a := range args {
+			got[i] = a.Value
+		}
+		m.t.Errorf("dbtest: %s %q got args %v, want %v", k, e.fragment, got, e.args)
+		return nil, errUnexpected
+	}
+	return e, e.err
+}
+
+func argsEqual(want []driver.Value, got []driver.NamedValue) bool {
+	if len(want) != len(got) {
+		return false
+	}
+	for i := range want {
+		w, g := want[i], got[i].Value
+		if wt, ok := w.(time.Time); ok {
+			if gt, ok := g.(time.Time); !ok || !wt.Equal(gt) {
+				return false
+			}
+			continue
+		}
+		if !reflect.DeepEqual(w, g) && fmt.Sprint(w) != fmt.Sprint(g) {
+			return false
+		}
+	}
+	return true
+}
+
+type connector struct{ m *Mock }
+
+func (c connector) Connect(context.Context) (driver.Conn, error) { return &conn{m: c.m}, nil }
+func (c connector) Driver() driver.Driver                         { return drv{} }
+
+type drv struct{}
+
+func (drv) Open(string) (driver.Conn, error) {
+	return nil, errors.New("dbtest: open connections with dbtest.New")
+}
+
+type conn struct{ m *Mock }
+
+func (c *conn) Prepare(query string) (driver.Stmt, error) { return &stmt{c: c, query: query}, nil }
+func (c *conn) Close() error                              { return nil }
+func (c *conn) Begin() (driver.Tx, error)                 { return c.BeginTx(context.Background(), driver.TxOptions{}) }
+
+func (c *conn) BeginTx(context.Context, driver.TxOptions) (driver.Tx, error) {
+	if _, err := c.m.next(kindBegin, "", nil); err != nil {
+		return nil, err
+	}
+	return tx{c}, nil
+}
+
+func (c *conn) QueryContext(_ context.Context, query string, args []driver.NamedValue) (driver.Rows, error) {
+	e, err := c.m.next(kindQuery, query, args)
+	if err != nil {
+		return nil, err
+	}
+	return &rows{columns: e.columns, data: e.rows}, nil
+}
+
+func (c *conn) ExecContext(_ context.Context, query string, args []driver.NamedValue) (driver.Result, error) {
+	e, err := c.m.next(kindExec, query, args)
+	if err != nil {
+		return nil, err
+	}
+	return driver.RowsAffected(e.affected), nil
+}
+
+type stmt struct {
+	c     *conn
+	query string
+}
+
+func (s *stmt) Close() error  { return nil }
+func (s *stmt) NumInput() int { return -1 }
+
+func (s *stmt) Exec(args []driver.Value) (driver.Result, error) {
+	return s.c.ExecContext(context.Background(), s.query, named(args))
+}
+
+func (s *stmt) Query(args []driver.Value) (driver.Rows, error) {
+	return s.c.QueryContext(context.Background(), s.query, named(args))
+}
+
+func named(args []driver.Value) []driver.NamedValue {
+	out := make([]driver.NamedValue, len(args))
+	for i, a := range args {
+		out[i] = driver.NamedValue{Ordinal: i + 1, Value: a}
+	}
+	return out
+}
+
+type tx struct{ c *conn }
+
+func (t tx) Commit() error {
+	_, err := t.c.m.next(kindCommit, "", nil)
+	return err
+}
+
+func (t tx) Rollback() error {
+	_, err := t.c.m.next(kindRollback, "", nil)
+	return err
+}
+
+type rows struct {
+	columns []string
+	data    [][]driver.Value
+	pos     int
+}
+
+func (r *rows) Columns() []string { return r.columns }
+func (r *rows) Close() error      { return nil }
+
+func (r *rows) Next(dest []driver.Value) error {
+	if r.pos >= len(r.data) {
+		return io.EOF
+	}
+	copy(dest, r.data[r.pos])
+	r.pos++
+	return nil
+}
@@ -0,0 +1,62 @@
+package dbtest
+
+// Statements that many handlers run through the shared helpers.
+
+// PermissionColumns are the columns helpers.LoadPermissions reads.
+var PermissionColumns = []string{
+	"edit_own_participation", "edit_any_participation",
+	"edit_own_songs", "edit_any_songs",
+	"edit_events", "edit_tracklists", "admin",
+}
+
+// Permission rows for the usual kinds of caller.
+var (
+	Member = []any{true, false, true, false, false, false, false}
+	Admin  = []any{true, true, true, true, true, true, true}
+)
+
+// ExpectPermissions expects helpers.LoadPermissions for userID. A nil row
+// means the user has no permissions row.
+func (m *Mock) ExpectPermissions(userID string, row []any) *Expectation {
+	e := m.ExpectQuery("FROM user_permissions WHERE user_id = $1").WithArgs(userID)
+	if row == nil {
+		return e
+	}
+	return e.WillReturnRows(PermissionColumns, row)
+}
+
+// SongColumns are the columns helpers.LoadSongDetails reads from song.
+var SongColumns = []string{
+	"id", "title", "artist", "description", "link_kind", "link_url",
+	"created_by", "thumbnail_url", "duration_seconds", "notes",
+}
+
+// ExpectSongDetails expects helpers.LoadSongDetails of a song with no roles,
+// votes or assignments, as seen by viewerID holding perms.
+func (m *Mock) ExpectSongDetails(song []any, viewerID string, perms []any) {
+	songID := song[0]
+	m.ExpectQuery("FROM song WHERE id = $1").WithArgs(songID).WillReturnRows(SongColumns, song)
+	m.ExpectQuery("SELECT role FROM song_role WHERE song_id = $1").WithArgs(songID)
+	m.ExpectPermissions(viewerID, perms)
+	m.ExpectQuery("SELECT COUNT(*) FROM song_vote").WithArgs(songID).WillReturnRows([]string{"count"}, []any{0})
+	m.ExpectQuery("FROM song_role_assignment sra JOIN app_user au").WithArgs(songID)
+}
+
+// EventColumns are the columns helpers.LoadEventDetails reads from event.
+var EventColumns = []string{
+	"id", "title", "start_at", "location",
+	"notify_day_before", "notify_hour_before", "created_by", "status",
+}
+
+// ExpectEventDetails expects helpers.LoadEventDetails of an event with no
+// dates, tracklist or participants, as seen by viewerID holding perms.
+func (m *Mock) ExpectEventDetails(event []any, viewerID string, perms []any) {
+	eventID := event[0]
+	m.ExpectQuery("FROM event WHERE id = $1 AND deleted_at IS NULL").WithArgs(eventID).WillReturnRows(EventColumns, event)
+	m.ExpectQuery("SELECT start_at FROM event_date").WithArgs(eventID)
+	m.ExpectQuery("FROM event_track_item eti LEFT JOIN song s").WithArgs(eventID)
+	m.ExpectQuery("FROM event_participant ep").WithArgs(eventID)
+	m.ExpectQuery("SELECT COUNT(*) FROM (").WithArgs(eventID).WillReturnRows([]string{"count"}, []any{0})
+	m.ExpectQuery("SELECT COALESCE(SUM(s.duration_seconds), 0)").WithArgs(eventID).WillReturnRows([]string{"total", "missing"}, []any{0, 0})
+	m.ExpectPermissions(viewerID, perms)
+}
@@ -0,0 +1,151 @@
+package helpers
+
+import (
+	"fmt"
+	"musicclubbot/backend/proto"
+	"sort"
+	"strings"
+
+	"google.golang.org/grpc"
+	healthpb "google.golang.org/grpc/health/grpc_health_v1"
+	reflectionpb "google.golang.org/grpc/reflection/grpc_reflection_v1"
+	reflectionalphapb "google.golang.org/grpc/reflection/grpc_reflection_v1alpha"
+)
+
+// Access is the minimal caller requirement for invoking an RPC.
+// The zero value means the method is not registered and is always rejected.
+// Mutating RPCs require a permission-backed level; handlers still perform
+// the finer ownership checks.
+type Access int
+
+const (
+	AccessPublic Access = iota + 1
+	AccessAuthenticated
+	AccessParticipant
+	AccessSongEditor
+	AccessEventEditor
+	AccessTracklistEditor
+	AccessAdmin
+)
+
+// RequiresPermissions reports whether the level is checked against the caller's permissions.
+func (a Access) RequiresPermissions() bool {
+	return a > AccessAuthenticated
+}
+
+// Allows reports whether the permission set satisfies the level.
+func (a Access) Allows(perms *proto.PermissionSet) bool {
+	switch a {
+	case AccessPublic, AccessAuthenticated:
+		return true
+	case AccessParticipant:
+		return perms != nil && perms.Join != nil && (perms.Join.EditOwnParticipation || perms.Join.EditAnyParticipation)
+	case AccessSongEditor:
+		return perms != nil && perms.Songs != nil && (perms.Songs.EditOwnSongs || perms.Songs.EditAnySongs)
+	case AccessEventEditor:
+		return PermissionAllowsEventEdit(perms)
+	case AccessTracklistEditor:
+		return PermissionAllowsTracklistEdit(perms)
+	case AccessAdmin:
+		return PermissionIsAdmin(perms)
+	default:
+		return false
+	}
+}
+
+// MethodAccess lists every RPC served by the backend with its requirement.
+// New RPCs must be added here, otherwise the server refuses to start.
+var MethodAccess = map[string]Access{
+	proto.AuthService_Register_FullMethodName:           AccessPublic,
+	proto.AuthService_Login_FullMethodName:              AccessPublic,
+	proto.AuthService_Refresh_FullMethodName:            AccessPublic,
+	proto.AuthService_TelegramWebAppAuth_FullMethodName: AccessPublic,
+	proto.AuthService_GetTgLoginLink_FullMethodName:     AccessAuthenticated,
+	proto.AuthService_GetProfile_FullMethodName:         AccessAuthenticated,
+
+	proto.SongService_StreamSearchSongs_FullMethodName:         AccessAuthenticated,
+	proto.SongService_ListSongs_FullMethodName:                 AccessAuthenticated,
+	proto.SongService_ListRecentSongs_FullMethodName:           AccessAuthenticated,
+	proto.SongService_ListSongsNotInEvent_FullMethodName:       AccessAuthenticated,
+	proto.SongService_ListSongsByVotes_FullMethodName:          AccessAuthenticated,
+	proto.SongService_GetSong_FullMethodName:                   AccessAuthenticated,
+	proto.SongService_CreateSong_FullMethodName:                AccessSongEditor,
+	proto.SongService_ValidateSong_FullMethodName:              AccessSongEditor,
+	proto.SongService_UpdateSong_FullMethodName:                AccessSongEditor,
+	proto.SongService_DeleteSong_FullMethodName:                AccessSongEditor,
+	proto.SongService_JoinRole_FullMethodName:                  AccessParticipant,
+	proto.SongService_LeaveRole_FullMethodName:                 AccessParticipant,
+	proto.SongService_UpdateRole_FullMethodName:                AccessParticipant,
+	proto.SongService_ReplaceParticipants_FullMethodName:       AccessAdmin,
+	proto.SongService_ListRecentParticipations_FullMethodName:  AccessAuthenticated,
+	proto.SongService_ListParticipations_FullMethodName:        AccessAuthenticated,
+	proto.SongService_ListParticipationsSince_FullMethodName:   AccessAuthenticated,
+	proto.SongService_CountParticipationsByRole_FullMethodName: AccessAuthenticated,
+	proto.SongService_CheckSongLinks_FullMethodName:            AccessSongEditor,
+	proto.SongService_GetLinkStatus_FullMethodName:             AccessAuthenticated,
+	proto.SongService_ListSongTags_FullMethodName:              AccessAuthenticated,
+	proto.SongService_AddSongTag_FullMethodName:                AccessSongEditor,
+	proto.SongService_RemoveSongTag_FullMethodName:             AccessSongEditor,
+	proto.SongService_BatchTagSongs_FullMethodName:             AccessSongEditor,
+	proto.SongService_VoteSong_FullMethodName:                  AccessAuthenticated,
+	proto.SongService_UnvoteSong_FullMethodName:                AccessAuthenticated,
+
+	proto.EventService_ListEvents_FullMethodName:         AccessAuthenticated,
+	proto.EventService_GetEvent_FullMethodName:           AccessAuthenticated,
+	proto.EventService_ListEventsForSong_FullMethodName:  AccessAuthenticated,
+	proto.EventService_CreateEvent_FullMethodName:        AccessEventEditor,
+	proto.EventService_CloneEvent_FullMethodName:         AccessEventEditor,
+	proto.EventService_UpdateEvent_FullMethodName:        AccessEventEditor,
+	proto.EventService_DeleteEvent_FullMethodName:        AccessEventEditor,
+	proto.EventService_SetTracklist_FullMethodName:       AccessTracklistEditor,
+	proto.EventService_SwapTracklistSongs_FullMethodName: AccessTracklistEditor,
+	proto.EventService_MoveTracklistSongs_FullMethodName: AccessTracklistEditor,
+	proto.EventService_BatchGetEvents_FullMethodName:     AccessAuthenticated,
+	proto.EventService_ListEventPeople_FullMethodName:    AccessAuthenticated,
+	proto.EventService_ListEventDates_FullMethodName:     AccessAuthenticated,
+	proto.EventService_AddEventDate_FullMethodName:       AccessEventEditor,
+	proto.EventService_RemoveEventDate_FullMethodName:    AccessEventEditor,
+
+	proto.AdminService_ExplainQuery_FullMethodName:           AccessAdmin,
+	proto.AdminService_MergeSongs_FullMethodName:             AccessAdmin,
+	proto.AdminService_BatchUpdateLinks_FullMethodName:       AccessAdmin,
+	proto.AdminService_SeedSampleData_FullMethodName:         AccessAdmin,
+	proto.AdminService_TransferParticipations_FullMethodName: AccessAdmin,
+	proto.AdminService_RenameRole_FullMethodName:             AccessAdmin,
+	proto.AdminService_GrantAdmin_FullMethodName:             AccessAdmin,
+	proto.AdminService_RevokeAdmin_FullMethodName:            AccessAdmin,
+	proto.AdminService_RestoreEvent_FullMethodName:           AccessAdmin,
+
+	proto.MetaService_Ping_FullMethodName:            AccessPublic,
+	proto.MetaService_GetServerConfig_FullMethodName: AccessPublic,
+
+	// Probed by orchestrators without credentials.
+	healthpb.Health_Check_FullMethodName: AccessPublic,
+	healthpb.Health_List_FullMethodName:  AccessPublic,
+	healthpb.Health_Watch_FullMethodName: AccessPublic,
+
+	// Server reflection, used by grpcurl and similar tools.
+	reflectionpb.ServerReflection_ServerReflectionInfo_FullMethodName:      AccessPublic,
+	reflectionalphapb.ServerReflection_ServerReflectionInfo_FullMethodName: AccessPublic,
+}
+
+// CheckMethodAccess verifies that every method of the application services is listed in MethodAccess.
+func CheckMethodAccess(services map[string]grpc.ServiceInfo) error {
+	var missing []string
+	for name, info := range services {
+		if !strings.HasPrefix(name, "musicclub.") {
+			continue
+		}
+		for _, m := range info.Methods {
+			full := "/" + name + "/" + m.Name
+			if _, ok := MethodAccess[full]; !ok {
+				missing = append(missing, full)
+			}
+		}
+	}
+	if len(missing) > 0 {
+		sort.Strings(missing)
+		return fmt.Errorf("methods without access policy: %s", strings.Join(missing, ", "))
+	}
+	return nil
+}
@@ -0,0 +1,51 @@
+package helpers
+
+import (
+	"context"
+	"database/sql"
+)
+
+type execer interface {
+	ExecContext(ctx context.Context, query string, args ...any) (sql.Result, error)
+}
+
+type queryRower interface {
+	QueryRowContext(ctx context.Context, query string, args ...any) *sql.Row
+}
+
+// CountAdmins returns how many users are recorded as admins.
+func CountAdmins(ctx context.Context, db queryRower) (int, error) {
+	var n int
+	err := db.QueryRowContext(ctx, `SELECT COUNT(*) FROM app_admin`).Scan(&n)
+	return n, err
+}
+
+// GrantAdmin records the user as an admin and gives them every permission.
+func GrantAdmin(ctx context.Context, db execer, userID string) error {
+	_, err := db.ExecContext(ctx, `
+		WITH granted AS (
+			INSERT INTO app_admin (user_id) VALUES ($1) ON CONFLICT DO NOTHING
+		)
+		INSERT INTO user_permissions (user_id, edit_own_participation, edit_any_participation,
+		                              edit_own_songs, edit_any_songs, edit_events, edit_tracklists)
+		VALUES ($1, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE)
+		ON CONFLICT (user_id) DO UPDATE
+		SET edit_own_participation = TRUE, edit_any_participation = TRUE,
+		    edit_own_songs = TRUE, edit_any_songs = TRUE, edit_events = TRUE, edit_tracklists = TRUE
+	`, userID)
+	return err
+}
+
+// RevokeAdmin removes the admin record and drops the permissions over other
+// people's data, keeping the defaults every member gets.
+func RevokeAdmin(ctx context.Context, db execer, userID string) error {
+	_, err := db.ExecContext(ctx, `
+		WITH revoked AS (
+			DELETE FROM app_admin WHERE user_id = $1
+		)
+		UPDATE user_permissions
+		SET edit_any_participation = FALSE, edit_any_songs = FALSE, edit_events = FALSE, edit_tracklists = FALSE
+		WHERE user_id = $1
+	`, userID)
+	return err
+}
@@ -0,0 +1,100 @@
+package helpers
+
+import (
+	"context"
+	"database/sql"
+	"musicclubbot/backend/internal/cache"
+	"musicclubbot/backend/proto"
+	"time"
+
+	protobuf "google.golang.org/protobuf/proto"
+)
+
+// DetailsCache keeps recently loaded song and event details keyed by id.
+// Per-user fields (permissions, editable_by_me) are recomputed on every hit.
+type DetailsCache struct {
+	Songs  *cache.TTL[*proto.SongDetails]
+	Events *cache.TTL[*proto.EventDetails]
+}
+
+// NewDetailsCache returns a cache keeping entries for ttl; a non-positive ttl disables caching.
+func NewDetailsCache(ttl time.Duration) *DetailsCache {
+	return &DetailsCache{
+		Songs:  cache.NewTTL[*proto.SongDetails](ttl),
+		Events: cache.NewTTL[*proto.EventDetails](ttl),
+	}
+}
+
+func detailsCacheFromCtx(ctx context.Context) *DetailsCache {
+	c, _ := ctx.Value("cache").(*DetailsCache)
+	if c == nil {
+		return &DetailsCache{}
+	}
+	return c
+}
+
+// CachedSongDetails is LoadSongDetails backed by the details cache.
+func CachedSongDetails(ctx context.Context, db *sql.DB, songID, currentUserID string) (*proto.SongDetails, error) {
+	songs := detailsCacheFromCtx(ctx).Songs
+	if cached, ok := songs.Get(songID); ok {
+		details := protobuf.Clone(cached).(*proto.SongDetails)
+		perms, err := LoadPermissions(ctx, db, currentUserID)
+		if err != nil {
+			return nil, err
+		}
+		details.Permissions = perms
+		creatorID := sql.NullString{String: details.Song.CreatedBy, Valid: details.Song.CreatedBy != ""}
+		details.Song.EditableByMe = PermissionAllowsSongEdit(perms, creatorID, currentUserID)
+		RedactSong(details.Song, perms)
+		return details, nil
+	}
+	// The cache holds the unredacted song so admins and members can share it.
+	details, err := loadSongDetails(ctx, db, songID, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+	songs.Set(songID, protobuf.Clone(details).(*proto.SongDetails))
+	RedactSong(details.Song, details.Permissions)
+	return details, nil
+}
+
+// CachedEventDetails is LoadEventDetails backed by the details cache. Events
+// hidden from the caller by EventVisible yield sql.ErrNoRows.
+func CachedEventDetails(ctx context.Context, db *sql.DB, eventID, currentUserID string) (*proto.EventDetails, error) {
+	events := detailsCacheFromCtx(ctx).Events
+	if cached, ok := events.Get(eventID); ok {
+		details := protobuf.Clone(cached).(*proto.EventDetails)
+		perms, err := LoadPermissions(ctx, db, currentUserID)
+		if err != nil {
+			return nil, err
+		}
+		details.Permissions = perms
+		return visibleEventDetails(details)
+	}
+	details, err := LoadEventDetails(ctx, db, eventID, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+	events.Set(eventID, protobuf.Clone(details).(*proto.EventDetails))
+	return visibleEventDetails(details)
+}
+
+// visibleEventDetails hides events the caller may not see as if they did not exist.
+func visibleEventDetails(details *proto.EventDetails) (*proto.EventDetails, error) {
+	if !EventVisible(details.GetPermissions(), details.GetEvent().GetStatus()) {
+		return nil, sql.ErrNoRows
+	}
+	return details, nil
+}
+
+// InvalidateSong drops the cached song. Events embed song participants, so they are dropped too.
+func InvalidateSong(ctx context.Context, songID string) {
+	c := detailsCacheFromCtx(ctx)
+	c.Songs.Delete(songID)
+	c.Events.Purge()
+}
+
+// InvalidateEvent drops the cached event.
+func InvalidateEvent(ctx context.Context, eventID string) {
+	detailsCacheFromCtx(ctx).Events.Delete(eventID)
+}
@@ -1,15 +1,18 @@
 package helpers
 
 import (
+	"cmp"
 	"context"
 	"database/sql"
 	"errors"
 	"fmt"
 	"musicclubbot/backend/proto"
+	"slices"
 	"strings"
 	"time"
 
 	"github.com/google/uuid"
+	"github.com/lib/pq"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 	"google.golang.org/protobuf/types/known/timestamppb"
@@ -59,12 +62,13 @@ func LoadPermissions(ctx context.Context, db *sql.DB, userID string) (*proto.Per
 	row := db.QueryRowContext(ctx, `
 		SELECT edit_own_participation, edit_any_participation,
 		       edit_own_songs, edit_any_songs,
-		       edit_events, edit_tracklists
+		       edit_events, edit_tracklists,
+		       EXISTS (SELECT 1 FROM app_admin WHERE app_admin.user_id = $1)
 		FROM user_permissions WHERE user_id = $1
 	`, userID)
 	var p proto.PermissionSet
 	var joinOwn, joinAny, songsOwn, songsAny, events, tracks bool
-	switch err := row.Scan(&joinOwn, &joinAny, &songsOwn, &songsAny, &events, &tracks); err {
+	switch err := row.Scan(&joinOwn, &joinAny, &songsOwn, &songsAny, &events, &tracks, &p.Admin); err {
 	case nil:
 		// ok
 	case sql.ErrNoRows:
@@ -114,6 +118,54 @@ func MapSongLinkKindToDB(kind proto.SongLinkType) (string, error) {
 	}
 }
 
+func MapParticipationStatus(dbValue string) proto.ParticipationStatus {
+	switch dbValue {
+	case "confirmed":
+		return proto.ParticipationStatus_PARTICIPATION_STATUS_CONFIRMED
+	case "tentative":
+		return proto.ParticipationStatus_PARTICIPATION_STATUS_TENTATIVE
+	default:
+		return proto.ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+	}
+}
+
+func MapParticipationStatusToDB(s proto.ParticipationStatus) (string, error) {
+	switch s {
+	case proto.ParticipationStatus_PARTICIPATION_STATUS_CONFIRMED:
+		return "confirmed", nil
+	case proto.ParticipationStatus_PARTICIPATION_STATUS_TENTATIVE:
+		return "tentative", nil
+	default:
+		return "", errors.New("unsupported participation status")
+	}
+}
+
+func MapEventStatus(dbValue string) proto.EventStatus {
+	switch dbValue {
+	case "draft":
+		return proto.EventStatus_EVENT_STATUS_DRAFT
+	case "published":
+		return proto.EventStatus_EVENT_STATUS_PUBLISHED
+	case "cancelled":
+		return proto.EventStatus_EVENT_STATUS_CANCELLED
+	default:
+		return proto.EventStatus_EVENT_STATUS_UNSPECIFIED
+	}
+}
+
+func MapEventStatusToDB(s proto.EventStatus) (string, error) {
+	switch s {
+	case proto.EventStatus_EVENT_STATUS_DRAFT:
+		return "draft", nil
+	case proto.EventStatus_EVENT_STATUS_PUBLISHED:
+		return "published", nil
+	case proto.EventStatus_EVENT_STATUS_CANCELLED:
+		return "cancelled", nil
+	default:
+		return "", errors.New("unsupported event status")
+	}
+}
+
 func PermissionAllowsSongEdit(perms *proto.PermissionSet, ownerID sql.NullString, currentID string) bool {
 	if perms == nil || perms.Songs == nil {
 		return false
@@ -138,23 +190,62 @@ func PermissionAllowsEventEdit(perms *proto.PermissionSet) bool {
 	return perms != nil && perms.Events != nil && perms.Events.EditEvents
 }
 
+// EventVisible reports whether an event with status s may be read: drafts and
+// cancelled events are only shown to those who manage events.
+func EventVisible(perms *proto.PermissionSet, s proto.EventStatus) bool {
+	return PermissionAllowsEventEdit(perms) || s == proto.EventStatus_EVENT_STATUS_PUBLISHED
+}
+
+// EventVisibilityFilter is the SQL form of EventVisible for the given status column.
+func EventVisibilityFilter(perms *proto.PermissionSet, column string) string {
+	if PermissionAllowsEventEdit(perms) {
+		return "TRUE"
+	}
+	return column + " = 'published'"
+}
+
 func PermissionAllowsTracklistEdit(perms *proto.PermissionSet) bool {
 	return perms != nil && perms.Events != nil && (perms.Events.EditTracklists || perms.Events.EditEvents)
 }
 
+// PermissionIsAdmin reports whether the user is recorded as a club admin.
+func PermissionIsAdmin(perms *proto.PermissionSet) bool {
+	return perms.GetAdmin()
+}
+
+// LoadSongDetails loads a song as seen by currentUserID; notes are stripped for non-admins.
 func LoadSongDetails(ctx context.Context, db *sql.DB, songID, currentUserID string) (*proto.SongDetails, error) {
+	details, err := loadSongDetails(ctx, db, songID, currentUserID)
+	if err != nil {
+		return nil, err
+	}
+	RedactSong(details.Song, details.Permissions)
+	return details, nil
+}
+
+// RedactSong clears the fields perms may not see.
+func RedactSong(s *proto.Song, perms *proto.PermissionSet) {
+	if !PermissionIsAdmin(perms) {
+		s.Notes = ""
+	}
+}
+
+func loadSongDetails(ctx context.Context, db *sql.DB, songID, currentUserID string) (*proto.SongDetails, error) {
 	row := db.QueryRowContext(ctx, `
-		SELECT id, title, artist, description, link_kind, link_url, COALESCE(created_by, NULL), COALESCE(thumbnail_url, '')
+		SELECT id, title, artist, description, link_kind, link_url, COALESCE(created_by, NULL), COALESCE(thumbnail_url, ''), duration_seconds, COALESCE(notes, '')
 		FROM song WHERE id = $1
 	`, songID)
 	var s proto.Song
 	var linkKind, linkURL, thumbnailURL string
 	var creatorID sql.NullString
-	if err := row.Scan(&s.Id, &s.Title, &s.Artist, &s.Description, &linkKind, &linkURL, &creatorID, &thumbnailURL); err != nil {
+	var duration sql.NullInt32
+	if err := row.Scan(&s.Id, &s.Title, &s.Artist, &s.Description, &linkKind, &linkURL, &creatorID, &thumbnailURL, &duration, &s.Notes); err != nil {
 		return nil, err
 	}
 	s.Link = &proto.SongLink{Kind: MapSongLinkType(linkKind), Url: linkURL}
 	s.ThumbnailUrl = thumbnailURL
+	s.CreatedBy = creatorID.String
+	s.DurationSeconds = duration.Int32
 
 	roles, err := LoadSongRoles(ctx, db, songID)
 	if err != nil {
@@ -168,6 +259,10 @@ func LoadSongDetails(ctx context.Context, db *sql.DB, songID, currentUserID stri
 	}
 	s.EditableByMe = PermissionAllowsSongEdit(perms, creatorID, currentUserID)
 
+	if s.VoteCount, err = CountSongVotes(ctx, db, songID); err != nil {
+		return nil, err
+	}
+
 	assignments, err := LoadSongAssignments(ctx, db, songID)
 	if err != nil {
 		return nil, err
@@ -180,6 +275,12 @@ func LoadSongDetails(ctx context.Context, db *sql.DB, songID, currentUserID stri
 	}, nil
 }
 
+func CountSongVotes(ctx context.Context, db *sql.DB, songID string) (int32, error) {
+	var n int32
+	err := db.QueryRowContext(ctx, `SELECT COUNT(*) FROM song_vote WHERE song_id = $1`, songID).Scan(&n)
+	return n, err
+}
+
 func LoadSongRoles(ctx context.Context, db *sql.DB, songID string) ([]string, error) {
 	rows, err := db.QueryContext(ctx, `SELECT role FROM song_role WHERE song_id = $1 ORDER BY role`, songID)
 	if err != nil {
@@ -198,24 +299,16 @@ func LoadSongRoles(ctx context.Context, db *sql.DB, songID string) ([]string, er
 }
 
 func LoadSongAssignments(ctx context.Context, db *sql.DB, songID string) ([]*proto.RoleAssignment, error) {
-	rows, err := db.QueryContext(ctx, `
-		SELECT sra.role,
-		       au.id, au.display_name, COALESCE(au.username, ''), COALESCE(au.avatar_url, ''),
-		       sra.joined_at
-		FROM song_role_assignment sra
-		JOIN app_user au ON sra.user_id = au.id
-		WHERE sra.song_id = $1
-		ORDER BY sra.joined_at ASC
-	`, songID)
+	rows, err := db.QueryContext(ctx, SongAssignmentsQuery, songID)
 	if err != nil {
 		return nil, err
 	}
 	defer rows.Close()
 	var items []*proto.RoleAssignment
 	for rows.Next() {
-		var role, uid, display, username, avatar string
+		var role, uid, display, username, avatar, assignmentStatus string
 		var joined time.Time
-		if err := rows.Scan(&role, &uid, &display, &username, &avatar, &joined); err != nil {
+		if err := rows.Scan(&role, &uid, &display, &username, &avatar, &joined, &assignmentStatus); err != nil {
 			return nil, err
 		}
 		items = append(items, &proto.RoleAssignment{
@@ -227,6 +320,7 @@ func LoadSongAssignments(ctx context.Context, db *sql.DB, songID string) ([]*pro
 				AvatarUrl:   avatar,
 			},
 			JoinedAt: timestamppb.New(joined),
+			Status:   MapParticipationStatus(assignmentStatus),
 		})
 	}
 	return items, rows.Err()
@@ -234,18 +328,26 @@ func LoadSongAssignments(ctx context.Context, db *sql.DB, songID string) ([]*pro
 
 func LoadEventDetails(ctx context.Context, db *sql.DB, eventID, currentUserID string) (*proto.EventDetails, error) {
 	row := db.QueryRowContext(ctx, `
-		SELECT id, title, start_at, location, notify_day_before, notify_hour_before
-		FROM event WHERE id = $1
+		SELECT `+EventColumns+`
+		FROM event WHERE id = $1 AND deleted_at IS NULL
 	`, eventID)
 	var e proto.Event
 	var start sql.NullTime
-	if err := row.Scan(&e.Id, &e.Title, &start, &e.Location, &e.NotifyDayBefore, &e.NotifyHourBefore); err != nil {
+	var eventStatus string
+	if err := row.Scan(&e.Id, &e.Title, &start, &e.Location, &e.NotifyDayBefore, &e.NotifyHourBefore, &e.CreatedBy, &eventStatus); err != nil {
 		return nil, err
 	}
+	e.Status = MapEventStatus(eventStatus)
 	if start.Valid {
 		e.StartAt = timestamppb.New(start.Time)
 	}
 
+	dates, err := LoadEventDates(ctx, db, eventID)
+	if err != nil {
+		return nil, err
+	}
+	e.Dates = dates
+
 	tracklist, err := LoadTracklist(ctx, db, eventID)
 	if err != nil {
 		return nil, err
@@ -256,25 +358,75 @@ func LoadEventDetails(ctx context.Context, db *sql.DB, eventID, currentUserID st
 		return nil, err
 	}
 
+	var participantCount uint32
+	if err := db.QueryRowContext(ctx, `
+		SELECT COUNT(*) FROM (
+			SELECT sra.user_id
+			FROM event_track_item eti
+			JOIN song_role_assignment sra ON sra.song_id = eti.song_id
+			WHERE eti.event_id = $1
+			UNION
+			SELECT user_id FROM event_participant WHERE event_id = $1
+		) p
+	`, eventID).Scan(&participantCount); err != nil {
+		return nil, err
+	}
+
+	var totalDuration, missingDuration uint32
+	if err := db.QueryRowContext(ctx, `
+		SELECT COALESCE(SUM(s.duration_seconds), 0), COUNT(*) FILTER (WHERE s.duration_seconds IS NULL)
+		FROM event_track_item eti
+		LEFT JOIN song s ON s.id = eti.song_id
+		WHERE eti.event_id = $1
+	`, eventID).Scan(&totalDuration, &missingDuration); err != nil {
+		return nil, err
+	}
+
 	perms, err := LoadPermissions(ctx, db, currentUserID)
 	if err != nil {
 		return nil, err
 	}
 
 	return &proto.EventDetails{
-		Event:        &e,
-		Tracklist:    tracklist,
-		Participants: participants,
-		Permissions:  perms,
+		Event:                &e,
+		Tracklist:            tracklist,
+		Participants:         participants,
+		Permissions:          perms,
+		SongCount:            uint32(len(tracklist.GetItems())),
+		ParticipantCount:     participantCount,
+		TotalDurationSeconds: totalDuration,
+		MissingDurationCount: missingDuration,
 	}, nil
 }
 
+func LoadEventDates(ctx context.Context, db *sql.DB, eventID string) ([]*timestamppb.Timestamp, error) {
+	rows, err := db.QueryContext(ctx, `
+		SELECT start_at FROM event_date WHERE event_id = $1 ORDER BY start_at
+	`, eventID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var dates []*timestamppb.Timestamp
+	for rows.Next() {
+		var d time.Time
+		if err := rows.Scan(&d); err != nil {
+			return nil, err
+		}
+		dates = append(dates, timestamppb.New(d))
+	}
+	return dates, rows.Err()
+}
+
 func LoadTracklist(ctx context.Context, db *sql.DB, eventID string) (*proto.Tracklist, error) {
 	rows, err := db.QueryContext(ctx, `
-		SELECT position, COALESCE(song_id, ''), COALESCE(custom_title, ''), COALESCE(custom_artist, '')
-		FROM event_track_item
-		WHERE event_id = $1
-		ORDER BY position
+		SELECT eti.position, COALESCE(eti.song_id::text, ''), COALESCE(eti.custom_title, ''), COALESCE(eti.custom_artist, ''),
+		       COALESCE(s.title, ''), COALESCE(s.artist, ''), COALESCE(s.link_kind, ''), COALESCE(s.link_url, ''),
+		       COALESCE(s.thumbnail_url, ''), COALESCE(s.duration_seconds, 0)
+		FROM event_track_item eti
+		LEFT JOIN song s ON s.id = eti.song_id
+		WHERE eti.event_id = $1
+		ORDER BY eti.position
 	`, eventID)
 	if err != nil {
 		return nil, err
@@ -282,21 +434,88 @@ func LoadTracklist(ctx context.Context, db *sql.DB, eventID string) (*proto.Trac
 	defer rows.Close()
 	var items []*proto.TrackItem
 	for rows.Next() {
-		var pos int32
-		var songID, customTitle, customArtist string
-		if err := rows.Scan(&pos, &songID, &customTitle, &customArtist); err != nil {
+		item, err := scanTrackItem(rows)
+		if err != nil {
 			return nil, err
 		}
-		items = append(items, &proto.TrackItem{
-			Order:        uint32(pos),
-			SongId:       songID,
-			CustomTitle:  customTitle,
-			CustomArtist: customArtist,
-		})
+		items = append(items, item)
 	}
 	return &proto.Tracklist{Items: items}, rows.Err()
 }
 
+// LoadTracklistPreviews loads the first limit tracklist items of each event in
+// one query, together with every event's full tracklist length. Events without
+// items are absent from both maps. eventIDs must be uuids.
+func LoadTracklistPreviews(ctx context.Context, db *sql.DB, eventIDs []string, limit int) (map[string]*proto.Tracklist, map[string]uint32, error) {
+	rows, err := db.QueryContext(ctx, `
+		SELECT eti.position, COALESCE(eti.song_id::text, ''), COALESCE(eti.custom_title, ''), COALESCE(eti.custom_artist, ''),
+		       COALESCE(s.title, ''), COALESCE(s.artist, ''), COALESCE(s.link_kind, ''), COALESCE(s.link_url, ''),
+		       COALESCE(s.thumbnail_url, ''), COALESCE(s.duration_seconds, 0),
+		       eti.event_id::text, eti.song_count
+		FROM (
+			SELECT *,
+			       ROW_NUMBER() OVER (PARTITION BY event_id ORDER BY position) AS rn,
+			       COUNT(*) OVER (PARTITION BY event_id) AS song_count
+			FROM event_track_item
+			WHERE event_id = ANY($1::uuid[])
+		) eti
+		LEFT JOIN song s ON s.id = eti.song_id
+		WHERE eti.rn <= $2
+		ORDER BY eti.event_id, eti.position
+	`, pq.Array(eventIDs), limit)
+	if err != nil {
+		return nil, nil, err
+	}
+	defer rows.Close()
+	tracklists := map[string]*proto.Tracklist{}
+	counts := map[string]uint32{}
+	for rows.Next() {
+		var eventID string
+		var count uint32
+		item, err := scanTrackItem(rows, &eventID, &count)
+		if err != nil {
+			return nil, nil, err
+		}
+		if tracklists[eventID] == nil {
+			tracklists[eventID] = &proto.Tracklist{}
+		}
+		tracklists[eventID].Items = append(tracklists[eventID].Items, item)
+		counts[eventID] = count
+	}
+	return tracklists, counts, rows.Err()
+}
+
+// scanTrackItem reads the track item columns selected by LoadTracklist,
+// followed by any extra columns into extra.
+func scanTrackItem(rows *sql.Rows, extra ...any) (*proto.TrackItem, error) {
+	var pos int32
+	var songID, customTitle, customArtist string
+	var title, artist, linkKind, linkURL, thumbnailURL string
+	var duration int32
+	dest := append([]any{&pos, &songID, &customTitle, &customArtist,
+		&title, &artist, &linkKind, &linkURL, &thumbnailURL, &duration}, extra...)
+	if err := rows.Scan(dest...); err != nil {
+		return nil, err
+	}
+	item := &proto.TrackItem{
+		Order:        uint32(pos),
+		SongId:       songID,
+		CustomTitle:  customTitle,
+		CustomArtist: customArtist,
+	}
+	if songID != "" {
+		item.Song = &proto.Song{
+			Id:              songID,
+			Title:           title,
+			Artist:          artist,
+			Link:            &proto.SongLink{Kind: MapSongLinkType(linkKind), Url: linkURL},
+			ThumbnailUrl:    thumbnailURL,
+			DurationSeconds: duration,
+		}
+	}
+	return item, nil
+}
+
 func LoadEventParticipants(ctx context.Context, db *sql.DB, eventID string) ([]*proto.RoleAssignment, error) {
 	rows, err := db.QueryContext(ctx, `
 		SELECT ep.role,
@@ -339,18 +558,37 @@ func ReplaceTracklist(ctx context.Context, tx *sql.Tx, eventID string, tracklist
 	if tracklist == nil {
 		return nil
 	}
-	for _, item := range tracklist.Items {
+	// Items keep their relative order but are stored gapless from 0.
+	items := slices.Clone(tracklist.Items)
+	slices.SortStableFunc(items, func(a, b *proto.TrackItem) int {
+		return cmp.Compare(a.GetOrder(), b.GetOrder())
+	})
+	for position, item := range items {
 		if _, err := tx.ExecContext(ctx, `
 			INSERT INTO event_track_item (event_id, position, song_id, custom_title, custom_artist)
 			VALUES ($1, $2, NULLIF($3, ''), NULLIF($4, ''), NULLIF($5, ''))
-		`, eventID, item.GetOrder(), item.GetSongId(), item.GetCustomTitle(), item.GetCustomArtist()); err != nil {
+		`, eventID, position, item.GetSongId(), item.GetCustomTitle(), item.GetCustomArtist()); err != nil {
 			return err
 		}
 	}
 	return nil
 }
 
+// IsUniqueViolation reports whether err is a Postgres unique_violation (23505).
+func IsUniqueViolation(err error) bool {
+	var pqErr *pq.Error
+	return errors.As(err, &pqErr) && pqErr.Code == "23505"
+}
+
 // Helper functions
+// MaxTelegramUserID is the largest Telegram user id: ids have at most 52 significant bits.
+const MaxTelegramUserID = 1<<52 - 1
+
+// ValidTelegramUserID rejects zero, negative and clearly bogus Telegram user ids.
+func ValidTelegramUserID(id int64) bool {
+	return id > 0 && id <= MaxTelegramUserID
+}
+
 func AcceptablePassword(password string) bool {
 	if password == "" {
 		return false
@@ -397,7 +635,8 @@ func GetUserPermissions(
 			edit_own_songs,
 			edit_any_songs,
 			edit_events,
-			edit_tracklists
+			edit_tracklists,
+			EXISTS (SELECT 1 FROM app_admin WHERE app_admin.user_id = $1)
 		FROM user_permissions
 		WHERE user_id = $1
 	`, userID).Scan(
@@ -407,6 +646,7 @@ func GetUserPermissions(
 		&permissions.Songs.EditAnySongs,
 		&permissions.Events.EditEvents,
 		&permissions.Events.EditTracklists,
+		&permissions.Admin,
 	)
 
 	if err != nil {
@@ -419,10 +659,3 @@ func GetUserPermissions(
 
 	return permissions, nil
 }
-
-var PublicMethods = map[string]bool{
-	"/musicclub.auth.AuthService/Login":              true,
-	"/musicclub.auth.AuthService/Register":           true,
-	"/musicclub.auth.AuthService/Refresh":            true,
-	"/musicclub.auth.AuthService/TelegramWebAppAuth": true,
-}
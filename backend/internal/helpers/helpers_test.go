@@ -0,0 +1,41 @@
+package helpers
+
+import (
+	"musicclubbot/backend/proto"
+	"testing"
+)
+
+func TestValidTelegramUserID(t *testing.T) {
+	tests := []struct {
+		id   int64
+		want bool
+	}{
+		{-1, false},
+		{0, false},
+		{1, true},
+		{123456789, true},
+		{MaxTelegramUserID, true},
+		{MaxTelegramUserID + 1, false},
+	}
+	for _, tt := range tests {
+		if got := ValidTelegramUserID(tt.id); got != tt.want {
+			t.Errorf("ValidTelegramUserID(%d) = %v, want %v", tt.id, got, tt.want)
+		}
+	}
+}
+
+func TestPermissionIsAdmin(t *testing.T) {
+	allFlags := &proto.PermissionSet{
+		Songs:  &proto.SongPermissions{EditOwnSongs: true, EditAnySongs: true},
+		Events: &proto.EventPermissions{EditEvents: true, EditTracklists: true},
+	}
+	if PermissionIsAdmin(allFlags) {
+		t.Error("edit flags alone made the user an admin")
+	}
+	if !PermissionIsAdmin(&proto.PermissionSet{Admin: true}) {
+		t.Error("recorded admin was not an admin")
+	}
+	if PermissionIsAdmin(nil) {
+		t.Error("nil permissions made the user an admin")
+	}
+}
@@ -0,0 +1,85 @@
+package helpers
+
+import (
+	"context"
+	"musicclubbot/backend/internal/linkcheck"
+	"net/url"
+	"strings"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func LinkCheckQueueFromCtx(ctx context.Context) (*linkcheck.Queue, error) {
+	q, ok := ctx.Value("linkcheck").(*linkcheck.Queue)
+	if !ok || q == nil {
+		return nil, status.Error(codes.Unavailable, "link checks are not available")
+	}
+	return q, nil
+}
+
+// ValidLinkURL reports whether s is an absolute http(s) URL with a host.
+func ValidLinkURL(s string) bool {
+	u, err := url.ParseRequestURI(s)
+	if err != nil {
+		return false
+	}
+	return (u.Scheme == "http" || u.Scheme == "https") && u.Host != ""
+}
+
+// trackingParams are query parameters that never identify the linked content.
+var trackingParams = []string{"utm_", "si", "feature", "fbclid", "gclid", "ref", "from"}
+
+// linkCanonicalizers rewrite a parsed link of the given link_kind in place.
+// Providers without an entry only get the generic cleanup.
+var linkCanonicalizers = map[string]func(u *url.URL){
+	"youtube":      canonicalizeYouTube,
+	"yandex_music": canonicalizeDropQuery,
+	"soundcloud":   canonicalizeSoundCloud,
+}
+
+// CanonicalizeLinkURL returns the stored form of a streaming link so that
+// variants of the same track compare equal. Unparseable input is returned trimmed.
+func CanonicalizeLinkURL(linkKind, raw string) string {
+	raw = strings.TrimSpace(raw)
+	u, err := url.Parse(raw)
+	if err != nil || u.Host == "" {
+		return raw
+	}
+
+	u.Scheme = "https"
+	u.Host = strings.TrimPrefix(strings.ToLower(u.Host), "www.")
+	u.Fragment = ""
+	q := u.Query()
+	for key := range q {
+		for _, p := range trackingParams {
+			if key == p || (strings.HasSuffix(p, "_") && strings.HasPrefix(key, p)) {
+				q.Del(key)
+			}
+		}
+	}
+	u.RawQuery = q.Encode()
+
+	if canonicalize, ok := linkCanonicalizers[linkKind]; ok {
+		canonicalize(u)
+	}
+	return u.String()
+}
+
+func canonicalizeYouTube(u *url.URL) {
+	id := extractYouTubeVideoID(u.String())
+	if id == "" {
+		return
+	}
+	*u = url.URL{Scheme: "https", Host: "www.youtube.com", Path: "/watch", RawQuery: "v=" + id}
+}
+
+func canonicalizeDropQuery(u *url.URL) {
+	u.RawQuery = ""
+	u.Path = strings.TrimSuffix(u.Path, "/")
+}
+
+func canonicalizeSoundCloud(u *url.URL) {
+	u.Host = strings.TrimPrefix(u.Host, "m.")
+	canonicalizeDropQuery(u)
+}
@@ -0,0 +1,24 @@
+package helpers
+
+import "testing"
+
+func TestCanonicalizeLinkURL(t *testing.T) {
+	tests := []struct {
+		kind, raw, want string
+	}{
+		{"youtube", "https://youtu.be/dQw4w9WgXcQ?si=abc", "https://www.youtube.com/watch?v=dQw4w9WgXcQ"},
+		{"youtube", "http://m.youtube.com/watch?v=dQw4w9WgXcQ&feature=share#t=1", "https://www.youtube.com/watch?v=dQw4w9WgXcQ"},
+		{"youtube", "https://www.youtube.com/embed/dQw4w9WgXcQ", "https://www.youtube.com/watch?v=dQw4w9WgXcQ"},
+		{"yandex_music", "https://music.yandex.ru/album/1/track/2/?utm_source=share&lang=en", "https://music.yandex.ru/album/1/track/2"},
+		{"soundcloud", "https://m.soundcloud.com/artist/track?in=playlist", "https://soundcloud.com/artist/track"},
+		{"other", "HTTP://WWW.Example.com/x?gclid=1&id=7#top", "https://example.com/x?id=7"},
+		{"other", "https://example.com/x?utm_source=a&utm_medium=b", "https://example.com/x"},
+		{"other", "  not a url  ", "not a url"},
+		{"youtube", "", ""},
+	}
+	for _, tt := range tests {
+		if got := CanonicalizeLinkURL(tt.kind, tt.raw); got != tt.want {
+			t.Errorf("CanonicalizeLinkURL(%q, %q) = %q, want %q", tt.kind, tt.raw, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,67 @@
+package helpers
+
+import (
+	"strings"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/types/known/fieldmaskpb"
+)
+
+// maskTree holds read mask paths split on dots. A nil subtree keeps the whole field.
+type maskTree map[string]maskTree
+
+// ValidateReadMask rejects masks naming fields that msg does not have.
+func ValidateReadMask(msg protoreflect.ProtoMessage, mask *fieldmaskpb.FieldMask) error {
+	if len(mask.GetPaths()) > 0 && !mask.IsValid(msg) {
+		return status.Errorf(codes.InvalidArgument, "read_mask: unknown field in %v", mask.GetPaths())
+	}
+	return nil
+}
+
+// ApplyReadMask clears every field of msg not named by mask. An empty mask keeps everything.
+// The mask must have passed ValidateReadMask.
+func ApplyReadMask(msg protoreflect.ProtoMessage, mask *fieldmaskpb.FieldMask) {
+	if len(mask.GetPaths()) == 0 {
+		return
+	}
+	tree := maskTree{}
+	for _, path := range mask.GetPaths() {
+		node := tree
+		parts := strings.Split(path, ".")
+		for i, part := range parts {
+			child, seen := node[part]
+			if seen && child == nil {
+				break // a shorter path already keeps this whole field
+			}
+			if i == len(parts)-1 {
+				node[part] = nil
+				break
+			}
+			if child == nil {
+				child = maskTree{}
+				node[part] = child
+			}
+			node = child
+		}
+	}
+	pruneMessage(msg.ProtoReflect(), tree)
+}
+
+func pruneMessage(m protoreflect.Message, keep maskTree) {
+	var drop []protoreflect.FieldDescriptor
+	m.Range(func(fd protoreflect.FieldDescriptor, v protoreflect.Value) bool {
+		sub, ok := keep[string(fd.Name())]
+		switch {
+		case !ok:
+			drop = append(drop, fd)
+		case sub != nil && fd.Message() != nil && !fd.IsList() && !fd.IsMap():
+			pruneMessage(v.Message(), sub)
+		}
+		return true
+	})
+	for _, fd := range drop {
+		m.Clear(fd)
+	}
+}
@@ -0,0 +1,104 @@
+package helpers
+
+import (
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/base64"
+	"musicclubbot/backend/internal/config"
+	"strconv"
+	"strings"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// Page tokens are "<kind>:<offset>.<signature>" encoded with base64url, where the
+// signature is an HMAC-SHA256 of "<kind>:<offset>" so clients cannot forge cursors.
+// The kind names the list a token came from, so a cursor from one list cannot be
+// replayed against another.
+
+// DefaultPageSize and MaxPageSize bound page_size on paginated list RPCs.
+const (
+	DefaultPageSize = 20
+	MaxPageSize     = 100
+)
+
+// PageSize returns the number of rows to fetch for a requested page_size:
+// DefaultPageSize when unset, clamped to MaxPageSize.
+func PageSize(requested uint32) int {
+	if requested == 0 {
+		return DefaultPageSize
+	}
+	if requested > MaxPageSize {
+		return MaxPageSize
+	}
+	return int(requested)
+}
+
+func pageTokenKey(ctx context.Context) []byte {
+	cfg, _ := ctx.Value("cfg").(config.Config)
+	return cfg.PageTokenKey
+}
+
+func signPageToken(key []byte, payload string) string {
+	mac := hmac.New(sha256.New, key)
+	mac.Write([]byte(payload))
+	return base64.RawURLEncoding.EncodeToString(mac.Sum(nil))
+}
+
+// EncodePageToken returns a signed token pointing at offset in the list named by kind.
+func EncodePageToken(ctx context.Context, kind string, offset int) string {
+	return EncodeCursor(ctx, kind, strconv.Itoa(offset))
+}
+
+// DecodePageToken verifies a token produced by EncodePageToken for the same kind of list.
+// An empty token means the first page.
+func DecodePageToken(ctx context.Context, kind, token string) (int, error) {
+	if token == "" {
+		return 0, nil
+	}
+	rawOffset, err := DecodeCursor(ctx, kind, token)
+	if err != nil {
+		return 0, err
+	}
+	offset, err := strconv.Atoi(rawOffset)
+	if err != nil || offset < 0 {
+		return 0, errInvalidPageToken
+	}
+	return offset, nil
+}
+
+var errInvalidPageToken = status.Error(codes.InvalidArgument, "invalid page token")
+
+// EncodeCursor returns a signed token carrying value, for lists that page by
+// key instead of by offset. value must not contain dots.
+func EncodeCursor(ctx context.Context, kind, value string) string {
+	payload := kind + ":" + value
+	token := payload + "." + signPageToken(pageTokenKey(ctx), payload)
+	return base64.RawURLEncoding.EncodeToString([]byte(token))
+}
+
+// DecodeCursor verifies a token produced by EncodeCursor for the same kind of
+// list and returns its value. An empty token yields an empty value.
+func DecodeCursor(ctx context.Context, kind, token string) (string, error) {
+	if token == "" {
+		return "", nil
+	}
+	raw, err := base64.RawURLEncoding.DecodeString(token)
+	if err != nil {
+		return "", errInvalidPageToken
+	}
+	payload, sig, ok := strings.Cut(string(raw), ".")
+	if !ok || !hmac.Equal([]byte(sig), []byte(signPageToken(pageTokenKey(ctx), payload))) {
+		return "", errInvalidPageToken
+	}
+	tokenKind, value, ok := strings.Cut(payload, ":")
+	if !ok {
+		return "", errInvalidPageToken
+	}
+	if tokenKind != kind {
+		return "", status.Errorf(codes.InvalidArgument, "page token belongs to a different list (%s)", tokenKind)
+	}
+	return value, nil
+}
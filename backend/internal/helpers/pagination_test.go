@@ -0,0 +1,90 @@
+package helpers
+
+import (
+	"context"
+	"encoding/base64"
+	"musicclubbot/backend/internal/config"
+	"testing"
+
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestPageSize(t *testing.T) {
+	tests := []struct {
+		requested uint32
+		want      int
+	}{
+		{0, DefaultPageSize},
+		{1, 1},
+		{DefaultPageSize, DefaultPageSize},
+		{MaxPageSize, MaxPageSize},
+		{MaxPageSize + 1, MaxPageSize},
+		{1 << 31, MaxPageSize},
+	}
+	for _, tt := range tests {
+		if got := PageSize(tt.requested); got != tt.want {
+			t.Errorf("PageSize(%d) = %d, want %d", tt.requested, got, tt.want)
+		}
+	}
+}
+
+func testTokenCtx(key string) context.Context {
+	return context.WithValue(context.Background(), "cfg", config.Config{PageTokenKey: []byte(key)})
+}
+
+func TestPageTokenRoundTrip(t *testing.T) {
+	ctx := testTokenCtx("test-key")
+	for _, offset := range []int{0, 1, 20, 12345} {
+		token := EncodePageToken(ctx, "songs", offset)
+		got, err := DecodePageToken(ctx, "songs", token)
+		if err != nil || got != offset {
+			t.Errorf("DecodePageToken(EncodePageToken(%d)) = %d, %v", offset, got, err)
+		}
+	}
+	if got, err := DecodePageToken(ctx, "songs", ""); err != nil || got != 0 {
+		t.Errorf("empty token = %d, %v, want the first page", got, err)
+	}
+}
+
+func TestDecodePageTokenRejectsTampering(t *testing.T) {
+	ctx := testTokenCtx("test-key")
+	valid := EncodePageToken(ctx, "songs", 20)
+	raw, _ := base64.RawURLEncoding.DecodeString(valid)
+
+	tests := []struct {
+		name  string
+		token string
+	}{
+		{"other key", EncodePageToken(testTokenCtx("other-key"), "songs", 20)},
+		{"not base64", "!!!"},
+		{"no signature", base64.RawURLEncoding.EncodeToString([]byte("songs:20"))},
+		{"negative offset", EncodeCursor(ctx, "songs", "-5")},
+		{"non-numeric offset", EncodeCursor(ctx, "songs", "abc")},
+	}
+	// Flipping any single byte of the payload or signature invalidates the token.
+	for i := range raw {
+		flipped := append([]byte(nil), raw...)
+		flipped[i] ^= 0x01
+		tests = append(tests, struct {
+			name  string
+			token string
+		}{"flipped byte", base64.RawURLEncoding.EncodeToString(flipped)})
+	}
+	for _, tt := range tests {
+		if _, err := DecodePageToken(ctx, "songs", tt.token); status.Code(err) != codes.InvalidArgument {
+			t.Errorf("%s: DecodePageToken(%q) = %v, want InvalidArgument", tt.name, tt.token, err)
+		}
+	}
+}
+
+func TestDecodePageTokenRejectsOtherList(t *testing.T) {
+	ctx := testTokenCtx("test-key")
+	songsToken := EncodePageToken(ctx, "songs", 20)
+	if _, err := DecodePageToken(ctx, "events", songsToken); status.Code(err) != codes.InvalidArgument {
+		t.Errorf("songs cursor on the events list = %v, want InvalidArgument", err)
+	}
+	if _, err := DecodeCursor(ctx, "songs/1", EncodeCursor(ctx, "songs/2", "x")); status.Code(err) != codes.InvalidArgument {
+		t.Errorf("cursor for other filters = %v, want InvalidArgument", err)
+	}
+}
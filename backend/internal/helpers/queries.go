@@ -0,0 +1,32 @@
+package helpers
+
+// SQL fragments shared by the list handlers and AdminService.ExplainQuery, so
+// the plans an admin inspects are the plans the handlers actually run.
+
+// SongColumns is the song column list scanSongs reads, in order. notes is
+// always selected; scanSongs drops it for everyone but admins.
+const SongColumns = `id, title, artist, description, link_kind, link_url, COALESCE(created_by, NULL), COALESCE(thumbnail_url, ''), duration_seconds, COALESCE(notes, '')`
+
+// SongListOrder is the ListSongs order; id breaks ties so offsets address
+// the same rows on every page.
+const SongListOrder = "created_at DESC, id DESC"
+
+// SongSearchFilter matches the ILIKE pattern in $1 against title and artist.
+const SongSearchFilter = "(title ILIKE $1 OR artist ILIKE $1)"
+
+// EventColumns is the event column list scanEvents reads, in order.
+const EventColumns = `id, title, start_at, location, notify_day_before, notify_hour_before, COALESCE(created_by::text, ''), status`
+
+// EventListOrder is the ListEvents order.
+const EventListOrder = "start_at NULLS LAST"
+
+// SongAssignmentsQuery loads the role assignments of the song in $1.
+const SongAssignmentsQuery = `
+	SELECT sra.role,
+	       au.id, au.display_name, COALESCE(au.username, ''), COALESCE(au.avatar_url, ''),
+	       sra.joined_at, sra.status
+	FROM song_role_assignment sra
+	JOIN app_user au ON sra.user_id = au.id
+	WHERE sra.song_id = $1
+	ORDER BY sra.joined_at ASC
+`
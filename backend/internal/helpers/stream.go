@@ -0,0 +1,22 @@
+package helpers
+
+import (
+	"context"
+
+	"google.golang.org/grpc"
+)
+
+type contextStream struct {
+	grpc.ServerStream
+	ctx context.Context
+}
+
+func (s contextStream) Context() context.Context {
+	return s.ctx
+}
+
+// WithStreamContext returns ss with its context replaced by ctx, so stream
+// interceptors can pass values on to the handler.
+func WithStreamContext(ss grpc.ServerStream, ctx context.Context) grpc.ServerStream {
+	return contextStream{ServerStream: ss, ctx: ctx}
+}
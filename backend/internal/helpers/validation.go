@@ -0,0 +1,105 @@
+package helpers
+
+import (
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/google/uuid"
+	"google.golang.org/genproto/googleapis/rpc/errdetails"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// Violations accumulates request validation failures so that a client gets
+// every bad field in a single InvalidArgument response.
+type Violations struct {
+	fields []*errdetails.BadRequest_FieldViolation
+}
+
+// Check records a failure for field unless ok is true.
+func (v *Violations) Check(ok bool, field, description string) {
+	if !ok {
+		v.Add(field, description)
+	}
+}
+
+// Add records a failure for field.
+func (v *Violations) Add(field, description string) {
+	v.fields = append(v.fields, &errdetails.BadRequest_FieldViolation{
+		Field:       field,
+		Description: description,
+	})
+}
+
+// CheckText records a failure for field if s is not valid UTF-8 or contains
+// control characters. Multiline fields may additionally contain newlines.
+func (v *Violations) CheckText(field, s string, multiline bool) {
+	if !utf8.ValidString(s) {
+		v.Add(field, "must be valid UTF-8")
+		return
+	}
+	for _, r := range s {
+		if unicode.IsControl(r) && !(multiline && r == '\n') {
+			v.Add(field, "must not contain control characters")
+			return
+		}
+	}
+}
+
+// CheckRole records a failure for field if role is blank or contains control
+// or invisible format characters such as zero-width spaces, which would make
+// roles that look identical compare unequal.
+func (v *Violations) CheckRole(field, role string) {
+	if strings.TrimSpace(role) == "" {
+		v.Add(field, "must not be empty")
+		return
+	}
+	if !utf8.ValidString(role) {
+		v.Add(field, "must be valid UTF-8")
+		return
+	}
+	for _, r := range role {
+		if unicode.IsControl(r) || unicode.Is(unicode.Cf, r) {
+			v.Add(field, "must not contain control or invisible characters")
+			return
+		}
+	}
+}
+
+// CheckUUID records a failure for field unless s is a uuid, and returns the
+// parsed id, or uuid.Nil on failure.
+func (v *Violations) CheckUUID(field, s string) uuid.UUID {
+	if s == "" {
+		v.Add(field, "must not be empty")
+		return uuid.Nil
+	}
+	id, err := uuid.Parse(s)
+	if err != nil {
+		v.Add(field, "must be a uuid")
+		return uuid.Nil
+	}
+	return id
+}
+
+// Fields returns the recorded violations in the order they were added.
+func (v *Violations) Fields() []*errdetails.BadRequest_FieldViolation {
+	return v.fields
+}
+
+// Err returns nil when nothing was recorded, otherwise an InvalidArgument
+// status carrying a BadRequest detail with all violations.
+func (v *Violations) Err() error {
+	if len(v.fields) == 0 {
+		return nil
+	}
+	msg := "invalid request: " + v.fields[0].Field + ": " + v.fields[0].Description
+	if len(v.fields) > 1 {
+		msg += " (and more)"
+	}
+	st := status.New(codes.InvalidArgument, msg)
+	if withDetails, err := st.WithDetails(&errdetails.BadRequest{FieldViolations: v.fields}); err == nil {
+		st = withDetails
+	}
+	return st.Err()
+}
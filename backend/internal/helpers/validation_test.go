@@ -0,0 +1,116 @@
+package helpers
+
+import (
+	"testing"
+
+	"google.golang.org/genproto/googleapis/rpc/errdetails"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func TestViolationsErr(t *testing.T) {
+	v := &Violations{}
+	v.Check(true, "title", "must not be empty")
+	if err := v.Err(); err != nil {
+		t.Fatalf("Err() with no violations = %v, want nil", err)
+	}
+
+	v.Check(false, "title", "must not be empty")
+	v.Add("items[0].order", "position already taken")
+	st := status.Convert(v.Err())
+	if st.Code() != codes.InvalidArgument {
+		t.Fatalf("code = %s, want InvalidArgument", st.Code())
+	}
+	if want := "invalid request: title: must not be empty (and more)"; st.Message() != want {
+		t.Errorf("message = %q, want %q", st.Message(), want)
+	}
+	var fields []string
+	for _, d := range st.Details() {
+		if br, ok := d.(*errdetails.BadRequest); ok {
+			for _, f := range br.GetFieldViolations() {
+				fields = append(fields, f.GetField())
+			}
+		}
+	}
+	if len(fields) != 2 || fields[0] != "title" || fields[1] != "items[0].order" {
+		t.Errorf("detail fields = %v, want [title items[0].order]", fields)
+	}
+}
+
+func TestCheckText(t *testing.T) {
+	tests := []struct {
+		s         string
+		multiline bool
+		wantOK    bool
+	}{
+		{"plain", false, true},
+		{"two\nlines", true, true},
+		{"two\nlines", false, false},
+		{"null\x00byte", true, false},
+		{"bell\x07", false, false},
+		{"tab\there", true, false},
+		{"bad\xff", true, false},
+	}
+	for _, tt := range tests {
+		v := &Violations{}
+		v.CheckText("field", tt.s, tt.multiline)
+		if ok := len(v.Fields()) == 0; ok != tt.wantOK {
+			t.Errorf("CheckText(%q, %v) ok = %v, want %v", tt.s, tt.multiline, ok, tt.wantOK)
+		}
+	}
+}
+
+func TestCheckRole(t *testing.T) {
+	tests := []struct {
+		role string
+		want string
+	}{
+		{"drums", ""},
+		{"бас-гитара", ""},
+		{"lead vocals", ""},
+		{"", "must not be empty"},
+		{"   ", "must not be empty"},
+		{"dr\u200bums", "must not contain control or invisible characters"},
+		{"dr\tums", "must not contain control or invisible characters"},
+		{"\ufeffdrums", "must not contain control or invisible characters"},
+		{"dr\xffums", "must be valid UTF-8"},
+	}
+	for _, tt := range tests {
+		v := &Violations{}
+		v.CheckRole("role", tt.role)
+		got := ""
+		if fields := v.Fields(); len(fields) > 0 {
+			got = fields[0].GetDescription()
+		}
+		if got != tt.want {
+			t.Errorf("CheckRole(%q) = %q, want %q", tt.role, got, tt.want)
+		}
+	}
+}
+
+func TestCheckUUID(t *testing.T) {
+	const id = "5f0c6a8e-4b1d-4a55-9a3e-2f7c1d0b9e11"
+	tests := []struct {
+		in   string
+		want string
+	}{
+		{id, ""},
+		{"5F0C6A8E-4B1D-4A55-9A3E-2F7C1D0B9E11", ""},
+		{"", "must not be empty"},
+		{"5f0c6a8e", "must be a uuid"},
+	}
+	for _, tt := range tests {
+		v := &Violations{}
+		got := v.CheckUUID("id", tt.in)
+		desc := ""
+		if fields := v.Fields(); len(fields) > 0 {
+			desc = fields[0].GetDescription()
+		}
+		if desc != tt.want {
+			t.Errorf("CheckUUID(%q) recorded %q, want %q", tt.in, desc, tt.want)
+		}
+		if tt.want == "" && got.String() != id {
+			t.Errorf("CheckUUID(%q) = %s, want %s", tt.in, got, id)
+		}
+	}
+}
@@ -0,0 +1,209 @@
+package linkcheck
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"net"
+	"net/http"
+	"net/url"
+	"strings"
+	"syscall"
+	"time"
+
+	"github.com/apsdehal/go-logger"
+)
+
+// Health values stored in link_status.status.
+const (
+	HealthOK          = "ok"
+	HealthBroken      = "broken"
+	HealthUnreachable = "unreachable"
+	HealthMissing     = "missing"
+)
+
+// Errors stored in link_status.error. They are shown to every member, so they
+// never include addresses or dial errors.
+const (
+	errInvalidURL     = "invalid link"
+	errHostNotAllowed = "host is not allowed for this link kind"
+	errUnreachable    = "request failed"
+)
+
+// maxRedirects bounds how many redirects a probe follows.
+const maxRedirects = 5
+
+// allowedHosts lists the domains each link_kind may point to; subdomains
+// are allowed too. Links of other kinds are never probed.
+var allowedHosts = map[string][]string{
+	"youtube":      {"youtube.com", "youtu.be"},
+	"yandex_music": {"music.yandex.ru", "music.yandex.com", "music.yandex.by", "music.yandex.kz"},
+	"soundcloud":   {"soundcloud.com", "snd.sc"},
+}
+
+var errBlockedAddress = errors.New("address is not publicly routable")
+
+// Queue is a bounded queue of song ids whose links should be checked.
+// A single worker started with Run drains it in the background.
+type Queue struct {
+	ids    chan string
+	client *http.Client
+}
+
+// NewQueue returns a queue holding at most size pending checks.
+func NewQueue(size int) *Queue {
+	if size <= 0 {
+		size = 1
+	}
+	dialer := &net.Dialer{Timeout: 5 * time.Second, Control: refusePrivateAddress}
+	return &Queue{
+		ids: make(chan string, size),
+		client: &http.Client{
+			Timeout: 10 * time.Second,
+			// No proxy: the dialer must see the real destination address.
+			Transport: &http.Transport{DialContext: dialer.DialContext},
+			CheckRedirect: func(req *http.Request, via []*http.Request) error {
+				if len(via) >= maxRedirects {
+					return http.ErrUseLastResponse
+				}
+				return nil
+			},
+		},
+	}
+}
+
+// Enqueue schedules a check without blocking and reports false when the queue is full.
+func (q *Queue) Enqueue(songID string) bool {
+	select {
+	case q.ids <- songID:
+		return true
+	default:
+		return false
+	}
+}
+
+// statusStore reads song links and records check results.
+type statusStore interface {
+	songLink(ctx context.Context, songID string) (kind, link string, found bool, err error)
+	saveStatus(ctx context.Context, songID, health string, httpStatus int, checkErr string) error
+}
+
+type dbStore struct {
+	db *sql.DB
+}
+
+func (s dbStore) songLink(ctx context.Context, songID string) (string, string, bool, error) {
+	var kind, link string
+	err := s.db.QueryRowContext(ctx, `SELECT link_kind, link_url FROM song WHERE id = $1`, songID).Scan(&kind, &link)
+	if err == sql.ErrNoRows {
+		return "", "", false, nil
+	}
+	return kind, link, err == nil, err
+}
+
+func (s dbStore) saveStatus(ctx context.Context, songID, health string, httpStatus int, checkErr string) error {
+	_, err := s.db.ExecContext(ctx, `
+		INSERT INTO link_status (song_id, status, http_status, error, checked_at)
+		VALUES ($1, $2, $3, $4, NOW())
+		ON CONFLICT (song_id) DO UPDATE
+		SET status = EXCLUDED.status, http_status = EXCLUDED.http_status,
+		    error = EXCLUDED.error, checked_at = EXCLUDED.checked_at
+	`, songID, health, httpStatus, checkErr)
+	return err
+}
+
+// Run checks queued songs one by one until ctx is cancelled.
+func (q *Queue) Run(ctx context.Context, db *sql.DB, log *logger.Logger) {
+	q.run(ctx, dbStore{db: db}, log.Warningf)
+}
+
+func (q *Queue) run(ctx context.Context, store statusStore, warnf func(format string, a ...interface{})) {
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case songID := <-q.ids:
+			if err := q.check(ctx, store, songID); err != nil {
+				warnf("link check for song %s failed: %v", songID, err)
+			}
+		}
+	}
+}
+
+func (q *Queue) check(ctx context.Context, store statusStore, songID string) error {
+	kind, link, found, err := store.songLink(ctx, songID)
+	if err != nil {
+		return err
+	}
+	if !found {
+		return nil // deleted while queued
+	}
+
+	health, httpStatus, checkErr := HealthMissing, 0, ""
+	if link != "" {
+		health, httpStatus, checkErr = q.probe(ctx, kind, link)
+	}
+	return store.saveStatus(ctx, songID, health, httpStatus, checkErr)
+}
+
+// probe tries HEAD first and falls back to GET for hosts that reject HEAD.
+func (q *Queue) probe(ctx context.Context, kind, link string) (string, int, string) {
+	u, err := url.Parse(link)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") {
+		return HealthBroken, 0, errInvalidURL
+	}
+	if !HostAllowed(kind, u.Hostname()) {
+		return HealthBroken, 0, errHostNotAllowed
+	}
+
+	code := 0
+	for _, method := range []string{http.MethodHead, http.MethodGet} {
+		req, err := http.NewRequestWithContext(ctx, method, u.String(), nil)
+		if err != nil {
+			return HealthBroken, 0, errInvalidURL
+		}
+		resp, err := q.client.Do(req)
+		if err != nil {
+			return HealthUnreachable, 0, errUnreachable
+		}
+		resp.Body.Close()
+		code = resp.StatusCode
+		if code != http.StatusMethodNotAllowed && code != http.StatusForbidden {
+			break
+		}
+	}
+	if code >= 400 {
+		return HealthBroken, code, http.StatusText(code)
+	}
+	return HealthOK, code, ""
+}
+
+// HostAllowed reports whether host belongs to a domain allowed for link_kind.
+func HostAllowed(kind, host string) bool {
+	host = strings.TrimSuffix(strings.ToLower(host), ".")
+	for _, domain := range allowedHosts[kind] {
+		if host == domain || strings.HasSuffix(host, "."+domain) {
+			return true
+		}
+	}
+	return false
+}
+
+// refusePrivateAddress runs for every connection, redirects included, after
+// DNS resolution, so names resolving to internal addresses are refused too.
+func refusePrivateAddress(_, address string, _ syscall.RawConn) error {
+	host, _, err := net.SplitHostPort(address)
+	if err != nil {
+		return err
+	}
+	ip := net.ParseIP(host)
+	if ip == nil || !publicIP(ip) {
+		return errBlockedAddress
+	}
+	return nil
+}
+
+func publicIP(ip net.IP) bool {
+	return !(ip.IsLoopback() || ip.IsPrivate() || ip.IsLinkLocalUnicast() || ip.IsLinkLocalMulticast() ||
+		ip.IsInterfaceLocalMulticast() || ip.IsMulticast() || ip.IsUnspecified())
+}
@@ -0,0 +1,138 @@
+package linkcheck
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+type savedStatus struct {
+	songID, health, checkErr string
+	httpStatus               int
+}
+
+type fakeStore struct {
+	links map[string][2]string // song id -> link_kind, link_url
+	saved chan savedStatus
+}
+
+func (s *fakeStore) songLink(_ context.Context, songID string) (string, string, bool, error) {
+	link, ok := s.links[songID]
+	return link[0], link[1], ok, nil
+}
+
+func (s *fakeStore) saveStatus(_ context.Context, songID, health string, httpStatus int, checkErr string) error {
+	s.saved <- savedStatus{songID: songID, health: health, httpStatus: httpStatus, checkErr: checkErr}
+	return nil
+}
+
+func TestEnqueueRecordsStatus(t *testing.T) {
+	tests := []struct {
+		name     string
+		kind     string
+		link     string
+		health   string
+		checkErr string
+	}{
+		{"missing link", "youtube", "", HealthMissing, ""},
+		{"internal address", "youtube", "http://10.0.0.5:5432/", HealthBroken, errHostNotAllowed},
+		{"metadata service", "soundcloud", "http://169.254.169.254/latest/meta-data", HealthBroken, errHostNotAllowed},
+		{"foreign host", "youtube", "https://soundcloud.com/artist/track", HealthBroken, errHostNotAllowed},
+		{"not http", "youtube", "ftp://youtube.com/x", HealthBroken, errInvalidURL},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			store := &fakeStore{
+				links: map[string][2]string{"song": {tt.kind, tt.link}},
+				saved: make(chan savedStatus, 1),
+			}
+			q := NewQueue(1)
+			ctx, cancel := context.WithCancel(context.Background())
+			defer cancel()
+			go q.run(ctx, store, t.Logf)
+
+			if !q.Enqueue("song") {
+				t.Fatal("Enqueue on an empty queue returned false")
+			}
+			select {
+			case got := <-store.saved:
+				if got.songID != "song" || got.health != tt.health || got.checkErr != tt.checkErr {
+					t.Errorf("recorded %+v, want health %q error %q", got, tt.health, tt.checkErr)
+				}
+			case <-time.After(2 * time.Second):
+				t.Fatal("worker did not record a status")
+			}
+		})
+	}
+}
+
+func TestEnqueueSkipsDeletedSong(t *testing.T) {
+	store := &fakeStore{links: map[string][2]string{}, saved: make(chan savedStatus, 1)}
+	q := NewQueue(1)
+	if err := q.check(context.Background(), store, "gone"); err != nil {
+		t.Fatalf("check: %v", err)
+	}
+	select {
+	case got := <-store.saved:
+		t.Errorf("recorded %+v for a deleted song", got)
+	default:
+	}
+}
+
+func TestEnqueueFullQueue(t *testing.T) {
+	q := NewQueue(1)
+	if !q.Enqueue("a") {
+		t.Fatal("first Enqueue returned false")
+	}
+	if q.Enqueue("b") {
+		t.Error("Enqueue on a full queue returned true")
+	}
+}
+
+func TestHostAllowed(t *testing.T) {
+	tests := []struct {
+		kind, host string
+		want       bool
+	}{
+		{"youtube", "youtube.com", true},
+		{"youtube", "www.youtube.com", true},
+		{"youtube", "YouTu.be", true},
+		{"youtube", "notyoutube.com", false},
+		{"youtube", "youtube.com.evil.example", false},
+		{"soundcloud", "m.soundcloud.com", true},
+		{"yandex_music", "music.yandex.ru", true},
+		{"yandex_music", "yandex.ru", false},
+		{"", "youtube.com", false},
+		{"youtube", "10.0.0.5", false},
+	}
+	for _, tt := range tests {
+		if got := HostAllowed(tt.kind, tt.host); got != tt.want {
+			t.Errorf("HostAllowed(%q, %q) = %v, want %v", tt.kind, tt.host, got, tt.want)
+		}
+	}
+}
+
+func TestRefusePrivateAddress(t *testing.T) {
+	tests := []struct {
+		address string
+		allowed bool
+	}{
+		{"127.0.0.1:80", false},
+		{"10.0.0.5:5432", false},
+		{"172.16.3.4:443", false},
+		{"192.168.1.1:80", false},
+		{"169.254.169.254:80", false},
+		{"0.0.0.0:80", false},
+		{"[::1]:443", false},
+		{"[fe80::1]:443", false},
+		{"[fd00::1]:443", false},
+		{"142.250.74.46:443", true},
+		{"[2a00:1450:4001:82a::200e]:443", true},
+	}
+	for _, tt := range tests {
+		err := refusePrivateAddress("tcp", tt.address, nil)
+		if (err == nil) != tt.allowed {
+			t.Errorf("refusePrivateAddress(%q) = %v, want allowed=%v", tt.address, err, tt.allowed)
+		}
+	}
+}
@@ -0,0 +1,48 @@
+package logging
+
+import (
+	"encoding/json"
+	"io"
+	"strings"
+	"time"
+)
+
+// fieldSep separates the fields of JSONFormat; it never appears in log text.
+const fieldSep = "\x1f"
+
+// JSONFormat is the go-logger format whose output JSONWriter understands.
+const JSONFormat = "%{lvl}" + fieldSep + "%{message}"
+
+type jsonLine struct {
+	Time    string `json:"time"`
+	Level   string `json:"level"`
+	Message string `json:"message"`
+}
+
+// JSONWriter turns each line written with JSONFormat into one JSON object.
+type JSONWriter struct {
+	out io.Writer
+}
+
+func NewJSONWriter(out io.Writer) *JSONWriter {
+	return &JSONWriter{out: out}
+}
+
+func (w *JSONWriter) Write(p []byte) (int, error) {
+	line := jsonLine{Time: time.Now().UTC().Format(time.RFC3339Nano)}
+	level, message, ok := strings.Cut(strings.TrimRight(string(p), "\n"), fieldSep)
+	if ok {
+		line.Level, line.Message = strings.TrimSpace(level), message
+	} else {
+		line.Message = level
+	}
+
+	encoded, err := json.Marshal(line)
+	if err != nil {
+		return 0, err
+	}
+	if _, err := w.out.Write(append(encoded, '\n')); err != nil {
+		return 0, err
+	}
+	return len(p), nil
+}
@@ -0,0 +1,1053 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v3.21.12
+// source: admin.proto
+
+package proto
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Whitelisted queries that may be explained. Arbitrary SQL is never accepted.
+type ExplainTarget int32
+
+const (
+	ExplainTarget_EXPLAIN_TARGET_UNKNOWN                  ExplainTarget = 0
+	ExplainTarget_EXPLAIN_TARGET_LIST_SONGS               ExplainTarget = 1
+	ExplainTarget_EXPLAIN_TARGET_SEARCH_SONGS             ExplainTarget = 2
+	ExplainTarget_EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG ExplainTarget = 3
+	ExplainTarget_EXPLAIN_TARGET_LIST_EVENTS              ExplainTarget = 4
+)
+
+// Enum value maps for ExplainTarget.
+var (
+	ExplainTarget_name = map[int32]string{
+		0: "EXPLAIN_TARGET_UNKNOWN",
+		1: "EXPLAIN_TARGET_LIST_SONGS",
+		2: "EXPLAIN_TARGET_SEARCH_SONGS",
+		3: "EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG",
+		4: "EXPLAIN_TARGET_LIST_EVENTS",
+	}
+	ExplainTarget_value = map[string]int32{
+		"EXPLAIN_TARGET_UNKNOWN":                  0,
+		"EXPLAIN_TARGET_LIST_SONGS":               1,
+		"EXPLAIN_TARGET_SEARCH_SONGS":             2,
+		"EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG": 3,
+		"EXPLAIN_TARGET_LIST_EVENTS":              4,
+	}
+)
+
+func (x ExplainTarget) Enum() *ExplainTarget {
+	p := new(ExplainTarget)
+	*p = x
+	return p
+}
+
+func (x ExplainTarget) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ExplainTarget) Descriptor() protoreflect.EnumDescriptor {
+	return file_admin_proto_enumTypes[0].Descriptor()
+}
+
+func (ExplainTarget) Type() protoreflect.EnumType {
+	return &file_admin_proto_enumTypes[0]
+}
+
+func (x ExplainTarget) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ExplainTarget.Descriptor instead.
+func (ExplainTarget) EnumDescriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{0}
+}
+
+type LinkUpdateStatus int32
+
+const (
+	LinkUpdateStatus_LINK_UPDATE_STATUS_UNKNOWN   LinkUpdateStatus = 0
+	LinkUpdateStatus_LINK_UPDATE_STATUS_UPDATED   LinkUpdateStatus = 1
+	LinkUpdateStatus_LINK_UPDATE_STATUS_NOT_FOUND LinkUpdateStatus = 2
+	LinkUpdateStatus_LINK_UPDATE_STATUS_INVALID   LinkUpdateStatus = 3
+)
+
+// Enum value maps for LinkUpdateStatus.
+var (
+	LinkUpdateStatus_name = map[int32]string{
+		0: "LINK_UPDATE_STATUS_UNKNOWN",
+		1: "LINK_UPDATE_STATUS_UPDATED",
+		2: "LINK_UPDATE_STATUS_NOT_FOUND",
+		3: "LINK_UPDATE_STATUS_INVALID",
+	}
+	LinkUpdateStatus_value = map[string]int32{
+		"LINK_UPDATE_STATUS_UNKNOWN":   0,
+		"LINK_UPDATE_STATUS_UPDATED":   1,
+		"LINK_UPDATE_STATUS_NOT_FOUND": 2,
+		"LINK_UPDATE_STATUS_INVALID":   3,
+	}
+)
+
+func (x LinkUpdateStatus) Enum() *LinkUpdateStatus {
+	p := new(LinkUpdateStatus)
+	*p = x
+	return p
+}
+
+func (x LinkUpdateStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (LinkUpdateStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_admin_proto_enumTypes[1].Descriptor()
+}
+
+func (LinkUpdateStatus) Type() protoreflect.EnumType {
+	return &file_admin_proto_enumTypes[1]
+}
+
+func (x LinkUpdateStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use LinkUpdateStatus.Descriptor instead.
+func (LinkUpdateStatus) EnumDescriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{1}
+}
+
+type ExplainQueryRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Target        ExplainTarget          `protobuf:"varint,1,opt,name=target,proto3,enum=musicclub.admin.ExplainTarget" json:"target,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ExplainQueryRequest) Reset() {
+	*x = ExplainQueryRequest{}
+	mi := &file_admin_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ExplainQueryRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExplainQueryRequest) ProtoMessage() {}
+
+func (x *ExplainQueryRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExplainQueryRequest.ProtoReflect.Descriptor instead.
+func (*ExplainQueryRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *ExplainQueryRequest) GetTarget() ExplainTarget {
+	if x != nil {
+		return x.Target
+	}
+	return ExplainTarget_EXPLAIN_TARGET_UNKNOWN
+}
+
+type ExplainQueryResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Plan produced by EXPLAIN (FORMAT JSON).
+	PlanJson      string `protobuf:"bytes,1,opt,name=plan_json,json=planJson,proto3" json:"plan_json,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ExplainQueryResponse) Reset() {
+	*x = ExplainQueryResponse{}
+	mi := &file_admin_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ExplainQueryResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExplainQueryResponse) ProtoMessage() {}
+
+func (x *ExplainQueryResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExplainQueryResponse.ProtoReflect.Descriptor instead.
+func (*ExplainQueryResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *ExplainQueryResponse) GetPlanJson() string {
+	if x != nil {
+		return x.PlanJson
+	}
+	return ""
+}
+
+type MergeSongsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Duplicate song that is removed after the merge.
+	SourceId string `protobuf:"bytes,1,opt,name=source_id,json=sourceId,proto3" json:"source_id,omitempty"`
+	// Song that receives the participations.
+	TargetId      string `protobuf:"bytes,2,opt,name=target_id,json=targetId,proto3" json:"target_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MergeSongsRequest) Reset() {
+	*x = MergeSongsRequest{}
+	mi := &file_admin_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MergeSongsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MergeSongsRequest) ProtoMessage() {}
+
+func (x *MergeSongsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MergeSongsRequest.ProtoReflect.Descriptor instead.
+func (*MergeSongsRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *MergeSongsRequest) GetSourceId() string {
+	if x != nil {
+		return x.SourceId
+	}
+	return ""
+}
+
+func (x *MergeSongsRequest) GetTargetId() string {
+	if x != nil {
+		return x.TargetId
+	}
+	return ""
+}
+
+type MergeSongsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Participations moved to the target song.
+	Moved int32 `protobuf:"varint,1,opt,name=moved,proto3" json:"moved,omitempty"`
+	// Participations dropped because the target already had them.
+	Skipped       int32 `protobuf:"varint,2,opt,name=skipped,proto3" json:"skipped,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MergeSongsResponse) Reset() {
+	*x = MergeSongsResponse{}
+	mi := &file_admin_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MergeSongsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MergeSongsResponse) ProtoMessage() {}
+
+func (x *MergeSongsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MergeSongsResponse.ProtoReflect.Descriptor instead.
+func (*MergeSongsResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *MergeSongsResponse) GetMoved() int32 {
+	if x != nil {
+		return x.Moved
+	}
+	return 0
+}
+
+func (x *MergeSongsResponse) GetSkipped() int32 {
+	if x != nil {
+		return x.Skipped
+	}
+	return 0
+}
+
+type AdminUserRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	UserId        string                 `protobuf:"bytes,1,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *AdminUserRequest) Reset() {
+	*x = AdminUserRequest{}
+	mi := &file_admin_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *AdminUserRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AdminUserRequest) ProtoMessage() {}
+
+func (x *AdminUserRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AdminUserRequest.ProtoReflect.Descriptor instead.
+func (*AdminUserRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *AdminUserRequest) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+type TransferParticipationsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	FromUserId    string                 `protobuf:"bytes,1,opt,name=from_user_id,json=fromUserId,proto3" json:"from_user_id,omitempty"`
+	ToUserId      string                 `protobuf:"bytes,2,opt,name=to_user_id,json=toUserId,proto3" json:"to_user_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TransferParticipationsRequest) Reset() {
+	*x = TransferParticipationsRequest{}
+	mi := &file_admin_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TransferParticipationsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TransferParticipationsRequest) ProtoMessage() {}
+
+func (x *TransferParticipationsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TransferParticipationsRequest.ProtoReflect.Descriptor instead.
+func (*TransferParticipationsRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *TransferParticipationsRequest) GetFromUserId() string {
+	if x != nil {
+		return x.FromUserId
+	}
+	return ""
+}
+
+func (x *TransferParticipationsRequest) GetToUserId() string {
+	if x != nil {
+		return x.ToUserId
+	}
+	return ""
+}
+
+type TransferParticipationsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Participations moved to the target user.
+	Moved int32 `protobuf:"varint,1,opt,name=moved,proto3" json:"moved,omitempty"`
+	// Participations dropped because the target user already had them.
+	Skipped       int32 `protobuf:"varint,2,opt,name=skipped,proto3" json:"skipped,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TransferParticipationsResponse) Reset() {
+	*x = TransferParticipationsResponse{}
+	mi := &file_admin_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TransferParticipationsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TransferParticipationsResponse) ProtoMessage() {}
+
+func (x *TransferParticipationsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TransferParticipationsResponse.ProtoReflect.Descriptor instead.
+func (*TransferParticipationsResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *TransferParticipationsResponse) GetMoved() int32 {
+	if x != nil {
+		return x.Moved
+	}
+	return 0
+}
+
+func (x *TransferParticipationsResponse) GetSkipped() int32 {
+	if x != nil {
+		return x.Skipped
+	}
+	return 0
+}
+
+type RenameRoleRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Exact role name to replace, e.g. "Vox".
+	From          string `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
+	To            string `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RenameRoleRequest) Reset() {
+	*x = RenameRoleRequest{}
+	mi := &file_admin_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RenameRoleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RenameRoleRequest) ProtoMessage() {}
+
+func (x *RenameRoleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RenameRoleRequest.ProtoReflect.Descriptor instead.
+func (*RenameRoleRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *RenameRoleRequest) GetFrom() string {
+	if x != nil {
+		return x.From
+	}
+	return ""
+}
+
+func (x *RenameRoleRequest) GetTo() string {
+	if x != nil {
+		return x.To
+	}
+	return ""
+}
+
+type RenameRoleResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Participations moved to the new role.
+	Moved int32 `protobuf:"varint,1,opt,name=moved,proto3" json:"moved,omitempty"`
+	// Participations dropped because the user already held the new role on that song.
+	Skipped       int32 `protobuf:"varint,2,opt,name=skipped,proto3" json:"skipped,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RenameRoleResponse) Reset() {
+	*x = RenameRoleResponse{}
+	mi := &file_admin_proto_msgTypes[8]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RenameRoleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RenameRoleResponse) ProtoMessage() {}
+
+func (x *RenameRoleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[8]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RenameRoleResponse.ProtoReflect.Descriptor instead.
+func (*RenameRoleResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *RenameRoleResponse) GetMoved() int32 {
+	if x != nil {
+		return x.Moved
+	}
+	return 0
+}
+
+func (x *RenameRoleResponse) GetSkipped() int32 {
+	if x != nil {
+		return x.Skipped
+	}
+	return 0
+}
+
+type LinkUpdate struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Link          *SongLink              `protobuf:"bytes,2,opt,name=link,proto3" json:"link,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *LinkUpdate) Reset() {
+	*x = LinkUpdate{}
+	mi := &file_admin_proto_msgTypes[9]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *LinkUpdate) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LinkUpdate) ProtoMessage() {}
+
+func (x *LinkUpdate) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[9]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LinkUpdate.ProtoReflect.Descriptor instead.
+func (*LinkUpdate) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *LinkUpdate) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *LinkUpdate) GetLink() *SongLink {
+	if x != nil {
+		return x.Link
+	}
+	return nil
+}
+
+type BatchUpdateLinksRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Updates       []*LinkUpdate          `protobuf:"bytes,1,rep,name=updates,proto3" json:"updates,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *BatchUpdateLinksRequest) Reset() {
+	*x = BatchUpdateLinksRequest{}
+	mi := &file_admin_proto_msgTypes[10]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BatchUpdateLinksRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchUpdateLinksRequest) ProtoMessage() {}
+
+func (x *BatchUpdateLinksRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[10]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchUpdateLinksRequest.ProtoReflect.Descriptor instead.
+func (*BatchUpdateLinksRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *BatchUpdateLinksRequest) GetUpdates() []*LinkUpdate {
+	if x != nil {
+		return x.Updates
+	}
+	return nil
+}
+
+type LinkUpdateResult struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	SongId string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Status LinkUpdateStatus       `protobuf:"varint,2,opt,name=status,proto3,enum=musicclub.admin.LinkUpdateStatus" json:"status,omitempty"`
+	// Human readable reason for INVALID results.
+	Error         string `protobuf:"bytes,3,opt,name=error,proto3" json:"error,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *LinkUpdateResult) Reset() {
+	*x = LinkUpdateResult{}
+	mi := &file_admin_proto_msgTypes[11]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *LinkUpdateResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LinkUpdateResult) ProtoMessage() {}
+
+func (x *LinkUpdateResult) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[11]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LinkUpdateResult.ProtoReflect.Descriptor instead.
+func (*LinkUpdateResult) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *LinkUpdateResult) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *LinkUpdateResult) GetStatus() LinkUpdateStatus {
+	if x != nil {
+		return x.Status
+	}
+	return LinkUpdateStatus_LINK_UPDATE_STATUS_UNKNOWN
+}
+
+func (x *LinkUpdateResult) GetError() string {
+	if x != nil {
+		return x.Error
+	}
+	return ""
+}
+
+type BatchUpdateLinksResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// One result per update, in request order.
+	Results       []*LinkUpdateResult `protobuf:"bytes,1,rep,name=results,proto3" json:"results,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *BatchUpdateLinksResponse) Reset() {
+	*x = BatchUpdateLinksResponse{}
+	mi := &file_admin_proto_msgTypes[12]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BatchUpdateLinksResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchUpdateLinksResponse) ProtoMessage() {}
+
+func (x *BatchUpdateLinksResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[12]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchUpdateLinksResponse.ProtoReflect.Descriptor instead.
+func (*BatchUpdateLinksResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{12}
+}
+
+func (x *BatchUpdateLinksResponse) GetResults() []*LinkUpdateResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+type SeedSampleDataRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SeedSampleDataRequest) Reset() {
+	*x = SeedSampleDataRequest{}
+	mi := &file_admin_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SeedSampleDataRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SeedSampleDataRequest) ProtoMessage() {}
+
+func (x *SeedSampleDataRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SeedSampleDataRequest.ProtoReflect.Descriptor instead.
+func (*SeedSampleDataRequest) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{13}
+}
+
+type SeedSampleDataResponse struct {
+	state   protoimpl.MessageState `protogen:"open.v1"`
+	SongIds []string               `protobuf:"bytes,1,rep,name=song_ids,json=songIds,proto3" json:"song_ids,omitempty"`
+	EventId string                 `protobuf:"bytes,2,opt,name=event_id,json=eventId,proto3" json:"event_id,omitempty"`
+	// Number of role assignments created for the caller.
+	ParticipationsCreated uint32 `protobuf:"varint,3,opt,name=participations_created,json=participationsCreated,proto3" json:"participations_created,omitempty"`
+	unknownFields         protoimpl.UnknownFields
+	sizeCache             protoimpl.SizeCache
+}
+
+func (x *SeedSampleDataResponse) Reset() {
+	*x = SeedSampleDataResponse{}
+	mi := &file_admin_proto_msgTypes[14]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SeedSampleDataResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SeedSampleDataResponse) ProtoMessage() {}
+
+func (x *SeedSampleDataResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_admin_proto_msgTypes[14]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SeedSampleDataResponse.ProtoReflect.Descriptor instead.
+func (*SeedSampleDataResponse) Descriptor() ([]byte, []int) {
+	return file_admin_proto_rawDescGZIP(), []int{14}
+}
+
+func (x *SeedSampleDataResponse) GetSongIds() []string {
+	if x != nil {
+		return x.SongIds
+	}
+	return nil
+}
+
+func (x *SeedSampleDataResponse) GetEventId() string {
+	if x != nil {
+		return x.EventId
+	}
+	return ""
+}
+
+func (x *SeedSampleDataResponse) GetParticipationsCreated() uint32 {
+	if x != nil {
+		return x.ParticipationsCreated
+	}
+	return 0
+}
+
+var File_admin_proto protoreflect.FileDescriptor
+
+const file_admin_proto_rawDesc = "" +
+	"\n" +
+	"\vadmin.proto\x12\x0fmusicclub.admin\x1a\vevent.proto\x1a\n" +
+	"song.proto\x1a\x11permissions.proto\"M\n" +
+	"\x13ExplainQueryRequest\x126\n" +
+	"\x06target\x18\x01 \x01(\x0e2\x1e.musicclub.admin.ExplainTargetR\x06target\"3\n" +
+	"\x14ExplainQueryResponse\x12\x1b\n" +
+	"\tplan_json\x18\x01 \x01(\tR\bplanJson\"M\n" +
+	"\x11MergeSongsRequest\x12\x1b\n" +
+	"\tsource_id\x18\x01 \x01(\tR\bsourceId\x12\x1b\n" +
+	"\ttarget_id\x18\x02 \x01(\tR\btargetId\"D\n" +
+	"\x12MergeSongsResponse\x12\x14\n" +
+	"\x05moved\x18\x01 \x01(\x05R\x05moved\x12\x18\n" +
+	"\askipped\x18\x02 \x01(\x05R\askipped\"+\n" +
+	"\x10AdminUserRequest\x12\x17\n" +
+	"\auser_id\x18\x01 \x01(\tR\x06userId\"_\n" +
+	"\x1dTransferParticipationsRequest\x12 \n" +
+	"\ffrom_user_id\x18\x01 \x01(\tR\n" +
+	"fromUserId\x12\x1c\n" +
+	"\n" +
+	"to_user_id\x18\x02 \x01(\tR\btoUserId\"P\n" +
+	"\x1eTransferParticipationsResponse\x12\x14\n" +
+	"\x05moved\x18\x01 \x01(\x05R\x05moved\x12\x18\n" +
+	"\askipped\x18\x02 \x01(\x05R\askipped\"7\n" +
+	"\x11RenameRoleRequest\x12\x12\n" +
+	"\x04from\x18\x01 \x01(\tR\x04from\x12\x0e\n" +
+	"\x02to\x18\x02 \x01(\tR\x02to\"D\n" +
+	"\x12RenameRoleResponse\x12\x14\n" +
+	"\x05moved\x18\x01 \x01(\x05R\x05moved\x12\x18\n" +
+	"\askipped\x18\x02 \x01(\x05R\askipped\"S\n" +
+	"\n" +
+	"LinkUpdate\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12,\n" +
+	"\x04link\x18\x02 \x01(\v2\x18.musicclub.song.SongLinkR\x04link\"P\n" +
+	"\x17BatchUpdateLinksRequest\x125\n" +
+	"\aupdates\x18\x01 \x03(\v2\x1b.musicclub.admin.LinkUpdateR\aupdates\"|\n" +
+	"\x10LinkUpdateResult\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x129\n" +
+	"\x06status\x18\x02 \x01(\x0e2!.musicclub.admin.LinkUpdateStatusR\x06status\x12\x14\n" +
+	"\x05error\x18\x03 \x01(\tR\x05error\"W\n" +
+	"\x18BatchUpdateLinksResponse\x12;\n" +
+	"\aresults\x18\x01 \x03(\v2!.musicclub.admin.LinkUpdateResultR\aresults\"\x17\n" +
+	"\x15SeedSampleDataRequest\"\x85\x01\n" +
+	"\x16SeedSampleDataResponse\x12\x19\n" +
+	"\bsong_ids\x18\x01 \x03(\tR\asongIds\x12\x19\n" +
+	"\bevent_id\x18\x02 \x01(\tR\aeventId\x125\n" +
+	"\x16participations_created\x18\x03 \x01(\rR\x15participationsCreated*\xb8\x01\n" +
+	"\rExplainTarget\x12\x1a\n" +
+	"\x16EXPLAIN_TARGET_UNKNOWN\x10\x00\x12\x1d\n" +
+	"\x19EXPLAIN_TARGET_LIST_SONGS\x10\x01\x12\x1f\n" +
+	"\x1bEXPLAIN_TARGET_SEARCH_SONGS\x10\x02\x12+\n" +
+	"'EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG\x10\x03\x12\x1e\n" +
+	"\x1aEXPLAIN_TARGET_LIST_EVENTS\x10\x04*\x94\x01\n" +
+	"\x10LinkUpdateStatus\x12\x1e\n" +
+	"\x1aLINK_UPDATE_STATUS_UNKNOWN\x10\x00\x12\x1e\n" +
+	"\x1aLINK_UPDATE_STATUS_UPDATED\x10\x01\x12 \n" +
+	"\x1cLINK_UPDATE_STATUS_NOT_FOUND\x10\x02\x12\x1e\n" +
+	"\x1aLINK_UPDATE_STATUS_INVALID\x10\x032\xd8\x06\n" +
+	"\fAdminService\x12[\n" +
+	"\fExplainQuery\x12$.musicclub.admin.ExplainQueryRequest\x1a%.musicclub.admin.ExplainQueryResponse\x12U\n" +
+	"\n" +
+	"MergeSongs\x12\".musicclub.admin.MergeSongsRequest\x1a#.musicclub.admin.MergeSongsResponse\x12g\n" +
+	"\x10BatchUpdateLinks\x12(.musicclub.admin.BatchUpdateLinksRequest\x1a).musicclub.admin.BatchUpdateLinksResponse\x12y\n" +
+	"\x16TransferParticipations\x12..musicclub.admin.TransferParticipationsRequest\x1a/.musicclub.admin.TransferParticipationsResponse\x12U\n" +
+	"\n" +
+	"RenameRole\x12\".musicclub.admin.RenameRoleRequest\x1a#.musicclub.admin.RenameRoleResponse\x12U\n" +
+	"\n" +
+	"GrantAdmin\x12!.musicclub.admin.AdminUserRequest\x1a$.musicclub.permissions.PermissionSet\x12V\n" +
+	"\vRevokeAdmin\x12!.musicclub.admin.AdminUserRequest\x1a$.musicclub.permissions.PermissionSet\x12G\n" +
+	"\fRestoreEvent\x12\x18.musicclub.event.EventId\x1a\x1d.musicclub.event.EventDetails\x12a\n" +
+	"\x0eSeedSampleData\x12&.musicclub.admin.SeedSampleDataRequest\x1a'.musicclub.admin.SeedSampleDataResponseB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
+
+var (
+	file_admin_proto_rawDescOnce sync.Once
+	file_admin_proto_rawDescData []byte
+)
+
+func file_admin_proto_rawDescGZIP() []byte {
+	file_admin_proto_rawDescOnce.Do(func() {
+		file_admin_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_admin_proto_rawDesc), len(file_admin_proto_rawDesc)))
+	})
+	return file_admin_proto_rawDescData
+}
+
+var file_admin_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
+var file_admin_proto_msgTypes = make([]protoimpl.MessageInfo, 15)
+var file_admin_proto_goTypes = []any{
+	(ExplainTarget)(0),                     // 0: musicclub.admin.ExplainTarget
+	(LinkUpdateStatus)(0),                  // 1: musicclub.admin.LinkUpdateStatus
+	(*ExplainQueryRequest)(nil),            // 2: musicclub.admin.ExplainQueryRequest
+	(*ExplainQueryResponse)(nil),           // 3: musicclub.admin.ExplainQueryResponse
+	(*MergeSongsRequest)(nil),              // 4: musicclub.admin.MergeSongsRequest
+	(*MergeSongsResponse)(nil),             // 5: musicclub.admin.MergeSongsResponse
+	(*AdminUserRequest)(nil),               // 6: musicclub.admin.AdminUserRequest
+	(*TransferParticipationsRequest)(nil),  // 7: musicclub.admin.TransferParticipationsRequest
+	(*TransferParticipationsResponse)(nil), // 8: musicclub.admin.TransferParticipationsResponse
+	(*RenameRoleRequest)(nil),              // 9: musicclub.admin.RenameRoleRequest
+	(*RenameRoleResponse)(nil),             // 10: musicclub.admin.RenameRoleResponse
+	(*LinkUpdate)(nil),                     // 11: musicclub.admin.LinkUpdate
+	(*BatchUpdateLinksRequest)(nil),        // 12: musicclub.admin.BatchUpdateLinksRequest
+	(*LinkUpdateResult)(nil),               // 13: musicclub.admin.LinkUpdateResult
+	(*BatchUpdateLinksResponse)(nil),       // 14: musicclub.admin.BatchUpdateLinksResponse
+	(*SeedSampleDataRequest)(nil),          // 15: musicclub.admin.SeedSampleDataRequest
+	(*SeedSampleDataResponse)(nil),         // 16: musicclub.admin.SeedSampleDataResponse
+	(*SongLink)(nil),                       // 17: musicclub.song.SongLink
+	(*EventId)(nil),                        // 18: musicclub.event.EventId
+	(*PermissionSet)(nil),                  // 19: musicclub.permissions.PermissionSet
+	(*EventDetails)(nil),                   // 20: musicclub.event.EventDetails
+}
+var file_admin_proto_depIdxs = []int32{
+	0,  // 0: musicclub.admin.ExplainQueryRequest.target:type_name -> musicclub.admin.ExplainTarget
+	17, // 1: musicclub.admin.LinkUpdate.link:type_name -> musicclub.song.SongLink
+	11, // 2: musicclub.admin.BatchUpdateLinksRequest.updates:type_name -> musicclub.admin.LinkUpdate
+	1,  // 3: musicclub.admin.LinkUpdateResult.status:type_name -> musicclub.admin.LinkUpdateStatus
+	13, // 4: musicclub.admin.BatchUpdateLinksResponse.results:type_name -> musicclub.admin.LinkUpdateResult
+	2,  // 5: musicclub.admin.AdminService.ExplainQuery:input_type -> musicclub.admin.ExplainQueryRequest
+	4,  // 6: musicclub.admin.AdminService.MergeSongs:input_type -> musicclub.admin.MergeSongsRequest
+	12, // 7: musicclub.admin.AdminService.BatchUpdateLinks:input_type -> musicclub.admin.BatchUpdateLinksRequest
+	7,  // 8: musicclub.admin.AdminService.TransferParticipations:input_type -> musicclub.admin.TransferParticipationsRequest
+	9,  // 9: musicclub.admin.AdminService.RenameRole:input_type -> musicclub.admin.RenameRoleRequest
+	6,  // 10: musicclub.admin.AdminService.GrantAdmin:input_type -> musicclub.admin.AdminUserRequest
+	6,  // 11: musicclub.admin.AdminService.RevokeAdmin:input_type -> musicclub.admin.AdminUserRequest
+	18, // 12: musicclub.admin.AdminService.RestoreEvent:input_type -> musicclub.event.EventId
+	15, // 13: musicclub.admin.AdminService.SeedSampleData:input_type -> musicclub.admin.SeedSampleDataRequest
+	3,  // 14: musicclub.admin.AdminService.ExplainQuery:output_type -> musicclub.admin.ExplainQueryResponse
+	5,  // 15: musicclub.admin.AdminService.MergeSongs:output_type -> musicclub.admin.MergeSongsResponse
+	14, // 16: musicclub.admin.AdminService.BatchUpdateLinks:output_type -> musicclub.admin.BatchUpdateLinksResponse
+	8,  // 17: musicclub.admin.AdminService.TransferParticipations:output_type -> musicclub.admin.TransferParticipationsResponse
+	10, // 18: musicclub.admin.AdminService.RenameRole:output_type -> musicclub.admin.RenameRoleResponse
+	19, // 19: musicclub.admin.AdminService.GrantAdmin:output_type -> musicclub.permissions.PermissionSet
+	19, // 20: musicclub.admin.AdminService.RevokeAdmin:output_type -> musicclub.permissions.PermissionSet
+	20, // 21: musicclub.admin.AdminService.RestoreEvent:output_type -> musicclub.event.EventDetails
+	16, // 22: musicclub.admin.AdminService.SeedSampleData:output_type -> musicclub.admin.SeedSampleDataResponse
+	14, // [14:23] is the sub-list for method output_type
+	5,  // [5:14] is the sub-list for method input_type
+	5,  // [5:5] is the sub-list for extension type_name
+	5,  // [5:5] is the sub-list for extension extendee
+	0,  // [0:5] is the sub-list for field type_name
+}
+
+func init() { file_admin_proto_init() }
+func file_admin_proto_init() {
+	if File_admin_proto != nil {
+		return
+	}
+	file_event_proto_init()
+	file_song_proto_init()
+	file_permissions_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_admin_proto_rawDesc), len(file_admin_proto_rawDesc)),
+			NumEnums:      2,
+			NumMessages:   15,
+			NumExtensions: 0,
+			NumServices:   1,
+		},
+		GoTypes:           file_admin_proto_goTypes,
+		DependencyIndexes: file_admin_proto_depIdxs,
+		EnumInfos:         file_admin_proto_enumTypes,
+		MessageInfos:      file_admin_proto_msgTypes,
+	}.Build()
+	File_admin_proto = out.File
+	file_admin_proto_goTypes = nil
+	file_admin_proto_depIdxs = nil
+}
@@ -0,0 +1,451 @@
+// Code generated by protoc-gen-go-grpc. DO NOT EDIT.
+// versions:
+// - protoc-gen-go-grpc v1.6.0
+// - protoc             v3.21.12
+// source: admin.proto
+
+package proto
+
+import (
+	context "context"
+	grpc "google.golang.org/grpc"
+	codes "google.golang.org/grpc/codes"
+	status "google.golang.org/grpc/status"
+)
+
+// This is a compile-time assertion to ensure that this generated file
+// is compatible with the grpc package it is being compiled against.
+// Requires gRPC-Go v1.64.0 or later.
+const _ = grpc.SupportPackageIsVersion9
+
+const (
+	AdminService_ExplainQuery_FullMethodName           = "/musicclub.admin.AdminService/ExplainQuery"
+	AdminService_MergeSongs_FullMethodName             = "/musicclub.admin.AdminService/MergeSongs"
+	AdminService_BatchUpdateLinks_FullMethodName       = "/musicclub.admin.AdminService/BatchUpdateLinks"
+	AdminService_TransferParticipations_FullMethodName = "/musicclub.admin.AdminService/TransferParticipations"
+	AdminService_RenameRole_FullMethodName             = "/musicclub.admin.AdminService/RenameRole"
+	AdminService_GrantAdmin_FullMethodName             = "/musicclub.admin.AdminService/GrantAdmin"
+	AdminService_RevokeAdmin_FullMethodName            = "/musicclub.admin.AdminService/RevokeAdmin"
+	AdminService_RestoreEvent_FullMethodName           = "/musicclub.admin.AdminService/RestoreEvent"
+	AdminService_SeedSampleData_FullMethodName         = "/musicclub.admin.AdminService/SeedSampleData"
+)
+
+// AdminServiceClient is the client API for AdminService service.
+//
+// For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
+//
+// Maintenance tooling available to administrators only.
+type AdminServiceClient interface {
+	// Returns the EXPLAIN plan of one of the backend's own queries.
+	ExplainQuery(ctx context.Context, in *ExplainQueryRequest, opts ...grpc.CallOption) (*ExplainQueryResponse, error)
+	// Merges a duplicate song into another one and deletes the duplicate.
+	MergeSongs(ctx context.Context, in *MergeSongsRequest, opts ...grpc.CallOption) (*MergeSongsResponse, error)
+	// Rewrites links of many songs in one transaction.
+	BatchUpdateLinks(ctx context.Context, in *BatchUpdateLinksRequest, opts ...grpc.CallOption) (*BatchUpdateLinksResponse, error)
+	// Moves every role assignment of one user to another, e.g. after a
+	// Telegram account change.
+	TransferParticipations(ctx context.Context, in *TransferParticipationsRequest, opts ...grpc.CallOption) (*TransferParticipationsResponse, error)
+	// Renames a role on every song, moving its participations along.
+	RenameRole(ctx context.Context, in *RenameRoleRequest, opts ...grpc.CallOption) (*RenameRoleResponse, error)
+	// Gives a user every permission. Returns the resulting permissions.
+	GrantAdmin(ctx context.Context, in *AdminUserRequest, opts ...grpc.CallOption) (*PermissionSet, error)
+	// Takes admin permissions away again; the last admin cannot be revoked.
+	RevokeAdmin(ctx context.Context, in *AdminUserRequest, opts ...grpc.CallOption) (*PermissionSet, error)
+	// Brings back an event hidden by DeleteEvent.
+	RestoreEvent(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventDetails, error)
+	// Inserts a fixed set of demo songs, one event and participations.
+	// Only available when the server runs with DEV_MODE=true.
+	SeedSampleData(ctx context.Context, in *SeedSampleDataRequest, opts ...grpc.CallOption) (*SeedSampleDataResponse, error)
+}
+
+type adminServiceClient struct {
+	cc grpc.ClientConnInterface
+}
+
+func NewAdminServiceClient(cc grpc.ClientConnInterface) AdminServiceClient {
+	return &adminServiceClient{cc}
+}
+
+func (c *adminServiceClient) ExplainQuery(ctx context.Context, in *ExplainQueryRequest, opts ...grpc.CallOption) (*ExplainQueryResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ExplainQueryResponse)
+	err := c.cc.Invoke(ctx, AdminService_ExplainQuery_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) MergeSongs(ctx context.Context, in *MergeSongsRequest, opts ...grpc.CallOption) (*MergeSongsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(MergeSongsResponse)
+	err := c.cc.Invoke(ctx, AdminService_MergeSongs_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) BatchUpdateLinks(ctx context.Context, in *BatchUpdateLinksRequest, opts ...grpc.CallOption) (*BatchUpdateLinksResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(BatchUpdateLinksResponse)
+	err := c.cc.Invoke(ctx, AdminService_BatchUpdateLinks_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) TransferParticipations(ctx context.Context, in *TransferParticipationsRequest, opts ...grpc.CallOption) (*TransferParticipationsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(TransferParticipationsResponse)
+	err := c.cc.Invoke(ctx, AdminService_TransferParticipations_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) RenameRole(ctx context.Context, in *RenameRoleRequest, opts ...grpc.CallOption) (*RenameRoleResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(RenameRoleResponse)
+	err := c.cc.Invoke(ctx, AdminService_RenameRole_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) GrantAdmin(ctx context.Context, in *AdminUserRequest, opts ...grpc.CallOption) (*PermissionSet, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(PermissionSet)
+	err := c.cc.Invoke(ctx, AdminService_GrantAdmin_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) RevokeAdmin(ctx context.Context, in *AdminUserRequest, opts ...grpc.CallOption) (*PermissionSet, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(PermissionSet)
+	err := c.cc.Invoke(ctx, AdminService_RevokeAdmin_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) RestoreEvent(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, AdminService_RestoreEvent_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *adminServiceClient) SeedSampleData(ctx context.Context, in *SeedSampleDataRequest, opts ...grpc.CallOption) (*SeedSampleDataResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SeedSampleDataResponse)
+	err := c.cc.Invoke(ctx, AdminService_SeedSampleData_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// AdminServiceServer is the server API for AdminService service.
+// All implementations must embed UnimplementedAdminServiceServer
+// for forward compatibility.
+//
+// Maintenance tooling available to administrators only.
+type AdminServiceServer interface {
+	// Returns the EXPLAIN plan of one of the backend's own queries.
+	ExplainQuery(context.Context, *ExplainQueryRequest) (*ExplainQueryResponse, error)
+	// Merges a duplicate song into another one and deletes the duplicate.
+	MergeSongs(context.Context, *MergeSongsRequest) (*MergeSongsResponse, error)
+	// Rewrites links of many songs in one transaction.
+	BatchUpdateLinks(context.Context, *BatchUpdateLinksRequest) (*BatchUpdateLinksResponse, error)
+	// Moves every role assignment of one user to another, e.g. after a
+	// Telegram account change.
+	TransferParticipations(context.Context, *TransferParticipationsRequest) (*TransferParticipationsResponse, error)
+	// Renames a role on every song, moving its participations along.
+	RenameRole(context.Context, *RenameRoleRequest) (*RenameRoleResponse, error)
+	// Gives a user every permission. Returns the resulting permissions.
+	GrantAdmin(context.Context, *AdminUserRequest) (*PermissionSet, error)
+	// Takes admin permissions away again; the last admin cannot be revoked.
+	RevokeAdmin(context.Context, *AdminUserRequest) (*PermissionSet, error)
+	// Brings back an event hidden by DeleteEvent.
+	RestoreEvent(context.Context, *EventId) (*EventDetails, error)
+	// Inserts a fixed set of demo songs, one event and participations.
+	// Only available when the server runs with DEV_MODE=true.
+	SeedSampleData(context.Context, *SeedSampleDataRequest) (*SeedSampleDataResponse, error)
+	mustEmbedUnimplementedAdminServiceServer()
+}
+
+// UnimplementedAdminServiceServer must be embedded to have
+// forward compatible implementations.
+//
+// NOTE: this should be embedded by value instead of pointer to avoid a nil
+// pointer dereference when methods are called.
+type UnimplementedAdminServiceServer struct{}
+
+func (UnimplementedAdminServiceServer) ExplainQuery(context.Context, *ExplainQueryRequest) (*ExplainQueryResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ExplainQuery not implemented")
+}
+func (UnimplementedAdminServiceServer) MergeSongs(context.Context, *MergeSongsRequest) (*MergeSongsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method MergeSongs not implemented")
+}
+func (UnimplementedAdminServiceServer) BatchUpdateLinks(context.Context, *BatchUpdateLinksRequest) (*BatchUpdateLinksResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method BatchUpdateLinks not implemented")
+}
+func (UnimplementedAdminServiceServer) TransferParticipations(context.Context, *TransferParticipationsRequest) (*TransferParticipationsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method TransferParticipations not implemented")
+}
+func (UnimplementedAdminServiceServer) RenameRole(context.Context, *RenameRoleRequest) (*RenameRoleResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method RenameRole not implemented")
+}
+func (UnimplementedAdminServiceServer) GrantAdmin(context.Context, *AdminUserRequest) (*PermissionSet, error) {
+	return nil, status.Error(codes.Unimplemented, "method GrantAdmin not implemented")
+}
+func (UnimplementedAdminServiceServer) RevokeAdmin(context.Context, *AdminUserRequest) (*PermissionSet, error) {
+	return nil, status.Error(codes.Unimplemented, "method RevokeAdmin not implemented")
+}
+func (UnimplementedAdminServiceServer) RestoreEvent(context.Context, *EventId) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method RestoreEvent not implemented")
+}
+func (UnimplementedAdminServiceServer) SeedSampleData(context.Context, *SeedSampleDataRequest) (*SeedSampleDataResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method SeedSampleData not implemented")
+}
+func (UnimplementedAdminServiceServer) mustEmbedUnimplementedAdminServiceServer() {}
+func (UnimplementedAdminServiceServer) testEmbeddedByValue()                      {}
+
+// UnsafeAdminServiceServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to AdminServiceServer will
+// result in compilation errors.
+type UnsafeAdminServiceServer interface {
+	mustEmbedUnimplementedAdminServiceServer()
+}
+
+func RegisterAdminServiceServer(s grpc.ServiceRegistrar, srv AdminServiceServer) {
+	// If the following call panics, it indicates UnimplementedAdminServiceServer was
+	// embedded by pointer and is nil.  This will cause panics if an
+	// unimplemented method is ever invoked, so we test this at initialization
+	// time to prevent it from happening at runtime later due to I/O.
+	if t, ok := srv.(interface{ testEmbeddedByValue() }); ok {
+		t.testEmbeddedByValue()
+	}
+	s.RegisterService(&AdminService_ServiceDesc, srv)
+}
+
+func _AdminService_ExplainQuery_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ExplainQueryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).ExplainQuery(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_ExplainQuery_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).ExplainQuery(ctx, req.(*ExplainQueryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_MergeSongs_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(MergeSongsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).MergeSongs(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_MergeSongs_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).MergeSongs(ctx, req.(*MergeSongsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_BatchUpdateLinks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchUpdateLinksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).BatchUpdateLinks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_BatchUpdateLinks_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).BatchUpdateLinks(ctx, req.(*BatchUpdateLinksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_TransferParticipations_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TransferParticipationsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).TransferParticipations(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_TransferParticipations_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).TransferParticipations(ctx, req.(*TransferParticipationsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_RenameRole_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RenameRoleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).RenameRole(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_RenameRole_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).RenameRole(ctx, req.(*RenameRoleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_GrantAdmin_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AdminUserRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).GrantAdmin(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_GrantAdmin_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).GrantAdmin(ctx, req.(*AdminUserRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_RevokeAdmin_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AdminUserRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).RevokeAdmin(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_RevokeAdmin_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).RevokeAdmin(ctx, req.(*AdminUserRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_RestoreEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EventId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).RestoreEvent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_RestoreEvent_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).RestoreEvent(ctx, req.(*EventId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _AdminService_SeedSampleData_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SeedSampleDataRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(AdminServiceServer).SeedSampleData(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: AdminService_SeedSampleData_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(AdminServiceServer).SeedSampleData(ctx, req.(*SeedSampleDataRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+// AdminService_ServiceDesc is the grpc.ServiceDesc for AdminService service.
+// It's only intended for direct use with grpc.RegisterService,
+// and not to be introspected or modified (even as a copy)
+var AdminService_ServiceDesc = grpc.ServiceDesc{
+	ServiceName: "musicclub.admin.AdminService",
+	HandlerType: (*AdminServiceServer)(nil),
+	Methods: []grpc.MethodDesc{
+		{
+			MethodName: "ExplainQuery",
+			Handler:    _AdminService_ExplainQuery_Handler,
+		},
+		{
+			MethodName: "MergeSongs",
+			Handler:    _AdminService_MergeSongs_Handler,
+		},
+		{
+			MethodName: "BatchUpdateLinks",
+			Handler:    _AdminService_BatchUpdateLinks_Handler,
+		},
+		{
+			MethodName: "TransferParticipations",
+			Handler:    _AdminService_TransferParticipations_Handler,
+		},
+		{
+			MethodName: "RenameRole",
+			Handler:    _AdminService_RenameRole_Handler,
+		},
+		{
+			MethodName: "GrantAdmin",
+			Handler:    _AdminService_GrantAdmin_Handler,
+		},
+		{
+			MethodName: "RevokeAdmin",
+			Handler:    _AdminService_RevokeAdmin_Handler,
+		},
+		{
+			MethodName: "RestoreEvent",
+			Handler:    _AdminService_RestoreEvent_Handler,
+		},
+		{
+			MethodName: "SeedSampleData",
+			Handler:    _AdminService_SeedSampleData_Handler,
+		},
+	},
+	Streams:  []grpc.StreamDesc{},
+	Metadata: "admin.proto",
+}
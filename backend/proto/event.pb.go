@@ -23,6 +23,60 @@ const (
 	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
 )
 
+type EventStatus int32
+
+const (
+	// Not set; keeps the current status on update.
+	EventStatus_EVENT_STATUS_UNSPECIFIED EventStatus = 0
+	EventStatus_EVENT_STATUS_DRAFT       EventStatus = 1
+	EventStatus_EVENT_STATUS_PUBLISHED   EventStatus = 2
+	// Final: a cancelled event cannot be reopened.
+	EventStatus_EVENT_STATUS_CANCELLED EventStatus = 3
+)
+
+// Enum value maps for EventStatus.
+var (
+	EventStatus_name = map[int32]string{
+		0: "EVENT_STATUS_UNSPECIFIED",
+		1: "EVENT_STATUS_DRAFT",
+		2: "EVENT_STATUS_PUBLISHED",
+		3: "EVENT_STATUS_CANCELLED",
+	}
+	EventStatus_value = map[string]int32{
+		"EVENT_STATUS_UNSPECIFIED": 0,
+		"EVENT_STATUS_DRAFT":       1,
+		"EVENT_STATUS_PUBLISHED":   2,
+		"EVENT_STATUS_CANCELLED":   3,
+	}
+)
+
+func (x EventStatus) Enum() *EventStatus {
+	p := new(EventStatus)
+	*p = x
+	return p
+}
+
+func (x EventStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (EventStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_event_proto_enumTypes[0].Descriptor()
+}
+
+func (EventStatus) Type() protoreflect.EnumType {
+	return &file_event_proto_enumTypes[0]
+}
+
+func (x EventStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use EventStatus.Descriptor instead.
+func (EventStatus) EnumDescriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{0}
+}
+
 type EventId struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Id            string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
@@ -67,18 +121,83 @@ func (x *EventId) GetId() string {
 	return ""
 }
 
-type ListEventsRequest struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	From          *timestamppb.Timestamp `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
-	To            *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
-	Limit         uint32                 `protobuf:"varint,3,opt,name=limit,proto3" json:"limit,omitempty"`
+type DeleteEventRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	Id    string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// Succeed instead of returning NOT_FOUND when the event is already gone.
+	AllowMissing bool `protobuf:"varint,2,opt,name=allow_missing,json=allowMissing,proto3" json:"allow_missing,omitempty"`
+	// Remove the event and its history instead of hiding it. Also purges
+	// events that were deleted before.
+	Force         bool `protobuf:"varint,3,opt,name=force,proto3" json:"force,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
+func (x *DeleteEventRequest) Reset() {
+	*x = DeleteEventRequest{}
+	mi := &file_event_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *DeleteEventRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteEventRequest) ProtoMessage() {}
+
+func (x *DeleteEventRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteEventRequest.ProtoReflect.Descriptor instead.
+func (*DeleteEventRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *DeleteEventRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *DeleteEventRequest) GetAllowMissing() bool {
+	if x != nil {
+		return x.AllowMissing
+	}
+	return false
+}
+
+func (x *DeleteEventRequest) GetForce() bool {
+	if x != nil {
+		return x.Force
+	}
+	return false
+}
+
+type ListEventsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	From  *timestamppb.Timestamp `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
+	To    *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
+	Limit uint32                 `protobuf:"varint,3,opt,name=limit,proto3" json:"limit,omitempty"`
+	// Also return the first few tracklist items and the song count of each event.
+	IncludeTracklist bool `protobuf:"varint,4,opt,name=include_tracklist,json=includeTracklist,proto3" json:"include_tracklist,omitempty"`
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
 func (x *ListEventsRequest) Reset() {
 	*x = ListEventsRequest{}
-	mi := &file_event_proto_msgTypes[1]
+	mi := &file_event_proto_msgTypes[2]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -90,7 +209,7 @@ func (x *ListEventsRequest) String() string {
 func (*ListEventsRequest) ProtoMessage() {}
 
 func (x *ListEventsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[1]
+	mi := &file_event_proto_msgTypes[2]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -103,7 +222,7 @@ func (x *ListEventsRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListEventsRequest.ProtoReflect.Descriptor instead.
 func (*ListEventsRequest) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{1}
+	return file_event_proto_rawDescGZIP(), []int{2}
 }
 
 func (x *ListEventsRequest) GetFrom() *timestamppb.Timestamp {
@@ -127,16 +246,25 @@ func (x *ListEventsRequest) GetLimit() uint32 {
 	return 0
 }
 
+func (x *ListEventsRequest) GetIncludeTracklist() bool {
+	if x != nil {
+		return x.IncludeTracklist
+	}
+	return false
+}
+
 type ListEventsResponse struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Events        []*Event               `protobuf:"bytes,1,rep,name=events,proto3" json:"events,omitempty"`
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	Events []*Event               `protobuf:"bytes,1,rep,name=events,proto3" json:"events,omitempty"`
+	// Whether more events match than the requested limit.
+	HasMore       bool `protobuf:"varint,2,opt,name=has_more,json=hasMore,proto3" json:"has_more,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *ListEventsResponse) Reset() {
 	*x = ListEventsResponse{}
-	mi := &file_event_proto_msgTypes[2]
+	mi := &file_event_proto_msgTypes[3]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -148,7 +276,7 @@ func (x *ListEventsResponse) String() string {
 func (*ListEventsResponse) ProtoMessage() {}
 
 func (x *ListEventsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[2]
+	mi := &file_event_proto_msgTypes[3]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -161,7 +289,7 @@ func (x *ListEventsResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListEventsResponse.ProtoReflect.Descriptor instead.
 func (*ListEventsResponse) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{2}
+	return file_event_proto_rawDescGZIP(), []int{3}
 }
 
 func (x *ListEventsResponse) GetEvents() []*Event {
@@ -171,6 +299,112 @@ func (x *ListEventsResponse) GetEvents() []*Event {
 	return nil
 }
 
+func (x *ListEventsResponse) GetHasMore() bool {
+	if x != nil {
+		return x.HasMore
+	}
+	return false
+}
+
+type BatchGetEventsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Every id must be a uuid. Duplicates are ignored; at most 100 distinct ids.
+	Ids           []string `protobuf:"bytes,1,rep,name=ids,proto3" json:"ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *BatchGetEventsRequest) Reset() {
+	*x = BatchGetEventsRequest{}
+	mi := &file_event_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BatchGetEventsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchGetEventsRequest) ProtoMessage() {}
+
+func (x *BatchGetEventsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchGetEventsRequest.ProtoReflect.Descriptor instead.
+func (*BatchGetEventsRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *BatchGetEventsRequest) GetIds() []string {
+	if x != nil {
+		return x.Ids
+	}
+	return nil
+}
+
+type BatchGetEventsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Found events, in the order their ids were first requested.
+	Events []*Event `protobuf:"bytes,1,rep,name=events,proto3" json:"events,omitempty"`
+	// Requested ids with no (visible) event, in canonical lowercase form.
+	MissingIds    []string `protobuf:"bytes,2,rep,name=missing_ids,json=missingIds,proto3" json:"missing_ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *BatchGetEventsResponse) Reset() {
+	*x = BatchGetEventsResponse{}
+	mi := &file_event_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BatchGetEventsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchGetEventsResponse) ProtoMessage() {}
+
+func (x *BatchGetEventsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchGetEventsResponse.ProtoReflect.Descriptor instead.
+func (*BatchGetEventsResponse) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *BatchGetEventsResponse) GetEvents() []*Event {
+	if x != nil {
+		return x.Events
+	}
+	return nil
+}
+
+func (x *BatchGetEventsResponse) GetMissingIds() []string {
+	if x != nil {
+		return x.MissingIds
+	}
+	return nil
+}
+
 type Event struct {
 	state    protoimpl.MessageState `protogen:"open.v1"`
 	Id       string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
@@ -180,13 +414,24 @@ type Event struct {
 	// Notification preferences for reminders.
 	NotifyDayBefore  bool `protobuf:"varint,5,opt,name=notify_day_before,json=notifyDayBefore,proto3" json:"notify_day_before,omitempty"`
 	NotifyHourBefore bool `protobuf:"varint,6,opt,name=notify_hour_before,json=notifyHourBefore,proto3" json:"notify_hour_before,omitempty"`
-	unknownFields    protoimpl.UnknownFields
-	sizeCache        protoimpl.SizeCache
+	// ID of the user who created the event. Empty for legacy rows.
+	CreatedBy string `protobuf:"bytes,7,opt,name=created_by,json=createdBy,proto3" json:"created_by,omitempty"`
+	// All performance dates, sorted; start_at is the earliest of them.
+	// Only populated in EventDetails.
+	Dates []*timestamppb.Timestamp `protobuf:"bytes,8,rep,name=dates,proto3" json:"dates,omitempty"`
+	// First tracklist items and total number of items. Only populated by
+	// ListEvents with include_tracklist.
+	TracklistPreview *Tracklist `protobuf:"bytes,9,opt,name=tracklist_preview,json=tracklistPreview,proto3" json:"tracklist_preview,omitempty"`
+	SongCount        uint32     `protobuf:"varint,10,opt,name=song_count,json=songCount,proto3" json:"song_count,omitempty"`
+	// New events are drafts; ListEvents shows members only published events.
+	Status        EventStatus `protobuf:"varint,11,opt,name=status,proto3,enum=musicclub.event.EventStatus" json:"status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *Event) Reset() {
 	*x = Event{}
-	mi := &file_event_proto_msgTypes[3]
+	mi := &file_event_proto_msgTypes[6]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -198,7 +443,7 @@ func (x *Event) String() string {
 func (*Event) ProtoMessage() {}
 
 func (x *Event) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[3]
+	mi := &file_event_proto_msgTypes[6]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -211,7 +456,7 @@ func (x *Event) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Event.ProtoReflect.Descriptor instead.
 func (*Event) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{3}
+	return file_event_proto_rawDescGZIP(), []int{6}
 }
 
 func (x *Event) GetId() string {
@@ -256,19 +501,62 @@ func (x *Event) GetNotifyHourBefore() bool {
 	return false
 }
 
+func (x *Event) GetCreatedBy() string {
+	if x != nil {
+		return x.CreatedBy
+	}
+	return ""
+}
+
+func (x *Event) GetDates() []*timestamppb.Timestamp {
+	if x != nil {
+		return x.Dates
+	}
+	return nil
+}
+
+func (x *Event) GetTracklistPreview() *Tracklist {
+	if x != nil {
+		return x.TracklistPreview
+	}
+	return nil
+}
+
+func (x *Event) GetSongCount() uint32 {
+	if x != nil {
+		return x.SongCount
+	}
+	return 0
+}
+
+func (x *Event) GetStatus() EventStatus {
+	if x != nil {
+		return x.Status
+	}
+	return EventStatus_EVENT_STATUS_UNSPECIFIED
+}
+
 type EventDetails struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Event         *Event                 `protobuf:"bytes,1,opt,name=event,proto3" json:"event,omitempty"`
-	Tracklist     *Tracklist             `protobuf:"bytes,2,opt,name=tracklist,proto3" json:"tracklist,omitempty"`
-	Participants  []*RoleAssignment      `protobuf:"bytes,3,rep,name=participants,proto3" json:"participants,omitempty"`
-	Permissions   *PermissionSet         `protobuf:"bytes,4,opt,name=permissions,proto3" json:"permissions,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	state        protoimpl.MessageState `protogen:"open.v1"`
+	Event        *Event                 `protobuf:"bytes,1,opt,name=event,proto3" json:"event,omitempty"`
+	Tracklist    *Tracklist             `protobuf:"bytes,2,opt,name=tracklist,proto3" json:"tracklist,omitempty"`
+	Participants []*RoleAssignment      `protobuf:"bytes,3,rep,name=participants,proto3" json:"participants,omitempty"`
+	Permissions  *PermissionSet         `protobuf:"bytes,4,opt,name=permissions,proto3" json:"permissions,omitempty"`
+	// Number of tracklist items, catalog and custom alike.
+	SongCount uint32 `protobuf:"varint,5,opt,name=song_count,json=songCount,proto3" json:"song_count,omitempty"`
+	// Distinct people playing a tracklist song or registered for the event.
+	ParticipantCount uint32 `protobuf:"varint,6,opt,name=participant_count,json=participantCount,proto3" json:"participant_count,omitempty"`
+	// Sum of known song durations in the tracklist, in seconds.
+	TotalDurationSeconds uint32 `protobuf:"varint,7,opt,name=total_duration_seconds,json=totalDurationSeconds,proto3" json:"total_duration_seconds,omitempty"`
+	// Tracklist items without a known duration, custom items included.
+	MissingDurationCount uint32 `protobuf:"varint,8,opt,name=missing_duration_count,json=missingDurationCount,proto3" json:"missing_duration_count,omitempty"`
+	unknownFields        protoimpl.UnknownFields
+	sizeCache            protoimpl.SizeCache
 }
 
 func (x *EventDetails) Reset() {
 	*x = EventDetails{}
-	mi := &file_event_proto_msgTypes[4]
+	mi := &file_event_proto_msgTypes[7]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -280,7 +568,7 @@ func (x *EventDetails) String() string {
 func (*EventDetails) ProtoMessage() {}
 
 func (x *EventDetails) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[4]
+	mi := &file_event_proto_msgTypes[7]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -293,7 +581,7 @@ func (x *EventDetails) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use EventDetails.ProtoReflect.Descriptor instead.
 func (*EventDetails) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{4}
+	return file_event_proto_rawDescGZIP(), []int{7}
 }
 
 func (x *EventDetails) GetEvent() *Event {
@@ -324,6 +612,34 @@ func (x *EventDetails) GetPermissions() *PermissionSet {
 	return nil
 }
 
+func (x *EventDetails) GetSongCount() uint32 {
+	if x != nil {
+		return x.SongCount
+	}
+	return 0
+}
+
+func (x *EventDetails) GetParticipantCount() uint32 {
+	if x != nil {
+		return x.ParticipantCount
+	}
+	return 0
+}
+
+func (x *EventDetails) GetTotalDurationSeconds() uint32 {
+	if x != nil {
+		return x.TotalDurationSeconds
+	}
+	return 0
+}
+
+func (x *EventDetails) GetMissingDurationCount() uint32 {
+	if x != nil {
+		return x.MissingDurationCount
+	}
+	return 0
+}
+
 type Tracklist struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Items         []*TrackItem           `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
@@ -333,7 +649,7 @@ type Tracklist struct {
 
 func (x *Tracklist) Reset() {
 	*x = Tracklist{}
-	mi := &file_event_proto_msgTypes[5]
+	mi := &file_event_proto_msgTypes[8]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -345,7 +661,7 @@ func (x *Tracklist) String() string {
 func (*Tracklist) ProtoMessage() {}
 
 func (x *Tracklist) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[5]
+	mi := &file_event_proto_msgTypes[8]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -358,7 +674,7 @@ func (x *Tracklist) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Tracklist.ProtoReflect.Descriptor instead.
 func (*Tracklist) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{5}
+	return file_event_proto_rawDescGZIP(), []int{8}
 }
 
 func (x *Tracklist) GetItems() []*TrackItem {
@@ -374,15 +690,18 @@ type TrackItem struct {
 	// Reference to a song in the catalog.
 	SongId string `protobuf:"bytes,2,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
 	// If song is not in catalog, allow a custom title/artist.
-	CustomTitle   string `protobuf:"bytes,3,opt,name=custom_title,json=customTitle,proto3" json:"custom_title,omitempty"`
-	CustomArtist  string `protobuf:"bytes,4,opt,name=custom_artist,json=customArtist,proto3" json:"custom_artist,omitempty"`
+	CustomTitle  string `protobuf:"bytes,3,opt,name=custom_title,json=customTitle,proto3" json:"custom_title,omitempty"`
+	CustomArtist string `protobuf:"bytes,4,opt,name=custom_artist,json=customArtist,proto3" json:"custom_artist,omitempty"`
+	// Output only: catalog song referenced by song_id, without roles and
+	// per-user fields. Ignored in requests.
+	Song          *Song `protobuf:"bytes,5,opt,name=song,proto3" json:"song,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *TrackItem) Reset() {
 	*x = TrackItem{}
-	mi := &file_event_proto_msgTypes[6]
+	mi := &file_event_proto_msgTypes[9]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -394,7 +713,7 @@ func (x *TrackItem) String() string {
 func (*TrackItem) ProtoMessage() {}
 
 func (x *TrackItem) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[6]
+	mi := &file_event_proto_msgTypes[9]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -407,7 +726,7 @@ func (x *TrackItem) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use TrackItem.ProtoReflect.Descriptor instead.
 func (*TrackItem) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{6}
+	return file_event_proto_rawDescGZIP(), []int{9}
 }
 
 func (x *TrackItem) GetOrder() uint32 {
@@ -438,6 +757,13 @@ func (x *TrackItem) GetCustomArtist() string {
 	return ""
 }
 
+func (x *TrackItem) GetSong() *Song {
+	if x != nil {
+		return x.Song
+	}
+	return nil
+}
+
 type CreateEventRequest struct {
 	state            protoimpl.MessageState `protogen:"open.v1"`
 	Title            string                 `protobuf:"bytes,1,opt,name=title,proto3" json:"title,omitempty"`
@@ -446,13 +772,15 @@ type CreateEventRequest struct {
 	NotifyDayBefore  bool                   `protobuf:"varint,4,opt,name=notify_day_before,json=notifyDayBefore,proto3" json:"notify_day_before,omitempty"`
 	NotifyHourBefore bool                   `protobuf:"varint,5,opt,name=notify_hour_before,json=notifyHourBefore,proto3" json:"notify_hour_before,omitempty"`
 	Tracklist        *Tracklist             `protobuf:"bytes,6,opt,name=tracklist,proto3" json:"tracklist,omitempty"`
-	unknownFields    protoimpl.UnknownFields
-	sizeCache        protoimpl.SizeCache
+	// Defaults to DRAFT. New events cannot start out cancelled.
+	Status        EventStatus `protobuf:"varint,7,opt,name=status,proto3,enum=musicclub.event.EventStatus" json:"status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *CreateEventRequest) Reset() {
 	*x = CreateEventRequest{}
-	mi := &file_event_proto_msgTypes[7]
+	mi := &file_event_proto_msgTypes[10]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -464,7 +792,7 @@ func (x *CreateEventRequest) String() string {
 func (*CreateEventRequest) ProtoMessage() {}
 
 func (x *CreateEventRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[7]
+	mi := &file_event_proto_msgTypes[10]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -477,7 +805,7 @@ func (x *CreateEventRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreateEventRequest.ProtoReflect.Descriptor instead.
 func (*CreateEventRequest) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{7}
+	return file_event_proto_rawDescGZIP(), []int{10}
 }
 
 func (x *CreateEventRequest) GetTitle() string {
@@ -522,6 +850,82 @@ func (x *CreateEventRequest) GetTracklist() *Tracklist {
 	return nil
 }
 
+func (x *CreateEventRequest) GetStatus() EventStatus {
+	if x != nil {
+		return x.Status
+	}
+	return EventStatus_EVENT_STATUS_UNSPECIFIED
+}
+
+type CloneEventRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SourceEventId string                 `protobuf:"bytes,1,opt,name=source_event_id,json=sourceEventId,proto3" json:"source_event_id,omitempty"`
+	Title         string                 `protobuf:"bytes,2,opt,name=title,proto3" json:"title,omitempty"`
+	StartAt       *timestamppb.Timestamp `protobuf:"bytes,3,opt,name=start_at,json=startAt,proto3" json:"start_at,omitempty"`
+	// Also register the source event's participants for the new event.
+	CopyParticipants bool `protobuf:"varint,4,opt,name=copy_participants,json=copyParticipants,proto3" json:"copy_participants,omitempty"`
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
+func (x *CloneEventRequest) Reset() {
+	*x = CloneEventRequest{}
+	mi := &file_event_proto_msgTypes[11]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *CloneEventRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CloneEventRequest) ProtoMessage() {}
+
+func (x *CloneEventRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[11]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CloneEventRequest.ProtoReflect.Descriptor instead.
+func (*CloneEventRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *CloneEventRequest) GetSourceEventId() string {
+	if x != nil {
+		return x.SourceEventId
+	}
+	return ""
+}
+
+func (x *CloneEventRequest) GetTitle() string {
+	if x != nil {
+		return x.Title
+	}
+	return ""
+}
+
+func (x *CloneEventRequest) GetStartAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.StartAt
+	}
+	return nil
+}
+
+func (x *CloneEventRequest) GetCopyParticipants() bool {
+	if x != nil {
+		return x.CopyParticipants
+	}
+	return false
+}
+
 type UpdateEventRequest struct {
 	state            protoimpl.MessageState `protogen:"open.v1"`
 	Id               string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
@@ -530,13 +934,14 @@ type UpdateEventRequest struct {
 	Location         string                 `protobuf:"bytes,4,opt,name=location,proto3" json:"location,omitempty"`
 	NotifyDayBefore  bool                   `protobuf:"varint,5,opt,name=notify_day_before,json=notifyDayBefore,proto3" json:"notify_day_before,omitempty"`
 	NotifyHourBefore bool                   `protobuf:"varint,6,opt,name=notify_hour_before,json=notifyHourBefore,proto3" json:"notify_hour_before,omitempty"`
+	Status           EventStatus            `protobuf:"varint,7,opt,name=status,proto3,enum=musicclub.event.EventStatus" json:"status,omitempty"`
 	unknownFields    protoimpl.UnknownFields
 	sizeCache        protoimpl.SizeCache
 }
 
 func (x *UpdateEventRequest) Reset() {
 	*x = UpdateEventRequest{}
-	mi := &file_event_proto_msgTypes[8]
+	mi := &file_event_proto_msgTypes[12]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -548,7 +953,7 @@ func (x *UpdateEventRequest) String() string {
 func (*UpdateEventRequest) ProtoMessage() {}
 
 func (x *UpdateEventRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[8]
+	mi := &file_event_proto_msgTypes[12]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -561,7 +966,7 @@ func (x *UpdateEventRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdateEventRequest.ProtoReflect.Descriptor instead.
 func (*UpdateEventRequest) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{8}
+	return file_event_proto_rawDescGZIP(), []int{12}
 }
 
 func (x *UpdateEventRequest) GetId() string {
@@ -606,6 +1011,13 @@ func (x *UpdateEventRequest) GetNotifyHourBefore() bool {
 	return false
 }
 
+func (x *UpdateEventRequest) GetStatus() EventStatus {
+	if x != nil {
+		return x.Status
+	}
+	return EventStatus_EVENT_STATUS_UNSPECIFIED
+}
+
 type SetTracklistRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	EventId       string                 `protobuf:"bytes,1,opt,name=event_id,json=eventId,proto3" json:"event_id,omitempty"`
@@ -616,7 +1028,7 @@ type SetTracklistRequest struct {
 
 func (x *SetTracklistRequest) Reset() {
 	*x = SetTracklistRequest{}
-	mi := &file_event_proto_msgTypes[9]
+	mi := &file_event_proto_msgTypes[13]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -628,7 +1040,7 @@ func (x *SetTracklistRequest) String() string {
 func (*SetTracklistRequest) ProtoMessage() {}
 
 func (x *SetTracklistRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_event_proto_msgTypes[9]
+	mi := &file_event_proto_msgTypes[13]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -641,7 +1053,7 @@ func (x *SetTracklistRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SetTracklistRequest.ProtoReflect.Descriptor instead.
 func (*SetTracklistRequest) Descriptor() ([]byte, []int) {
-	return file_event_proto_rawDescGZIP(), []int{9}
+	return file_event_proto_rawDescGZIP(), []int{13}
 }
 
 func (x *SetTracklistRequest) GetEventId() string {
@@ -658,6 +1070,270 @@ func (x *SetTracklistRequest) GetTracklist() *Tracklist {
 	return nil
 }
 
+type SwapTracklistSongsRequest struct {
+	state   protoimpl.MessageState `protogen:"open.v1"`
+	EventId string                 `protobuf:"bytes,1,opt,name=event_id,json=eventId,proto3" json:"event_id,omitempty"`
+	// Both songs must appear exactly once in the tracklist.
+	FirstSongId   string `protobuf:"bytes,2,opt,name=first_song_id,json=firstSongId,proto3" json:"first_song_id,omitempty"`
+	SecondSongId  string `protobuf:"bytes,3,opt,name=second_song_id,json=secondSongId,proto3" json:"second_song_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SwapTracklistSongsRequest) Reset() {
+	*x = SwapTracklistSongsRequest{}
+	mi := &file_event_proto_msgTypes[14]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SwapTracklistSongsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SwapTracklistSongsRequest) ProtoMessage() {}
+
+func (x *SwapTracklistSongsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[14]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SwapTracklistSongsRequest.ProtoReflect.Descriptor instead.
+func (*SwapTracklistSongsRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{14}
+}
+
+func (x *SwapTracklistSongsRequest) GetEventId() string {
+	if x != nil {
+		return x.EventId
+	}
+	return ""
+}
+
+func (x *SwapTracklistSongsRequest) GetFirstSongId() string {
+	if x != nil {
+		return x.FirstSongId
+	}
+	return ""
+}
+
+func (x *SwapTracklistSongsRequest) GetSecondSongId() string {
+	if x != nil {
+		return x.SecondSongId
+	}
+	return ""
+}
+
+type MoveTracklistSongsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SourceEventId string                 `protobuf:"bytes,1,opt,name=source_event_id,json=sourceEventId,proto3" json:"source_event_id,omitempty"`
+	TargetEventId string                 `protobuf:"bytes,2,opt,name=target_event_id,json=targetEventId,proto3" json:"target_event_id,omitempty"`
+	// Each song must appear exactly once in the source tracklist. They are
+	// appended to the target in this order.
+	SongIds       []string `protobuf:"bytes,3,rep,name=song_ids,json=songIds,proto3" json:"song_ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MoveTracklistSongsRequest) Reset() {
+	*x = MoveTracklistSongsRequest{}
+	mi := &file_event_proto_msgTypes[15]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MoveTracklistSongsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MoveTracklistSongsRequest) ProtoMessage() {}
+
+func (x *MoveTracklistSongsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[15]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MoveTracklistSongsRequest.ProtoReflect.Descriptor instead.
+func (*MoveTracklistSongsRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{15}
+}
+
+func (x *MoveTracklistSongsRequest) GetSourceEventId() string {
+	if x != nil {
+		return x.SourceEventId
+	}
+	return ""
+}
+
+func (x *MoveTracklistSongsRequest) GetTargetEventId() string {
+	if x != nil {
+		return x.TargetEventId
+	}
+	return ""
+}
+
+func (x *MoveTracklistSongsRequest) GetSongIds() []string {
+	if x != nil {
+		return x.SongIds
+	}
+	return nil
+}
+
+type EventDates struct {
+	state         protoimpl.MessageState   `protogen:"open.v1"`
+	Dates         []*timestamppb.Timestamp `protobuf:"bytes,1,rep,name=dates,proto3" json:"dates,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *EventDates) Reset() {
+	*x = EventDates{}
+	mi := &file_event_proto_msgTypes[16]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *EventDates) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*EventDates) ProtoMessage() {}
+
+func (x *EventDates) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[16]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use EventDates.ProtoReflect.Descriptor instead.
+func (*EventDates) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{16}
+}
+
+func (x *EventDates) GetDates() []*timestamppb.Timestamp {
+	if x != nil {
+		return x.Dates
+	}
+	return nil
+}
+
+type EventPeople struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Ordered by display name.
+	People        []*User `protobuf:"bytes,1,rep,name=people,proto3" json:"people,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *EventPeople) Reset() {
+	*x = EventPeople{}
+	mi := &file_event_proto_msgTypes[17]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *EventPeople) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*EventPeople) ProtoMessage() {}
+
+func (x *EventPeople) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[17]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use EventPeople.ProtoReflect.Descriptor instead.
+func (*EventPeople) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{17}
+}
+
+func (x *EventPeople) GetPeople() []*User {
+	if x != nil {
+		return x.People
+	}
+	return nil
+}
+
+type EventDateRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	EventId       string                 `protobuf:"bytes,1,opt,name=event_id,json=eventId,proto3" json:"event_id,omitempty"`
+	Date          *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=date,proto3" json:"date,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *EventDateRequest) Reset() {
+	*x = EventDateRequest{}
+	mi := &file_event_proto_msgTypes[18]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *EventDateRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*EventDateRequest) ProtoMessage() {}
+
+func (x *EventDateRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_event_proto_msgTypes[18]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use EventDateRequest.ProtoReflect.Descriptor instead.
+func (*EventDateRequest) Descriptor() ([]byte, []int) {
+	return file_event_proto_rawDescGZIP(), []int{18}
+}
+
+func (x *EventDateRequest) GetEventId() string {
+	if x != nil {
+		return x.EventId
+	}
+	return ""
+}
+
+func (x *EventDateRequest) GetDate() *timestamppb.Timestamp {
+	if x != nil {
+		return x.Date
+	}
+	return nil
+}
+
 var File_event_proto protoreflect.FileDescriptor
 
 const file_event_proto_rawDesc = "" +
@@ -666,57 +1342,121 @@ const file_event_proto_rawDesc = "" +
 	"song.proto\x1a\n" +
 	"user.proto\x1a\x11permissions.proto\"\x19\n" +
 	"\aEventId\x12\x0e\n" +
-	"\x02id\x18\x01 \x01(\tR\x02id\"\x85\x01\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\"_\n" +
+	"\x12DeleteEventRequest\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x12#\n" +
+	"\rallow_missing\x18\x02 \x01(\bR\fallowMissing\x12\x14\n" +
+	"\x05force\x18\x03 \x01(\bR\x05force\"\xb2\x01\n" +
 	"\x11ListEventsRequest\x12.\n" +
 	"\x04from\x18\x01 \x01(\v2\x1a.google.protobuf.TimestampR\x04from\x12*\n" +
 	"\x02to\x18\x02 \x01(\v2\x1a.google.protobuf.TimestampR\x02to\x12\x14\n" +
-	"\x05limit\x18\x03 \x01(\rR\x05limit\"D\n" +
+	"\x05limit\x18\x03 \x01(\rR\x05limit\x12+\n" +
+	"\x11include_tracklist\x18\x04 \x01(\bR\x10includeTracklist\"_\n" +
 	"\x12ListEventsResponse\x12.\n" +
-	"\x06events\x18\x01 \x03(\v2\x16.musicclub.event.EventR\x06events\"\xda\x01\n" +
+	"\x06events\x18\x01 \x03(\v2\x16.musicclub.event.EventR\x06events\x12\x19\n" +
+	"\bhas_more\x18\x02 \x01(\bR\ahasMore\")\n" +
+	"\x15BatchGetEventsRequest\x12\x10\n" +
+	"\x03ids\x18\x01 \x03(\tR\x03ids\"i\n" +
+	"\x16BatchGetEventsResponse\x12.\n" +
+	"\x06events\x18\x01 \x03(\v2\x16.musicclub.event.EventR\x06events\x12\x1f\n" +
+	"\vmissing_ids\x18\x02 \x03(\tR\n" +
+	"missingIds\"\xc9\x03\n" +
 	"\x05Event\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x14\n" +
 	"\x05title\x18\x02 \x01(\tR\x05title\x125\n" +
 	"\bstart_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\astartAt\x12\x1a\n" +
 	"\blocation\x18\x04 \x01(\tR\blocation\x12*\n" +
 	"\x11notify_day_before\x18\x05 \x01(\bR\x0fnotifyDayBefore\x12,\n" +
-	"\x12notify_hour_before\x18\x06 \x01(\bR\x10notifyHourBefore\"\x82\x02\n" +
+	"\x12notify_hour_before\x18\x06 \x01(\bR\x10notifyHourBefore\x12\x1d\n" +
+	"\n" +
+	"created_by\x18\a \x01(\tR\tcreatedBy\x120\n" +
+	"\x05dates\x18\b \x03(\v2\x1a.google.protobuf.TimestampR\x05dates\x12G\n" +
+	"\x11tracklist_preview\x18\t \x01(\v2\x1a.musicclub.event.TracklistR\x10tracklistPreview\x12\x1d\n" +
+	"\n" +
+	"song_count\x18\n" +
+	" \x01(\rR\tsongCount\x124\n" +
+	"\x06status\x18\v \x01(\x0e2\x1c.musicclub.event.EventStatusR\x06status\"\xba\x03\n" +
 	"\fEventDetails\x12,\n" +
 	"\x05event\x18\x01 \x01(\v2\x16.musicclub.event.EventR\x05event\x128\n" +
 	"\ttracklist\x18\x02 \x01(\v2\x1a.musicclub.event.TracklistR\ttracklist\x12B\n" +
 	"\fparticipants\x18\x03 \x03(\v2\x1e.musicclub.song.RoleAssignmentR\fparticipants\x12F\n" +
-	"\vpermissions\x18\x04 \x01(\v2$.musicclub.permissions.PermissionSetR\vpermissions\"=\n" +
+	"\vpermissions\x18\x04 \x01(\v2$.musicclub.permissions.PermissionSetR\vpermissions\x12\x1d\n" +
+	"\n" +
+	"song_count\x18\x05 \x01(\rR\tsongCount\x12+\n" +
+	"\x11participant_count\x18\x06 \x01(\rR\x10participantCount\x124\n" +
+	"\x16total_duration_seconds\x18\a \x01(\rR\x14totalDurationSeconds\x124\n" +
+	"\x16missing_duration_count\x18\b \x01(\rR\x14missingDurationCount\"=\n" +
 	"\tTracklist\x120\n" +
-	"\x05items\x18\x01 \x03(\v2\x1a.musicclub.event.TrackItemR\x05items\"\x82\x01\n" +
+	"\x05items\x18\x01 \x03(\v2\x1a.musicclub.event.TrackItemR\x05items\"\xac\x01\n" +
 	"\tTrackItem\x12\x14\n" +
 	"\x05order\x18\x01 \x01(\rR\x05order\x12\x17\n" +
 	"\asong_id\x18\x02 \x01(\tR\x06songId\x12!\n" +
 	"\fcustom_title\x18\x03 \x01(\tR\vcustomTitle\x12#\n" +
-	"\rcustom_artist\x18\x04 \x01(\tR\fcustomArtist\"\x91\x02\n" +
+	"\rcustom_artist\x18\x04 \x01(\tR\fcustomArtist\x12(\n" +
+	"\x04song\x18\x05 \x01(\v2\x14.musicclub.song.SongR\x04song\"\xc7\x02\n" +
 	"\x12CreateEventRequest\x12\x14\n" +
 	"\x05title\x18\x01 \x01(\tR\x05title\x125\n" +
 	"\bstart_at\x18\x02 \x01(\v2\x1a.google.protobuf.TimestampR\astartAt\x12\x1a\n" +
 	"\blocation\x18\x03 \x01(\tR\blocation\x12*\n" +
 	"\x11notify_day_before\x18\x04 \x01(\bR\x0fnotifyDayBefore\x12,\n" +
 	"\x12notify_hour_before\x18\x05 \x01(\bR\x10notifyHourBefore\x128\n" +
-	"\ttracklist\x18\x06 \x01(\v2\x1a.musicclub.event.TracklistR\ttracklist\"\xe7\x01\n" +
+	"\ttracklist\x18\x06 \x01(\v2\x1a.musicclub.event.TracklistR\ttracklist\x124\n" +
+	"\x06status\x18\a \x01(\x0e2\x1c.musicclub.event.EventStatusR\x06status\"\xb5\x01\n" +
+	"\x11CloneEventRequest\x12&\n" +
+	"\x0fsource_event_id\x18\x01 \x01(\tR\rsourceEventId\x12\x14\n" +
+	"\x05title\x18\x02 \x01(\tR\x05title\x125\n" +
+	"\bstart_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\astartAt\x12+\n" +
+	"\x11copy_participants\x18\x04 \x01(\bR\x10copyParticipants\"\x9d\x02\n" +
 	"\x12UpdateEventRequest\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x14\n" +
 	"\x05title\x18\x02 \x01(\tR\x05title\x125\n" +
 	"\bstart_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\astartAt\x12\x1a\n" +
 	"\blocation\x18\x04 \x01(\tR\blocation\x12*\n" +
 	"\x11notify_day_before\x18\x05 \x01(\bR\x0fnotifyDayBefore\x12,\n" +
-	"\x12notify_hour_before\x18\x06 \x01(\bR\x10notifyHourBefore\"j\n" +
+	"\x12notify_hour_before\x18\x06 \x01(\bR\x10notifyHourBefore\x124\n" +
+	"\x06status\x18\a \x01(\x0e2\x1c.musicclub.event.EventStatusR\x06status\"j\n" +
 	"\x13SetTracklistRequest\x12\x19\n" +
 	"\bevent_id\x18\x01 \x01(\tR\aeventId\x128\n" +
-	"\ttracklist\x18\x02 \x01(\v2\x1a.musicclub.event.TracklistR\ttracklist2\xe6\x03\n" +
+	"\ttracklist\x18\x02 \x01(\v2\x1a.musicclub.event.TracklistR\ttracklist\"\x80\x01\n" +
+	"\x19SwapTracklistSongsRequest\x12\x19\n" +
+	"\bevent_id\x18\x01 \x01(\tR\aeventId\x12\"\n" +
+	"\rfirst_song_id\x18\x02 \x01(\tR\vfirstSongId\x12$\n" +
+	"\x0esecond_song_id\x18\x03 \x01(\tR\fsecondSongId\"\x86\x01\n" +
+	"\x19MoveTracklistSongsRequest\x12&\n" +
+	"\x0fsource_event_id\x18\x01 \x01(\tR\rsourceEventId\x12&\n" +
+	"\x0ftarget_event_id\x18\x02 \x01(\tR\rtargetEventId\x12\x19\n" +
+	"\bsong_ids\x18\x03 \x03(\tR\asongIds\">\n" +
+	"\n" +
+	"EventDates\x120\n" +
+	"\x05dates\x18\x01 \x03(\v2\x1a.google.protobuf.TimestampR\x05dates\";\n" +
+	"\vEventPeople\x12,\n" +
+	"\x06people\x18\x01 \x03(\v2\x14.musicclub.user.UserR\x06people\"]\n" +
+	"\x10EventDateRequest\x12\x19\n" +
+	"\bevent_id\x18\x01 \x01(\tR\aeventId\x12.\n" +
+	"\x04date\x18\x02 \x01(\v2\x1a.google.protobuf.TimestampR\x04date*{\n" +
+	"\vEventStatus\x12\x1c\n" +
+	"\x18EVENT_STATUS_UNSPECIFIED\x10\x00\x12\x16\n" +
+	"\x12EVENT_STATUS_DRAFT\x10\x01\x12\x1a\n" +
+	"\x16EVENT_STATUS_PUBLISHED\x10\x02\x12\x1a\n" +
+	"\x16EVENT_STATUS_CANCELLED\x10\x032\xf4\t\n" +
 	"\fEventService\x12U\n" +
 	"\n" +
 	"ListEvents\x12\".musicclub.event.ListEventsRequest\x1a#.musicclub.event.ListEventsResponse\x12C\n" +
-	"\bGetEvent\x12\x18.musicclub.event.EventId\x1a\x1d.musicclub.event.EventDetails\x12Q\n" +
-	"\vCreateEvent\x12#.musicclub.event.CreateEventRequest\x1a\x1d.musicclub.event.EventDetails\x12Q\n" +
-	"\vUpdateEvent\x12#.musicclub.event.UpdateEventRequest\x1a\x1d.musicclub.event.EventDetails\x12?\n" +
-	"\vDeleteEvent\x12\x18.musicclub.event.EventId\x1a\x16.google.protobuf.Empty\x12S\n" +
-	"\fSetTracklist\x12$.musicclub.event.SetTracklistRequest\x1a\x1d.musicclub.event.EventDetailsB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
+	"\bGetEvent\x12\x18.musicclub.event.EventId\x1a\x1d.musicclub.event.EventDetails\x12a\n" +
+	"\x0eBatchGetEvents\x12&.musicclub.event.BatchGetEventsRequest\x1a'.musicclub.event.BatchGetEventsResponse\x12P\n" +
+	"\x11ListEventsForSong\x12\x16.musicclub.song.SongId\x1a#.musicclub.event.ListEventsResponse\x12Q\n" +
+	"\vCreateEvent\x12#.musicclub.event.CreateEventRequest\x1a\x1d.musicclub.event.EventDetails\x12O\n" +
+	"\n" +
+	"CloneEvent\x12\".musicclub.event.CloneEventRequest\x1a\x1d.musicclub.event.EventDetails\x12Q\n" +
+	"\vUpdateEvent\x12#.musicclub.event.UpdateEventRequest\x1a\x1d.musicclub.event.EventDetails\x12J\n" +
+	"\vDeleteEvent\x12#.musicclub.event.DeleteEventRequest\x1a\x16.google.protobuf.Empty\x12S\n" +
+	"\fSetTracklist\x12$.musicclub.event.SetTracklistRequest\x1a\x1d.musicclub.event.EventDetails\x12_\n" +
+	"\x12SwapTracklistSongs\x12*.musicclub.event.SwapTracklistSongsRequest\x1a\x1d.musicclub.event.EventDetails\x12_\n" +
+	"\x12MoveTracklistSongs\x12*.musicclub.event.MoveTracklistSongsRequest\x1a\x1d.musicclub.event.EventDetails\x12I\n" +
+	"\x0fListEventPeople\x12\x18.musicclub.event.EventId\x1a\x1c.musicclub.event.EventPeople\x12G\n" +
+	"\x0eListEventDates\x12\x18.musicclub.event.EventId\x1a\x1b.musicclub.event.EventDates\x12P\n" +
+	"\fAddEventDate\x12!.musicclub.event.EventDateRequest\x1a\x1d.musicclub.event.EventDetails\x12S\n" +
+	"\x0fRemoveEventDate\x12!.musicclub.event.EventDateRequest\x1a\x1d.musicclub.event.EventDetailsB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
 
 var (
 	file_event_proto_rawDescOnce sync.Once
@@ -730,54 +1470,97 @@ func file_event_proto_rawDescGZIP() []byte {
 	return file_event_proto_rawDescData
 }
 
-var file_event_proto_msgTypes = make([]protoimpl.MessageInfo, 10)
+var file_event_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_event_proto_msgTypes = make([]protoimpl.MessageInfo, 19)
 var file_event_proto_goTypes = []any{
-	(*EventId)(nil),               // 0: musicclub.event.EventId
-	(*ListEventsRequest)(nil),     // 1: musicclub.event.ListEventsRequest
-	(*ListEventsResponse)(nil),    // 2: musicclub.event.ListEventsResponse
-	(*Event)(nil),                 // 3: musicclub.event.Event
-	(*EventDetails)(nil),          // 4: musicclub.event.EventDetails
-	(*Tracklist)(nil),             // 5: musicclub.event.Tracklist
-	(*TrackItem)(nil),             // 6: musicclub.event.TrackItem
-	(*CreateEventRequest)(nil),    // 7: musicclub.event.CreateEventRequest
-	(*UpdateEventRequest)(nil),    // 8: musicclub.event.UpdateEventRequest
-	(*SetTracklistRequest)(nil),   // 9: musicclub.event.SetTracklistRequest
-	(*timestamppb.Timestamp)(nil), // 10: google.protobuf.Timestamp
-	(*RoleAssignment)(nil),        // 11: musicclub.song.RoleAssignment
-	(*PermissionSet)(nil),         // 12: musicclub.permissions.PermissionSet
-	(*emptypb.Empty)(nil),         // 13: google.protobuf.Empty
+	(EventStatus)(0),                  // 0: musicclub.event.EventStatus
+	(*EventId)(nil),                   // 1: musicclub.event.EventId
+	(*DeleteEventRequest)(nil),        // 2: musicclub.event.DeleteEventRequest
+	(*ListEventsRequest)(nil),         // 3: musicclub.event.ListEventsRequest
+	(*ListEventsResponse)(nil),        // 4: musicclub.event.ListEventsResponse
+	(*BatchGetEventsRequest)(nil),     // 5: musicclub.event.BatchGetEventsRequest
+	(*BatchGetEventsResponse)(nil),    // 6: musicclub.event.BatchGetEventsResponse
+	(*Event)(nil),                     // 7: musicclub.event.Event
+	(*EventDetails)(nil),              // 8: musicclub.event.EventDetails
+	(*Tracklist)(nil),                 // 9: musicclub.event.Tracklist
+	(*TrackItem)(nil),                 // 10: musicclub.event.TrackItem
+	(*CreateEventRequest)(nil),        // 11: musicclub.event.CreateEventRequest
+	(*CloneEventRequest)(nil),         // 12: musicclub.event.CloneEventRequest
+	(*UpdateEventRequest)(nil),        // 13: musicclub.event.UpdateEventRequest
+	(*SetTracklistRequest)(nil),       // 14: musicclub.event.SetTracklistRequest
+	(*SwapTracklistSongsRequest)(nil), // 15: musicclub.event.SwapTracklistSongsRequest
+	(*MoveTracklistSongsRequest)(nil), // 16: musicclub.event.MoveTracklistSongsRequest
+	(*EventDates)(nil),                // 17: musicclub.event.EventDates
+	(*EventPeople)(nil),               // 18: musicclub.event.EventPeople
+	(*EventDateRequest)(nil),          // 19: musicclub.event.EventDateRequest
+	(*timestamppb.Timestamp)(nil),     // 20: google.protobuf.Timestamp
+	(*RoleAssignment)(nil),            // 21: musicclub.song.RoleAssignment
+	(*PermissionSet)(nil),             // 22: musicclub.permissions.PermissionSet
+	(*Song)(nil),                      // 23: musicclub.song.Song
+	(*User)(nil),                      // 24: musicclub.user.User
+	(*SongId)(nil),                    // 25: musicclub.song.SongId
+	(*emptypb.Empty)(nil),             // 26: google.protobuf.Empty
 }
 var file_event_proto_depIdxs = []int32{
-	10, // 0: musicclub.event.ListEventsRequest.from:type_name -> google.protobuf.Timestamp
-	10, // 1: musicclub.event.ListEventsRequest.to:type_name -> google.protobuf.Timestamp
-	3,  // 2: musicclub.event.ListEventsResponse.events:type_name -> musicclub.event.Event
-	10, // 3: musicclub.event.Event.start_at:type_name -> google.protobuf.Timestamp
-	3,  // 4: musicclub.event.EventDetails.event:type_name -> musicclub.event.Event
-	5,  // 5: musicclub.event.EventDetails.tracklist:type_name -> musicclub.event.Tracklist
-	11, // 6: musicclub.event.EventDetails.participants:type_name -> musicclub.song.RoleAssignment
-	12, // 7: musicclub.event.EventDetails.permissions:type_name -> musicclub.permissions.PermissionSet
-	6,  // 8: musicclub.event.Tracklist.items:type_name -> musicclub.event.TrackItem
-	10, // 9: musicclub.event.CreateEventRequest.start_at:type_name -> google.protobuf.Timestamp
-	5,  // 10: musicclub.event.CreateEventRequest.tracklist:type_name -> musicclub.event.Tracklist
-	10, // 11: musicclub.event.UpdateEventRequest.start_at:type_name -> google.protobuf.Timestamp
-	5,  // 12: musicclub.event.SetTracklistRequest.tracklist:type_name -> musicclub.event.Tracklist
-	1,  // 13: musicclub.event.EventService.ListEvents:input_type -> musicclub.event.ListEventsRequest
-	0,  // 14: musicclub.event.EventService.GetEvent:input_type -> musicclub.event.EventId
-	7,  // 15: musicclub.event.EventService.CreateEvent:input_type -> musicclub.event.CreateEventRequest
-	8,  // 16: musicclub.event.EventService.UpdateEvent:input_type -> musicclub.event.UpdateEventRequest
-	0,  // 17: musicclub.event.EventService.DeleteEvent:input_type -> musicclub.event.EventId
-	9,  // 18: musicclub.event.EventService.SetTracklist:input_type -> musicclub.event.SetTracklistRequest
-	2,  // 19: musicclub.event.EventService.ListEvents:output_type -> musicclub.event.ListEventsResponse
-	4,  // 20: musicclub.event.EventService.GetEvent:output_type -> musicclub.event.EventDetails
-	4,  // 21: musicclub.event.EventService.CreateEvent:output_type -> musicclub.event.EventDetails
-	4,  // 22: musicclub.event.EventService.UpdateEvent:output_type -> musicclub.event.EventDetails
-	13, // 23: musicclub.event.EventService.DeleteEvent:output_type -> google.protobuf.Empty
-	4,  // 24: musicclub.event.EventService.SetTracklist:output_type -> musicclub.event.EventDetails
-	19, // [19:25] is the sub-list for method output_type
-	13, // [13:19] is the sub-list for method input_type
-	13, // [13:13] is the sub-list for extension type_name
-	13, // [13:13] is the sub-list for extension extendee
-	0,  // [0:13] is the sub-list for field type_name
+	20, // 0: musicclub.event.ListEventsRequest.from:type_name -> google.protobuf.Timestamp
+	20, // 1: musicclub.event.ListEventsRequest.to:type_name -> google.protobuf.Timestamp
+	7,  // 2: musicclub.event.ListEventsResponse.events:type_name -> musicclub.event.Event
+	7,  // 3: musicclub.event.BatchGetEventsResponse.events:type_name -> musicclub.event.Event
+	20, // 4: musicclub.event.Event.start_at:type_name -> google.protobuf.Timestamp
+	20, // 5: musicclub.event.Event.dates:type_name -> google.protobuf.Timestamp
+	9,  // 6: musicclub.event.Event.tracklist_preview:type_name -> musicclub.event.Tracklist
+	0,  // 7: musicclub.event.Event.status:type_name -> musicclub.event.EventStatus
+	7,  // 8: musicclub.event.EventDetails.event:type_name -> musicclub.event.Event
+	9,  // 9: musicclub.event.EventDetails.tracklist:type_name -> musicclub.event.Tracklist
+	21, // 10: musicclub.event.EventDetails.participants:type_name -> musicclub.song.RoleAssignment
+	22, // 11: musicclub.event.EventDetails.permissions:type_name -> musicclub.permissions.PermissionSet
+	10, // 12: musicclub.event.Tracklist.items:type_name -> musicclub.event.TrackItem
+	23, // 13: musicclub.event.TrackItem.song:type_name -> musicclub.song.Song
+	20, // 14: musicclub.event.CreateEventRequest.start_at:type_name -> google.protobuf.Timestamp
+	9,  // 15: musicclub.event.CreateEventRequest.tracklist:type_name -> musicclub.event.Tracklist
+	0,  // 16: musicclub.event.CreateEventRequest.status:type_name -> musicclub.event.EventStatus
+	20, // 17: musicclub.event.CloneEventRequest.start_at:type_name -> google.protobuf.Timestamp
+	20, // 18: musicclub.event.UpdateEventRequest.start_at:type_name -> google.protobuf.Timestamp
+	0,  // 19: musicclub.event.UpdateEventRequest.status:type_name -> musicclub.event.EventStatus
+	9,  // 20: musicclub.event.SetTracklistRequest.tracklist:type_name -> musicclub.event.Tracklist
+	20, // 21: musicclub.event.EventDates.dates:type_name -> google.protobuf.Timestamp
+	24, // 22: musicclub.event.EventPeople.people:type_name -> musicclub.user.User
+	20, // 23: musicclub.event.EventDateRequest.date:type_name -> google.protobuf.Timestamp
+	3,  // 24: musicclub.event.EventService.ListEvents:input_type -> musicclub.event.ListEventsRequest
+	1,  // 25: musicclub.event.EventService.GetEvent:input_type -> musicclub.event.EventId
+	5,  // 26: musicclub.event.EventService.BatchGetEvents:input_type -> musicclub.event.BatchGetEventsRequest
+	25, // 27: musicclub.event.EventService.ListEventsForSong:input_type -> musicclub.song.SongId
+	11, // 28: musicclub.event.EventService.CreateEvent:input_type -> musicclub.event.CreateEventRequest
+	12, // 29: musicclub.event.EventService.CloneEvent:input_type -> musicclub.event.CloneEventRequest
+	13, // 30: musicclub.event.EventService.UpdateEvent:input_type -> musicclub.event.UpdateEventRequest
+	2,  // 31: musicclub.event.EventService.DeleteEvent:input_type -> musicclub.event.DeleteEventRequest
+	14, // 32: musicclub.event.EventService.SetTracklist:input_type -> musicclub.event.SetTracklistRequest
+	15, // 33: musicclub.event.EventService.SwapTracklistSongs:input_type -> musicclub.event.SwapTracklistSongsRequest
+	16, // 34: musicclub.event.EventService.MoveTracklistSongs:input_type -> musicclub.event.MoveTracklistSongsRequest
+	1,  // 35: musicclub.event.EventService.ListEventPeople:input_type -> musicclub.event.EventId
+	1,  // 36: musicclub.event.EventService.ListEventDates:input_type -> musicclub.event.EventId
+	19, // 37: musicclub.event.EventService.AddEventDate:input_type -> musicclub.event.EventDateRequest
+	19, // 38: musicclub.event.EventService.RemoveEventDate:input_type -> musicclub.event.EventDateRequest
+	4,  // 39: musicclub.event.EventService.ListEvents:output_type -> musicclub.event.ListEventsResponse
+	8,  // 40: musicclub.event.EventService.GetEvent:output_type -> musicclub.event.EventDetails
+	6,  // 41: musicclub.event.EventService.BatchGetEvents:output_type -> musicclub.event.BatchGetEventsResponse
+	4,  // 42: musicclub.event.EventService.ListEventsForSong:output_type -> musicclub.event.ListEventsResponse
+	8,  // 43: musicclub.event.EventService.CreateEvent:output_type -> musicclub.event.EventDetails
+	8,  // 44: musicclub.event.EventService.CloneEvent:output_type -> musicclub.event.EventDetails
+	8,  // 45: musicclub.event.EventService.UpdateEvent:output_type -> musicclub.event.EventDetails
+	26, // 46: musicclub.event.EventService.DeleteEvent:output_type -> google.protobuf.Empty
+	8,  // 47: musicclub.event.EventService.SetTracklist:output_type -> musicclub.event.EventDetails
+	8,  // 48: musicclub.event.EventService.SwapTracklistSongs:output_type -> musicclub.event.EventDetails
+	8,  // 49: musicclub.event.EventService.MoveTracklistSongs:output_type -> musicclub.event.EventDetails
+	18, // 50: musicclub.event.EventService.ListEventPeople:output_type -> musicclub.event.EventPeople
+	17, // 51: musicclub.event.EventService.ListEventDates:output_type -> musicclub.event.EventDates
+	8,  // 52: musicclub.event.EventService.AddEventDate:output_type -> musicclub.event.EventDetails
+	8,  // 53: musicclub.event.EventService.RemoveEventDate:output_type -> musicclub.event.EventDetails
+	39, // [39:54] is the sub-list for method output_type
+	24, // [24:39] is the sub-list for method input_type
+	24, // [24:24] is the sub-list for extension type_name
+	24, // [24:24] is the sub-list for extension extendee
+	0,  // [0:24] is the sub-list for field type_name
 }
 
 func init() { file_event_proto_init() }
@@ -793,13 +1576,14 @@ func file_event_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_event_proto_rawDesc), len(file_event_proto_rawDesc)),
-			NumEnums:      0,
-			NumMessages:   10,
+			NumEnums:      1,
+			NumMessages:   19,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
 		GoTypes:           file_event_proto_goTypes,
 		DependencyIndexes: file_event_proto_depIdxs,
+		EnumInfos:         file_event_proto_enumTypes,
 		MessageInfos:      file_event_proto_msgTypes,
 	}.Build()
 	File_event_proto = out.File
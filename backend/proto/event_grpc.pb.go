@@ -20,12 +20,21 @@ import (
 const _ = grpc.SupportPackageIsVersion9
 
 const (
-	EventService_ListEvents_FullMethodName   = "/musicclub.event.EventService/ListEvents"
-	EventService_GetEvent_FullMethodName     = "/musicclub.event.EventService/GetEvent"
-	EventService_CreateEvent_FullMethodName  = "/musicclub.event.EventService/CreateEvent"
-	EventService_UpdateEvent_FullMethodName  = "/musicclub.event.EventService/UpdateEvent"
-	EventService_DeleteEvent_FullMethodName  = "/musicclub.event.EventService/DeleteEvent"
-	EventService_SetTracklist_FullMethodName = "/musicclub.event.EventService/SetTracklist"
+	EventService_ListEvents_FullMethodName         = "/musicclub.event.EventService/ListEvents"
+	EventService_GetEvent_FullMethodName           = "/musicclub.event.EventService/GetEvent"
+	EventService_BatchGetEvents_FullMethodName     = "/musicclub.event.EventService/BatchGetEvents"
+	EventService_ListEventsForSong_FullMethodName  = "/musicclub.event.EventService/ListEventsForSong"
+	EventService_CreateEvent_FullMethodName        = "/musicclub.event.EventService/CreateEvent"
+	EventService_CloneEvent_FullMethodName         = "/musicclub.event.EventService/CloneEvent"
+	EventService_UpdateEvent_FullMethodName        = "/musicclub.event.EventService/UpdateEvent"
+	EventService_DeleteEvent_FullMethodName        = "/musicclub.event.EventService/DeleteEvent"
+	EventService_SetTracklist_FullMethodName       = "/musicclub.event.EventService/SetTracklist"
+	EventService_SwapTracklistSongs_FullMethodName = "/musicclub.event.EventService/SwapTracklistSongs"
+	EventService_MoveTracklistSongs_FullMethodName = "/musicclub.event.EventService/MoveTracklistSongs"
+	EventService_ListEventPeople_FullMethodName    = "/musicclub.event.EventService/ListEventPeople"
+	EventService_ListEventDates_FullMethodName     = "/musicclub.event.EventService/ListEventDates"
+	EventService_AddEventDate_FullMethodName       = "/musicclub.event.EventService/AddEventDate"
+	EventService_RemoveEventDate_FullMethodName    = "/musicclub.event.EventService/RemoveEventDate"
 )
 
 // EventServiceClient is the client API for EventService service.
@@ -38,14 +47,32 @@ type EventServiceClient interface {
 	ListEvents(ctx context.Context, in *ListEventsRequest, opts ...grpc.CallOption) (*ListEventsResponse, error)
 	// Returns a single event with full details and tracklist.
 	GetEvent(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventDetails, error)
+	// Returns several events at once, in request order.
+	BatchGetEvents(ctx context.Context, in *BatchGetEventsRequest, opts ...grpc.CallOption) (*BatchGetEventsResponse, error)
+	// Lists the events whose tracklist includes a song, by start date.
+	ListEventsForSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*ListEventsResponse, error)
 	// Create events (requires permissions).
 	CreateEvent(ctx context.Context, in *CreateEventRequest, opts ...grpc.CallOption) (*EventDetails, error)
+	// Create a draft event with the tracklist of an existing one (requires permissions).
+	CloneEvent(ctx context.Context, in *CloneEventRequest, opts ...grpc.CallOption) (*EventDetails, error)
 	// Update events (requires permissions).
 	UpdateEvent(ctx context.Context, in *UpdateEventRequest, opts ...grpc.CallOption) (*EventDetails, error)
-	// Delete events (requires permissions).
-	DeleteEvent(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// Hide events (requires permissions); admins can restore them.
+	DeleteEvent(ctx context.Context, in *DeleteEventRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	// Replace the entire tracklist in one call.
 	SetTracklist(ctx context.Context, in *SetTracklistRequest, opts ...grpc.CallOption) (*EventDetails, error)
+	// Swap the positions of two catalog songs in a tracklist.
+	SwapTracklistSongs(ctx context.Context, in *SwapTracklistSongsRequest, opts ...grpc.CallOption) (*EventDetails, error)
+	// Move catalog songs from one event's tracklist to the end of another's.
+	MoveTracklistSongs(ctx context.Context, in *MoveTracklistSongsRequest, opts ...grpc.CallOption) (*EventDetails, error)
+	// Lists everyone holding a role on any song of the tracklist, once each.
+	ListEventPeople(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventPeople, error)
+	// Returns all performance dates of an event, sorted.
+	ListEventDates(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventDates, error)
+	// Add a performance date to a multi-night event.
+	AddEventDate(ctx context.Context, in *EventDateRequest, opts ...grpc.CallOption) (*EventDetails, error)
+	// Remove a performance date from an event.
+	RemoveEventDate(ctx context.Context, in *EventDateRequest, opts ...grpc.CallOption) (*EventDetails, error)
 }
 
 type eventServiceClient struct {
@@ -76,6 +103,26 @@ func (c *eventServiceClient) GetEvent(ctx context.Context, in *EventId, opts ...
 	return out, nil
 }
 
+func (c *eventServiceClient) BatchGetEvents(ctx context.Context, in *BatchGetEventsRequest, opts ...grpc.CallOption) (*BatchGetEventsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(BatchGetEventsResponse)
+	err := c.cc.Invoke(ctx, EventService_BatchGetEvents_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) ListEventsForSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*ListEventsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListEventsResponse)
+	err := c.cc.Invoke(ctx, EventService_ListEventsForSong_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *eventServiceClient) CreateEvent(ctx context.Context, in *CreateEventRequest, opts ...grpc.CallOption) (*EventDetails, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(EventDetails)
@@ -86,6 +133,16 @@ func (c *eventServiceClient) CreateEvent(ctx context.Context, in *CreateEventReq
 	return out, nil
 }
 
+func (c *eventServiceClient) CloneEvent(ctx context.Context, in *CloneEventRequest, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, EventService_CloneEvent_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *eventServiceClient) UpdateEvent(ctx context.Context, in *UpdateEventRequest, opts ...grpc.CallOption) (*EventDetails, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(EventDetails)
@@ -96,7 +153,7 @@ func (c *eventServiceClient) UpdateEvent(ctx context.Context, in *UpdateEventReq
 	return out, nil
 }
 
-func (c *eventServiceClient) DeleteEvent(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+func (c *eventServiceClient) DeleteEvent(ctx context.Context, in *DeleteEventRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(emptypb.Empty)
 	err := c.cc.Invoke(ctx, EventService_DeleteEvent_FullMethodName, in, out, cOpts...)
@@ -116,6 +173,66 @@ func (c *eventServiceClient) SetTracklist(ctx context.Context, in *SetTracklistR
 	return out, nil
 }
 
+func (c *eventServiceClient) SwapTracklistSongs(ctx context.Context, in *SwapTracklistSongsRequest, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, EventService_SwapTracklistSongs_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) MoveTracklistSongs(ctx context.Context, in *MoveTracklistSongsRequest, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, EventService_MoveTracklistSongs_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) ListEventPeople(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventPeople, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventPeople)
+	err := c.cc.Invoke(ctx, EventService_ListEventPeople_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) ListEventDates(ctx context.Context, in *EventId, opts ...grpc.CallOption) (*EventDates, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDates)
+	err := c.cc.Invoke(ctx, EventService_ListEventDates_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) AddEventDate(ctx context.Context, in *EventDateRequest, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, EventService_AddEventDate_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *eventServiceClient) RemoveEventDate(ctx context.Context, in *EventDateRequest, opts ...grpc.CallOption) (*EventDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EventDetails)
+	err := c.cc.Invoke(ctx, EventService_RemoveEventDate_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // EventServiceServer is the server API for EventService service.
 // All implementations must embed UnimplementedEventServiceServer
 // for forward compatibility.
@@ -126,14 +243,32 @@ type EventServiceServer interface {
 	ListEvents(context.Context, *ListEventsRequest) (*ListEventsResponse, error)
 	// Returns a single event with full details and tracklist.
 	GetEvent(context.Context, *EventId) (*EventDetails, error)
+	// Returns several events at once, in request order.
+	BatchGetEvents(context.Context, *BatchGetEventsRequest) (*BatchGetEventsResponse, error)
+	// Lists the events whose tracklist includes a song, by start date.
+	ListEventsForSong(context.Context, *SongId) (*ListEventsResponse, error)
 	// Create events (requires permissions).
 	CreateEvent(context.Context, *CreateEventRequest) (*EventDetails, error)
+	// Create a draft event with the tracklist of an existing one (requires permissions).
+	CloneEvent(context.Context, *CloneEventRequest) (*EventDetails, error)
 	// Update events (requires permissions).
 	UpdateEvent(context.Context, *UpdateEventRequest) (*EventDetails, error)
-	// Delete events (requires permissions).
-	DeleteEvent(context.Context, *EventId) (*emptypb.Empty, error)
+	// Hide events (requires permissions); admins can restore them.
+	DeleteEvent(context.Context, *DeleteEventRequest) (*emptypb.Empty, error)
 	// Replace the entire tracklist in one call.
 	SetTracklist(context.Context, *SetTracklistRequest) (*EventDetails, error)
+	// Swap the positions of two catalog songs in a tracklist.
+	SwapTracklistSongs(context.Context, *SwapTracklistSongsRequest) (*EventDetails, error)
+	// Move catalog songs from one event's tracklist to the end of another's.
+	MoveTracklistSongs(context.Context, *MoveTracklistSongsRequest) (*EventDetails, error)
+	// Lists everyone holding a role on any song of the tracklist, once each.
+	ListEventPeople(context.Context, *EventId) (*EventPeople, error)
+	// Returns all performance dates of an event, sorted.
+	ListEventDates(context.Context, *EventId) (*EventDates, error)
+	// Add a performance date to a multi-night event.
+	AddEventDate(context.Context, *EventDateRequest) (*EventDetails, error)
+	// Remove a performance date from an event.
+	RemoveEventDate(context.Context, *EventDateRequest) (*EventDetails, error)
 	mustEmbedUnimplementedEventServiceServer()
 }
 
@@ -150,18 +285,45 @@ func (UnimplementedEventServiceServer) ListEvents(context.Context, *ListEventsRe
 func (UnimplementedEventServiceServer) GetEvent(context.Context, *EventId) (*EventDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method GetEvent not implemented")
 }
+func (UnimplementedEventServiceServer) BatchGetEvents(context.Context, *BatchGetEventsRequest) (*BatchGetEventsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method BatchGetEvents not implemented")
+}
+func (UnimplementedEventServiceServer) ListEventsForSong(context.Context, *SongId) (*ListEventsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListEventsForSong not implemented")
+}
 func (UnimplementedEventServiceServer) CreateEvent(context.Context, *CreateEventRequest) (*EventDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method CreateEvent not implemented")
 }
+func (UnimplementedEventServiceServer) CloneEvent(context.Context, *CloneEventRequest) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method CloneEvent not implemented")
+}
 func (UnimplementedEventServiceServer) UpdateEvent(context.Context, *UpdateEventRequest) (*EventDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method UpdateEvent not implemented")
 }
-func (UnimplementedEventServiceServer) DeleteEvent(context.Context, *EventId) (*emptypb.Empty, error) {
+func (UnimplementedEventServiceServer) DeleteEvent(context.Context, *DeleteEventRequest) (*emptypb.Empty, error) {
 	return nil, status.Error(codes.Unimplemented, "method DeleteEvent not implemented")
 }
 func (UnimplementedEventServiceServer) SetTracklist(context.Context, *SetTracklistRequest) (*EventDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method SetTracklist not implemented")
 }
+func (UnimplementedEventServiceServer) SwapTracklistSongs(context.Context, *SwapTracklistSongsRequest) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method SwapTracklistSongs not implemented")
+}
+func (UnimplementedEventServiceServer) MoveTracklistSongs(context.Context, *MoveTracklistSongsRequest) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method MoveTracklistSongs not implemented")
+}
+func (UnimplementedEventServiceServer) ListEventPeople(context.Context, *EventId) (*EventPeople, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListEventPeople not implemented")
+}
+func (UnimplementedEventServiceServer) ListEventDates(context.Context, *EventId) (*EventDates, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListEventDates not implemented")
+}
+func (UnimplementedEventServiceServer) AddEventDate(context.Context, *EventDateRequest) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method AddEventDate not implemented")
+}
+func (UnimplementedEventServiceServer) RemoveEventDate(context.Context, *EventDateRequest) (*EventDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method RemoveEventDate not implemented")
+}
 func (UnimplementedEventServiceServer) mustEmbedUnimplementedEventServiceServer() {}
 func (UnimplementedEventServiceServer) testEmbeddedByValue()                      {}
 
@@ -219,6 +381,42 @@ func _EventService_GetEvent_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _EventService_BatchGetEvents_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchGetEventsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).BatchGetEvents(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_BatchGetEvents_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).BatchGetEvents(ctx, req.(*BatchGetEventsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_ListEventsForSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).ListEventsForSong(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_ListEventsForSong_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).ListEventsForSong(ctx, req.(*SongId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _EventService_CreateEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(CreateEventRequest)
 	if err := dec(in); err != nil {
@@ -237,6 +435,24 @@ func _EventService_CreateEvent_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _EventService_CloneEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CloneEventRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).CloneEvent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_CloneEvent_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).CloneEvent(ctx, req.(*CloneEventRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _EventService_UpdateEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(UpdateEventRequest)
 	if err := dec(in); err != nil {
@@ -256,7 +472,7 @@ func _EventService_UpdateEvent_Handler(srv interface{}, ctx context.Context, dec
 }
 
 func _EventService_DeleteEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(EventId)
+	in := new(DeleteEventRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
@@ -268,7 +484,7 @@ func _EventService_DeleteEvent_Handler(srv interface{}, ctx context.Context, dec
 		FullMethod: EventService_DeleteEvent_FullMethodName,
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(EventServiceServer).DeleteEvent(ctx, req.(*EventId))
+		return srv.(EventServiceServer).DeleteEvent(ctx, req.(*DeleteEventRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
@@ -291,6 +507,114 @@ func _EventService_SetTracklist_Handler(srv interface{}, ctx context.Context, de
 	return interceptor(ctx, in, info, handler)
 }
 
+func _EventService_SwapTracklistSongs_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SwapTracklistSongsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).SwapTracklistSongs(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_SwapTracklistSongs_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).SwapTracklistSongs(ctx, req.(*SwapTracklistSongsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_MoveTracklistSongs_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(MoveTracklistSongsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).MoveTracklistSongs(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_MoveTracklistSongs_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).MoveTracklistSongs(ctx, req.(*MoveTracklistSongsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_ListEventPeople_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EventId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).ListEventPeople(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_ListEventPeople_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).ListEventPeople(ctx, req.(*EventId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_ListEventDates_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EventId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).ListEventDates(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_ListEventDates_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).ListEventDates(ctx, req.(*EventId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_AddEventDate_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EventDateRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).AddEventDate(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_AddEventDate_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).AddEventDate(ctx, req.(*EventDateRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _EventService_RemoveEventDate_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EventDateRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(EventServiceServer).RemoveEventDate(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: EventService_RemoveEventDate_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(EventServiceServer).RemoveEventDate(ctx, req.(*EventDateRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // EventService_ServiceDesc is the grpc.ServiceDesc for EventService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -306,10 +630,22 @@ var EventService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetEvent",
 			Handler:    _EventService_GetEvent_Handler,
 		},
+		{
+			MethodName: "BatchGetEvents",
+			Handler:    _EventService_BatchGetEvents_Handler,
+		},
+		{
+			MethodName: "ListEventsForSong",
+			Handler:    _EventService_ListEventsForSong_Handler,
+		},
 		{
 			MethodName: "CreateEvent",
 			Handler:    _EventService_CreateEvent_Handler,
 		},
+		{
+			MethodName: "CloneEvent",
+			Handler:    _EventService_CloneEvent_Handler,
+		},
 		{
 			MethodName: "UpdateEvent",
 			Handler:    _EventService_UpdateEvent_Handler,
@@ -322,6 +658,30 @@ var EventService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "SetTracklist",
 			Handler:    _EventService_SetTracklist_Handler,
 		},
+		{
+			MethodName: "SwapTracklistSongs",
+			Handler:    _EventService_SwapTracklistSongs_Handler,
+		},
+		{
+			MethodName: "MoveTracklistSongs",
+			Handler:    _EventService_MoveTracklistSongs_Handler,
+		},
+		{
+			MethodName: "ListEventPeople",
+			Handler:    _EventService_ListEventPeople_Handler,
+		},
+		{
+			MethodName: "ListEventDates",
+			Handler:    _EventService_ListEventDates_Handler,
+		},
+		{
+			MethodName: "AddEventDate",
+			Handler:    _EventService_AddEventDate_Handler,
+		},
+		{
+			MethodName: "RemoveEventDate",
+			Handler:    _EventService_RemoveEventDate_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "event.proto",
@@ -0,0 +1,306 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v3.21.12
+// source: meta.proto
+
+package proto
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	emptypb "google.golang.org/protobuf/types/known/emptypb"
+	timestamppb "google.golang.org/protobuf/types/known/timestamppb"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+type PingRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Nonce         string                 `protobuf:"bytes,1,opt,name=nonce,proto3" json:"nonce,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PingRequest) Reset() {
+	*x = PingRequest{}
+	mi := &file_meta_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PingRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PingRequest) ProtoMessage() {}
+
+func (x *PingRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_meta_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PingRequest.ProtoReflect.Descriptor instead.
+func (*PingRequest) Descriptor() ([]byte, []int) {
+	return file_meta_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *PingRequest) GetNonce() string {
+	if x != nil {
+		return x.Nonce
+	}
+	return ""
+}
+
+type PingResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Nonce         string                 `protobuf:"bytes,1,opt,name=nonce,proto3" json:"nonce,omitempty"`
+	ServerTime    *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=server_time,json=serverTime,proto3" json:"server_time,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PingResponse) Reset() {
+	*x = PingResponse{}
+	mi := &file_meta_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PingResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PingResponse) ProtoMessage() {}
+
+func (x *PingResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_meta_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PingResponse.ProtoReflect.Descriptor instead.
+func (*PingResponse) Descriptor() ([]byte, []int) {
+	return file_meta_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *PingResponse) GetNonce() string {
+	if x != nil {
+		return x.Nonce
+	}
+	return ""
+}
+
+func (x *PingResponse) GetServerTime() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ServerTime
+	}
+	return nil
+}
+
+type ServerConfig struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// page_size used when a list request leaves it unset.
+	DefaultPageSize uint32 `protobuf:"varint,1,opt,name=default_page_size,json=defaultPageSize,proto3" json:"default_page_size,omitempty"`
+	// Larger page_size values are clamped to this.
+	MaxPageSize                uint32 `protobuf:"varint,2,opt,name=max_page_size,json=maxPageSize,proto3" json:"max_page_size,omitempty"`
+	AccessTokenTtlSeconds      int64  `protobuf:"varint,3,opt,name=access_token_ttl_seconds,json=accessTokenTtlSeconds,proto3" json:"access_token_ttl_seconds,omitempty"`
+	AdminAccessTokenTtlSeconds int64  `protobuf:"varint,4,opt,name=admin_access_token_ttl_seconds,json=adminAccessTokenTtlSeconds,proto3" json:"admin_access_token_ttl_seconds,omitempty"`
+	RefreshTokenTtlSeconds     int64  `protobuf:"varint,5,opt,name=refresh_token_ttl_seconds,json=refreshTokenTtlSeconds,proto3" json:"refresh_token_ttl_seconds,omitempty"`
+	// Lowest x-api-version the server accepts; 0 when unchecked.
+	MinApiVersion int32 `protobuf:"varint,6,opt,name=min_api_version,json=minApiVersion,proto3" json:"min_api_version,omitempty"`
+	// 0 means unlimited.
+	MaxRolesPerEvent  int32 `protobuf:"varint,7,opt,name=max_roles_per_event,json=maxRolesPerEvent,proto3" json:"max_roles_per_event,omitempty"`
+	CompressResponses bool  `protobuf:"varint,8,opt,name=compress_responses,json=compressResponses,proto3" json:"compress_responses,omitempty"`
+	unknownFields     protoimpl.UnknownFields
+	sizeCache         protoimpl.SizeCache
+}
+
+func (x *ServerConfig) Reset() {
+	*x = ServerConfig{}
+	mi := &file_meta_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ServerConfig) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ServerConfig) ProtoMessage() {}
+
+func (x *ServerConfig) ProtoReflect() protoreflect.Message {
+	mi := &file_meta_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ServerConfig.ProtoReflect.Descriptor instead.
+func (*ServerConfig) Descriptor() ([]byte, []int) {
+	return file_meta_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *ServerConfig) GetDefaultPageSize() uint32 {
+	if x != nil {
+		return x.DefaultPageSize
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetMaxPageSize() uint32 {
+	if x != nil {
+		return x.MaxPageSize
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetAccessTokenTtlSeconds() int64 {
+	if x != nil {
+		return x.AccessTokenTtlSeconds
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetAdminAccessTokenTtlSeconds() int64 {
+	if x != nil {
+		return x.AdminAccessTokenTtlSeconds
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetRefreshTokenTtlSeconds() int64 {
+	if x != nil {
+		return x.RefreshTokenTtlSeconds
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetMinApiVersion() int32 {
+	if x != nil {
+		return x.MinApiVersion
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetMaxRolesPerEvent() int32 {
+	if x != nil {
+		return x.MaxRolesPerEvent
+	}
+	return 0
+}
+
+func (x *ServerConfig) GetCompressResponses() bool {
+	if x != nil {
+		return x.CompressResponses
+	}
+	return false
+}
+
+var File_meta_proto protoreflect.FileDescriptor
+
+const file_meta_proto_rawDesc = "" +
+	"\n" +
+	"\n" +
+	"meta.proto\x12\x0emusicclub.meta\x1a\x1bgoogle/protobuf/empty.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"#\n" +
+	"\vPingRequest\x12\x14\n" +
+	"\x05nonce\x18\x01 \x01(\tR\x05nonce\"a\n" +
+	"\fPingResponse\x12\x14\n" +
+	"\x05nonce\x18\x01 \x01(\tR\x05nonce\x12;\n" +
+	"\vserver_time\x18\x02 \x01(\v2\x1a.google.protobuf.TimestampR\n" +
+	"serverTime\"\x9c\x03\n" +
+	"\fServerConfig\x12*\n" +
+	"\x11default_page_size\x18\x01 \x01(\rR\x0fdefaultPageSize\x12\"\n" +
+	"\rmax_page_size\x18\x02 \x01(\rR\vmaxPageSize\x127\n" +
+	"\x18access_token_ttl_seconds\x18\x03 \x01(\x03R\x15accessTokenTtlSeconds\x12B\n" +
+	"\x1eadmin_access_token_ttl_seconds\x18\x04 \x01(\x03R\x1aadminAccessTokenTtlSeconds\x129\n" +
+	"\x19refresh_token_ttl_seconds\x18\x05 \x01(\x03R\x16refreshTokenTtlSeconds\x12&\n" +
+	"\x0fmin_api_version\x18\x06 \x01(\x05R\rminApiVersion\x12-\n" +
+	"\x13max_roles_per_event\x18\a \x01(\x05R\x10maxRolesPerEvent\x12-\n" +
+	"\x12compress_responses\x18\b \x01(\bR\x11compressResponses2\x99\x01\n" +
+	"\vMetaService\x12A\n" +
+	"\x04Ping\x12\x1b.musicclub.meta.PingRequest\x1a\x1c.musicclub.meta.PingResponse\x12G\n" +
+	"\x0fGetServerConfig\x12\x16.google.protobuf.Empty\x1a\x1c.musicclub.meta.ServerConfigB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
+
+var (
+	file_meta_proto_rawDescOnce sync.Once
+	file_meta_proto_rawDescData []byte
+)
+
+func file_meta_proto_rawDescGZIP() []byte {
+	file_meta_proto_rawDescOnce.Do(func() {
+		file_meta_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_meta_proto_rawDesc), len(file_meta_proto_rawDesc)))
+	})
+	return file_meta_proto_rawDescData
+}
+
+var file_meta_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_meta_proto_goTypes = []any{
+	(*PingRequest)(nil),           // 0: musicclub.meta.PingRequest
+	(*PingResponse)(nil),          // 1: musicclub.meta.PingResponse
+	(*ServerConfig)(nil),          // 2: musicclub.meta.ServerConfig
+	(*timestamppb.Timestamp)(nil), // 3: google.protobuf.Timestamp
+	(*emptypb.Empty)(nil),         // 4: google.protobuf.Empty
+}
+var file_meta_proto_depIdxs = []int32{
+	3, // 0: musicclub.meta.PingResponse.server_time:type_name -> google.protobuf.Timestamp
+	0, // 1: musicclub.meta.MetaService.Ping:input_type -> musicclub.meta.PingRequest
+	4, // 2: musicclub.meta.MetaService.GetServerConfig:input_type -> google.protobuf.Empty
+	1, // 3: musicclub.meta.MetaService.Ping:output_type -> musicclub.meta.PingResponse
+	2, // 4: musicclub.meta.MetaService.GetServerConfig:output_type -> musicclub.meta.ServerConfig
+	3, // [3:5] is the sub-list for method output_type
+	1, // [1:3] is the sub-list for method input_type
+	1, // [1:1] is the sub-list for extension type_name
+	1, // [1:1] is the sub-list for extension extendee
+	0, // [0:1] is the sub-list for field type_name
+}
+
+func init() { file_meta_proto_init() }
+func file_meta_proto_init() {
+	if File_meta_proto != nil {
+		return
+	}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_meta_proto_rawDesc), len(file_meta_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   3,
+			NumExtensions: 0,
+			NumServices:   1,
+		},
+		GoTypes:           file_meta_proto_goTypes,
+		DependencyIndexes: file_meta_proto_depIdxs,
+		MessageInfos:      file_meta_proto_msgTypes,
+	}.Build()
+	File_meta_proto = out.File
+	file_meta_proto_goTypes = nil
+	file_meta_proto_depIdxs = nil
+}
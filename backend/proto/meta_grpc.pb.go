@@ -0,0 +1,168 @@
+// Code generated by protoc-gen-go-grpc. DO NOT EDIT.
+// versions:
+// - protoc-gen-go-grpc v1.6.0
+// - protoc             v3.21.12
+// source: meta.proto
+
+package proto
+
+import (
+	context "context"
+	grpc "google.golang.org/grpc"
+	codes "google.golang.org/grpc/codes"
+	status "google.golang.org/grpc/status"
+	emptypb "google.golang.org/protobuf/types/known/emptypb"
+)
+
+// This is a compile-time assertion to ensure that this generated file
+// is compatible with the grpc package it is being compiled against.
+// Requires gRPC-Go v1.64.0 or later.
+const _ = grpc.SupportPackageIsVersion9
+
+const (
+	MetaService_Ping_FullMethodName            = "/musicclub.meta.MetaService/Ping"
+	MetaService_GetServerConfig_FullMethodName = "/musicclub.meta.MetaService/GetServerConfig"
+)
+
+// MetaServiceClient is the client API for MetaService service.
+//
+// For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
+//
+// Connection-level helpers that need neither authentication nor the database.
+type MetaServiceClient interface {
+	// Echoes the nonce back with the server time, for latency measurement.
+	Ping(ctx context.Context, in *PingRequest, opts ...grpc.CallOption) (*PingResponse, error)
+	// Returns the non-sensitive server settings clients may tune themselves to.
+	GetServerConfig(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*ServerConfig, error)
+}
+
+type metaServiceClient struct {
+	cc grpc.ClientConnInterface
+}
+
+func NewMetaServiceClient(cc grpc.ClientConnInterface) MetaServiceClient {
+	return &metaServiceClient{cc}
+}
+
+func (c *metaServiceClient) Ping(ctx context.Context, in *PingRequest, opts ...grpc.CallOption) (*PingResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(PingResponse)
+	err := c.cc.Invoke(ctx, MetaService_Ping_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *metaServiceClient) GetServerConfig(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*ServerConfig, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ServerConfig)
+	err := c.cc.Invoke(ctx, MetaService_GetServerConfig_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// MetaServiceServer is the server API for MetaService service.
+// All implementations must embed UnimplementedMetaServiceServer
+// for forward compatibility.
+//
+// Connection-level helpers that need neither authentication nor the database.
+type MetaServiceServer interface {
+	// Echoes the nonce back with the server time, for latency measurement.
+	Ping(context.Context, *PingRequest) (*PingResponse, error)
+	// Returns the non-sensitive server settings clients may tune themselves to.
+	GetServerConfig(context.Context, *emptypb.Empty) (*ServerConfig, error)
+	mustEmbedUnimplementedMetaServiceServer()
+}
+
+// UnimplementedMetaServiceServer must be embedded to have
+// forward compatible implementations.
+//
+// NOTE: this should be embedded by value instead of pointer to avoid a nil
+// pointer dereference when methods are called.
+type UnimplementedMetaServiceServer struct{}
+
+func (UnimplementedMetaServiceServer) Ping(context.Context, *PingRequest) (*PingResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method Ping not implemented")
+}
+func (UnimplementedMetaServiceServer) GetServerConfig(context.Context, *emptypb.Empty) (*ServerConfig, error) {
+	return nil, status.Error(codes.Unimplemented, "method GetServerConfig not implemented")
+}
+func (UnimplementedMetaServiceServer) mustEmbedUnimplementedMetaServiceServer() {}
+func (UnimplementedMetaServiceServer) testEmbeddedByValue()                     {}
+
+// UnsafeMetaServiceServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to MetaServiceServer will
+// result in compilation errors.
+type UnsafeMetaServiceServer interface {
+	mustEmbedUnimplementedMetaServiceServer()
+}
+
+func RegisterMetaServiceServer(s grpc.ServiceRegistrar, srv MetaServiceServer) {
+	// If the following call panics, it indicates UnimplementedMetaServiceServer was
+	// embedded by pointer and is nil.  This will cause panics if an
+	// unimplemented method is ever invoked, so we test this at initialization
+	// time to prevent it from happening at runtime later due to I/O.
+	if t, ok := srv.(interface{ testEmbeddedByValue() }); ok {
+		t.testEmbeddedByValue()
+	}
+	s.RegisterService(&MetaService_ServiceDesc, srv)
+}
+
+func _MetaService_Ping_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PingRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(MetaServiceServer).Ping(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: MetaService_Ping_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(MetaServiceServer).Ping(ctx, req.(*PingRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _MetaService_GetServerConfig_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(emptypb.Empty)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(MetaServiceServer).GetServerConfig(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: MetaService_GetServerConfig_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(MetaServiceServer).GetServerConfig(ctx, req.(*emptypb.Empty))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+// MetaService_ServiceDesc is the grpc.ServiceDesc for MetaService service.
+// It's only intended for direct use with grpc.RegisterService,
+// and not to be introspected or modified (even as a copy)
+var MetaService_ServiceDesc = grpc.ServiceDesc{
+	ServiceName: "musicclub.meta.MetaService",
+	HandlerType: (*MetaServiceServer)(nil),
+	Methods: []grpc.MethodDesc{
+		{
+			MethodName: "Ping",
+			Handler:    _MetaService_Ping_Handler,
+		},
+		{
+			MethodName: "GetServerConfig",
+			Handler:    _MetaService_GetServerConfig_Handler,
+		},
+	},
+	Streams:  []grpc.StreamDesc{},
+	Metadata: "meta.proto",
+}
@@ -23,10 +23,13 @@ const (
 
 // Aggregated permissions for a user session.
 type PermissionSet struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Join          *JoinPermissions       `protobuf:"bytes,1,opt,name=join,proto3" json:"join,omitempty"`
-	Songs         *SongPermissions       `protobuf:"bytes,2,opt,name=songs,proto3" json:"songs,omitempty"`
-	Events        *EventPermissions      `protobuf:"bytes,3,opt,name=events,proto3" json:"events,omitempty"`
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	Join   *JoinPermissions       `protobuf:"bytes,1,opt,name=join,proto3" json:"join,omitempty"`
+	Songs  *SongPermissions       `protobuf:"bytes,2,opt,name=songs,proto3" json:"songs,omitempty"`
+	Events *EventPermissions      `protobuf:"bytes,3,opt,name=events,proto3" json:"events,omitempty"`
+	// Club admin: sees song notes and manages other admins. Granted
+	// explicitly, not implied by the permissions above.
+	Admin         bool `protobuf:"varint,4,opt,name=admin,proto3" json:"admin,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -82,6 +85,13 @@ func (x *PermissionSet) GetEvents() *EventPermissions {
 	return nil
 }
 
+func (x *PermissionSet) GetAdmin() bool {
+	if x != nil {
+		return x.Admin
+	}
+	return false
+}
+
 // Rights around participation in roles.
 type JoinPermissions struct {
 	state                protoimpl.MessageState `protogen:"open.v1"`
@@ -245,11 +255,12 @@ var File_permissions_proto protoreflect.FileDescriptor
 
 const file_permissions_proto_rawDesc = "" +
 	"\n" +
-	"\x11permissions.proto\x12\x15musicclub.permissions\"\xca\x01\n" +
+	"\x11permissions.proto\x12\x15musicclub.permissions\"\xe0\x01\n" +
 	"\rPermissionSet\x12:\n" +
 	"\x04join\x18\x01 \x01(\v2&.musicclub.permissions.JoinPermissionsR\x04join\x12<\n" +
 	"\x05songs\x18\x02 \x01(\v2&.musicclub.permissions.SongPermissionsR\x05songs\x12?\n" +
-	"\x06events\x18\x03 \x01(\v2'.musicclub.permissions.EventPermissionsR\x06events\"}\n" +
+	"\x06events\x18\x03 \x01(\v2'.musicclub.permissions.EventPermissionsR\x06events\x12\x14\n" +
+	"\x05admin\x18\x04 \x01(\bR\x05admin\"}\n" +
 	"\x0fJoinPermissions\x124\n" +
 	"\x16edit_own_participation\x18\x01 \x01(\bR\x14editOwnParticipation\x124\n" +
 	"\x16edit_any_participation\x18\x02 \x01(\bR\x14editAnyParticipation\"]\n" +
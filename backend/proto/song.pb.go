@@ -10,6 +10,7 @@ import (
 	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
 	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
 	emptypb "google.golang.org/protobuf/types/known/emptypb"
+	fieldmaskpb "google.golang.org/protobuf/types/known/fieldmaskpb"
 	timestamppb "google.golang.org/protobuf/types/known/timestamppb"
 	reflect "reflect"
 	sync "sync"
@@ -75,13 +76,126 @@ func (SongLinkType) EnumDescriptor() ([]byte, []int) {
 	return file_song_proto_rawDescGZIP(), []int{0}
 }
 
+type ParticipationStatus int32
+
+const (
+	// Not set; treated as CONFIRMED on writes and ignored as a list filter.
+	ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED ParticipationStatus = 0
+	ParticipationStatus_PARTICIPATION_STATUS_CONFIRMED   ParticipationStatus = 1
+	ParticipationStatus_PARTICIPATION_STATUS_TENTATIVE   ParticipationStatus = 2
+)
+
+// Enum value maps for ParticipationStatus.
+var (
+	ParticipationStatus_name = map[int32]string{
+		0: "PARTICIPATION_STATUS_UNSPECIFIED",
+		1: "PARTICIPATION_STATUS_CONFIRMED",
+		2: "PARTICIPATION_STATUS_TENTATIVE",
+	}
+	ParticipationStatus_value = map[string]int32{
+		"PARTICIPATION_STATUS_UNSPECIFIED": 0,
+		"PARTICIPATION_STATUS_CONFIRMED":   1,
+		"PARTICIPATION_STATUS_TENTATIVE":   2,
+	}
+)
+
+func (x ParticipationStatus) Enum() *ParticipationStatus {
+	p := new(ParticipationStatus)
+	*p = x
+	return p
+}
+
+func (x ParticipationStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ParticipationStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_song_proto_enumTypes[1].Descriptor()
+}
+
+func (ParticipationStatus) Type() protoreflect.EnumType {
+	return &file_song_proto_enumTypes[1]
+}
+
+func (x ParticipationStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ParticipationStatus.Descriptor instead.
+func (ParticipationStatus) EnumDescriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{1}
+}
+
+type LinkHealth int32
+
+const (
+	LinkHealth_LINK_HEALTH_UNKNOWN     LinkHealth = 0
+	LinkHealth_LINK_HEALTH_OK          LinkHealth = 1
+	LinkHealth_LINK_HEALTH_BROKEN      LinkHealth = 2
+	LinkHealth_LINK_HEALTH_UNREACHABLE LinkHealth = 3
+	LinkHealth_LINK_HEALTH_MISSING     LinkHealth = 4
+)
+
+// Enum value maps for LinkHealth.
+var (
+	LinkHealth_name = map[int32]string{
+		0: "LINK_HEALTH_UNKNOWN",
+		1: "LINK_HEALTH_OK",
+		2: "LINK_HEALTH_BROKEN",
+		3: "LINK_HEALTH_UNREACHABLE",
+		4: "LINK_HEALTH_MISSING",
+	}
+	LinkHealth_value = map[string]int32{
+		"LINK_HEALTH_UNKNOWN":     0,
+		"LINK_HEALTH_OK":          1,
+		"LINK_HEALTH_BROKEN":      2,
+		"LINK_HEALTH_UNREACHABLE": 3,
+		"LINK_HEALTH_MISSING":     4,
+	}
+)
+
+func (x LinkHealth) Enum() *LinkHealth {
+	p := new(LinkHealth)
+	*p = x
+	return p
+}
+
+func (x LinkHealth) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (LinkHealth) Descriptor() protoreflect.EnumDescriptor {
+	return file_song_proto_enumTypes[2].Descriptor()
+}
+
+func (LinkHealth) Type() protoreflect.EnumType {
+	return &file_song_proto_enumTypes[2]
+}
+
+func (x LinkHealth) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use LinkHealth.Descriptor instead.
+func (LinkHealth) EnumDescriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{2}
+}
+
 type ListSongsRequest struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Optional substring filter by title or artist.
 	Query string `protobuf:"bytes,1,opt,name=query,proto3" json:"query,omitempty"`
 	// Pagination cursor (opaque to client).
-	PageToken     string `protobuf:"bytes,2,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
-	PageSize      uint32 `protobuf:"varint,3,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
+	PageToken string `protobuf:"bytes,2,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
+	PageSize  uint32 `protobuf:"varint,3,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
+	// Only return songs without a streaming link, ordered by id.
+	MissingLink bool `protobuf:"varint,4,opt,name=missing_link,json=missingLink,proto3" json:"missing_link,omitempty"`
+	// Only return songs this user holds at least one role on.
+	ParticipantId string `protobuf:"bytes,5,opt,name=participant_id,json=participantId,proto3" json:"participant_id,omitempty"`
+	// Only return songs with this tag (case-insensitive).
+	Tag string `protobuf:"bytes,6,opt,name=tag,proto3" json:"tag,omitempty"`
+	// Song fields to return, e.g. "id" and "title"; empty returns every field.
+	ReadMask      *fieldmaskpb.FieldMask `protobuf:"bytes,7,opt,name=read_mask,json=readMask,proto3" json:"read_mask,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -137,28 +251,56 @@ func (x *ListSongsRequest) GetPageSize() uint32 {
 	return 0
 }
 
-type ListSongsResponse struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Songs         []*Song                `protobuf:"bytes,1,rep,name=songs,proto3" json:"songs,omitempty"`
-	NextPageToken string                 `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"`
+func (x *ListSongsRequest) GetMissingLink() bool {
+	if x != nil {
+		return x.MissingLink
+	}
+	return false
+}
+
+func (x *ListSongsRequest) GetParticipantId() string {
+	if x != nil {
+		return x.ParticipantId
+	}
+	return ""
+}
+
+func (x *ListSongsRequest) GetTag() string {
+	if x != nil {
+		return x.Tag
+	}
+	return ""
+}
+
+func (x *ListSongsRequest) GetReadMask() *fieldmaskpb.FieldMask {
+	if x != nil {
+		return x.ReadMask
+	}
+	return nil
+}
+
+type StreamSearchSongsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Substring filter by title or artist, matched like ListSongsRequest.query.
+	Query         string `protobuf:"bytes,1,opt,name=query,proto3" json:"query,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *ListSongsResponse) Reset() {
-	*x = ListSongsResponse{}
+func (x *StreamSearchSongsRequest) Reset() {
+	*x = StreamSearchSongsRequest{}
 	mi := &file_song_proto_msgTypes[1]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *ListSongsResponse) String() string {
+func (x *StreamSearchSongsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListSongsResponse) ProtoMessage() {}
+func (*StreamSearchSongsRequest) ProtoMessage() {}
 
-func (x *ListSongsResponse) ProtoReflect() protoreflect.Message {
+func (x *StreamSearchSongsRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_song_proto_msgTypes[1]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -170,46 +312,42 @@ func (x *ListSongsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListSongsResponse.ProtoReflect.Descriptor instead.
-func (*ListSongsResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use StreamSearchSongsRequest.ProtoReflect.Descriptor instead.
+func (*StreamSearchSongsRequest) Descriptor() ([]byte, []int) {
 	return file_song_proto_rawDescGZIP(), []int{1}
 }
 
-func (x *ListSongsResponse) GetSongs() []*Song {
-	if x != nil {
-		return x.Songs
-	}
-	return nil
-}
-
-func (x *ListSongsResponse) GetNextPageToken() string {
+func (x *StreamSearchSongsRequest) GetQuery() string {
 	if x != nil {
-		return x.NextPageToken
+		return x.Query
 	}
 	return ""
 }
 
-type SongId struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Id            string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+type ListSongsNotInEventRequest struct {
+	state   protoimpl.MessageState `protogen:"open.v1"`
+	EventId string                 `protobuf:"bytes,1,opt,name=event_id,json=eventId,proto3" json:"event_id,omitempty"`
+	// Pagination cursor (opaque to client).
+	PageToken     string `protobuf:"bytes,2,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
+	PageSize      uint32 `protobuf:"varint,3,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *SongId) Reset() {
-	*x = SongId{}
+func (x *ListSongsNotInEventRequest) Reset() {
+	*x = ListSongsNotInEventRequest{}
 	mi := &file_song_proto_msgTypes[2]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *SongId) String() string {
+func (x *ListSongsNotInEventRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SongId) ProtoMessage() {}
+func (*ListSongsNotInEventRequest) ProtoMessage() {}
 
-func (x *SongId) ProtoReflect() protoreflect.Message {
+func (x *ListSongsNotInEventRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_song_proto_msgTypes[2]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -221,50 +359,55 @@ func (x *SongId) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SongId.ProtoReflect.Descriptor instead.
-func (*SongId) Descriptor() ([]byte, []int) {
+// Deprecated: Use ListSongsNotInEventRequest.ProtoReflect.Descriptor instead.
+func (*ListSongsNotInEventRequest) Descriptor() ([]byte, []int) {
 	return file_song_proto_rawDescGZIP(), []int{2}
 }
 
-func (x *SongId) GetId() string {
+func (x *ListSongsNotInEventRequest) GetEventId() string {
 	if x != nil {
-		return x.Id
+		return x.EventId
 	}
 	return ""
 }
 
-type Song struct {
-	state          protoimpl.MessageState `protogen:"open.v1"`
-	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
-	Title          string                 `protobuf:"bytes,2,opt,name=title,proto3" json:"title,omitempty"`
-	Artist         string                 `protobuf:"bytes,3,opt,name=artist,proto3" json:"artist,omitempty"`
-	Link           *SongLink              `protobuf:"bytes,4,opt,name=link,proto3" json:"link,omitempty"`
-	Description    string                 `protobuf:"bytes,5,opt,name=description,proto3" json:"description,omitempty"`
-	AvailableRoles []string               `protobuf:"bytes,6,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
-	// Whether current user may edit this song.
-	EditableByMe bool `protobuf:"varint,7,opt,name=editable_by_me,json=editableByMe,proto3" json:"editable_by_me,omitempty"`
-	// Number of participants currently assigned to this song.
-	AssignmentCount int32 `protobuf:"varint,8,opt,name=assignment_count,json=assignmentCount,proto3" json:"assignment_count,omitempty"`
-	// Thumbnail image URL (auto-extracted from link or custom).
-	ThumbnailUrl  string `protobuf:"bytes,9,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
+func (x *ListSongsNotInEventRequest) GetPageToken() string {
+	if x != nil {
+		return x.PageToken
+	}
+	return ""
+}
+
+func (x *ListSongsNotInEventRequest) GetPageSize() uint32 {
+	if x != nil {
+		return x.PageSize
+	}
+	return 0
+}
+
+type ListSongsByVotesRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Pagination cursor (opaque to client).
+	PageToken     string `protobuf:"bytes,1,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
+	PageSize      uint32 `protobuf:"varint,2,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *Song) Reset() {
-	*x = Song{}
+func (x *ListSongsByVotesRequest) Reset() {
+	*x = ListSongsByVotesRequest{}
 	mi := &file_song_proto_msgTypes[3]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *Song) String() string {
+func (x *ListSongsByVotesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*Song) ProtoMessage() {}
+func (*ListSongsByVotesRequest) ProtoMessage() {}
 
-func (x *Song) ProtoReflect() protoreflect.Message {
+func (x *ListSongsByVotesRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_song_proto_msgTypes[3]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -276,98 +419,104 @@ func (x *Song) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use Song.ProtoReflect.Descriptor instead.
-func (*Song) Descriptor() ([]byte, []int) {
+// Deprecated: Use ListSongsByVotesRequest.ProtoReflect.Descriptor instead.
+func (*ListSongsByVotesRequest) Descriptor() ([]byte, []int) {
 	return file_song_proto_rawDescGZIP(), []int{3}
 }
 
-func (x *Song) GetId() string {
+func (x *ListSongsByVotesRequest) GetPageToken() string {
 	if x != nil {
-		return x.Id
+		return x.PageToken
 	}
 	return ""
 }
 
-func (x *Song) GetTitle() string {
+func (x *ListSongsByVotesRequest) GetPageSize() uint32 {
 	if x != nil {
-		return x.Title
+		return x.PageSize
 	}
-	return ""
+	return 0
 }
 
-func (x *Song) GetArtist() string {
-	if x != nil {
-		return x.Artist
-	}
-	return ""
+type GetSongRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	Id    string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// Song fields to return; empty returns every field. Assignments and
+	// permissions are always included.
+	ReadMask      *fieldmaskpb.FieldMask `protobuf:"bytes,2,opt,name=read_mask,json=readMask,proto3" json:"read_mask,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
-func (x *Song) GetLink() *SongLink {
-	if x != nil {
-		return x.Link
-	}
-	return nil
+func (x *GetSongRequest) Reset() {
+	*x = GetSongRequest{}
+	mi := &file_song_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
-func (x *Song) GetDescription() string {
-	if x != nil {
-		return x.Description
-	}
-	return ""
+func (x *GetSongRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *Song) GetAvailableRoles() []string {
+func (*GetSongRequest) ProtoMessage() {}
+
+func (x *GetSongRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[4]
 	if x != nil {
-		return x.AvailableRoles
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
+	return mi.MessageOf(x)
 }
 
-func (x *Song) GetEditableByMe() bool {
-	if x != nil {
-		return x.EditableByMe
-	}
-	return false
+// Deprecated: Use GetSongRequest.ProtoReflect.Descriptor instead.
+func (*GetSongRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{4}
 }
 
-func (x *Song) GetAssignmentCount() int32 {
+func (x *GetSongRequest) GetId() string {
 	if x != nil {
-		return x.AssignmentCount
+		return x.Id
 	}
-	return 0
+	return ""
 }
 
-func (x *Song) GetThumbnailUrl() string {
+func (x *GetSongRequest) GetReadMask() *fieldmaskpb.FieldMask {
 	if x != nil {
-		return x.ThumbnailUrl
+		return x.ReadMask
 	}
-	return ""
+	return nil
 }
 
-type SongDetails struct {
+type ListSongsResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
-	Song          *Song                  `protobuf:"bytes,1,opt,name=song,proto3" json:"song,omitempty"`
-	Assignments   []*RoleAssignment      `protobuf:"bytes,2,rep,name=assignments,proto3" json:"assignments,omitempty"`
-	Permissions   *PermissionSet         `protobuf:"bytes,3,opt,name=permissions,proto3" json:"permissions,omitempty"`
+	Songs         []*Song                `protobuf:"bytes,1,rep,name=songs,proto3" json:"songs,omitempty"`
+	NextPageToken string                 `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"`
+	// Whether more songs exist after this page.
+	HasMore       bool `protobuf:"varint,3,opt,name=has_more,json=hasMore,proto3" json:"has_more,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *SongDetails) Reset() {
-	*x = SongDetails{}
-	mi := &file_song_proto_msgTypes[4]
+func (x *ListSongsResponse) Reset() {
+	*x = ListSongsResponse{}
+	mi := &file_song_proto_msgTypes[5]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *SongDetails) String() string {
+func (x *ListSongsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SongDetails) ProtoMessage() {}
+func (*ListSongsResponse) ProtoMessage() {}
 
-func (x *SongDetails) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[4]
+func (x *ListSongsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[5]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -378,55 +527,1703 @@ func (x *SongDetails) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SongDetails.ProtoReflect.Descriptor instead.
-func (*SongDetails) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{4}
+// Deprecated: Use ListSongsResponse.ProtoReflect.Descriptor instead.
+func (*ListSongsResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{5}
 }
 
-func (x *SongDetails) GetSong() *Song {
+func (x *ListSongsResponse) GetSongs() []*Song {
 	if x != nil {
-		return x.Song
+		return x.Songs
 	}
 	return nil
 }
 
-func (x *SongDetails) GetAssignments() []*RoleAssignment {
+func (x *ListSongsResponse) GetNextPageToken() string {
 	if x != nil {
-		return x.Assignments
+		return x.NextPageToken
 	}
-	return nil
+	return ""
 }
 
-func (x *SongDetails) GetPermissions() *PermissionSet {
+func (x *ListSongsResponse) GetHasMore() bool {
 	if x != nil {
-		return x.Permissions
+		return x.HasMore
 	}
-	return nil
+	return false
 }
 
-type SongLink struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Kind          SongLinkType           `protobuf:"varint,1,opt,name=kind,proto3,enum=musicclub.song.SongLinkType" json:"kind,omitempty"`
-	Url           string                 `protobuf:"bytes,2,opt,name=url,proto3" json:"url,omitempty"`
+type ListRecentSongsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Number of songs to return (default 10, max 50).
+	Limit         uint32 `protobuf:"varint,1,opt,name=limit,proto3" json:"limit,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListRecentSongsRequest) Reset() {
+	*x = ListRecentSongsRequest{}
+	mi := &file_song_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListRecentSongsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRecentSongsRequest) ProtoMessage() {}
+
+func (x *ListRecentSongsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRecentSongsRequest.ProtoReflect.Descriptor instead.
+func (*ListRecentSongsRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *ListRecentSongsRequest) GetLimit() uint32 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type SongId struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Id            string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SongId) Reset() {
+	*x = SongId{}
+	mi := &file_song_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SongId) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SongId) ProtoMessage() {}
+
+func (x *SongId) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SongId.ProtoReflect.Descriptor instead.
+func (*SongId) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *SongId) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+type DeleteSongRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	Id    string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// Succeed instead of returning NOT_FOUND when the song is already gone.
+	AllowMissing  bool `protobuf:"varint,2,opt,name=allow_missing,json=allowMissing,proto3" json:"allow_missing,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *DeleteSongRequest) Reset() {
+	*x = DeleteSongRequest{}
+	mi := &file_song_proto_msgTypes[8]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *DeleteSongRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteSongRequest) ProtoMessage() {}
+
+func (x *DeleteSongRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[8]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteSongRequest.ProtoReflect.Descriptor instead.
+func (*DeleteSongRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *DeleteSongRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *DeleteSongRequest) GetAllowMissing() bool {
+	if x != nil {
+		return x.AllowMissing
+	}
+	return false
+}
+
+type Song struct {
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Title          string                 `protobuf:"bytes,2,opt,name=title,proto3" json:"title,omitempty"`
+	Artist         string                 `protobuf:"bytes,3,opt,name=artist,proto3" json:"artist,omitempty"`
+	Link           *SongLink              `protobuf:"bytes,4,opt,name=link,proto3" json:"link,omitempty"`
+	Description    string                 `protobuf:"bytes,5,opt,name=description,proto3" json:"description,omitempty"`
+	AvailableRoles []string               `protobuf:"bytes,6,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
+	// Whether current user may edit this song.
+	EditableByMe bool `protobuf:"varint,7,opt,name=editable_by_me,json=editableByMe,proto3" json:"editable_by_me,omitempty"`
+	// Number of participants currently assigned to this song.
+	AssignmentCount int32 `protobuf:"varint,8,opt,name=assignment_count,json=assignmentCount,proto3" json:"assignment_count,omitempty"`
+	// Thumbnail image URL (auto-extracted from link or custom).
+	ThumbnailUrl string `protobuf:"bytes,9,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
+	// ID of the user who added the song. Empty for legacy rows.
+	CreatedBy string `protobuf:"bytes,10,opt,name=created_by,json=createdBy,proto3" json:"created_by,omitempty"`
+	// Length of the song in seconds, 0 when unknown.
+	DurationSeconds int32 `protobuf:"varint,11,opt,name=duration_seconds,json=durationSeconds,proto3" json:"duration_seconds,omitempty"`
+	// Number of members who upvoted the song.
+	VoteCount int32 `protobuf:"varint,12,opt,name=vote_count,json=voteCount,proto3" json:"vote_count,omitempty"`
+	// Private organizer notes. Only returned to admins.
+	Notes         string `protobuf:"bytes,13,opt,name=notes,proto3" json:"notes,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *Song) Reset() {
+	*x = Song{}
+	mi := &file_song_proto_msgTypes[9]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Song) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Song) ProtoMessage() {}
+
+func (x *Song) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[9]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Song.ProtoReflect.Descriptor instead.
+func (*Song) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *Song) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *Song) GetTitle() string {
+	if x != nil {
+		return x.Title
+	}
+	return ""
+}
+
+func (x *Song) GetArtist() string {
+	if x != nil {
+		return x.Artist
+	}
+	return ""
+}
+
+func (x *Song) GetLink() *SongLink {
+	if x != nil {
+		return x.Link
+	}
+	return nil
+}
+
+func (x *Song) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *Song) GetAvailableRoles() []string {
+	if x != nil {
+		return x.AvailableRoles
+	}
+	return nil
+}
+
+func (x *Song) GetEditableByMe() bool {
+	if x != nil {
+		return x.EditableByMe
+	}
+	return false
+}
+
+func (x *Song) GetAssignmentCount() int32 {
+	if x != nil {
+		return x.AssignmentCount
+	}
+	return 0
+}
+
+func (x *Song) GetThumbnailUrl() string {
+	if x != nil {
+		return x.ThumbnailUrl
+	}
+	return ""
+}
+
+func (x *Song) GetCreatedBy() string {
+	if x != nil {
+		return x.CreatedBy
+	}
+	return ""
+}
+
+func (x *Song) GetDurationSeconds() int32 {
+	if x != nil {
+		return x.DurationSeconds
+	}
+	return 0
+}
+
+func (x *Song) GetVoteCount() int32 {
+	if x != nil {
+		return x.VoteCount
+	}
+	return 0
+}
+
+func (x *Song) GetNotes() string {
+	if x != nil {
+		return x.Notes
+	}
+	return ""
+}
+
+type SongDetails struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Song          *Song                  `protobuf:"bytes,1,opt,name=song,proto3" json:"song,omitempty"`
+	Assignments   []*RoleAssignment      `protobuf:"bytes,2,rep,name=assignments,proto3" json:"assignments,omitempty"`
+	Permissions   *PermissionSet         `protobuf:"bytes,3,opt,name=permissions,proto3" json:"permissions,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SongDetails) Reset() {
+	*x = SongDetails{}
+	mi := &file_song_proto_msgTypes[10]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SongDetails) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SongDetails) ProtoMessage() {}
+
+func (x *SongDetails) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[10]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SongDetails.ProtoReflect.Descriptor instead.
+func (*SongDetails) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *SongDetails) GetSong() *Song {
+	if x != nil {
+		return x.Song
+	}
+	return nil
+}
+
+func (x *SongDetails) GetAssignments() []*RoleAssignment {
+	if x != nil {
+		return x.Assignments
+	}
+	return nil
+}
+
+func (x *SongDetails) GetPermissions() *PermissionSet {
+	if x != nil {
+		return x.Permissions
+	}
+	return nil
+}
+
+type SongLink struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Kind          SongLinkType           `protobuf:"varint,1,opt,name=kind,proto3,enum=musicclub.song.SongLinkType" json:"kind,omitempty"`
+	Url           string                 `protobuf:"bytes,2,opt,name=url,proto3" json:"url,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SongLink) Reset() {
+	*x = SongLink{}
+	mi := &file_song_proto_msgTypes[11]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SongLink) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SongLink) ProtoMessage() {}
+
+func (x *SongLink) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[11]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SongLink.ProtoReflect.Descriptor instead.
+func (*SongLink) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *SongLink) GetKind() SongLinkType {
+	if x != nil {
+		return x.Kind
+	}
+	return SongLinkType_SONG_LINK_TYPE_UNKNOWN
+}
+
+func (x *SongLink) GetUrl() string {
+	if x != nil {
+		return x.Url
+	}
+	return ""
+}
+
+type RoleAssignment struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Role          string                 `protobuf:"bytes,1,opt,name=role,proto3" json:"role,omitempty"`
+	User          *User                  `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
+	JoinedAt      *timestamppb.Timestamp `protobuf:"bytes,3,opt,name=joined_at,json=joinedAt,proto3" json:"joined_at,omitempty"`
+	Status        ParticipationStatus    `protobuf:"varint,4,opt,name=status,proto3,enum=musicclub.song.ParticipationStatus" json:"status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RoleAssignment) Reset() {
+	*x = RoleAssignment{}
+	mi := &file_song_proto_msgTypes[12]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RoleAssignment) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RoleAssignment) ProtoMessage() {}
+
+func (x *RoleAssignment) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[12]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RoleAssignment.ProtoReflect.Descriptor instead.
+func (*RoleAssignment) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{12}
+}
+
+func (x *RoleAssignment) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *RoleAssignment) GetUser() *User {
+	if x != nil {
+		return x.User
+	}
+	return nil
+}
+
+func (x *RoleAssignment) GetJoinedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.JoinedAt
+	}
+	return nil
+}
+
+func (x *RoleAssignment) GetStatus() ParticipationStatus {
+	if x != nil {
+		return x.Status
+	}
+	return ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+}
+
+type CreateSongRequest struct {
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Title          string                 `protobuf:"bytes,1,opt,name=title,proto3" json:"title,omitempty"`
+	Artist         string                 `protobuf:"bytes,2,opt,name=artist,proto3" json:"artist,omitempty"`
+	Link           *SongLink              `protobuf:"bytes,3,opt,name=link,proto3" json:"link,omitempty"`
+	Description    string                 `protobuf:"bytes,4,opt,name=description,proto3" json:"description,omitempty"`
+	AvailableRoles []string               `protobuf:"bytes,5,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
+	ThumbnailUrl   string                 `protobuf:"bytes,6,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
+	// 0 leaves the duration unknown; negative values are rejected.
+	DurationSeconds int32 `protobuf:"varint,7,opt,name=duration_seconds,json=durationSeconds,proto3" json:"duration_seconds,omitempty"`
+	// Admins only.
+	Notes         string `protobuf:"bytes,8,opt,name=notes,proto3" json:"notes,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *CreateSongRequest) Reset() {
+	*x = CreateSongRequest{}
+	mi := &file_song_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *CreateSongRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateSongRequest) ProtoMessage() {}
+
+func (x *CreateSongRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateSongRequest.ProtoReflect.Descriptor instead.
+func (*CreateSongRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{13}
+}
+
+func (x *CreateSongRequest) GetTitle() string {
+	if x != nil {
+		return x.Title
+	}
+	return ""
+}
+
+func (x *CreateSongRequest) GetArtist() string {
+	if x != nil {
+		return x.Artist
+	}
+	return ""
+}
+
+func (x *CreateSongRequest) GetLink() *SongLink {
+	if x != nil {
+		return x.Link
+	}
+	return nil
+}
+
+func (x *CreateSongRequest) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *CreateSongRequest) GetAvailableRoles() []string {
+	if x != nil {
+		return x.AvailableRoles
+	}
+	return nil
+}
+
+func (x *CreateSongRequest) GetThumbnailUrl() string {
+	if x != nil {
+		return x.ThumbnailUrl
+	}
+	return ""
+}
+
+func (x *CreateSongRequest) GetDurationSeconds() int32 {
+	if x != nil {
+		return x.DurationSeconds
+	}
+	return 0
+}
+
+func (x *CreateSongRequest) GetNotes() string {
+	if x != nil {
+		return x.Notes
+	}
+	return ""
+}
+
+type FieldViolation struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Field         string                 `protobuf:"bytes,1,opt,name=field,proto3" json:"field,omitempty"`
+	Description   string                 `protobuf:"bytes,2,opt,name=description,proto3" json:"description,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *FieldViolation) Reset() {
+	*x = FieldViolation{}
+	mi := &file_song_proto_msgTypes[14]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *FieldViolation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*FieldViolation) ProtoMessage() {}
+
+func (x *FieldViolation) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[14]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use FieldViolation.ProtoReflect.Descriptor instead.
+func (*FieldViolation) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{14}
+}
+
+func (x *FieldViolation) GetField() string {
+	if x != nil {
+		return x.Field
+	}
+	return ""
+}
+
+func (x *FieldViolation) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+type ValidateSongResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Empty when the song could be created as is.
+	Violations    []*FieldViolation `protobuf:"bytes,1,rep,name=violations,proto3" json:"violations,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ValidateSongResponse) Reset() {
+	*x = ValidateSongResponse{}
+	mi := &file_song_proto_msgTypes[15]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ValidateSongResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ValidateSongResponse) ProtoMessage() {}
+
+func (x *ValidateSongResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[15]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ValidateSongResponse.ProtoReflect.Descriptor instead.
+func (*ValidateSongResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{15}
+}
+
+func (x *ValidateSongResponse) GetViolations() []*FieldViolation {
+	if x != nil {
+		return x.Violations
+	}
+	return nil
+}
+
+type UpdateSongRequest struct {
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Title          string                 `protobuf:"bytes,2,opt,name=title,proto3" json:"title,omitempty"`
+	Artist         string                 `protobuf:"bytes,3,opt,name=artist,proto3" json:"artist,omitempty"`
+	Link           *SongLink              `protobuf:"bytes,4,opt,name=link,proto3" json:"link,omitempty"`
+	Description    string                 `protobuf:"bytes,5,opt,name=description,proto3" json:"description,omitempty"`
+	AvailableRoles []string               `protobuf:"bytes,6,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
+	ThumbnailUrl   string                 `protobuf:"bytes,7,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
+	// 0 clears the duration; negative values are rejected.
+	DurationSeconds int32 `protobuf:"varint,8,opt,name=duration_seconds,json=durationSeconds,proto3" json:"duration_seconds,omitempty"`
+	// Admins only; replaces the notes when update_mask lists "notes".
+	Notes string `protobuf:"bytes,9,opt,name=notes,proto3" json:"notes,omitempty"`
+	// Optional fields to overwrite. Only "notes" is recognised; notes are left untouched unless listed.
+	UpdateMask    *fieldmaskpb.FieldMask `protobuf:"bytes,10,opt,name=update_mask,json=updateMask,proto3" json:"update_mask,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *UpdateSongRequest) Reset() {
+	*x = UpdateSongRequest{}
+	mi := &file_song_proto_msgTypes[16]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *UpdateSongRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateSongRequest) ProtoMessage() {}
+
+func (x *UpdateSongRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[16]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateSongRequest.ProtoReflect.Descriptor instead.
+func (*UpdateSongRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{16}
+}
+
+func (x *UpdateSongRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetTitle() string {
+	if x != nil {
+		return x.Title
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetArtist() string {
+	if x != nil {
+		return x.Artist
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetLink() *SongLink {
+	if x != nil {
+		return x.Link
+	}
+	return nil
+}
+
+func (x *UpdateSongRequest) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetAvailableRoles() []string {
+	if x != nil {
+		return x.AvailableRoles
+	}
+	return nil
+}
+
+func (x *UpdateSongRequest) GetThumbnailUrl() string {
+	if x != nil {
+		return x.ThumbnailUrl
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetDurationSeconds() int32 {
+	if x != nil {
+		return x.DurationSeconds
+	}
+	return 0
+}
+
+func (x *UpdateSongRequest) GetNotes() string {
+	if x != nil {
+		return x.Notes
+	}
+	return ""
+}
+
+func (x *UpdateSongRequest) GetUpdateMask() *fieldmaskpb.FieldMask {
+	if x != nil {
+		return x.UpdateMask
+	}
+	return nil
+}
+
+type JoinRoleRequest struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	SongId string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Role   string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+	// Defaults to CONFIRMED. Joining a role already held updates its status.
+	Status        ParticipationStatus `protobuf:"varint,3,opt,name=status,proto3,enum=musicclub.song.ParticipationStatus" json:"status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *JoinRoleRequest) Reset() {
+	*x = JoinRoleRequest{}
+	mi := &file_song_proto_msgTypes[17]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *JoinRoleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*JoinRoleRequest) ProtoMessage() {}
+
+func (x *JoinRoleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[17]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use JoinRoleRequest.ProtoReflect.Descriptor instead.
+func (*JoinRoleRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{17}
+}
+
+func (x *JoinRoleRequest) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *JoinRoleRequest) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *JoinRoleRequest) GetStatus() ParticipationStatus {
+	if x != nil {
+		return x.Status
+	}
+	return ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+}
+
+type LeaveRoleRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Role          string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *LeaveRoleRequest) Reset() {
+	*x = LeaveRoleRequest{}
+	mi := &file_song_proto_msgTypes[18]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *LeaveRoleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LeaveRoleRequest) ProtoMessage() {}
+
+func (x *LeaveRoleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[18]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LeaveRoleRequest.ProtoReflect.Descriptor instead.
+func (*LeaveRoleRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{18}
+}
+
+func (x *LeaveRoleRequest) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *LeaveRoleRequest) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+// Identifies a single existing role assignment.
+type RoleAssignmentKey struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Role          string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+	UserId        string                 `protobuf:"bytes,3,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RoleAssignmentKey) Reset() {
+	*x = RoleAssignmentKey{}
+	mi := &file_song_proto_msgTypes[19]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RoleAssignmentKey) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RoleAssignmentKey) ProtoMessage() {}
+
+func (x *RoleAssignmentKey) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[19]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RoleAssignmentKey.ProtoReflect.Descriptor instead.
+func (*RoleAssignmentKey) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{19}
+}
+
+func (x *RoleAssignmentKey) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *RoleAssignmentKey) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *RoleAssignmentKey) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+type UpdateRoleRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Assignment to update.
+	Key *RoleAssignmentKey `protobuf:"bytes,1,opt,name=key,proto3" json:"key,omitempty"`
+	// Role the assignment is moved to.
+	NewRole string `protobuf:"bytes,2,opt,name=new_role,json=newRole,proto3" json:"new_role,omitempty"`
+	// New availability; UNSPECIFIED keeps the current one.
+	NewStatus     ParticipationStatus `protobuf:"varint,3,opt,name=new_status,json=newStatus,proto3,enum=musicclub.song.ParticipationStatus" json:"new_status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *UpdateRoleRequest) Reset() {
+	*x = UpdateRoleRequest{}
+	mi := &file_song_proto_msgTypes[20]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *UpdateRoleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateRoleRequest) ProtoMessage() {}
+
+func (x *UpdateRoleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[20]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateRoleRequest.ProtoReflect.Descriptor instead.
+func (*UpdateRoleRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{20}
+}
+
+func (x *UpdateRoleRequest) GetKey() *RoleAssignmentKey {
+	if x != nil {
+		return x.Key
+	}
+	return nil
+}
+
+func (x *UpdateRoleRequest) GetNewRole() string {
+	if x != nil {
+		return x.NewRole
+	}
+	return ""
+}
+
+func (x *UpdateRoleRequest) GetNewStatus() ParticipationStatus {
+	if x != nil {
+		return x.NewStatus
+	}
+	return ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+}
+
+type ListParticipationsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Optional filter by participant.
+	UserId string `protobuf:"bytes,1,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	// Optional filter by song.
+	SongId string `protobuf:"bytes,2,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	// Optional case-insensitive filter by role, e.g. "drums".
+	Role string `protobuf:"bytes,3,opt,name=role,proto3" json:"role,omitempty"`
+	// Pagination cursor (opaque to client).
+	PageToken string `protobuf:"bytes,4,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
+	PageSize  uint32 `protobuf:"varint,5,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
+	// Optional filter by availability.
+	Status        ParticipationStatus `protobuf:"varint,6,opt,name=status,proto3,enum=musicclub.song.ParticipationStatus" json:"status,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListParticipationsRequest) Reset() {
+	*x = ListParticipationsRequest{}
+	mi := &file_song_proto_msgTypes[21]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListParticipationsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListParticipationsRequest) ProtoMessage() {}
+
+func (x *ListParticipationsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[21]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListParticipationsRequest.ProtoReflect.Descriptor instead.
+func (*ListParticipationsRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{21}
+}
+
+func (x *ListParticipationsRequest) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+func (x *ListParticipationsRequest) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *ListParticipationsRequest) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *ListParticipationsRequest) GetPageToken() string {
+	if x != nil {
+		return x.PageToken
+	}
+	return ""
+}
+
+func (x *ListParticipationsRequest) GetPageSize() uint32 {
+	if x != nil {
+		return x.PageSize
+	}
+	return 0
+}
+
+func (x *ListParticipationsRequest) GetStatus() ParticipationStatus {
+	if x != nil {
+		return x.Status
+	}
+	return ParticipationStatus_PARTICIPATION_STATUS_UNSPECIFIED
+}
+
+type Participation struct {
+	state      protoimpl.MessageState `protogen:"open.v1"`
+	SongId     string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	SongTitle  string                 `protobuf:"bytes,2,opt,name=song_title,json=songTitle,proto3" json:"song_title,omitempty"`
+	SongArtist string                 `protobuf:"bytes,3,opt,name=song_artist,json=songArtist,proto3" json:"song_artist,omitempty"`
+	Assignment *RoleAssignment        `protobuf:"bytes,4,opt,name=assignment,proto3" json:"assignment,omitempty"`
+	// When the assignment was created or last moved to another role.
+	UpdatedAt     *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=updated_at,json=updatedAt,proto3" json:"updated_at,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *Participation) Reset() {
+	*x = Participation{}
+	mi := &file_song_proto_msgTypes[22]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Participation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Participation) ProtoMessage() {}
+
+func (x *Participation) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[22]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Participation.ProtoReflect.Descriptor instead.
+func (*Participation) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{22}
+}
+
+func (x *Participation) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *Participation) GetSongTitle() string {
+	if x != nil {
+		return x.SongTitle
+	}
+	return ""
+}
+
+func (x *Participation) GetSongArtist() string {
+	if x != nil {
+		return x.SongArtist
+	}
+	return ""
+}
+
+func (x *Participation) GetAssignment() *RoleAssignment {
+	if x != nil {
+		return x.Assignment
+	}
+	return nil
+}
+
+func (x *Participation) GetUpdatedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.UpdatedAt
+	}
+	return nil
+}
+
+type ListParticipationsResponse struct {
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Participations []*Participation       `protobuf:"bytes,1,rep,name=participations,proto3" json:"participations,omitempty"`
+	NextPageToken  string                 `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"`
+	// Whether more assignments exist after this page.
+	HasMore       bool `protobuf:"varint,3,opt,name=has_more,json=hasMore,proto3" json:"has_more,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListParticipationsResponse) Reset() {
+	*x = ListParticipationsResponse{}
+	mi := &file_song_proto_msgTypes[23]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListParticipationsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListParticipationsResponse) ProtoMessage() {}
+
+func (x *ListParticipationsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[23]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListParticipationsResponse.ProtoReflect.Descriptor instead.
+func (*ListParticipationsResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{23}
+}
+
+func (x *ListParticipationsResponse) GetParticipations() []*Participation {
+	if x != nil {
+		return x.Participations
+	}
+	return nil
+}
+
+func (x *ListParticipationsResponse) GetNextPageToken() string {
+	if x != nil {
+		return x.NextPageToken
+	}
+	return ""
+}
+
+func (x *ListParticipationsResponse) GetHasMore() bool {
+	if x != nil {
+		return x.HasMore
+	}
+	return false
+}
+
+type ListRecentParticipationsRequest struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	UserId string                 `protobuf:"bytes,1,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	// Number of assignments to return (default 10, max 50).
+	Limit         uint32 `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListRecentParticipationsRequest) Reset() {
+	*x = ListRecentParticipationsRequest{}
+	mi := &file_song_proto_msgTypes[24]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListRecentParticipationsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRecentParticipationsRequest) ProtoMessage() {}
+
+func (x *ListRecentParticipationsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[24]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRecentParticipationsRequest.ProtoReflect.Descriptor instead.
+func (*ListRecentParticipationsRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{24}
+}
+
+func (x *ListRecentParticipationsRequest) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+func (x *ListRecentParticipationsRequest) GetLimit() uint32 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type ListParticipationsSinceRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Only assignments with updated_at strictly after this are returned.
+	Since         *timestamppb.Timestamp `protobuf:"bytes,1,opt,name=since,proto3" json:"since,omitempty"`
+	PageToken     string                 `protobuf:"bytes,2,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"`
+	PageSize      uint32                 `protobuf:"varint,3,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListParticipationsSinceRequest) Reset() {
+	*x = ListParticipationsSinceRequest{}
+	mi := &file_song_proto_msgTypes[25]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListParticipationsSinceRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListParticipationsSinceRequest) ProtoMessage() {}
+
+func (x *ListParticipationsSinceRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[25]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListParticipationsSinceRequest.ProtoReflect.Descriptor instead.
+func (*ListParticipationsSinceRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{25}
+}
+
+func (x *ListParticipationsSinceRequest) GetSince() *timestamppb.Timestamp {
+	if x != nil {
+		return x.Since
+	}
+	return nil
+}
+
+func (x *ListParticipationsSinceRequest) GetPageToken() string {
+	if x != nil {
+		return x.PageToken
+	}
+	return ""
+}
+
+func (x *ListParticipationsSinceRequest) GetPageSize() uint32 {
+	if x != nil {
+		return x.PageSize
+	}
+	return 0
+}
+
+type ListParticipationsSinceResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Ordered by updated_at.
+	Participations []*Participation `protobuf:"bytes,1,rep,name=participations,proto3" json:"participations,omitempty"`
+	NextPageToken  string           `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"`
+	// Whether more changes exist after this page.
+	HasMore bool `protobuf:"varint,3,opt,name=has_more,json=hasMore,proto3" json:"has_more,omitempty"`
+	// Assignments removed after the watermark, ordered by removed_at. A page
+	// holds the oldest page_size changes of either kind. A removal only cancels
+	// a participation with the same key that was updated before it.
+	Removals      []*RemovedParticipation `protobuf:"bytes,4,rep,name=removals,proto3" json:"removals,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListParticipationsSinceResponse) Reset() {
+	*x = ListParticipationsSinceResponse{}
+	mi := &file_song_proto_msgTypes[26]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListParticipationsSinceResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListParticipationsSinceResponse) ProtoMessage() {}
+
+func (x *ListParticipationsSinceResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[26]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListParticipationsSinceResponse.ProtoReflect.Descriptor instead.
+func (*ListParticipationsSinceResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{26}
+}
+
+func (x *ListParticipationsSinceResponse) GetParticipations() []*Participation {
+	if x != nil {
+		return x.Participations
+	}
+	return nil
+}
+
+func (x *ListParticipationsSinceResponse) GetNextPageToken() string {
+	if x != nil {
+		return x.NextPageToken
+	}
+	return ""
+}
+
+func (x *ListParticipationsSinceResponse) GetHasMore() bool {
+	if x != nil {
+		return x.HasMore
+	}
+	return false
+}
+
+func (x *ListParticipationsSinceResponse) GetRemovals() []*RemovedParticipation {
+	if x != nil {
+		return x.Removals
+	}
+	return nil
+}
+
+// Tombstone of a role assignment that no longer exists.
+type RemovedParticipation struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Role          string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+	UserId        string                 `protobuf:"bytes,3,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	RemovedAt     *timestamppb.Timestamp `protobuf:"bytes,4,opt,name=removed_at,json=removedAt,proto3" json:"removed_at,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemovedParticipation) Reset() {
+	*x = RemovedParticipation{}
+	mi := &file_song_proto_msgTypes[27]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemovedParticipation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemovedParticipation) ProtoMessage() {}
+
+func (x *RemovedParticipation) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[27]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemovedParticipation.ProtoReflect.Descriptor instead.
+func (*RemovedParticipation) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{27}
+}
+
+func (x *RemovedParticipation) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *RemovedParticipation) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *RemovedParticipation) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+func (x *RemovedParticipation) GetRemovedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.RemovedAt
+	}
+	return nil
+}
+
+type ReplaceParticipantsRequest struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	SongId string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	// Complete desired set of assignments; anything else is removed.
+	Participants  []*ParticipantSlot `protobuf:"bytes,2,rep,name=participants,proto3" json:"participants,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReplaceParticipantsRequest) Reset() {
+	*x = ReplaceParticipantsRequest{}
+	mi := &file_song_proto_msgTypes[28]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReplaceParticipantsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReplaceParticipantsRequest) ProtoMessage() {}
+
+func (x *ReplaceParticipantsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[28]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReplaceParticipantsRequest.ProtoReflect.Descriptor instead.
+func (*ReplaceParticipantsRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{28}
+}
+
+func (x *ReplaceParticipantsRequest) GetSongId() string {
+	if x != nil {
+		return x.SongId
+	}
+	return ""
+}
+
+func (x *ReplaceParticipantsRequest) GetParticipants() []*ParticipantSlot {
+	if x != nil {
+		return x.Participants
+	}
+	return nil
+}
+
+type ParticipantSlot struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Role          string                 `protobuf:"bytes,1,opt,name=role,proto3" json:"role,omitempty"`
+	UserId        string                 `protobuf:"bytes,2,opt,name=user_id,json=userId,proto3" json:"user_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ParticipantSlot) Reset() {
+	*x = ParticipantSlot{}
+	mi := &file_song_proto_msgTypes[29]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ParticipantSlot) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ParticipantSlot) ProtoMessage() {}
+
+func (x *ParticipantSlot) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[29]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ParticipantSlot.ProtoReflect.Descriptor instead.
+func (*ParticipantSlot) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{29}
+}
+
+func (x *ParticipantSlot) GetRole() string {
+	if x != nil {
+		return x.Role
+	}
+	return ""
+}
+
+func (x *ParticipantSlot) GetUserId() string {
+	if x != nil {
+		return x.UserId
+	}
+	return ""
+}
+
+type CountParticipationsByRoleRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *CountParticipationsByRoleRequest) Reset() {
+	*x = CountParticipationsByRoleRequest{}
+	mi := &file_song_proto_msgTypes[30]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *CountParticipationsByRoleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CountParticipationsByRoleRequest) ProtoMessage() {}
+
+func (x *CountParticipationsByRoleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[30]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CountParticipationsByRoleRequest.ProtoReflect.Descriptor instead.
+func (*CountParticipationsByRoleRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{30}
+}
+
+type RoleCount struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Role          string                 `protobuf:"bytes,1,opt,name=role,proto3" json:"role,omitempty"`
+	Count         uint32                 `protobuf:"varint,2,opt,name=count,proto3" json:"count,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *SongLink) Reset() {
-	*x = SongLink{}
-	mi := &file_song_proto_msgTypes[5]
+func (x *RoleCount) Reset() {
+	*x = RoleCount{}
+	mi := &file_song_proto_msgTypes[31]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *SongLink) String() string {
+func (x *RoleCount) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SongLink) ProtoMessage() {}
+func (*RoleCount) ProtoMessage() {}
 
-func (x *SongLink) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[5]
+func (x *RoleCount) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[31]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -437,49 +2234,47 @@ func (x *SongLink) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SongLink.ProtoReflect.Descriptor instead.
-func (*SongLink) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{5}
+// Deprecated: Use RoleCount.ProtoReflect.Descriptor instead.
+func (*RoleCount) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{31}
 }
 
-func (x *SongLink) GetKind() SongLinkType {
+func (x *RoleCount) GetRole() string {
 	if x != nil {
-		return x.Kind
+		return x.Role
 	}
-	return SongLinkType_SONG_LINK_TYPE_UNKNOWN
+	return ""
 }
 
-func (x *SongLink) GetUrl() string {
+func (x *RoleCount) GetCount() uint32 {
 	if x != nil {
-		return x.Url
+		return x.Count
 	}
-	return ""
+	return 0
 }
 
-type RoleAssignment struct {
+type CountParticipationsByRoleResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
-	Role          string                 `protobuf:"bytes,1,opt,name=role,proto3" json:"role,omitempty"`
-	User          *User                  `protobuf:"bytes,2,opt,name=user,proto3" json:"user,omitempty"`
-	JoinedAt      *timestamppb.Timestamp `protobuf:"bytes,3,opt,name=joined_at,json=joinedAt,proto3" json:"joined_at,omitempty"`
+	Counts        []*RoleCount           `protobuf:"bytes,1,rep,name=counts,proto3" json:"counts,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *RoleAssignment) Reset() {
-	*x = RoleAssignment{}
-	mi := &file_song_proto_msgTypes[6]
+func (x *CountParticipationsByRoleResponse) Reset() {
+	*x = CountParticipationsByRoleResponse{}
+	mi := &file_song_proto_msgTypes[32]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *RoleAssignment) String() string {
+func (x *CountParticipationsByRoleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*RoleAssignment) ProtoMessage() {}
+func (*CountParticipationsByRoleResponse) ProtoMessage() {}
 
-func (x *RoleAssignment) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[6]
+func (x *CountParticipationsByRoleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[32]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -490,59 +2285,42 @@ func (x *RoleAssignment) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use RoleAssignment.ProtoReflect.Descriptor instead.
-func (*RoleAssignment) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{6}
-}
-
-func (x *RoleAssignment) GetRole() string {
-	if x != nil {
-		return x.Role
-	}
-	return ""
-}
-
-func (x *RoleAssignment) GetUser() *User {
-	if x != nil {
-		return x.User
-	}
-	return nil
+// Deprecated: Use CountParticipationsByRoleResponse.ProtoReflect.Descriptor instead.
+func (*CountParticipationsByRoleResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{32}
 }
 
-func (x *RoleAssignment) GetJoinedAt() *timestamppb.Timestamp {
+func (x *CountParticipationsByRoleResponse) GetCounts() []*RoleCount {
 	if x != nil {
-		return x.JoinedAt
+		return x.Counts
 	}
 	return nil
 }
 
-type CreateSongRequest struct {
-	state          protoimpl.MessageState `protogen:"open.v1"`
-	Title          string                 `protobuf:"bytes,1,opt,name=title,proto3" json:"title,omitempty"`
-	Artist         string                 `protobuf:"bytes,2,opt,name=artist,proto3" json:"artist,omitempty"`
-	Link           *SongLink              `protobuf:"bytes,3,opt,name=link,proto3" json:"link,omitempty"`
-	Description    string                 `protobuf:"bytes,4,opt,name=description,proto3" json:"description,omitempty"`
-	AvailableRoles []string               `protobuf:"bytes,5,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
-	ThumbnailUrl   string                 `protobuf:"bytes,6,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
-	unknownFields  protoimpl.UnknownFields
-	sizeCache      protoimpl.SizeCache
+type SongTagRequest struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	SongId string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	// Normalized to lowercase.
+	Tag           string `protobuf:"bytes,2,opt,name=tag,proto3" json:"tag,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
-func (x *CreateSongRequest) Reset() {
-	*x = CreateSongRequest{}
-	mi := &file_song_proto_msgTypes[7]
+func (x *SongTagRequest) Reset() {
+	*x = SongTagRequest{}
+	mi := &file_song_proto_msgTypes[33]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *CreateSongRequest) String() string {
+func (x *SongTagRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateSongRequest) ProtoMessage() {}
+func (*SongTagRequest) ProtoMessage() {}
 
-func (x *CreateSongRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[7]
+func (x *SongTagRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[33]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -553,81 +2331,100 @@ func (x *CreateSongRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateSongRequest.ProtoReflect.Descriptor instead.
-func (*CreateSongRequest) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{7}
+// Deprecated: Use SongTagRequest.ProtoReflect.Descriptor instead.
+func (*SongTagRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{33}
 }
 
-func (x *CreateSongRequest) GetTitle() string {
+func (x *SongTagRequest) GetSongId() string {
 	if x != nil {
-		return x.Title
+		return x.SongId
 	}
 	return ""
 }
 
-func (x *CreateSongRequest) GetArtist() string {
+func (x *SongTagRequest) GetTag() string {
 	if x != nil {
-		return x.Artist
+		return x.Tag
 	}
 	return ""
 }
 
-func (x *CreateSongRequest) GetLink() *SongLink {
-	if x != nil {
-		return x.Link
-	}
-	return nil
+type SongTags struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	Tags          []string               `protobuf:"bytes,2,rep,name=tags,proto3" json:"tags,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
-func (x *CreateSongRequest) GetDescription() string {
+func (x *SongTags) Reset() {
+	*x = SongTags{}
+	mi := &file_song_proto_msgTypes[34]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SongTags) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SongTags) ProtoMessage() {}
+
+func (x *SongTags) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[34]
 	if x != nil {
-		return x.Description
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return ""
+	return mi.MessageOf(x)
 }
 
-func (x *CreateSongRequest) GetAvailableRoles() []string {
+// Deprecated: Use SongTags.ProtoReflect.Descriptor instead.
+func (*SongTags) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{34}
+}
+
+func (x *SongTags) GetSongId() string {
 	if x != nil {
-		return x.AvailableRoles
+		return x.SongId
 	}
-	return nil
+	return ""
 }
 
-func (x *CreateSongRequest) GetThumbnailUrl() string {
+func (x *SongTags) GetTags() []string {
 	if x != nil {
-		return x.ThumbnailUrl
+		return x.Tags
 	}
-	return ""
+	return nil
 }
 
-type UpdateSongRequest struct {
-	state          protoimpl.MessageState `protogen:"open.v1"`
-	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
-	Title          string                 `protobuf:"bytes,2,opt,name=title,proto3" json:"title,omitempty"`
-	Artist         string                 `protobuf:"bytes,3,opt,name=artist,proto3" json:"artist,omitempty"`
-	Link           *SongLink              `protobuf:"bytes,4,opt,name=link,proto3" json:"link,omitempty"`
-	Description    string                 `protobuf:"bytes,5,opt,name=description,proto3" json:"description,omitempty"`
-	AvailableRoles []string               `protobuf:"bytes,6,rep,name=available_roles,json=availableRoles,proto3" json:"available_roles,omitempty"`
-	ThumbnailUrl   string                 `protobuf:"bytes,7,opt,name=thumbnail_url,json=thumbnailUrl,proto3" json:"thumbnail_url,omitempty"`
-	unknownFields  protoimpl.UnknownFields
-	sizeCache      protoimpl.SizeCache
+type BatchTagSongsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Tag           string                 `protobuf:"bytes,1,opt,name=tag,proto3" json:"tag,omitempty"`
+	SongIds       []string               `protobuf:"bytes,2,rep,name=song_ids,json=songIds,proto3" json:"song_ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
-func (x *UpdateSongRequest) Reset() {
-	*x = UpdateSongRequest{}
-	mi := &file_song_proto_msgTypes[8]
+func (x *BatchTagSongsRequest) Reset() {
+	*x = BatchTagSongsRequest{}
+	mi := &file_song_proto_msgTypes[35]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *UpdateSongRequest) String() string {
+func (x *BatchTagSongsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UpdateSongRequest) ProtoMessage() {}
+func (*BatchTagSongsRequest) ProtoMessage() {}
 
-func (x *UpdateSongRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[8]
+func (x *BatchTagSongsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[35]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -638,83 +2435,101 @@ func (x *UpdateSongRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UpdateSongRequest.ProtoReflect.Descriptor instead.
-func (*UpdateSongRequest) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{8}
+// Deprecated: Use BatchTagSongsRequest.ProtoReflect.Descriptor instead.
+func (*BatchTagSongsRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{35}
 }
 
-func (x *UpdateSongRequest) GetId() string {
+func (x *BatchTagSongsRequest) GetTag() string {
 	if x != nil {
-		return x.Id
+		return x.Tag
 	}
 	return ""
 }
 
-func (x *UpdateSongRequest) GetTitle() string {
+func (x *BatchTagSongsRequest) GetSongIds() []string {
 	if x != nil {
-		return x.Title
+		return x.SongIds
 	}
-	return ""
+	return nil
 }
 
-func (x *UpdateSongRequest) GetArtist() string {
-	if x != nil {
-		return x.Artist
-	}
-	return ""
+type BatchTagSongsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Normalized tag that was applied.
+	Tag string `protobuf:"bytes,1,opt,name=tag,proto3" json:"tag,omitempty"`
+	// Songs that did not have the tag before this call.
+	TaggedCount   int32 `protobuf:"varint,2,opt,name=tagged_count,json=taggedCount,proto3" json:"tagged_count,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
-func (x *UpdateSongRequest) GetLink() *SongLink {
-	if x != nil {
-		return x.Link
-	}
-	return nil
+func (x *BatchTagSongsResponse) Reset() {
+	*x = BatchTagSongsResponse{}
+	mi := &file_song_proto_msgTypes[36]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
-func (x *UpdateSongRequest) GetDescription() string {
+func (x *BatchTagSongsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchTagSongsResponse) ProtoMessage() {}
+
+func (x *BatchTagSongsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[36]
 	if x != nil {
-		return x.Description
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return ""
+	return mi.MessageOf(x)
 }
 
-func (x *UpdateSongRequest) GetAvailableRoles() []string {
+// Deprecated: Use BatchTagSongsResponse.ProtoReflect.Descriptor instead.
+func (*BatchTagSongsResponse) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{36}
+}
+
+func (x *BatchTagSongsResponse) GetTag() string {
 	if x != nil {
-		return x.AvailableRoles
+		return x.Tag
 	}
-	return nil
+	return ""
 }
 
-func (x *UpdateSongRequest) GetThumbnailUrl() string {
+func (x *BatchTagSongsResponse) GetTaggedCount() int32 {
 	if x != nil {
-		return x.ThumbnailUrl
+		return x.TaggedCount
 	}
-	return ""
+	return 0
 }
 
-type JoinRoleRequest struct {
+type CheckSongLinksRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
-	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
-	Role          string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+	SongIds       []string               `protobuf:"bytes,1,rep,name=song_ids,json=songIds,proto3" json:"song_ids,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *JoinRoleRequest) Reset() {
-	*x = JoinRoleRequest{}
-	mi := &file_song_proto_msgTypes[9]
+func (x *CheckSongLinksRequest) Reset() {
+	*x = CheckSongLinksRequest{}
+	mi := &file_song_proto_msgTypes[37]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *JoinRoleRequest) String() string {
+func (x *CheckSongLinksRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*JoinRoleRequest) ProtoMessage() {}
+func (*CheckSongLinksRequest) ProtoMessage() {}
 
-func (x *JoinRoleRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[9]
+func (x *CheckSongLinksRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[37]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -725,48 +2540,45 @@ func (x *JoinRoleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use JoinRoleRequest.ProtoReflect.Descriptor instead.
-func (*JoinRoleRequest) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{9}
-}
-
-func (x *JoinRoleRequest) GetSongId() string {
-	if x != nil {
-		return x.SongId
-	}
-	return ""
+// Deprecated: Use CheckSongLinksRequest.ProtoReflect.Descriptor instead.
+func (*CheckSongLinksRequest) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{37}
 }
 
-func (x *JoinRoleRequest) GetRole() string {
+func (x *CheckSongLinksRequest) GetSongIds() []string {
 	if x != nil {
-		return x.Role
+		return x.SongIds
 	}
-	return ""
+	return nil
 }
 
-type LeaveRoleRequest struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	SongId        string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
-	Role          string                 `protobuf:"bytes,2,opt,name=role,proto3" json:"role,omitempty"`
+type LinkStatus struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	SongId string                 `protobuf:"bytes,1,opt,name=song_id,json=songId,proto3" json:"song_id,omitempty"`
+	// UNKNOWN until the first check has finished.
+	Health        LinkHealth             `protobuf:"varint,2,opt,name=health,proto3,enum=musicclub.song.LinkHealth" json:"health,omitempty"`
+	HttpStatus    uint32                 `protobuf:"varint,3,opt,name=http_status,json=httpStatus,proto3" json:"http_status,omitempty"`
+	Error         string                 `protobuf:"bytes,4,opt,name=error,proto3" json:"error,omitempty"`
+	CheckedAt     *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=checked_at,json=checkedAt,proto3" json:"checked_at,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *LeaveRoleRequest) Reset() {
-	*x = LeaveRoleRequest{}
-	mi := &file_song_proto_msgTypes[10]
+func (x *LinkStatus) Reset() {
+	*x = LinkStatus{}
+	mi := &file_song_proto_msgTypes[38]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *LeaveRoleRequest) String() string {
+func (x *LinkStatus) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*LeaveRoleRequest) ProtoMessage() {}
+func (*LinkStatus) ProtoMessage() {}
 
-func (x *LeaveRoleRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_song_proto_msgTypes[10]
+func (x *LinkStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_song_proto_msgTypes[38]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -777,42 +2589,87 @@ func (x *LeaveRoleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use LeaveRoleRequest.ProtoReflect.Descriptor instead.
-func (*LeaveRoleRequest) Descriptor() ([]byte, []int) {
-	return file_song_proto_rawDescGZIP(), []int{10}
+// Deprecated: Use LinkStatus.ProtoReflect.Descriptor instead.
+func (*LinkStatus) Descriptor() ([]byte, []int) {
+	return file_song_proto_rawDescGZIP(), []int{38}
 }
 
-func (x *LeaveRoleRequest) GetSongId() string {
+func (x *LinkStatus) GetSongId() string {
 	if x != nil {
 		return x.SongId
 	}
 	return ""
 }
 
-func (x *LeaveRoleRequest) GetRole() string {
+func (x *LinkStatus) GetHealth() LinkHealth {
 	if x != nil {
-		return x.Role
+		return x.Health
+	}
+	return LinkHealth_LINK_HEALTH_UNKNOWN
+}
+
+func (x *LinkStatus) GetHttpStatus() uint32 {
+	if x != nil {
+		return x.HttpStatus
+	}
+	return 0
+}
+
+func (x *LinkStatus) GetError() string {
+	if x != nil {
+		return x.Error
 	}
 	return ""
 }
 
+func (x *LinkStatus) GetCheckedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.CheckedAt
+	}
+	return nil
+}
+
 var File_song_proto protoreflect.FileDescriptor
 
 const file_song_proto_rawDesc = "" +
 	"\n" +
 	"\n" +
-	"song.proto\x12\x0emusicclub.song\x1a\x1bgoogle/protobuf/empty.proto\x1a\x1fgoogle/protobuf/timestamp.proto\x1a\n" +
-	"user.proto\x1a\x11permissions.proto\"d\n" +
+	"song.proto\x12\x0emusicclub.song\x1a\x1bgoogle/protobuf/empty.proto\x1a google/protobuf/field_mask.proto\x1a\x1fgoogle/protobuf/timestamp.proto\x1a\n" +
+	"user.proto\x1a\x11permissions.proto\"\xf9\x01\n" +
 	"\x10ListSongsRequest\x12\x14\n" +
 	"\x05query\x18\x01 \x01(\tR\x05query\x12\x1d\n" +
 	"\n" +
 	"page_token\x18\x02 \x01(\tR\tpageToken\x12\x1b\n" +
-	"\tpage_size\x18\x03 \x01(\rR\bpageSize\"g\n" +
+	"\tpage_size\x18\x03 \x01(\rR\bpageSize\x12!\n" +
+	"\fmissing_link\x18\x04 \x01(\bR\vmissingLink\x12%\n" +
+	"\x0eparticipant_id\x18\x05 \x01(\tR\rparticipantId\x12\x10\n" +
+	"\x03tag\x18\x06 \x01(\tR\x03tag\x127\n" +
+	"\tread_mask\x18\a \x01(\v2\x1a.google.protobuf.FieldMaskR\breadMask\"0\n" +
+	"\x18StreamSearchSongsRequest\x12\x14\n" +
+	"\x05query\x18\x01 \x01(\tR\x05query\"s\n" +
+	"\x1aListSongsNotInEventRequest\x12\x19\n" +
+	"\bevent_id\x18\x01 \x01(\tR\aeventId\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x02 \x01(\tR\tpageToken\x12\x1b\n" +
+	"\tpage_size\x18\x03 \x01(\rR\bpageSize\"U\n" +
+	"\x17ListSongsByVotesRequest\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x01 \x01(\tR\tpageToken\x12\x1b\n" +
+	"\tpage_size\x18\x02 \x01(\rR\bpageSize\"Y\n" +
+	"\x0eGetSongRequest\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x127\n" +
+	"\tread_mask\x18\x02 \x01(\v2\x1a.google.protobuf.FieldMaskR\breadMask\"\x82\x01\n" +
 	"\x11ListSongsResponse\x12*\n" +
 	"\x05songs\x18\x01 \x03(\v2\x14.musicclub.song.SongR\x05songs\x12&\n" +
-	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\"\x18\n" +
+	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\x12\x19\n" +
+	"\bhas_more\x18\x03 \x01(\bR\ahasMore\".\n" +
+	"\x16ListRecentSongsRequest\x12\x14\n" +
+	"\x05limit\x18\x01 \x01(\rR\x05limit\"\x18\n" +
 	"\x06SongId\x12\x0e\n" +
-	"\x02id\x18\x01 \x01(\tR\x02id\"\xb3\x02\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\"H\n" +
+	"\x11DeleteSongRequest\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x12#\n" +
+	"\rallow_missing\x18\x02 \x01(\bR\fallowMissing\"\xb2\x03\n" +
 	"\x04Song\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x14\n" +
 	"\x05title\x18\x02 \x01(\tR\x05title\x12\x16\n" +
@@ -822,25 +2679,42 @@ const file_song_proto_rawDesc = "" +
 	"\x0favailable_roles\x18\x06 \x03(\tR\x0eavailableRoles\x12$\n" +
 	"\x0eeditable_by_me\x18\a \x01(\bR\feditableByMe\x12)\n" +
 	"\x10assignment_count\x18\b \x01(\x05R\x0fassignmentCount\x12#\n" +
-	"\rthumbnail_url\x18\t \x01(\tR\fthumbnailUrl\"\xc1\x01\n" +
+	"\rthumbnail_url\x18\t \x01(\tR\fthumbnailUrl\x12\x1d\n" +
+	"\n" +
+	"created_by\x18\n" +
+	" \x01(\tR\tcreatedBy\x12)\n" +
+	"\x10duration_seconds\x18\v \x01(\x05R\x0fdurationSeconds\x12\x1d\n" +
+	"\n" +
+	"vote_count\x18\f \x01(\x05R\tvoteCount\x12\x14\n" +
+	"\x05notes\x18\r \x01(\tR\x05notes\"\xc1\x01\n" +
 	"\vSongDetails\x12(\n" +
 	"\x04song\x18\x01 \x01(\v2\x14.musicclub.song.SongR\x04song\x12@\n" +
 	"\vassignments\x18\x02 \x03(\v2\x1e.musicclub.song.RoleAssignmentR\vassignments\x12F\n" +
 	"\vpermissions\x18\x03 \x01(\v2$.musicclub.permissions.PermissionSetR\vpermissions\"N\n" +
 	"\bSongLink\x120\n" +
 	"\x04kind\x18\x01 \x01(\x0e2\x1c.musicclub.song.SongLinkTypeR\x04kind\x12\x10\n" +
-	"\x03url\x18\x02 \x01(\tR\x03url\"\x87\x01\n" +
+	"\x03url\x18\x02 \x01(\tR\x03url\"\xc4\x01\n" +
 	"\x0eRoleAssignment\x12\x12\n" +
 	"\x04role\x18\x01 \x01(\tR\x04role\x12(\n" +
 	"\x04user\x18\x02 \x01(\v2\x14.musicclub.user.UserR\x04user\x127\n" +
-	"\tjoined_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\bjoinedAt\"\xdf\x01\n" +
+	"\tjoined_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\bjoinedAt\x12;\n" +
+	"\x06status\x18\x04 \x01(\x0e2#.musicclub.song.ParticipationStatusR\x06status\"\xa0\x02\n" +
 	"\x11CreateSongRequest\x12\x14\n" +
 	"\x05title\x18\x01 \x01(\tR\x05title\x12\x16\n" +
 	"\x06artist\x18\x02 \x01(\tR\x06artist\x12,\n" +
 	"\x04link\x18\x03 \x01(\v2\x18.musicclub.song.SongLinkR\x04link\x12 \n" +
 	"\vdescription\x18\x04 \x01(\tR\vdescription\x12'\n" +
 	"\x0favailable_roles\x18\x05 \x03(\tR\x0eavailableRoles\x12#\n" +
-	"\rthumbnail_url\x18\x06 \x01(\tR\fthumbnailUrl\"\xef\x01\n" +
+	"\rthumbnail_url\x18\x06 \x01(\tR\fthumbnailUrl\x12)\n" +
+	"\x10duration_seconds\x18\a \x01(\x05R\x0fdurationSeconds\x12\x14\n" +
+	"\x05notes\x18\b \x01(\tR\x05notes\"H\n" +
+	"\x0eFieldViolation\x12\x14\n" +
+	"\x05field\x18\x01 \x01(\tR\x05field\x12 \n" +
+	"\vdescription\x18\x02 \x01(\tR\vdescription\"V\n" +
+	"\x14ValidateSongResponse\x12>\n" +
+	"\n" +
+	"violations\x18\x01 \x03(\v2\x1e.musicclub.song.FieldViolationR\n" +
+	"violations\"\xed\x02\n" +
 	"\x11UpdateSongRequest\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x14\n" +
 	"\x05title\x18\x02 \x01(\tR\x05title\x12\x16\n" +
@@ -848,29 +2722,154 @@ const file_song_proto_rawDesc = "" +
 	"\x04link\x18\x04 \x01(\v2\x18.musicclub.song.SongLinkR\x04link\x12 \n" +
 	"\vdescription\x18\x05 \x01(\tR\vdescription\x12'\n" +
 	"\x0favailable_roles\x18\x06 \x03(\tR\x0eavailableRoles\x12#\n" +
-	"\rthumbnail_url\x18\a \x01(\tR\fthumbnailUrl\">\n" +
+	"\rthumbnail_url\x18\a \x01(\tR\fthumbnailUrl\x12)\n" +
+	"\x10duration_seconds\x18\b \x01(\x05R\x0fdurationSeconds\x12\x14\n" +
+	"\x05notes\x18\t \x01(\tR\x05notes\x12;\n" +
+	"\vupdate_mask\x18\n" +
+	" \x01(\v2\x1a.google.protobuf.FieldMaskR\n" +
+	"updateMask\"{\n" +
 	"\x0fJoinRoleRequest\x12\x17\n" +
 	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x12\n" +
-	"\x04role\x18\x02 \x01(\tR\x04role\"?\n" +
+	"\x04role\x18\x02 \x01(\tR\x04role\x12;\n" +
+	"\x06status\x18\x03 \x01(\x0e2#.musicclub.song.ParticipationStatusR\x06status\"?\n" +
 	"\x10LeaveRoleRequest\x12\x17\n" +
 	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x12\n" +
-	"\x04role\x18\x02 \x01(\tR\x04role*\x86\x01\n" +
+	"\x04role\x18\x02 \x01(\tR\x04role\"Y\n" +
+	"\x11RoleAssignmentKey\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x12\n" +
+	"\x04role\x18\x02 \x01(\tR\x04role\x12\x17\n" +
+	"\auser_id\x18\x03 \x01(\tR\x06userId\"\xa7\x01\n" +
+	"\x11UpdateRoleRequest\x123\n" +
+	"\x03key\x18\x01 \x01(\v2!.musicclub.song.RoleAssignmentKeyR\x03key\x12\x19\n" +
+	"\bnew_role\x18\x02 \x01(\tR\anewRole\x12B\n" +
+	"\n" +
+	"new_status\x18\x03 \x01(\x0e2#.musicclub.song.ParticipationStatusR\tnewStatus\"\xda\x01\n" +
+	"\x19ListParticipationsRequest\x12\x17\n" +
+	"\auser_id\x18\x01 \x01(\tR\x06userId\x12\x17\n" +
+	"\asong_id\x18\x02 \x01(\tR\x06songId\x12\x12\n" +
+	"\x04role\x18\x03 \x01(\tR\x04role\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x04 \x01(\tR\tpageToken\x12\x1b\n" +
+	"\tpage_size\x18\x05 \x01(\rR\bpageSize\x12;\n" +
+	"\x06status\x18\x06 \x01(\x0e2#.musicclub.song.ParticipationStatusR\x06status\"\xe3\x01\n" +
+	"\rParticipation\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x1d\n" +
+	"\n" +
+	"song_title\x18\x02 \x01(\tR\tsongTitle\x12\x1f\n" +
+	"\vsong_artist\x18\x03 \x01(\tR\n" +
+	"songArtist\x12>\n" +
+	"\n" +
+	"assignment\x18\x04 \x01(\v2\x1e.musicclub.song.RoleAssignmentR\n" +
+	"assignment\x129\n" +
+	"\n" +
+	"updated_at\x18\x05 \x01(\v2\x1a.google.protobuf.TimestampR\tupdatedAt\"\xa6\x01\n" +
+	"\x1aListParticipationsResponse\x12E\n" +
+	"\x0eparticipations\x18\x01 \x03(\v2\x1d.musicclub.song.ParticipationR\x0eparticipations\x12&\n" +
+	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\x12\x19\n" +
+	"\bhas_more\x18\x03 \x01(\bR\ahasMore\"P\n" +
+	"\x1fListRecentParticipationsRequest\x12\x17\n" +
+	"\auser_id\x18\x01 \x01(\tR\x06userId\x12\x14\n" +
+	"\x05limit\x18\x02 \x01(\rR\x05limit\"\x8e\x01\n" +
+	"\x1eListParticipationsSinceRequest\x120\n" +
+	"\x05since\x18\x01 \x01(\v2\x1a.google.protobuf.TimestampR\x05since\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x02 \x01(\tR\tpageToken\x12\x1b\n" +
+	"\tpage_size\x18\x03 \x01(\rR\bpageSize\"\xed\x01\n" +
+	"\x1fListParticipationsSinceResponse\x12E\n" +
+	"\x0eparticipations\x18\x01 \x03(\v2\x1d.musicclub.song.ParticipationR\x0eparticipations\x12&\n" +
+	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\x12\x19\n" +
+	"\bhas_more\x18\x03 \x01(\bR\ahasMore\x12@\n" +
+	"\bremovals\x18\x04 \x03(\v2$.musicclub.song.RemovedParticipationR\bremovals\"\x97\x01\n" +
+	"\x14RemovedParticipation\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x12\n" +
+	"\x04role\x18\x02 \x01(\tR\x04role\x12\x17\n" +
+	"\auser_id\x18\x03 \x01(\tR\x06userId\x129\n" +
+	"\n" +
+	"removed_at\x18\x04 \x01(\v2\x1a.google.protobuf.TimestampR\tremovedAt\"z\n" +
+	"\x1aReplaceParticipantsRequest\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12C\n" +
+	"\fparticipants\x18\x02 \x03(\v2\x1f.musicclub.song.ParticipantSlotR\fparticipants\">\n" +
+	"\x0fParticipantSlot\x12\x12\n" +
+	"\x04role\x18\x01 \x01(\tR\x04role\x12\x17\n" +
+	"\auser_id\x18\x02 \x01(\tR\x06userId\"\"\n" +
+	" CountParticipationsByRoleRequest\"5\n" +
+	"\tRoleCount\x12\x12\n" +
+	"\x04role\x18\x01 \x01(\tR\x04role\x12\x14\n" +
+	"\x05count\x18\x02 \x01(\rR\x05count\"V\n" +
+	"!CountParticipationsByRoleResponse\x121\n" +
+	"\x06counts\x18\x01 \x03(\v2\x19.musicclub.song.RoleCountR\x06counts\";\n" +
+	"\x0eSongTagRequest\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x10\n" +
+	"\x03tag\x18\x02 \x01(\tR\x03tag\"7\n" +
+	"\bSongTags\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x12\x12\n" +
+	"\x04tags\x18\x02 \x03(\tR\x04tags\"C\n" +
+	"\x14BatchTagSongsRequest\x12\x10\n" +
+	"\x03tag\x18\x01 \x01(\tR\x03tag\x12\x19\n" +
+	"\bsong_ids\x18\x02 \x03(\tR\asongIds\"L\n" +
+	"\x15BatchTagSongsResponse\x12\x10\n" +
+	"\x03tag\x18\x01 \x01(\tR\x03tag\x12!\n" +
+	"\ftagged_count\x18\x02 \x01(\x05R\vtaggedCount\"2\n" +
+	"\x15CheckSongLinksRequest\x12\x19\n" +
+	"\bsong_ids\x18\x01 \x03(\tR\asongIds\"\xcb\x01\n" +
+	"\n" +
+	"LinkStatus\x12\x17\n" +
+	"\asong_id\x18\x01 \x01(\tR\x06songId\x122\n" +
+	"\x06health\x18\x02 \x01(\x0e2\x1a.musicclub.song.LinkHealthR\x06health\x12\x1f\n" +
+	"\vhttp_status\x18\x03 \x01(\rR\n" +
+	"httpStatus\x12\x14\n" +
+	"\x05error\x18\x04 \x01(\tR\x05error\x129\n" +
+	"\n" +
+	"checked_at\x18\x05 \x01(\v2\x1a.google.protobuf.TimestampR\tcheckedAt*\x86\x01\n" +
 	"\fSongLinkType\x12\x1a\n" +
 	"\x16SONG_LINK_TYPE_UNKNOWN\x10\x00\x12\x1a\n" +
 	"\x16SONG_LINK_TYPE_YOUTUBE\x10\x01\x12\x1f\n" +
 	"\x1bSONG_LINK_TYPE_YANDEX_MUSIC\x10\x02\x12\x1d\n" +
-	"\x19SONG_LINK_TYPE_SOUNDCLOUD\x10\x032\x8f\x04\n" +
+	"\x19SONG_LINK_TYPE_SOUNDCLOUD\x10\x03*\x83\x01\n" +
+	"\x13ParticipationStatus\x12$\n" +
+	" PARTICIPATION_STATUS_UNSPECIFIED\x10\x00\x12\"\n" +
+	"\x1ePARTICIPATION_STATUS_CONFIRMED\x10\x01\x12\"\n" +
+	"\x1ePARTICIPATION_STATUS_TENTATIVE\x10\x02*\x87\x01\n" +
+	"\n" +
+	"LinkHealth\x12\x17\n" +
+	"\x13LINK_HEALTH_UNKNOWN\x10\x00\x12\x12\n" +
+	"\x0eLINK_HEALTH_OK\x10\x01\x12\x16\n" +
+	"\x12LINK_HEALTH_BROKEN\x10\x02\x12\x1b\n" +
+	"\x17LINK_HEALTH_UNREACHABLE\x10\x03\x12\x17\n" +
+	"\x13LINK_HEALTH_MISSING\x10\x042\xd6\x11\n" +
 	"\vSongService\x12P\n" +
-	"\tListSongs\x12 .musicclub.song.ListSongsRequest\x1a!.musicclub.song.ListSongsResponse\x12>\n" +
-	"\aGetSong\x12\x16.musicclub.song.SongId\x1a\x1b.musicclub.song.SongDetails\x12L\n" +
+	"\tListSongs\x12 .musicclub.song.ListSongsRequest\x1a!.musicclub.song.ListSongsResponse\x12U\n" +
+	"\x11StreamSearchSongs\x12(.musicclub.song.StreamSearchSongsRequest\x1a\x14.musicclub.song.Song0\x01\x12\\\n" +
+	"\x0fListRecentSongs\x12&.musicclub.song.ListRecentSongsRequest\x1a!.musicclub.song.ListSongsResponse\x12d\n" +
+	"\x13ListSongsNotInEvent\x12*.musicclub.song.ListSongsNotInEventRequest\x1a!.musicclub.song.ListSongsResponse\x12^\n" +
+	"\x10ListSongsByVotes\x12'.musicclub.song.ListSongsByVotesRequest\x1a!.musicclub.song.ListSongsResponse\x12F\n" +
+	"\aGetSong\x12\x1e.musicclub.song.GetSongRequest\x1a\x1b.musicclub.song.SongDetails\x12L\n" +
 	"\n" +
-	"CreateSong\x12!.musicclub.song.CreateSongRequest\x1a\x1b.musicclub.song.SongDetails\x12L\n" +
+	"CreateSong\x12!.musicclub.song.CreateSongRequest\x1a\x1b.musicclub.song.SongDetails\x12W\n" +
+	"\fValidateSong\x12!.musicclub.song.CreateSongRequest\x1a$.musicclub.song.ValidateSongResponse\x12L\n" +
 	"\n" +
-	"UpdateSong\x12!.musicclub.song.UpdateSongRequest\x1a\x1b.musicclub.song.SongDetails\x12<\n" +
+	"UpdateSong\x12!.musicclub.song.UpdateSongRequest\x1a\x1b.musicclub.song.SongDetails\x12G\n" +
 	"\n" +
-	"DeleteSong\x12\x16.musicclub.song.SongId\x1a\x16.google.protobuf.Empty\x12H\n" +
+	"DeleteSong\x12!.musicclub.song.DeleteSongRequest\x1a\x16.google.protobuf.Empty\x12H\n" +
 	"\bJoinRole\x12\x1f.musicclub.song.JoinRoleRequest\x1a\x1b.musicclub.song.SongDetails\x12J\n" +
-	"\tLeaveRole\x12 .musicclub.song.LeaveRoleRequest\x1a\x1b.musicclub.song.SongDetailsB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
+	"\tLeaveRole\x12 .musicclub.song.LeaveRoleRequest\x1a\x1b.musicclub.song.SongDetails\x12L\n" +
+	"\n" +
+	"UpdateRole\x12!.musicclub.song.UpdateRoleRequest\x1a\x1b.musicclub.song.SongDetails\x12^\n" +
+	"\x13ReplaceParticipants\x12*.musicclub.song.ReplaceParticipantsRequest\x1a\x1b.musicclub.song.SongDetails\x12k\n" +
+	"\x12ListParticipations\x12).musicclub.song.ListParticipationsRequest\x1a*.musicclub.song.ListParticipationsResponse\x12z\n" +
+	"\x17ListParticipationsSince\x12..musicclub.song.ListParticipationsSinceRequest\x1a/.musicclub.song.ListParticipationsSinceResponse\x12w\n" +
+	"\x18ListRecentParticipations\x12/.musicclub.song.ListRecentParticipationsRequest\x1a*.musicclub.song.ListParticipationsResponse\x12\x80\x01\n" +
+	"\x19CountParticipationsByRole\x120.musicclub.song.CountParticipationsByRoleRequest\x1a1.musicclub.song.CountParticipationsByRoleResponse\x12O\n" +
+	"\x0eCheckSongLinks\x12%.musicclub.song.CheckSongLinksRequest\x1a\x16.google.protobuf.Empty\x12C\n" +
+	"\rGetLinkStatus\x12\x16.musicclub.song.SongId\x1a\x1a.musicclub.song.LinkStatus\x12@\n" +
+	"\fListSongTags\x12\x16.musicclub.song.SongId\x1a\x18.musicclub.song.SongTags\x12F\n" +
+	"\n" +
+	"AddSongTag\x12\x1e.musicclub.song.SongTagRequest\x1a\x18.musicclub.song.SongTags\x12I\n" +
+	"\rRemoveSongTag\x12\x1e.musicclub.song.SongTagRequest\x1a\x18.musicclub.song.SongTags\x12?\n" +
+	"\bVoteSong\x12\x16.musicclub.song.SongId\x1a\x1b.musicclub.song.SongDetails\x12A\n" +
+	"\n" +
+	"UnvoteSong\x12\x16.musicclub.song.SongId\x1a\x1b.musicclub.song.SongDetails\x12\\\n" +
+	"\rBatchTagSongs\x12$.musicclub.song.BatchTagSongsRequest\x1a%.musicclub.song.BatchTagSongsResponseB\x1cZ\x1amusicclubbot/backend/protob\x06proto3"
 
 var (
 	file_song_proto_rawDescOnce sync.Once
@@ -884,56 +2883,145 @@ func file_song_proto_rawDescGZIP() []byte {
 	return file_song_proto_rawDescData
 }
 
-var file_song_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
-var file_song_proto_msgTypes = make([]protoimpl.MessageInfo, 11)
+var file_song_proto_enumTypes = make([]protoimpl.EnumInfo, 3)
+var file_song_proto_msgTypes = make([]protoimpl.MessageInfo, 39)
 var file_song_proto_goTypes = []any{
-	(SongLinkType)(0),             // 0: musicclub.song.SongLinkType
-	(*ListSongsRequest)(nil),      // 1: musicclub.song.ListSongsRequest
-	(*ListSongsResponse)(nil),     // 2: musicclub.song.ListSongsResponse
-	(*SongId)(nil),                // 3: musicclub.song.SongId
-	(*Song)(nil),                  // 4: musicclub.song.Song
-	(*SongDetails)(nil),           // 5: musicclub.song.SongDetails
-	(*SongLink)(nil),              // 6: musicclub.song.SongLink
-	(*RoleAssignment)(nil),        // 7: musicclub.song.RoleAssignment
-	(*CreateSongRequest)(nil),     // 8: musicclub.song.CreateSongRequest
-	(*UpdateSongRequest)(nil),     // 9: musicclub.song.UpdateSongRequest
-	(*JoinRoleRequest)(nil),       // 10: musicclub.song.JoinRoleRequest
-	(*LeaveRoleRequest)(nil),      // 11: musicclub.song.LeaveRoleRequest
-	(*PermissionSet)(nil),         // 12: musicclub.permissions.PermissionSet
-	(*User)(nil),                  // 13: musicclub.user.User
-	(*timestamppb.Timestamp)(nil), // 14: google.protobuf.Timestamp
-	(*emptypb.Empty)(nil),         // 15: google.protobuf.Empty
+	(SongLinkType)(0),                         // 0: musicclub.song.SongLinkType
+	(ParticipationStatus)(0),                  // 1: musicclub.song.ParticipationStatus
+	(LinkHealth)(0),                           // 2: musicclub.song.LinkHealth
+	(*ListSongsRequest)(nil),                  // 3: musicclub.song.ListSongsRequest
+	(*StreamSearchSongsRequest)(nil),          // 4: musicclub.song.StreamSearchSongsRequest
+	(*ListSongsNotInEventRequest)(nil),        // 5: musicclub.song.ListSongsNotInEventRequest
+	(*ListSongsByVotesRequest)(nil),           // 6: musicclub.song.ListSongsByVotesRequest
+	(*GetSongRequest)(nil),                    // 7: musicclub.song.GetSongRequest
+	(*ListSongsResponse)(nil),                 // 8: musicclub.song.ListSongsResponse
+	(*ListRecentSongsRequest)(nil),            // 9: musicclub.song.ListRecentSongsRequest
+	(*SongId)(nil),                            // 10: musicclub.song.SongId
+	(*DeleteSongRequest)(nil),                 // 11: musicclub.song.DeleteSongRequest
+	(*Song)(nil),                              // 12: musicclub.song.Song
+	(*SongDetails)(nil),                       // 13: musicclub.song.SongDetails
+	(*SongLink)(nil),                          // 14: musicclub.song.SongLink
+	(*RoleAssignment)(nil),                    // 15: musicclub.song.RoleAssignment
+	(*CreateSongRequest)(nil),                 // 16: musicclub.song.CreateSongRequest
+	(*FieldViolation)(nil),                    // 17: musicclub.song.FieldViolation
+	(*ValidateSongResponse)(nil),              // 18: musicclub.song.ValidateSongResponse
+	(*UpdateSongRequest)(nil),                 // 19: musicclub.song.UpdateSongRequest
+	(*JoinRoleRequest)(nil),                   // 20: musicclub.song.JoinRoleRequest
+	(*LeaveRoleRequest)(nil),                  // 21: musicclub.song.LeaveRoleRequest
+	(*RoleAssignmentKey)(nil),                 // 22: musicclub.song.RoleAssignmentKey
+	(*UpdateRoleRequest)(nil),                 // 23: musicclub.song.UpdateRoleRequest
+	(*ListParticipationsRequest)(nil),         // 24: musicclub.song.ListParticipationsRequest
+	(*Participation)(nil),                     // 25: musicclub.song.Participation
+	(*ListParticipationsResponse)(nil),        // 26: musicclub.song.ListParticipationsResponse
+	(*ListRecentParticipationsRequest)(nil),   // 27: musicclub.song.ListRecentParticipationsRequest
+	(*ListParticipationsSinceRequest)(nil),    // 28: musicclub.song.ListParticipationsSinceRequest
+	(*ListParticipationsSinceResponse)(nil),   // 29: musicclub.song.ListParticipationsSinceResponse
+	(*RemovedParticipation)(nil),              // 30: musicclub.song.RemovedParticipation
+	(*ReplaceParticipantsRequest)(nil),        // 31: musicclub.song.ReplaceParticipantsRequest
+	(*ParticipantSlot)(nil),                   // 32: musicclub.song.ParticipantSlot
+	(*CountParticipationsByRoleRequest)(nil),  // 33: musicclub.song.CountParticipationsByRoleRequest
+	(*RoleCount)(nil),                         // 34: musicclub.song.RoleCount
+	(*CountParticipationsByRoleResponse)(nil), // 35: musicclub.song.CountParticipationsByRoleResponse
+	(*SongTagRequest)(nil),                    // 36: musicclub.song.SongTagRequest
+	(*SongTags)(nil),                          // 37: musicclub.song.SongTags
+	(*BatchTagSongsRequest)(nil),              // 38: musicclub.song.BatchTagSongsRequest
+	(*BatchTagSongsResponse)(nil),             // 39: musicclub.song.BatchTagSongsResponse
+	(*CheckSongLinksRequest)(nil),             // 40: musicclub.song.CheckSongLinksRequest
+	(*LinkStatus)(nil),                        // 41: musicclub.song.LinkStatus
+	(*fieldmaskpb.FieldMask)(nil),             // 42: google.protobuf.FieldMask
+	(*PermissionSet)(nil),                     // 43: musicclub.permissions.PermissionSet
+	(*User)(nil),                              // 44: musicclub.user.User
+	(*timestamppb.Timestamp)(nil),             // 45: google.protobuf.Timestamp
+	(*emptypb.Empty)(nil),                     // 46: google.protobuf.Empty
 }
 var file_song_proto_depIdxs = []int32{
-	4,  // 0: musicclub.song.ListSongsResponse.songs:type_name -> musicclub.song.Song
-	6,  // 1: musicclub.song.Song.link:type_name -> musicclub.song.SongLink
-	4,  // 2: musicclub.song.SongDetails.song:type_name -> musicclub.song.Song
-	7,  // 3: musicclub.song.SongDetails.assignments:type_name -> musicclub.song.RoleAssignment
-	12, // 4: musicclub.song.SongDetails.permissions:type_name -> musicclub.permissions.PermissionSet
-	0,  // 5: musicclub.song.SongLink.kind:type_name -> musicclub.song.SongLinkType
-	13, // 6: musicclub.song.RoleAssignment.user:type_name -> musicclub.user.User
-	14, // 7: musicclub.song.RoleAssignment.joined_at:type_name -> google.protobuf.Timestamp
-	6,  // 8: musicclub.song.CreateSongRequest.link:type_name -> musicclub.song.SongLink
-	6,  // 9: musicclub.song.UpdateSongRequest.link:type_name -> musicclub.song.SongLink
-	1,  // 10: musicclub.song.SongService.ListSongs:input_type -> musicclub.song.ListSongsRequest
-	3,  // 11: musicclub.song.SongService.GetSong:input_type -> musicclub.song.SongId
-	8,  // 12: musicclub.song.SongService.CreateSong:input_type -> musicclub.song.CreateSongRequest
-	9,  // 13: musicclub.song.SongService.UpdateSong:input_type -> musicclub.song.UpdateSongRequest
-	3,  // 14: musicclub.song.SongService.DeleteSong:input_type -> musicclub.song.SongId
-	10, // 15: musicclub.song.SongService.JoinRole:input_type -> musicclub.song.JoinRoleRequest
-	11, // 16: musicclub.song.SongService.LeaveRole:input_type -> musicclub.song.LeaveRoleRequest
-	2,  // 17: musicclub.song.SongService.ListSongs:output_type -> musicclub.song.ListSongsResponse
-	5,  // 18: musicclub.song.SongService.GetSong:output_type -> musicclub.song.SongDetails
-	5,  // 19: musicclub.song.SongService.CreateSong:output_type -> musicclub.song.SongDetails
-	5,  // 20: musicclub.song.SongService.UpdateSong:output_type -> musicclub.song.SongDetails
-	15, // 21: musicclub.song.SongService.DeleteSong:output_type -> google.protobuf.Empty
-	5,  // 22: musicclub.song.SongService.JoinRole:output_type -> musicclub.song.SongDetails
-	5,  // 23: musicclub.song.SongService.LeaveRole:output_type -> musicclub.song.SongDetails
-	17, // [17:24] is the sub-list for method output_type
-	10, // [10:17] is the sub-list for method input_type
-	10, // [10:10] is the sub-list for extension type_name
-	10, // [10:10] is the sub-list for extension extendee
-	0,  // [0:10] is the sub-list for field type_name
+	42, // 0: musicclub.song.ListSongsRequest.read_mask:type_name -> google.protobuf.FieldMask
+	42, // 1: musicclub.song.GetSongRequest.read_mask:type_name -> google.protobuf.FieldMask
+	12, // 2: musicclub.song.ListSongsResponse.songs:type_name -> musicclub.song.Song
+	14, // 3: musicclub.song.Song.link:type_name -> musicclub.song.SongLink
+	12, // 4: musicclub.song.SongDetails.song:type_name -> musicclub.song.Song
+	15, // 5: musicclub.song.SongDetails.assignments:type_name -> musicclub.song.RoleAssignment
+	43, // 6: musicclub.song.SongDetails.permissions:type_name -> musicclub.permissions.PermissionSet
+	0,  // 7: musicclub.song.SongLink.kind:type_name -> musicclub.song.SongLinkType
+	44, // 8: musicclub.song.RoleAssignment.user:type_name -> musicclub.user.User
+	45, // 9: musicclub.song.RoleAssignment.joined_at:type_name -> google.protobuf.Timestamp
+	1,  // 10: musicclub.song.RoleAssignment.status:type_name -> musicclub.song.ParticipationStatus
+	14, // 11: musicclub.song.CreateSongRequest.link:type_name -> musicclub.song.SongLink
+	17, // 12: musicclub.song.ValidateSongResponse.violations:type_name -> musicclub.song.FieldViolation
+	14, // 13: musicclub.song.UpdateSongRequest.link:type_name -> musicclub.song.SongLink
+	42, // 14: musicclub.song.UpdateSongRequest.update_mask:type_name -> google.protobuf.FieldMask
+	1,  // 15: musicclub.song.JoinRoleRequest.status:type_name -> musicclub.song.ParticipationStatus
+	22, // 16: musicclub.song.UpdateRoleRequest.key:type_name -> musicclub.song.RoleAssignmentKey
+	1,  // 17: musicclub.song.UpdateRoleRequest.new_status:type_name -> musicclub.song.ParticipationStatus
+	1,  // 18: musicclub.song.ListParticipationsRequest.status:type_name -> musicclub.song.ParticipationStatus
+	15, // 19: musicclub.song.Participation.assignment:type_name -> musicclub.song.RoleAssignment
+	45, // 20: musicclub.song.Participation.updated_at:type_name -> google.protobuf.Timestamp
+	25, // 21: musicclub.song.ListParticipationsResponse.participations:type_name -> musicclub.song.Participation
+	45, // 22: musicclub.song.ListParticipationsSinceRequest.since:type_name -> google.protobuf.Timestamp
+	25, // 23: musicclub.song.ListParticipationsSinceResponse.participations:type_name -> musicclub.song.Participation
+	30, // 24: musicclub.song.ListParticipationsSinceResponse.removals:type_name -> musicclub.song.RemovedParticipation
+	45, // 25: musicclub.song.RemovedParticipation.removed_at:type_name -> google.protobuf.Timestamp
+	32, // 26: musicclub.song.ReplaceParticipantsRequest.participants:type_name -> musicclub.song.ParticipantSlot
+	34, // 27: musicclub.song.CountParticipationsByRoleResponse.counts:type_name -> musicclub.song.RoleCount
+	2,  // 28: musicclub.song.LinkStatus.health:type_name -> musicclub.song.LinkHealth
+	45, // 29: musicclub.song.LinkStatus.checked_at:type_name -> google.protobuf.Timestamp
+	3,  // 30: musicclub.song.SongService.ListSongs:input_type -> musicclub.song.ListSongsRequest
+	4,  // 31: musicclub.song.SongService.StreamSearchSongs:input_type -> musicclub.song.StreamSearchSongsRequest
+	9,  // 32: musicclub.song.SongService.ListRecentSongs:input_type -> musicclub.song.ListRecentSongsRequest
+	5,  // 33: musicclub.song.SongService.ListSongsNotInEvent:input_type -> musicclub.song.ListSongsNotInEventRequest
+	6,  // 34: musicclub.song.SongService.ListSongsByVotes:input_type -> musicclub.song.ListSongsByVotesRequest
+	7,  // 35: musicclub.song.SongService.GetSong:input_type -> musicclub.song.GetSongRequest
+	16, // 36: musicclub.song.SongService.CreateSong:input_type -> musicclub.song.CreateSongRequest
+	16, // 37: musicclub.song.SongService.ValidateSong:input_type -> musicclub.song.CreateSongRequest
+	19, // 38: musicclub.song.SongService.UpdateSong:input_type -> musicclub.song.UpdateSongRequest
+	11, // 39: musicclub.song.SongService.DeleteSong:input_type -> musicclub.song.DeleteSongRequest
+	20, // 40: musicclub.song.SongService.JoinRole:input_type -> musicclub.song.JoinRoleRequest
+	21, // 41: musicclub.song.SongService.LeaveRole:input_type -> musicclub.song.LeaveRoleRequest
+	23, // 42: musicclub.song.SongService.UpdateRole:input_type -> musicclub.song.UpdateRoleRequest
+	31, // 43: musicclub.song.SongService.ReplaceParticipants:input_type -> musicclub.song.ReplaceParticipantsRequest
+	24, // 44: musicclub.song.SongService.ListParticipations:input_type -> musicclub.song.ListParticipationsRequest
+	28, // 45: musicclub.song.SongService.ListParticipationsSince:input_type -> musicclub.song.ListParticipationsSinceRequest
+	27, // 46: musicclub.song.SongService.ListRecentParticipations:input_type -> musicclub.song.ListRecentParticipationsRequest
+	33, // 47: musicclub.song.SongService.CountParticipationsByRole:input_type -> musicclub.song.CountParticipationsByRoleRequest
+	40, // 48: musicclub.song.SongService.CheckSongLinks:input_type -> musicclub.song.CheckSongLinksRequest
+	10, // 49: musicclub.song.SongService.GetLinkStatus:input_type -> musicclub.song.SongId
+	10, // 50: musicclub.song.SongService.ListSongTags:input_type -> musicclub.song.SongId
+	36, // 51: musicclub.song.SongService.AddSongTag:input_type -> musicclub.song.SongTagRequest
+	36, // 52: musicclub.song.SongService.RemoveSongTag:input_type -> musicclub.song.SongTagRequest
+	10, // 53: musicclub.song.SongService.VoteSong:input_type -> musicclub.song.SongId
+	10, // 54: musicclub.song.SongService.UnvoteSong:input_type -> musicclub.song.SongId
+	38, // 55: musicclub.song.SongService.BatchTagSongs:input_type -> musicclub.song.BatchTagSongsRequest
+	8,  // 56: musicclub.song.SongService.ListSongs:output_type -> musicclub.song.ListSongsResponse
+	12, // 57: musicclub.song.SongService.StreamSearchSongs:output_type -> musicclub.song.Song
+	8,  // 58: musicclub.song.SongService.ListRecentSongs:output_type -> musicclub.song.ListSongsResponse
+	8,  // 59: musicclub.song.SongService.ListSongsNotInEvent:output_type -> musicclub.song.ListSongsResponse
+	8,  // 60: musicclub.song.SongService.ListSongsByVotes:output_type -> musicclub.song.ListSongsResponse
+	13, // 61: musicclub.song.SongService.GetSong:output_type -> musicclub.song.SongDetails
+	13, // 62: musicclub.song.SongService.CreateSong:output_type -> musicclub.song.SongDetails
+	18, // 63: musicclub.song.SongService.ValidateSong:output_type -> musicclub.song.ValidateSongResponse
+	13, // 64: musicclub.song.SongService.UpdateSong:output_type -> musicclub.song.SongDetails
+	46, // 65: musicclub.song.SongService.DeleteSong:output_type -> google.protobuf.Empty
+	13, // 66: musicclub.song.SongService.JoinRole:output_type -> musicclub.song.SongDetails
+	13, // 67: musicclub.song.SongService.LeaveRole:output_type -> musicclub.song.SongDetails
+	13, // 68: musicclub.song.SongService.UpdateRole:output_type -> musicclub.song.SongDetails
+	13, // 69: musicclub.song.SongService.ReplaceParticipants:output_type -> musicclub.song.SongDetails
+	26, // 70: musicclub.song.SongService.ListParticipations:output_type -> musicclub.song.ListParticipationsResponse
+	29, // 71: musicclub.song.SongService.ListParticipationsSince:output_type -> musicclub.song.ListParticipationsSinceResponse
+	26, // 72: musicclub.song.SongService.ListRecentParticipations:output_type -> musicclub.song.ListParticipationsResponse
+	35, // 73: musicclub.song.SongService.CountParticipationsByRole:output_type -> musicclub.song.CountParticipationsByRoleResponse
+	46, // 74: musicclub.song.SongService.CheckSongLinks:output_type -> google.protobuf.Empty
+	41, // 75: musicclub.song.SongService.GetLinkStatus:output_type -> musicclub.song.LinkStatus
+	37, // 76: musicclub.song.SongService.ListSongTags:output_type -> musicclub.song.SongTags
+	37, // 77: musicclub.song.SongService.AddSongTag:output_type -> musicclub.song.SongTags
+	37, // 78: musicclub.song.SongService.RemoveSongTag:output_type -> musicclub.song.SongTags
+	13, // 79: musicclub.song.SongService.VoteSong:output_type -> musicclub.song.SongDetails
+	13, // 80: musicclub.song.SongService.UnvoteSong:output_type -> musicclub.song.SongDetails
+	39, // 81: musicclub.song.SongService.BatchTagSongs:output_type -> musicclub.song.BatchTagSongsResponse
+	56, // [56:82] is the sub-list for method output_type
+	30, // [30:56] is the sub-list for method input_type
+	30, // [30:30] is the sub-list for extension type_name
+	30, // [30:30] is the sub-list for extension extendee
+	0,  // [0:30] is the sub-list for field type_name
 }
 
 func init() { file_song_proto_init() }
@@ -948,8 +3036,8 @@ func file_song_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_song_proto_rawDesc), len(file_song_proto_rawDesc)),
-			NumEnums:      1,
-			NumMessages:   11,
+			NumEnums:      3,
+			NumMessages:   39,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -20,13 +20,32 @@ import (
 const _ = grpc.SupportPackageIsVersion9
 
 const (
-	SongService_ListSongs_FullMethodName  = "/musicclub.song.SongService/ListSongs"
-	SongService_GetSong_FullMethodName    = "/musicclub.song.SongService/GetSong"
-	SongService_CreateSong_FullMethodName = "/musicclub.song.SongService/CreateSong"
-	SongService_UpdateSong_FullMethodName = "/musicclub.song.SongService/UpdateSong"
-	SongService_DeleteSong_FullMethodName = "/musicclub.song.SongService/DeleteSong"
-	SongService_JoinRole_FullMethodName   = "/musicclub.song.SongService/JoinRole"
-	SongService_LeaveRole_FullMethodName  = "/musicclub.song.SongService/LeaveRole"
+	SongService_ListSongs_FullMethodName                 = "/musicclub.song.SongService/ListSongs"
+	SongService_StreamSearchSongs_FullMethodName         = "/musicclub.song.SongService/StreamSearchSongs"
+	SongService_ListRecentSongs_FullMethodName           = "/musicclub.song.SongService/ListRecentSongs"
+	SongService_ListSongsNotInEvent_FullMethodName       = "/musicclub.song.SongService/ListSongsNotInEvent"
+	SongService_ListSongsByVotes_FullMethodName          = "/musicclub.song.SongService/ListSongsByVotes"
+	SongService_GetSong_FullMethodName                   = "/musicclub.song.SongService/GetSong"
+	SongService_CreateSong_FullMethodName                = "/musicclub.song.SongService/CreateSong"
+	SongService_ValidateSong_FullMethodName              = "/musicclub.song.SongService/ValidateSong"
+	SongService_UpdateSong_FullMethodName                = "/musicclub.song.SongService/UpdateSong"
+	SongService_DeleteSong_FullMethodName                = "/musicclub.song.SongService/DeleteSong"
+	SongService_JoinRole_FullMethodName                  = "/musicclub.song.SongService/JoinRole"
+	SongService_LeaveRole_FullMethodName                 = "/musicclub.song.SongService/LeaveRole"
+	SongService_UpdateRole_FullMethodName                = "/musicclub.song.SongService/UpdateRole"
+	SongService_ReplaceParticipants_FullMethodName       = "/musicclub.song.SongService/ReplaceParticipants"
+	SongService_ListParticipations_FullMethodName        = "/musicclub.song.SongService/ListParticipations"
+	SongService_ListParticipationsSince_FullMethodName   = "/musicclub.song.SongService/ListParticipationsSince"
+	SongService_ListRecentParticipations_FullMethodName  = "/musicclub.song.SongService/ListRecentParticipations"
+	SongService_CountParticipationsByRole_FullMethodName = "/musicclub.song.SongService/CountParticipationsByRole"
+	SongService_CheckSongLinks_FullMethodName            = "/musicclub.song.SongService/CheckSongLinks"
+	SongService_GetLinkStatus_FullMethodName             = "/musicclub.song.SongService/GetLinkStatus"
+	SongService_ListSongTags_FullMethodName              = "/musicclub.song.SongService/ListSongTags"
+	SongService_AddSongTag_FullMethodName                = "/musicclub.song.SongService/AddSongTag"
+	SongService_RemoveSongTag_FullMethodName             = "/musicclub.song.SongService/RemoveSongTag"
+	SongService_VoteSong_FullMethodName                  = "/musicclub.song.SongService/VoteSong"
+	SongService_UnvoteSong_FullMethodName                = "/musicclub.song.SongService/UnvoteSong"
+	SongService_BatchTagSongs_FullMethodName             = "/musicclub.song.SongService/BatchTagSongs"
 )
 
 // SongServiceClient is the client API for SongService service.
@@ -37,18 +56,57 @@ const (
 type SongServiceClient interface {
 	// Returns a paginated list of songs.
 	ListSongs(ctx context.Context, in *ListSongsRequest, opts ...grpc.CallOption) (*ListSongsResponse, error)
+	// Streams every song matching a search, for "select all" without paging.
+	// Songs carry only catalog fields: no roles, counts or per-user fields.
+	StreamSearchSongs(ctx context.Context, in *StreamSearchSongsRequest, opts ...grpc.CallOption) (grpc.ServerStreamingClient[Song], error)
+	// Returns the most recently added songs, newest first.
+	ListRecentSongs(ctx context.Context, in *ListRecentSongsRequest, opts ...grpc.CallOption) (*ListSongsResponse, error)
+	// Returns songs that are not yet in an event's tracklist.
+	ListSongsNotInEvent(ctx context.Context, in *ListSongsNotInEventRequest, opts ...grpc.CallOption) (*ListSongsResponse, error)
+	// Returns songs ranked by vote count, most voted first.
+	ListSongsByVotes(ctx context.Context, in *ListSongsByVotesRequest, opts ...grpc.CallOption) (*ListSongsResponse, error)
 	// Returns a single song with full metadata and assignments.
-	GetSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error)
+	GetSong(ctx context.Context, in *GetSongRequest, opts ...grpc.CallOption) (*SongDetails, error)
 	// Create songs (requires permissions).
 	CreateSong(ctx context.Context, in *CreateSongRequest, opts ...grpc.CallOption) (*SongDetails, error)
+	// Runs the CreateSong checks without inserting anything.
+	ValidateSong(ctx context.Context, in *CreateSongRequest, opts ...grpc.CallOption) (*ValidateSongResponse, error)
 	// Update songs (requires permissions).
 	UpdateSong(ctx context.Context, in *UpdateSongRequest, opts ...grpc.CallOption) (*SongDetails, error)
 	// Delete songs (requires permissions).
-	DeleteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	DeleteSong(ctx context.Context, in *DeleteSongRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	// Join a role for a song.
 	JoinRole(ctx context.Context, in *JoinRoleRequest, opts ...grpc.CallOption) (*SongDetails, error)
 	// Leave a role for a song.
 	LeaveRole(ctx context.Context, in *LeaveRoleRequest, opts ...grpc.CallOption) (*SongDetails, error)
+	// Move an existing role assignment to another role.
+	UpdateRole(ctx context.Context, in *UpdateRoleRequest, opts ...grpc.CallOption) (*SongDetails, error)
+	// Replace all role assignments of a song in one call (admins only).
+	ReplaceParticipants(ctx context.Context, in *ReplaceParticipantsRequest, opts ...grpc.CallOption) (*SongDetails, error)
+	// Lists role assignments across songs together with song titles.
+	ListParticipations(ctx context.Context, in *ListParticipationsRequest, opts ...grpc.CallOption) (*ListParticipationsResponse, error)
+	// Lists role assignments created, changed or removed after a watermark, oldest first.
+	ListParticipationsSince(ctx context.Context, in *ListParticipationsSinceRequest, opts ...grpc.CallOption) (*ListParticipationsSinceResponse, error)
+	// Returns a person's latest signups across songs, newest first.
+	ListRecentParticipations(ctx context.Context, in *ListRecentParticipationsRequest, opts ...grpc.CallOption) (*ListParticipationsResponse, error)
+	// Counts role assignments per role across all songs, most common first.
+	CountParticipationsByRole(ctx context.Context, in *CountParticipationsByRoleRequest, opts ...grpc.CallOption) (*CountParticipationsByRoleResponse, error)
+	// Queues background health checks for song links.
+	CheckSongLinks(ctx context.Context, in *CheckSongLinksRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// Returns the latest link health check result for a song.
+	GetLinkStatus(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*LinkStatus, error)
+	// Lists the tags of a song.
+	ListSongTags(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongTags, error)
+	// Adds a tag to a song (requires permissions).
+	AddSongTag(ctx context.Context, in *SongTagRequest, opts ...grpc.CallOption) (*SongTags, error)
+	// Removes a tag from a song (requires permissions).
+	RemoveSongTag(ctx context.Context, in *SongTagRequest, opts ...grpc.CallOption) (*SongTags, error)
+	// Upvotes a song for the current user; voting twice has no effect.
+	VoteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error)
+	// Withdraws the current user's vote.
+	UnvoteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error)
+	// Adds one tag to many songs in a single transaction (requires permissions).
+	BatchTagSongs(ctx context.Context, in *BatchTagSongsRequest, opts ...grpc.CallOption) (*BatchTagSongsResponse, error)
 }
 
 type songServiceClient struct {
@@ -69,7 +127,56 @@ func (c *songServiceClient) ListSongs(ctx context.Context, in *ListSongsRequest,
 	return out, nil
 }
 
-func (c *songServiceClient) GetSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error) {
+func (c *songServiceClient) StreamSearchSongs(ctx context.Context, in *StreamSearchSongsRequest, opts ...grpc.CallOption) (grpc.ServerStreamingClient[Song], error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	stream, err := c.cc.NewStream(ctx, &SongService_ServiceDesc.Streams[0], SongService_StreamSearchSongs_FullMethodName, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &grpc.GenericClientStream[StreamSearchSongsRequest, Song]{ClientStream: stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+// This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
+type SongService_StreamSearchSongsClient = grpc.ServerStreamingClient[Song]
+
+func (c *songServiceClient) ListRecentSongs(ctx context.Context, in *ListRecentSongsRequest, opts ...grpc.CallOption) (*ListSongsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListSongsResponse)
+	err := c.cc.Invoke(ctx, SongService_ListRecentSongs_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListSongsNotInEvent(ctx context.Context, in *ListSongsNotInEventRequest, opts ...grpc.CallOption) (*ListSongsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListSongsResponse)
+	err := c.cc.Invoke(ctx, SongService_ListSongsNotInEvent_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListSongsByVotes(ctx context.Context, in *ListSongsByVotesRequest, opts ...grpc.CallOption) (*ListSongsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListSongsResponse)
+	err := c.cc.Invoke(ctx, SongService_ListSongsByVotes_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) GetSong(ctx context.Context, in *GetSongRequest, opts ...grpc.CallOption) (*SongDetails, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(SongDetails)
 	err := c.cc.Invoke(ctx, SongService_GetSong_FullMethodName, in, out, cOpts...)
@@ -89,6 +196,16 @@ func (c *songServiceClient) CreateSong(ctx context.Context, in *CreateSongReques
 	return out, nil
 }
 
+func (c *songServiceClient) ValidateSong(ctx context.Context, in *CreateSongRequest, opts ...grpc.CallOption) (*ValidateSongResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ValidateSongResponse)
+	err := c.cc.Invoke(ctx, SongService_ValidateSong_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *songServiceClient) UpdateSong(ctx context.Context, in *UpdateSongRequest, opts ...grpc.CallOption) (*SongDetails, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(SongDetails)
@@ -99,7 +216,7 @@ func (c *songServiceClient) UpdateSong(ctx context.Context, in *UpdateSongReques
 	return out, nil
 }
 
-func (c *songServiceClient) DeleteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+func (c *songServiceClient) DeleteSong(ctx context.Context, in *DeleteSongRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(emptypb.Empty)
 	err := c.cc.Invoke(ctx, SongService_DeleteSong_FullMethodName, in, out, cOpts...)
@@ -129,6 +246,146 @@ func (c *songServiceClient) LeaveRole(ctx context.Context, in *LeaveRoleRequest,
 	return out, nil
 }
 
+func (c *songServiceClient) UpdateRole(ctx context.Context, in *UpdateRoleRequest, opts ...grpc.CallOption) (*SongDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongDetails)
+	err := c.cc.Invoke(ctx, SongService_UpdateRole_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ReplaceParticipants(ctx context.Context, in *ReplaceParticipantsRequest, opts ...grpc.CallOption) (*SongDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongDetails)
+	err := c.cc.Invoke(ctx, SongService_ReplaceParticipants_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListParticipations(ctx context.Context, in *ListParticipationsRequest, opts ...grpc.CallOption) (*ListParticipationsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListParticipationsResponse)
+	err := c.cc.Invoke(ctx, SongService_ListParticipations_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListParticipationsSince(ctx context.Context, in *ListParticipationsSinceRequest, opts ...grpc.CallOption) (*ListParticipationsSinceResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListParticipationsSinceResponse)
+	err := c.cc.Invoke(ctx, SongService_ListParticipationsSince_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListRecentParticipations(ctx context.Context, in *ListRecentParticipationsRequest, opts ...grpc.CallOption) (*ListParticipationsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListParticipationsResponse)
+	err := c.cc.Invoke(ctx, SongService_ListRecentParticipations_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) CountParticipationsByRole(ctx context.Context, in *CountParticipationsByRoleRequest, opts ...grpc.CallOption) (*CountParticipationsByRoleResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(CountParticipationsByRoleResponse)
+	err := c.cc.Invoke(ctx, SongService_CountParticipationsByRole_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) CheckSongLinks(ctx context.Context, in *CheckSongLinksRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, SongService_CheckSongLinks_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) GetLinkStatus(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*LinkStatus, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(LinkStatus)
+	err := c.cc.Invoke(ctx, SongService_GetLinkStatus_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) ListSongTags(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongTags, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongTags)
+	err := c.cc.Invoke(ctx, SongService_ListSongTags_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) AddSongTag(ctx context.Context, in *SongTagRequest, opts ...grpc.CallOption) (*SongTags, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongTags)
+	err := c.cc.Invoke(ctx, SongService_AddSongTag_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) RemoveSongTag(ctx context.Context, in *SongTagRequest, opts ...grpc.CallOption) (*SongTags, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongTags)
+	err := c.cc.Invoke(ctx, SongService_RemoveSongTag_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) VoteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongDetails)
+	err := c.cc.Invoke(ctx, SongService_VoteSong_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) UnvoteSong(ctx context.Context, in *SongId, opts ...grpc.CallOption) (*SongDetails, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SongDetails)
+	err := c.cc.Invoke(ctx, SongService_UnvoteSong_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *songServiceClient) BatchTagSongs(ctx context.Context, in *BatchTagSongsRequest, opts ...grpc.CallOption) (*BatchTagSongsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(BatchTagSongsResponse)
+	err := c.cc.Invoke(ctx, SongService_BatchTagSongs_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // SongServiceServer is the server API for SongService service.
 // All implementations must embed UnimplementedSongServiceServer
 // for forward compatibility.
@@ -137,18 +394,57 @@ func (c *songServiceClient) LeaveRole(ctx context.Context, in *LeaveRoleRequest,
 type SongServiceServer interface {
 	// Returns a paginated list of songs.
 	ListSongs(context.Context, *ListSongsRequest) (*ListSongsResponse, error)
+	// Streams every song matching a search, for "select all" without paging.
+	// Songs carry only catalog fields: no roles, counts or per-user fields.
+	StreamSearchSongs(*StreamSearchSongsRequest, grpc.ServerStreamingServer[Song]) error
+	// Returns the most recently added songs, newest first.
+	ListRecentSongs(context.Context, *ListRecentSongsRequest) (*ListSongsResponse, error)
+	// Returns songs that are not yet in an event's tracklist.
+	ListSongsNotInEvent(context.Context, *ListSongsNotInEventRequest) (*ListSongsResponse, error)
+	// Returns songs ranked by vote count, most voted first.
+	ListSongsByVotes(context.Context, *ListSongsByVotesRequest) (*ListSongsResponse, error)
 	// Returns a single song with full metadata and assignments.
-	GetSong(context.Context, *SongId) (*SongDetails, error)
+	GetSong(context.Context, *GetSongRequest) (*SongDetails, error)
 	// Create songs (requires permissions).
 	CreateSong(context.Context, *CreateSongRequest) (*SongDetails, error)
+	// Runs the CreateSong checks without inserting anything.
+	ValidateSong(context.Context, *CreateSongRequest) (*ValidateSongResponse, error)
 	// Update songs (requires permissions).
 	UpdateSong(context.Context, *UpdateSongRequest) (*SongDetails, error)
 	// Delete songs (requires permissions).
-	DeleteSong(context.Context, *SongId) (*emptypb.Empty, error)
+	DeleteSong(context.Context, *DeleteSongRequest) (*emptypb.Empty, error)
 	// Join a role for a song.
 	JoinRole(context.Context, *JoinRoleRequest) (*SongDetails, error)
 	// Leave a role for a song.
 	LeaveRole(context.Context, *LeaveRoleRequest) (*SongDetails, error)
+	// Move an existing role assignment to another role.
+	UpdateRole(context.Context, *UpdateRoleRequest) (*SongDetails, error)
+	// Replace all role assignments of a song in one call (admins only).
+	ReplaceParticipants(context.Context, *ReplaceParticipantsRequest) (*SongDetails, error)
+	// Lists role assignments across songs together with song titles.
+	ListParticipations(context.Context, *ListParticipationsRequest) (*ListParticipationsResponse, error)
+	// Lists role assignments created, changed or removed after a watermark, oldest first.
+	ListParticipationsSince(context.Context, *ListParticipationsSinceRequest) (*ListParticipationsSinceResponse, error)
+	// Returns a person's latest signups across songs, newest first.
+	ListRecentParticipations(context.Context, *ListRecentParticipationsRequest) (*ListParticipationsResponse, error)
+	// Counts role assignments per role across all songs, most common first.
+	CountParticipationsByRole(context.Context, *CountParticipationsByRoleRequest) (*CountParticipationsByRoleResponse, error)
+	// Queues background health checks for song links.
+	CheckSongLinks(context.Context, *CheckSongLinksRequest) (*emptypb.Empty, error)
+	// Returns the latest link health check result for a song.
+	GetLinkStatus(context.Context, *SongId) (*LinkStatus, error)
+	// Lists the tags of a song.
+	ListSongTags(context.Context, *SongId) (*SongTags, error)
+	// Adds a tag to a song (requires permissions).
+	AddSongTag(context.Context, *SongTagRequest) (*SongTags, error)
+	// Removes a tag from a song (requires permissions).
+	RemoveSongTag(context.Context, *SongTagRequest) (*SongTags, error)
+	// Upvotes a song for the current user; voting twice has no effect.
+	VoteSong(context.Context, *SongId) (*SongDetails, error)
+	// Withdraws the current user's vote.
+	UnvoteSong(context.Context, *SongId) (*SongDetails, error)
+	// Adds one tag to many songs in a single transaction (requires permissions).
+	BatchTagSongs(context.Context, *BatchTagSongsRequest) (*BatchTagSongsResponse, error)
 	mustEmbedUnimplementedSongServiceServer()
 }
 
@@ -162,16 +458,31 @@ type UnimplementedSongServiceServer struct{}
 func (UnimplementedSongServiceServer) ListSongs(context.Context, *ListSongsRequest) (*ListSongsResponse, error) {
 	return nil, status.Error(codes.Unimplemented, "method ListSongs not implemented")
 }
-func (UnimplementedSongServiceServer) GetSong(context.Context, *SongId) (*SongDetails, error) {
+func (UnimplementedSongServiceServer) StreamSearchSongs(*StreamSearchSongsRequest, grpc.ServerStreamingServer[Song]) error {
+	return status.Error(codes.Unimplemented, "method StreamSearchSongs not implemented")
+}
+func (UnimplementedSongServiceServer) ListRecentSongs(context.Context, *ListRecentSongsRequest) (*ListSongsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListRecentSongs not implemented")
+}
+func (UnimplementedSongServiceServer) ListSongsNotInEvent(context.Context, *ListSongsNotInEventRequest) (*ListSongsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListSongsNotInEvent not implemented")
+}
+func (UnimplementedSongServiceServer) ListSongsByVotes(context.Context, *ListSongsByVotesRequest) (*ListSongsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListSongsByVotes not implemented")
+}
+func (UnimplementedSongServiceServer) GetSong(context.Context, *GetSongRequest) (*SongDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method GetSong not implemented")
 }
 func (UnimplementedSongServiceServer) CreateSong(context.Context, *CreateSongRequest) (*SongDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method CreateSong not implemented")
 }
+func (UnimplementedSongServiceServer) ValidateSong(context.Context, *CreateSongRequest) (*ValidateSongResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ValidateSong not implemented")
+}
 func (UnimplementedSongServiceServer) UpdateSong(context.Context, *UpdateSongRequest) (*SongDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method UpdateSong not implemented")
 }
-func (UnimplementedSongServiceServer) DeleteSong(context.Context, *SongId) (*emptypb.Empty, error) {
+func (UnimplementedSongServiceServer) DeleteSong(context.Context, *DeleteSongRequest) (*emptypb.Empty, error) {
 	return nil, status.Error(codes.Unimplemented, "method DeleteSong not implemented")
 }
 func (UnimplementedSongServiceServer) JoinRole(context.Context, *JoinRoleRequest) (*SongDetails, error) {
@@ -180,6 +491,48 @@ func (UnimplementedSongServiceServer) JoinRole(context.Context, *JoinRoleRequest
 func (UnimplementedSongServiceServer) LeaveRole(context.Context, *LeaveRoleRequest) (*SongDetails, error) {
 	return nil, status.Error(codes.Unimplemented, "method LeaveRole not implemented")
 }
+func (UnimplementedSongServiceServer) UpdateRole(context.Context, *UpdateRoleRequest) (*SongDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method UpdateRole not implemented")
+}
+func (UnimplementedSongServiceServer) ReplaceParticipants(context.Context, *ReplaceParticipantsRequest) (*SongDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method ReplaceParticipants not implemented")
+}
+func (UnimplementedSongServiceServer) ListParticipations(context.Context, *ListParticipationsRequest) (*ListParticipationsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListParticipations not implemented")
+}
+func (UnimplementedSongServiceServer) ListParticipationsSince(context.Context, *ListParticipationsSinceRequest) (*ListParticipationsSinceResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListParticipationsSince not implemented")
+}
+func (UnimplementedSongServiceServer) ListRecentParticipations(context.Context, *ListRecentParticipationsRequest) (*ListParticipationsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListRecentParticipations not implemented")
+}
+func (UnimplementedSongServiceServer) CountParticipationsByRole(context.Context, *CountParticipationsByRoleRequest) (*CountParticipationsByRoleResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method CountParticipationsByRole not implemented")
+}
+func (UnimplementedSongServiceServer) CheckSongLinks(context.Context, *CheckSongLinksRequest) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method CheckSongLinks not implemented")
+}
+func (UnimplementedSongServiceServer) GetLinkStatus(context.Context, *SongId) (*LinkStatus, error) {
+	return nil, status.Error(codes.Unimplemented, "method GetLinkStatus not implemented")
+}
+func (UnimplementedSongServiceServer) ListSongTags(context.Context, *SongId) (*SongTags, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListSongTags not implemented")
+}
+func (UnimplementedSongServiceServer) AddSongTag(context.Context, *SongTagRequest) (*SongTags, error) {
+	return nil, status.Error(codes.Unimplemented, "method AddSongTag not implemented")
+}
+func (UnimplementedSongServiceServer) RemoveSongTag(context.Context, *SongTagRequest) (*SongTags, error) {
+	return nil, status.Error(codes.Unimplemented, "method RemoveSongTag not implemented")
+}
+func (UnimplementedSongServiceServer) VoteSong(context.Context, *SongId) (*SongDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method VoteSong not implemented")
+}
+func (UnimplementedSongServiceServer) UnvoteSong(context.Context, *SongId) (*SongDetails, error) {
+	return nil, status.Error(codes.Unimplemented, "method UnvoteSong not implemented")
+}
+func (UnimplementedSongServiceServer) BatchTagSongs(context.Context, *BatchTagSongsRequest) (*BatchTagSongsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method BatchTagSongs not implemented")
+}
 func (UnimplementedSongServiceServer) mustEmbedUnimplementedSongServiceServer() {}
 func (UnimplementedSongServiceServer) testEmbeddedByValue()                     {}
 
@@ -219,8 +572,73 @@ func _SongService_ListSongs_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SongService_StreamSearchSongs_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(StreamSearchSongsRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(SongServiceServer).StreamSearchSongs(m, &grpc.GenericServerStream[StreamSearchSongsRequest, Song]{ServerStream: stream})
+}
+
+// This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
+type SongService_StreamSearchSongsServer = grpc.ServerStreamingServer[Song]
+
+func _SongService_ListRecentSongs_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListRecentSongsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListRecentSongs(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListRecentSongs_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListRecentSongs(ctx, req.(*ListRecentSongsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListSongsNotInEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListSongsNotInEventRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListSongsNotInEvent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListSongsNotInEvent_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListSongsNotInEvent(ctx, req.(*ListSongsNotInEventRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListSongsByVotes_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListSongsByVotesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListSongsByVotes(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListSongsByVotes_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListSongsByVotes(ctx, req.(*ListSongsByVotesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _SongService_GetSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(SongId)
+	in := new(GetSongRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
@@ -232,7 +650,7 @@ func _SongService_GetSong_Handler(srv interface{}, ctx context.Context, dec func
 		FullMethod: SongService_GetSong_FullMethodName,
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(SongServiceServer).GetSong(ctx, req.(*SongId))
+		return srv.(SongServiceServer).GetSong(ctx, req.(*GetSongRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
@@ -255,6 +673,24 @@ func _SongService_CreateSong_Handler(srv interface{}, ctx context.Context, dec f
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SongService_ValidateSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateSongRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ValidateSong(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ValidateSong_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ValidateSong(ctx, req.(*CreateSongRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _SongService_UpdateSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(UpdateSongRequest)
 	if err := dec(in); err != nil {
@@ -274,7 +710,7 @@ func _SongService_UpdateSong_Handler(srv interface{}, ctx context.Context, dec f
 }
 
 func _SongService_DeleteSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(SongId)
+	in := new(DeleteSongRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
@@ -286,7 +722,7 @@ func _SongService_DeleteSong_Handler(srv interface{}, ctx context.Context, dec f
 		FullMethod: SongService_DeleteSong_FullMethodName,
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(SongServiceServer).DeleteSong(ctx, req.(*SongId))
+		return srv.(SongServiceServer).DeleteSong(ctx, req.(*DeleteSongRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
@@ -327,6 +763,258 @@ func _SongService_LeaveRole_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SongService_UpdateRole_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateRoleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).UpdateRole(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_UpdateRole_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).UpdateRole(ctx, req.(*UpdateRoleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ReplaceParticipants_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReplaceParticipantsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ReplaceParticipants(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ReplaceParticipants_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ReplaceParticipants(ctx, req.(*ReplaceParticipantsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListParticipations_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListParticipationsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListParticipations(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListParticipations_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListParticipations(ctx, req.(*ListParticipationsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListParticipationsSince_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListParticipationsSinceRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListParticipationsSince(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListParticipationsSince_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListParticipationsSince(ctx, req.(*ListParticipationsSinceRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListRecentParticipations_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListRecentParticipationsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListRecentParticipations(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListRecentParticipations_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListRecentParticipations(ctx, req.(*ListRecentParticipationsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_CountParticipationsByRole_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CountParticipationsByRoleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).CountParticipationsByRole(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_CountParticipationsByRole_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).CountParticipationsByRole(ctx, req.(*CountParticipationsByRoleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_CheckSongLinks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CheckSongLinksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).CheckSongLinks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_CheckSongLinks_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).CheckSongLinks(ctx, req.(*CheckSongLinksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_GetLinkStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).GetLinkStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_GetLinkStatus_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).GetLinkStatus(ctx, req.(*SongId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_ListSongTags_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).ListSongTags(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_ListSongTags_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).ListSongTags(ctx, req.(*SongId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_AddSongTag_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongTagRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).AddSongTag(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_AddSongTag_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).AddSongTag(ctx, req.(*SongTagRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_RemoveSongTag_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongTagRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).RemoveSongTag(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_RemoveSongTag_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).RemoveSongTag(ctx, req.(*SongTagRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_VoteSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).VoteSong(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_VoteSong_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).VoteSong(ctx, req.(*SongId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_UnvoteSong_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SongId)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).UnvoteSong(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_UnvoteSong_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).UnvoteSong(ctx, req.(*SongId))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SongService_BatchTagSongs_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchTagSongsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SongServiceServer).BatchTagSongs(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SongService_BatchTagSongs_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SongServiceServer).BatchTagSongs(ctx, req.(*BatchTagSongsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // SongService_ServiceDesc is the grpc.ServiceDesc for SongService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -338,6 +1026,18 @@ var SongService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "ListSongs",
 			Handler:    _SongService_ListSongs_Handler,
 		},
+		{
+			MethodName: "ListRecentSongs",
+			Handler:    _SongService_ListRecentSongs_Handler,
+		},
+		{
+			MethodName: "ListSongsNotInEvent",
+			Handler:    _SongService_ListSongsNotInEvent_Handler,
+		},
+		{
+			MethodName: "ListSongsByVotes",
+			Handler:    _SongService_ListSongsByVotes_Handler,
+		},
 		{
 			MethodName: "GetSong",
 			Handler:    _SongService_GetSong_Handler,
@@ -346,6 +1046,10 @@ var SongService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "CreateSong",
 			Handler:    _SongService_CreateSong_Handler,
 		},
+		{
+			MethodName: "ValidateSong",
+			Handler:    _SongService_ValidateSong_Handler,
+		},
 		{
 			MethodName: "UpdateSong",
 			Handler:    _SongService_UpdateSong_Handler,
@@ -362,7 +1066,69 @@ var SongService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "LeaveRole",
 			Handler:    _SongService_LeaveRole_Handler,
 		},
+		{
+			MethodName: "UpdateRole",
+			Handler:    _SongService_UpdateRole_Handler,
+		},
+		{
+			MethodName: "ReplaceParticipants",
+			Handler:    _SongService_ReplaceParticipants_Handler,
+		},
+		{
+			MethodName: "ListParticipations",
+			Handler:    _SongService_ListParticipations_Handler,
+		},
+		{
+			MethodName: "ListParticipationsSince",
+			Handler:    _SongService_ListParticipationsSince_Handler,
+		},
+		{
+			MethodName: "ListRecentParticipations",
+			Handler:    _SongService_ListRecentParticipations_Handler,
+		},
+		{
+			MethodName: "CountParticipationsByRole",
+			Handler:    _SongService_CountParticipationsByRole_Handler,
+		},
+		{
+			MethodName: "CheckSongLinks",
+			Handler:    _SongService_CheckSongLinks_Handler,
+		},
+		{
+			MethodName: "GetLinkStatus",
+			Handler:    _SongService_GetLinkStatus_Handler,
+		},
+		{
+			MethodName: "ListSongTags",
+			Handler:    _SongService_ListSongTags_Handler,
+		},
+		{
+			MethodName: "AddSongTag",
+			Handler:    _SongService_AddSongTag_Handler,
+		},
+		{
+			MethodName: "RemoveSongTag",
+			Handler:    _SongService_RemoveSongTag_Handler,
+		},
+		{
+			MethodName: "VoteSong",
+			Handler:    _SongService_VoteSong_Handler,
+		},
+		{
+			MethodName: "UnvoteSong",
+			Handler:    _SongService_UnvoteSong_Handler,
+		},
+		{
+			MethodName: "BatchTagSongs",
+			Handler:    _SongService_BatchTagSongs_Handler,
+		},
+	},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "StreamSearchSongs",
+			Handler:       _SongService_StreamSearchSongs_Handler,
+			ServerStreams: true,
+		},
 	},
-	Streams:  []grpc.StreamDesc{},
 	Metadata: "song.proto",
 }
@@ -2,6 +2,7 @@ import React, { useMemo, useState } from "react";
 import { useQuery, useQueryClient } from "@tanstack/react-query";
 import { createEvent, getEvent, listEvents, setTracklist, updateEvent } from "../services/api";
 import type { PermissionSet } from "../proto/permissions_pb";
+import { EventStatus } from "../proto/event_pb";
 import type { Event, EventDetails } from "../proto/event_pb";
 import CreateEventForm from "./forms/CreateEventForm";
 import type { Timestamp } from "@bufbuild/protobuf/wkt";
@@ -72,7 +73,7 @@ const EventList: React.FC<Props> = ({ permissions }) => {
 				<EventDetailsCard
 					data={detailQuery.data}
 					onClose={() => setSelectedId(null)}
-					onUpdate={async (payload: { title: string; startAt?: Timestamp; location?: string; notifyDayBefore?: boolean; notifyHourBefore?: boolean }) => {
+					onUpdate={async (payload: { title: string; startAt?: Timestamp; location?: string; notifyDayBefore?: boolean; notifyHourBefore?: boolean; status?: EventStatus }) => {
 						await updateEvent({ ...payload, id: selectedId });
 						queryClient.invalidateQueries({ queryKey: ["event", selectedId] });
 						queryClient.invalidateQueries({ queryKey: ["events"] });
@@ -96,7 +97,7 @@ const EventList: React.FC<Props> = ({ permissions }) => {
 type EventDetailsCardProps = {
 	data: EventDetails;
 	onClose: () => void;
-	onUpdate: (payload: { title: string; startAt?: Timestamp; location?: string; notifyDayBefore?: boolean; notifyHourBefore?: boolean }) => Promise<void>;
+	onUpdate: (payload: { title: string; startAt?: Timestamp; location?: string; notifyDayBefore?: boolean; notifyHourBefore?: boolean; status?: EventStatus }) => Promise<void>;
 	onSetTracklist?: (items: { order: number; songId: string; customTitle: string; customArtist: string }[]) => Promise<void>;
 	canEditEvents: boolean;
 	canEditTracklists: boolean;
@@ -117,6 +118,14 @@ const EventDetailsCard: React.FC<EventDetailsCardProps> = ({ data, onClose, onUp
 
 	const participants = useMemo(() => data.participants ?? [], [data.participants]);
 
+	const formPayload = () => ({
+		title: form.title,
+		startAt: form.startAt ? toTimestamp(new Date(form.startAt)) : undefined,
+		location: form.location,
+		notifyDayBefore: form.notifyDayBefore,
+		notifyHourBefore: form.notifyHourBefore,
+	});
+
 	return (
 		<div
 			style={{
@@ -145,6 +154,17 @@ const EventDetailsCard: React.FC<EventDetailsCardProps> = ({ data, onClose, onUp
 				</div>
 				<div style={{ color: "var(--muted)", marginBottom: 8 }}>{formatDate(timestampToDate(evt?.startAt as Timestamp | undefined))}</div>
 				{evt?.location && <div className="pill">{evt.location}</div>}
+				{evt?.status === EventStatus.DRAFT && (
+					<div style={{ display: "flex", gap: 8, alignItems: "center", marginTop: 8 }}>
+						<div className="pill">Черновик</div>
+						{canEditEvents && (
+							<button className="button" onClick={() => onUpdate({ ...formPayload(), status: EventStatus.PUBLISHED })}>
+								Опубликовать
+							</button>
+						)}
+					</div>
+				)}
+				{evt?.status === EventStatus.CANCELLED && <div className="pill">Отменено</div>}
 
 				<div style={{ marginTop: 12 }}>
 					<div className="card-title" style={{ marginBottom: 6 }}>
@@ -180,13 +200,7 @@ const EventDetailsCard: React.FC<EventDetailsCardProps> = ({ data, onClose, onUp
 						style={{ marginTop: 12 }}
 						onSubmit={(e) => {
 							e.preventDefault();
-							onUpdate({
-								title: form.title,
-								startAt: form.startAt ? toTimestamp(new Date(form.startAt)) : undefined,
-								location: form.location,
-								notifyDayBefore: form.notifyDayBefore,
-								notifyHourBefore: form.notifyHourBefore,
-							});
+							onUpdate(formPayload());
 						}}
 					>
 						<div className="card-title">Редактировать</div>
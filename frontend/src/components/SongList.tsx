@@ -49,6 +49,7 @@ const SongList: React.FC<Props> = ({ permissions, profile }) => {
 	});
 
 	const canCreate = Boolean(permissions?.songs?.editAnySongs || permissions?.songs?.editOwnSongs);
+	const isAdmin = Boolean(permissions?.admin);
 
 	return (
 		<div className="card">
@@ -98,7 +99,7 @@ const SongList: React.FC<Props> = ({ permissions, profile }) => {
 					onJoin={(role) => joinMutation.mutate({ songId: selectedId, role })}
 					onLeave={(role) => leaveMutation.mutate({ songId: selectedId, role })}
 					onUpdate={async (payload) => {
-						await updateSong({ ...payload, id: selectedId });
+						await updateSong({ ...payload, id: selectedId, notes: isAdmin ? detailQuery.data?.song?.notes : undefined });
 						queryClient.invalidateQueries({ queryKey: ["song", selectedId] });
 						queryClient.invalidateQueries({ queryKey: ["songs"] });
 					}}
@@ -15,6 +15,7 @@ type Props = {
 		linkKind: SongLinkType;
 		roles: string[];
 		thumbnailUrl?: string;
+		durationSeconds?: number;
 	}) => Promise<void>;
 	onDelete: () => Promise<void>;
 	canEdit: boolean;
@@ -32,6 +33,8 @@ const SongModal: React.FC<Props> = ({ details, onClose, onJoin, onLeave, onUpdat
 		linkKind: (song?.link?.kind ?? 0) as SongLinkType,
 		roles: song?.availableRoles ?? [],
 		thumbnailUrl: song?.thumbnailUrl ?? "",
+		// Not editable here; sent back so saving does not clear it.
+		durationSeconds: song?.durationSeconds ?? 0,
 	});
 
 	const assignments = details.assignments ?? [];
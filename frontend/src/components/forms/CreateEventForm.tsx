@@ -2,6 +2,7 @@ import React, { useState } from "react";
 import { create } from "@bufbuild/protobuf";
 import { TimestampSchema } from "@bufbuild/protobuf/wkt";
 import type { Timestamp } from "@bufbuild/protobuf/wkt";
+import { EventStatus } from "../../proto/event_pb";
 
 type Props = {
 	onSubmit: (payload: {
@@ -11,6 +12,7 @@ type Props = {
 		notifyDayBefore?: boolean;
 		notifyHourBefore?: boolean;
 		tracklist?: { order: number; songId: string; customTitle: string; customArtist: string }[];
+		status?: EventStatus;
 	}) => Promise<void>;
 };
 
@@ -21,6 +23,7 @@ const CreateEventForm: React.FC<Props> = ({ onSubmit }) => {
 		location: "",
 		notifyDayBefore: false,
 		notifyHourBefore: false,
+		publish: true,
 		tracklistText: "",
 	});
 	const [isSaving, setIsSaving] = useState(false);
@@ -52,8 +55,9 @@ const CreateEventForm: React.FC<Props> = ({ onSubmit }) => {
 							};
 						})
 					: undefined,
+				status: form.publish ? EventStatus.PUBLISHED : EventStatus.DRAFT,
 			});
-			setForm({ title: "", startAt: "", location: "", notifyDayBefore: false, notifyHourBefore: false, tracklistText: "" });
+			setForm({ title: "", startAt: "", location: "", notifyDayBefore: false, notifyHourBefore: false, publish: true, tracklistText: "" });
 		} catch (err) {
 			setError((err as Error).message);
 		} finally {
@@ -81,6 +85,10 @@ const CreateEventForm: React.FC<Props> = ({ onSubmit }) => {
 				<input type="checkbox" checked={form.notifyHourBefore} onChange={(e) => setForm({ ...form, notifyHourBefore: e.target.checked })} />
 				Напомнить за час
 			</label>
+			<label style={{ display: "flex", gap: 8, alignItems: "center" }}>
+				<input type="checkbox" checked={form.publish} onChange={(e) => setForm({ ...form, publish: e.target.checked })} />
+				Опубликовать сразу
+			</label>
 			<textarea
 				className="textarea"
 				rows={4}
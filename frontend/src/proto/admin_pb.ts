@@ -0,0 +1,522 @@
+// @generated by protoc-gen-es v2.10.2 with parameter "target=ts,import_extension=ts"
+// @generated from file admin.proto (package musicclub.admin, syntax proto3)
+/* eslint-disable */
+
+import type { GenEnum, GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
+import { enumDesc, fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
+import type { EventDetailsSchema, EventIdSchema } from "./event_pb.ts";
+import { file_event } from "./event_pb.ts";
+import type { SongLink } from "./song_pb.ts";
+import { file_song } from "./song_pb.ts";
+import type { PermissionSetSchema } from "./permissions_pb.ts";
+import { file_permissions } from "./permissions_pb.ts";
+import type { Message } from "@bufbuild/protobuf";
+
+/**
+ * Describes the file admin.proto.
+ */
+export const file_admin: GenFile = /*@__PURE__*/
+  fileDesc("CgthZG1pbi5wcm90bxIPbXVzaWNjbHViLmFkbWluIkUKE0V4cGxhaW5RdWVyeVJlcXVlc3QSLgoGdGFyZ2V0GAEgASgOMh4ubXVzaWNjbHViLmFkbWluLkV4cGxhaW5UYXJnZXQiKQoURXhwbGFpblF1ZXJ5UmVzcG9uc2USEQoJcGxhbl9qc29uGAEgASgJIjkKEU1lcmdlU29uZ3NSZXF1ZXN0EhEKCXNvdXJjZV9pZBgBIAEoCRIRCgl0YXJnZXRfaWQYAiABKAkiNAoSTWVyZ2VTb25nc1Jlc3BvbnNlEg0KBW1vdmVkGAEgASgFEg8KB3NraXBwZWQYAiABKAUiIwoQQWRtaW5Vc2VyUmVxdWVzdBIPCgd1c2VyX2lkGAEgASgJIkkKHVRyYW5zZmVyUGFydGljaXBhdGlvbnNSZXF1ZXN0EhQKDGZyb21fdXNlcl9pZBgBIAEoCRISCgp0b191c2VyX2lkGAIgASgJIkAKHlRyYW5zZmVyUGFydGljaXBhdGlvbnNSZXNwb25zZRINCgVtb3ZlZBgBIAEoBRIPCgdza2lwcGVkGAIgASgFIi0KEVJlbmFtZVJvbGVSZXF1ZXN0EgwKBGZyb20YASABKAkSCgoCdG8YAiABKAkiNAoSUmVuYW1lUm9sZVJlc3BvbnNlEg0KBW1vdmVkGAEgASgFEg8KB3NraXBwZWQYAiABKAUiRQoKTGlua1VwZGF0ZRIPCgdzb25nX2lkGAEgASgJEiYKBGxpbmsYAiABKAsyGC5tdXNpY2NsdWIuc29uZy5Tb25nTGluayJHChdCYXRjaFVwZGF0ZUxpbmtzUmVxdWVzdBIsCgd1cGRhdGVzGAEgAygLMhsubXVzaWNjbHViLmFkbWluLkxpbmtVcGRhdGUiZQoQTGlua1VwZGF0ZVJlc3VsdBIPCgdzb25nX2lkGAEgASgJEjEKBnN0YXR1cxgCIAEoDjIhLm11c2ljY2x1Yi5hZG1pbi5MaW5rVXBkYXRlU3RhdHVzEg0KBWVycm9yGAMgASgJIk4KGEJhdGNoVXBkYXRlTGlua3NSZXNwb25zZRIyCgdyZXN1bHRzGAEgAygLMiEubXVzaWNjbHViLmFkbWluLkxpbmtVcGRhdGVSZXN1bHQiFwoVU2VlZFNhbXBsZURhdGFSZXF1ZXN0IlwKFlNlZWRTYW1wbGVEYXRhUmVzcG9uc2USEAoIc29uZ19pZHMYASADKAkSEAoIZXZlbnRfaWQYAiABKAkSHgoWcGFydGljaXBhdGlvbnNfY3JlYXRlZBgDIAEoDSq4AQoNRXhwbGFpblRhcmdldBIaChZFWFBMQUlOX1RBUkdFVF9VTktOT1dOEAASHQoZRVhQTEFJTl9UQVJHRVRfTElTVF9TT05HUxABEh8KG0VYUExBSU5fVEFSR0VUX1NFQVJDSF9TT05HUxACEisKJ0VYUExBSU5fVEFSR0VUX0xJU1RfQVNTSUdOTUVOVFNfQllfU09ORxADEh4KGkVYUExBSU5fVEFSR0VUX0xJU1RfRVZFTlRTEAQqlAEKEExpbmtVcGRhdGVTdGF0dXMSHgoaTElOS19VUERBVEVfU1RBVFVTX1VOS05PV04QABIeChpMSU5LX1VQREFURV9TVEFUVVNfVVBEQVRFRBABEiAKHExJTktfVVBEQVRFX1NUQVRVU19OT1RfRk9VTkQQAhIeChpMSU5LX1VQREFURV9TVEFUVVNfSU5WQUxJRBADMtgGCgxBZG1pblNlcnZpY2USWwoMRXhwbGFpblF1ZXJ5EiQubXVzaWNjbHViLmFkbWluLkV4cGxhaW5RdWVyeVJlcXVlc3QaJS5tdXNpY2NsdWIuYWRtaW4uRXhwbGFpblF1ZXJ5UmVzcG9uc2USVQoKTWVyZ2VTb25ncxIiLm11c2ljY2x1Yi5hZG1pbi5NZXJnZVNvbmdzUmVxdWVzdBojLm11c2ljY2x1Yi5hZG1pbi5NZXJnZVNvbmdzUmVzcG9uc2USZwoQQmF0Y2hVcGRhdGVMaW5rcxIoLm11c2ljY2x1Yi5hZG1pbi5CYXRjaFVwZGF0ZUxpbmtzUmVxdWVzdBopLm11c2ljY2x1Yi5hZG1pbi5CYXRjaFVwZGF0ZUxpbmtzUmVzcG9uc2USeQoWVHJhbnNmZXJQYXJ0aWNpcGF0aW9ucxIuLm11c2ljY2x1Yi5hZG1pbi5UcmFuc2ZlclBhcnRpY2lwYXRpb25zUmVxdWVzdBovLm11c2ljY2x1Yi5hZG1pbi5UcmFuc2ZlclBhcnRpY2lwYXRpb25zUmVzcG9uc2USVQoKUmVuYW1lUm9sZRIiLm11c2ljY2x1Yi5hZG1pbi5SZW5hbWVSb2xlUmVxdWVzdBojLm11c2ljY2x1Yi5hZG1pbi5SZW5hbWVSb2xlUmVzcG9uc2USVQoKR3JhbnRBZG1pbhIhLm11c2ljY2x1Yi5hZG1pbi5BZG1pblVzZXJSZXF1ZXN0GiQubXVzaWNjbHViLnBlcm1pc3Npb25zLlBlcm1pc3Npb25TZXQSVgoLUmV2b2tlQWRtaW4SIS5tdXNpY2NsdWIuYWRtaW4uQWRtaW5Vc2VyUmVxdWVzdBokLm11c2ljY2x1Yi5wZXJtaXNzaW9ucy5QZXJtaXNzaW9uU2V0EkcKDFJlc3RvcmVFdmVudBIYLm11c2ljY2x1Yi5ldmVudC5FdmVudElkGh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJhCg5TZWVkU2FtcGxlRGF0YRImLm11c2ljY2x1Yi5hZG1pbi5TZWVkU2FtcGxlRGF0YVJlcXVlc3QaJy5tdXNpY2NsdWIuYWRtaW4uU2VlZFNhbXBsZURhdGFSZXNwb25zZUIcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z", [file_event, file_song, file_permissions]);
+
+/**
+ * @generated from message musicclub.admin.ExplainQueryRequest
+ */
+export type ExplainQueryRequest = Message<"musicclub.admin.ExplainQueryRequest"> & {
+  /**
+   * @generated from field: musicclub.admin.ExplainTarget target = 1;
+   */
+  target: ExplainTarget;
+};
+
+/**
+ * Describes the message musicclub.admin.ExplainQueryRequest.
+ * Use `create(ExplainQueryRequestSchema)` to create a new message.
+ */
+export const ExplainQueryRequestSchema: GenMessage<ExplainQueryRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 0);
+
+/**
+ * @generated from message musicclub.admin.ExplainQueryResponse
+ */
+export type ExplainQueryResponse = Message<"musicclub.admin.ExplainQueryResponse"> & {
+  /**
+   * Plan produced by EXPLAIN (FORMAT JSON).
+   *
+   * @generated from field: string plan_json = 1;
+   */
+  planJson: string;
+};
+
+/**
+ * Describes the message musicclub.admin.ExplainQueryResponse.
+ * Use `create(ExplainQueryResponseSchema)` to create a new message.
+ */
+export const ExplainQueryResponseSchema: GenMessage<ExplainQueryResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 1);
+
+/**
+ * @generated from message musicclub.admin.MergeSongsRequest
+ */
+export type MergeSongsRequest = Message<"musicclub.admin.MergeSongsRequest"> & {
+  /**
+   * Duplicate song that is removed after the merge.
+   *
+   * @generated from field: string source_id = 1;
+   */
+  sourceId: string;
+
+  /**
+   * Song that receives the participations.
+   *
+   * @generated from field: string target_id = 2;
+   */
+  targetId: string;
+};
+
+/**
+ * Describes the message musicclub.admin.MergeSongsRequest.
+ * Use `create(MergeSongsRequestSchema)` to create a new message.
+ */
+export const MergeSongsRequestSchema: GenMessage<MergeSongsRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 2);
+
+/**
+ * @generated from message musicclub.admin.MergeSongsResponse
+ */
+export type MergeSongsResponse = Message<"musicclub.admin.MergeSongsResponse"> & {
+  /**
+   * Participations moved to the target song.
+   *
+   * @generated from field: int32 moved = 1;
+   */
+  moved: number;
+
+  /**
+   * Participations dropped because the target already had them.
+   *
+   * @generated from field: int32 skipped = 2;
+   */
+  skipped: number;
+};
+
+/**
+ * Describes the message musicclub.admin.MergeSongsResponse.
+ * Use `create(MergeSongsResponseSchema)` to create a new message.
+ */
+export const MergeSongsResponseSchema: GenMessage<MergeSongsResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 3);
+
+/**
+ * @generated from message musicclub.admin.AdminUserRequest
+ */
+export type AdminUserRequest = Message<"musicclub.admin.AdminUserRequest"> & {
+  /**
+   * @generated from field: string user_id = 1;
+   */
+  userId: string;
+};
+
+/**
+ * Describes the message musicclub.admin.AdminUserRequest.
+ * Use `create(AdminUserRequestSchema)` to create a new message.
+ */
+export const AdminUserRequestSchema: GenMessage<AdminUserRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 4);
+
+/**
+ * @generated from message musicclub.admin.TransferParticipationsRequest
+ */
+export type TransferParticipationsRequest = Message<"musicclub.admin.TransferParticipationsRequest"> & {
+  /**
+   * @generated from field: string from_user_id = 1;
+   */
+  fromUserId: string;
+
+  /**
+   * @generated from field: string to_user_id = 2;
+   */
+  toUserId: string;
+};
+
+/**
+ * Describes the message musicclub.admin.TransferParticipationsRequest.
+ * Use `create(TransferParticipationsRequestSchema)` to create a new message.
+ */
+export const TransferParticipationsRequestSchema: GenMessage<TransferParticipationsRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 5);
+
+/**
+ * @generated from message musicclub.admin.TransferParticipationsResponse
+ */
+export type TransferParticipationsResponse = Message<"musicclub.admin.TransferParticipationsResponse"> & {
+  /**
+   * Participations moved to the target user.
+   *
+   * @generated from field: int32 moved = 1;
+   */
+  moved: number;
+
+  /**
+   * Participations dropped because the target user already had them.
+   *
+   * @generated from field: int32 skipped = 2;
+   */
+  skipped: number;
+};
+
+/**
+ * Describes the message musicclub.admin.TransferParticipationsResponse.
+ * Use `create(TransferParticipationsResponseSchema)` to create a new message.
+ */
+export const TransferParticipationsResponseSchema: GenMessage<TransferParticipationsResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 6);
+
+/**
+ * @generated from message musicclub.admin.RenameRoleRequest
+ */
+export type RenameRoleRequest = Message<"musicclub.admin.RenameRoleRequest"> & {
+  /**
+   * Exact role name to replace, e.g. "Vox".
+   *
+   * @generated from field: string from = 1;
+   */
+  from: string;
+
+  /**
+   * @generated from field: string to = 2;
+   */
+  to: string;
+};
+
+/**
+ * Describes the message musicclub.admin.RenameRoleRequest.
+ * Use `create(RenameRoleRequestSchema)` to create a new message.
+ */
+export const RenameRoleRequestSchema: GenMessage<RenameRoleRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 7);
+
+/**
+ * @generated from message musicclub.admin.RenameRoleResponse
+ */
+export type RenameRoleResponse = Message<"musicclub.admin.RenameRoleResponse"> & {
+  /**
+   * Participations moved to the new role.
+   *
+   * @generated from field: int32 moved = 1;
+   */
+  moved: number;
+
+  /**
+   * Participations dropped because the user already held the new role on that song.
+   *
+   * @generated from field: int32 skipped = 2;
+   */
+  skipped: number;
+};
+
+/**
+ * Describes the message musicclub.admin.RenameRoleResponse.
+ * Use `create(RenameRoleResponseSchema)` to create a new message.
+ */
+export const RenameRoleResponseSchema: GenMessage<RenameRoleResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 8);
+
+/**
+ * @generated from message musicclub.admin.LinkUpdate
+ */
+export type LinkUpdate = Message<"musicclub.admin.LinkUpdate"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * @generated from field: musicclub.song.SongLink link = 2;
+   */
+  link?: SongLink;
+};
+
+/**
+ * Describes the message musicclub.admin.LinkUpdate.
+ * Use `create(LinkUpdateSchema)` to create a new message.
+ */
+export const LinkUpdateSchema: GenMessage<LinkUpdate> = /*@__PURE__*/
+  messageDesc(file_admin, 9);
+
+/**
+ * @generated from message musicclub.admin.BatchUpdateLinksRequest
+ */
+export type BatchUpdateLinksRequest = Message<"musicclub.admin.BatchUpdateLinksRequest"> & {
+  /**
+   * @generated from field: repeated musicclub.admin.LinkUpdate updates = 1;
+   */
+  updates: LinkUpdate[];
+};
+
+/**
+ * Describes the message musicclub.admin.BatchUpdateLinksRequest.
+ * Use `create(BatchUpdateLinksRequestSchema)` to create a new message.
+ */
+export const BatchUpdateLinksRequestSchema: GenMessage<BatchUpdateLinksRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 10);
+
+/**
+ * @generated from message musicclub.admin.LinkUpdateResult
+ */
+export type LinkUpdateResult = Message<"musicclub.admin.LinkUpdateResult"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * @generated from field: musicclub.admin.LinkUpdateStatus status = 2;
+   */
+  status: LinkUpdateStatus;
+
+  /**
+   * Human readable reason for INVALID results.
+   *
+   * @generated from field: string error = 3;
+   */
+  error: string;
+};
+
+/**
+ * Describes the message musicclub.admin.LinkUpdateResult.
+ * Use `create(LinkUpdateResultSchema)` to create a new message.
+ */
+export const LinkUpdateResultSchema: GenMessage<LinkUpdateResult> = /*@__PURE__*/
+  messageDesc(file_admin, 11);
+
+/**
+ * @generated from message musicclub.admin.BatchUpdateLinksResponse
+ */
+export type BatchUpdateLinksResponse = Message<"musicclub.admin.BatchUpdateLinksResponse"> & {
+  /**
+   * One result per update, in request order.
+   *
+   * @generated from field: repeated musicclub.admin.LinkUpdateResult results = 1;
+   */
+  results: LinkUpdateResult[];
+};
+
+/**
+ * Describes the message musicclub.admin.BatchUpdateLinksResponse.
+ * Use `create(BatchUpdateLinksResponseSchema)` to create a new message.
+ */
+export const BatchUpdateLinksResponseSchema: GenMessage<BatchUpdateLinksResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 12);
+
+/**
+ * @generated from message musicclub.admin.SeedSampleDataRequest
+ */
+export type SeedSampleDataRequest = Message<"musicclub.admin.SeedSampleDataRequest"> & {
+};
+
+/**
+ * Describes the message musicclub.admin.SeedSampleDataRequest.
+ * Use `create(SeedSampleDataRequestSchema)` to create a new message.
+ */
+export const SeedSampleDataRequestSchema: GenMessage<SeedSampleDataRequest> = /*@__PURE__*/
+  messageDesc(file_admin, 13);
+
+/**
+ * @generated from message musicclub.admin.SeedSampleDataResponse
+ */
+export type SeedSampleDataResponse = Message<"musicclub.admin.SeedSampleDataResponse"> & {
+  /**
+   * @generated from field: repeated string song_ids = 1;
+   */
+  songIds: string[];
+
+  /**
+   * @generated from field: string event_id = 2;
+   */
+  eventId: string;
+
+  /**
+   * Number of role assignments created for the caller.
+   *
+   * @generated from field: uint32 participations_created = 3;
+   */
+  participationsCreated: number;
+};
+
+/**
+ * Describes the message musicclub.admin.SeedSampleDataResponse.
+ * Use `create(SeedSampleDataResponseSchema)` to create a new message.
+ */
+export const SeedSampleDataResponseSchema: GenMessage<SeedSampleDataResponse> = /*@__PURE__*/
+  messageDesc(file_admin, 14);
+
+/**
+ * Whitelisted queries that may be explained. Arbitrary SQL is never accepted.
+ *
+ * @generated from enum musicclub.admin.ExplainTarget
+ */
+export enum ExplainTarget {
+  /**
+   * @generated from enum value: EXPLAIN_TARGET_UNKNOWN = 0;
+   */
+  UNKNOWN = 0,
+
+  /**
+   * @generated from enum value: EXPLAIN_TARGET_LIST_SONGS = 1;
+   */
+  LIST_SONGS = 1,
+
+  /**
+   * @generated from enum value: EXPLAIN_TARGET_SEARCH_SONGS = 2;
+   */
+  SEARCH_SONGS = 2,
+
+  /**
+   * @generated from enum value: EXPLAIN_TARGET_LIST_ASSIGNMENTS_BY_SONG = 3;
+   */
+  LIST_ASSIGNMENTS_BY_SONG = 3,
+
+  /**
+   * @generated from enum value: EXPLAIN_TARGET_LIST_EVENTS = 4;
+   */
+  LIST_EVENTS = 4,
+}
+
+/**
+ * Describes the enum musicclub.admin.ExplainTarget.
+ */
+export const ExplainTargetSchema: GenEnum<ExplainTarget> = /*@__PURE__*/
+  enumDesc(file_admin, 0);
+
+/**
+ * @generated from enum musicclub.admin.LinkUpdateStatus
+ */
+export enum LinkUpdateStatus {
+  /**
+   * @generated from enum value: LINK_UPDATE_STATUS_UNKNOWN = 0;
+   */
+  UNKNOWN = 0,
+
+  /**
+   * @generated from enum value: LINK_UPDATE_STATUS_UPDATED = 1;
+   */
+  UPDATED = 1,
+
+  /**
+   * @generated from enum value: LINK_UPDATE_STATUS_NOT_FOUND = 2;
+   */
+  NOT_FOUND = 2,
+
+  /**
+   * @generated from enum value: LINK_UPDATE_STATUS_INVALID = 3;
+   */
+  INVALID = 3,
+}
+
+/**
+ * Describes the enum musicclub.admin.LinkUpdateStatus.
+ */
+export const LinkUpdateStatusSchema: GenEnum<LinkUpdateStatus> = /*@__PURE__*/
+  enumDesc(file_admin, 1);
+
+/**
+ * Maintenance tooling available to administrators only.
+ *
+ * @generated from service musicclub.admin.AdminService
+ */
+export const AdminService: GenService<{
+  /**
+   * Returns the EXPLAIN plan of one of the backend's own queries.
+   *
+   * @generated from rpc musicclub.admin.AdminService.ExplainQuery
+   */
+  explainQuery: {
+    methodKind: "unary";
+    input: typeof ExplainQueryRequestSchema;
+    output: typeof ExplainQueryResponseSchema;
+  },
+  /**
+   * Merges a duplicate song into another one and deletes the duplicate.
+   *
+   * @generated from rpc musicclub.admin.AdminService.MergeSongs
+   */
+  mergeSongs: {
+    methodKind: "unary";
+    input: typeof MergeSongsRequestSchema;
+    output: typeof MergeSongsResponseSchema;
+  },
+  /**
+   * Rewrites links of many songs in one transaction.
+   *
+   * @generated from rpc musicclub.admin.AdminService.BatchUpdateLinks
+   */
+  batchUpdateLinks: {
+    methodKind: "unary";
+    input: typeof BatchUpdateLinksRequestSchema;
+    output: typeof BatchUpdateLinksResponseSchema;
+  },
+  /**
+   * Moves every role assignment of one user to another, e.g. after a
+   * Telegram account change.
+   *
+   * @generated from rpc musicclub.admin.AdminService.TransferParticipations
+   */
+  transferParticipations: {
+    methodKind: "unary";
+    input: typeof TransferParticipationsRequestSchema;
+    output: typeof TransferParticipationsResponseSchema;
+  },
+  /**
+   * Renames a role on every song, moving its participations along.
+   *
+   * @generated from rpc musicclub.admin.AdminService.RenameRole
+   */
+  renameRole: {
+    methodKind: "unary";
+    input: typeof RenameRoleRequestSchema;
+    output: typeof RenameRoleResponseSchema;
+  },
+  /**
+   * Gives a user every permission. Returns the resulting permissions.
+   *
+   * @generated from rpc musicclub.admin.AdminService.GrantAdmin
+   */
+  grantAdmin: {
+    methodKind: "unary";
+    input: typeof AdminUserRequestSchema;
+    output: typeof PermissionSetSchema;
+  },
+  /**
+   * Takes admin permissions away again; the last admin cannot be revoked.
+   *
+   * @generated from rpc musicclub.admin.AdminService.RevokeAdmin
+   */
+  revokeAdmin: {
+    methodKind: "unary";
+    input: typeof AdminUserRequestSchema;
+    output: typeof PermissionSetSchema;
+  },
+  /**
+   * Brings back an event hidden by DeleteEvent.
+   *
+   * @generated from rpc musicclub.admin.AdminService.RestoreEvent
+   */
+  restoreEvent: {
+    methodKind: "unary";
+    input: typeof EventIdSchema;
+    output: typeof EventDetailsSchema;
+  },
+  /**
+   * Inserts a fixed set of demo songs, one event and participations.
+   * Only available when the server runs with DEV_MODE=true.
+   *
+   * @generated from rpc musicclub.admin.AdminService.SeedSampleData
+   */
+  seedSampleData: {
+    methodKind: "unary";
+    input: typeof SeedSampleDataRequestSchema;
+    output: typeof SeedSampleDataResponseSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_admin, 0);
+
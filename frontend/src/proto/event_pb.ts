@@ -2,12 +2,13 @@
 // @generated from file event.proto (package musicclub.event, syntax proto3)
 /* eslint-disable */
 
-import type { GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
-import { fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
+import type { GenEnum, GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
+import { enumDesc, fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
 import type { EmptySchema, Timestamp } from "@bufbuild/protobuf/wkt";
 import { file_google_protobuf_empty, file_google_protobuf_timestamp } from "@bufbuild/protobuf/wkt";
-import type { RoleAssignment } from "./song_pb.ts";
+import type { RoleAssignment, Song, SongIdSchema } from "./song_pb.ts";
 import { file_song } from "./song_pb.ts";
+import type { User } from "./user_pb.ts";
 import { file_user } from "./user_pb.ts";
 import type { PermissionSet } from "./permissions_pb.ts";
 import { file_permissions } from "./permissions_pb.ts";
@@ -17,7 +18,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file event.proto.
  */
 export const file_event: GenFile = /*@__PURE__*/
-  fileDesc("CgtldmVudC5wcm90bxIPbXVzaWNjbHViLmV2ZW50IhUKB0V2ZW50SWQSCgoCaWQYASABKAkidAoRTGlzdEV2ZW50c1JlcXVlc3QSKAoEZnJvbRgBIAEoCzIaLmdvb2dsZS5wcm90b2J1Zi5UaW1lc3RhbXASJgoCdG8YAiABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wEg0KBWxpbWl0GAMgASgNIjwKEkxpc3RFdmVudHNSZXNwb25zZRImCgZldmVudHMYASADKAsyFi5tdXNpY2NsdWIuZXZlbnQuRXZlbnQimQEKBUV2ZW50EgoKAmlkGAEgASgJEg0KBXRpdGxlGAIgASgJEiwKCHN0YXJ0X2F0GAMgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcBIQCghsb2NhdGlvbhgEIAEoCRIZChFub3RpZnlfZGF5X2JlZm9yZRgFIAEoCBIaChJub3RpZnlfaG91cl9iZWZvcmUYBiABKAgi1QEKDEV2ZW50RGV0YWlscxIlCgVldmVudBgBIAEoCzIWLm11c2ljY2x1Yi5ldmVudC5FdmVudBItCgl0cmFja2xpc3QYAiABKAsyGi5tdXNpY2NsdWIuZXZlbnQuVHJhY2tsaXN0EjQKDHBhcnRpY2lwYW50cxgDIAMoCzIeLm11c2ljY2x1Yi5zb25nLlJvbGVBc3NpZ25tZW50EjkKC3Blcm1pc3Npb25zGAQgASgLMiQubXVzaWNjbHViLnBlcm1pc3Npb25zLlBlcm1pc3Npb25TZXQiNgoJVHJhY2tsaXN0EikKBWl0ZW1zGAEgAygLMhoubXVzaWNjbHViLmV2ZW50LlRyYWNrSXRlbSJYCglUcmFja0l0ZW0SDQoFb3JkZXIYASABKA0SDwoHc29uZ19pZBgCIAEoCRIUCgxjdXN0b21fdGl0bGUYAyABKAkSFQoNY3VzdG9tX2FydGlzdBgEIAEoCSLJAQoSQ3JlYXRlRXZlbnRSZXF1ZXN0Eg0KBXRpdGxlGAEgASgJEiwKCHN0YXJ0X2F0GAIgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcBIQCghsb2NhdGlvbhgDIAEoCRIZChFub3RpZnlfZGF5X2JlZm9yZRgEIAEoCBIaChJub3RpZnlfaG91cl9iZWZvcmUYBSABKAgSLQoJdHJhY2tsaXN0GAYgASgLMhoubXVzaWNjbHViLmV2ZW50LlRyYWNrbGlzdCKmAQoSVXBkYXRlRXZlbnRSZXF1ZXN0EgoKAmlkGAEgASgJEg0KBXRpdGxlGAIgASgJEiwKCHN0YXJ0X2F0GAMgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcBIQCghsb2NhdGlvbhgEIAEoCRIZChFub3RpZnlfZGF5X2JlZm9yZRgFIAEoCBIaChJub3RpZnlfaG91cl9iZWZvcmUYBiABKAgiVgoTU2V0VHJhY2tsaXN0UmVxdWVzdBIQCghldmVudF9pZBgBIAEoCRItCgl0cmFja2xpc3QYAiABKAsyGi5tdXNpY2NsdWIuZXZlbnQuVHJhY2tsaXN0MuYDCgxFdmVudFNlcnZpY2USVQoKTGlzdEV2ZW50cxIiLm11c2ljY2x1Yi5ldmVudC5MaXN0RXZlbnRzUmVxdWVzdBojLm11c2ljY2x1Yi5ldmVudC5MaXN0RXZlbnRzUmVzcG9uc2USQwoIR2V0RXZlbnQSGC5tdXNpY2NsdWIuZXZlbnQuRXZlbnRJZBodLm11c2ljY2x1Yi5ldmVudC5FdmVudERldGFpbHMSUQoLQ3JlYXRlRXZlbnQSIy5tdXNpY2NsdWIuZXZlbnQuQ3JlYXRlRXZlbnRSZXF1ZXN0Gh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJRCgtVcGRhdGVFdmVudBIjLm11c2ljY2x1Yi5ldmVudC5VcGRhdGVFdmVudFJlcXVlc3QaHS5tdXNpY2NsdWIuZXZlbnQuRXZlbnREZXRhaWxzEj8KC0RlbGV0ZUV2ZW50EhgubXVzaWNjbHViLmV2ZW50LkV2ZW50SWQaFi5nb29nbGUucHJvdG9idWYuRW1wdHkSUwoMU2V0VHJhY2tsaXN0EiQubXVzaWNjbHViLmV2ZW50LlNldFRyYWNrbGlzdFJlcXVlc3QaHS5tdXNpY2NsdWIuZXZlbnQuRXZlbnREZXRhaWxzQhxaGm11c2ljY2x1YmJvdC9iYWNrZW5kL3Byb3RvYgZwcm90bzM", [file_google_protobuf_empty, file_google_protobuf_timestamp, file_song, file_user, file_permissions]);
+  fileDesc("CgtldmVudC5wcm90bxIPbXVzaWNjbHViLmV2ZW50IhUKB0V2ZW50SWQSCgoCaWQYASABKAkiRgoSRGVsZXRlRXZlbnRSZXF1ZXN0EgoKAmlkGAEgASgJEhUKDWFsbG93X21pc3NpbmcYAiABKAgSDQoFZm9yY2UYAyABKAgijwEKEUxpc3RFdmVudHNSZXF1ZXN0EigKBGZyb20YASABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wEiYKAnRvGAIgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcBINCgVsaW1pdBgDIAEoDRIZChFpbmNsdWRlX3RyYWNrbGlzdBgEIAEoCCJOChJMaXN0RXZlbnRzUmVzcG9uc2USJgoGZXZlbnRzGAEgAygLMhYubXVzaWNjbHViLmV2ZW50LkV2ZW50EhAKCGhhc19tb3JlGAIgASgIIiQKFUJhdGNoR2V0RXZlbnRzUmVxdWVzdBILCgNpZHMYASADKAkiVQoWQmF0Y2hHZXRFdmVudHNSZXNwb25zZRImCgZldmVudHMYASADKAsyFi5tdXNpY2NsdWIuZXZlbnQuRXZlbnQSEwoLbWlzc2luZ19pZHMYAiADKAki0QIKBUV2ZW50EgoKAmlkGAEgASgJEg0KBXRpdGxlGAIgASgJEiwKCHN0YXJ0X2F0GAMgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcBIQCghsb2NhdGlvbhgEIAEoCRIZChFub3RpZnlfZGF5X2JlZm9yZRgFIAEoCBIaChJub3RpZnlfaG91cl9iZWZvcmUYBiABKAgSEgoKY3JlYXRlZF9ieRgHIAEoCRIpCgVkYXRlcxgIIAMoCzIaLmdvb2dsZS5wcm90b2J1Zi5UaW1lc3RhbXASNQoRdHJhY2tsaXN0X3ByZXZpZXcYCSABKAsyGi5tdXNpY2NsdWIuZXZlbnQuVHJhY2tsaXN0EhIKCnNvbmdfY291bnQYCiABKA0SLAoGc3RhdHVzGAsgASgOMhwubXVzaWNjbHViLmV2ZW50LkV2ZW50U3RhdHVzIsQCCgxFdmVudERldGFpbHMSJQoFZXZlbnQYASABKAsyFi5tdXNpY2NsdWIuZXZlbnQuRXZlbnQSLQoJdHJhY2tsaXN0GAIgASgLMhoubXVzaWNjbHViLmV2ZW50LlRyYWNrbGlzdBI0CgxwYXJ0aWNpcGFudHMYAyADKAsyHi5tdXNpY2NsdWIuc29uZy5Sb2xlQXNzaWdubWVudBI5CgtwZXJtaXNzaW9ucxgEIAEoCzIkLm11c2ljY2x1Yi5wZXJtaXNzaW9ucy5QZXJtaXNzaW9uU2V0EhIKCnNvbmdfY291bnQYBSABKA0SGQoRcGFydGljaXBhbnRfY291bnQYBiABKA0SHgoWdG90YWxfZHVyYXRpb25fc2Vjb25kcxgHIAEoDRIeChZtaXNzaW5nX2R1cmF0aW9uX2NvdW50GAggASgNIjYKCVRyYWNrbGlzdBIpCgVpdGVtcxgBIAMoCzIaLm11c2ljY2x1Yi5ldmVudC5UcmFja0l0ZW0ifAoJVHJhY2tJdGVtEg0KBW9yZGVyGAEgASgNEg8KB3NvbmdfaWQYAiABKAkSFAoMY3VzdG9tX3RpdGxlGAMgASgJEhUKDWN1c3RvbV9hcnRpc3QYBCABKAkSIgoEc29uZxgFIAEoCzIULm11c2ljY2x1Yi5zb25nLlNvbmci9wEKEkNyZWF0ZUV2ZW50UmVxdWVzdBINCgV0aXRsZRgBIAEoCRIsCghzdGFydF9hdBgCIAEoCzIaLmdvb2dsZS5wcm90b2J1Zi5UaW1lc3RhbXASEAoIbG9jYXRpb24YAyABKAkSGQoRbm90aWZ5X2RheV9iZWZvcmUYBCABKAgSGgoSbm90aWZ5X2hvdXJfYmVmb3JlGAUgASgIEi0KCXRyYWNrbGlzdBgGIAEoCzIaLm11c2ljY2x1Yi5ldmVudC5UcmFja2xpc3QSLAoGc3RhdHVzGAcgASgOMhwubXVzaWNjbHViLmV2ZW50LkV2ZW50U3RhdHVzIoQBChFDbG9uZUV2ZW50UmVxdWVzdBIXCg9zb3VyY2VfZXZlbnRfaWQYASABKAkSDQoFdGl0bGUYAiABKAkSLAoIc3RhcnRfYXQYAyABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wEhkKEWNvcHlfcGFydGljaXBhbnRzGAQgASgIItQBChJVcGRhdGVFdmVudFJlcXVlc3QSCgoCaWQYASABKAkSDQoFdGl0bGUYAiABKAkSLAoIc3RhcnRfYXQYAyABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wEhAKCGxvY2F0aW9uGAQgASgJEhkKEW5vdGlmeV9kYXlfYmVmb3JlGAUgASgIEhoKEm5vdGlmeV9ob3VyX2JlZm9yZRgGIAEoCBIsCgZzdGF0dXMYByABKA4yHC5tdXNpY2NsdWIuZXZlbnQuRXZlbnRTdGF0dXMiVgoTU2V0VHJhY2tsaXN0UmVxdWVzdBIQCghldmVudF9pZBgBIAEoCRItCgl0cmFja2xpc3QYAiABKAsyGi5tdXNpY2NsdWIuZXZlbnQuVHJhY2tsaXN0IlwKGVN3YXBUcmFja2xpc3RTb25nc1JlcXVlc3QSEAoIZXZlbnRfaWQYASABKAkSFQoNZmlyc3Rfc29uZ19pZBgCIAEoCRIWCg5zZWNvbmRfc29uZ19pZBgDIAEoCSJfChlNb3ZlVHJhY2tsaXN0U29uZ3NSZXF1ZXN0EhcKD3NvdXJjZV9ldmVudF9pZBgBIAEoCRIXCg90YXJnZXRfZXZlbnRfaWQYAiABKAkSEAoIc29uZ19pZHMYAyADKAkiNwoKRXZlbnREYXRlcxIpCgVkYXRlcxgBIAMoCzIaLmdvb2dsZS5wcm90b2J1Zi5UaW1lc3RhbXAiMwoLRXZlbnRQZW9wbGUSJAoGcGVvcGxlGAEgAygLMhQubXVzaWNjbHViLnVzZXIuVXNlciJOChBFdmVudERhdGVSZXF1ZXN0EhAKCGV2ZW50X2lkGAEgASgJEigKBGRhdGUYAiABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wKnsKC0V2ZW50U3RhdHVzEhwKGEVWRU5UX1NUQVRVU19VTlNQRUNJRklFRBAAEhYKEkVWRU5UX1NUQVRVU19EUkFGVBABEhoKFkVWRU5UX1NUQVRVU19QVUJMSVNIRUQQAhIaChZFVkVOVF9TVEFUVVNfQ0FOQ0VMTEVEEAMy9AkKDEV2ZW50U2VydmljZRJVCgpMaXN0RXZlbnRzEiIubXVzaWNjbHViLmV2ZW50Lkxpc3RFdmVudHNSZXF1ZXN0GiMubXVzaWNjbHViLmV2ZW50Lkxpc3RFdmVudHNSZXNwb25zZRJDCghHZXRFdmVudBIYLm11c2ljY2x1Yi5ldmVudC5FdmVudElkGh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJhCg5CYXRjaEdldEV2ZW50cxImLm11c2ljY2x1Yi5ldmVudC5CYXRjaEdldEV2ZW50c1JlcXVlc3QaJy5tdXNpY2NsdWIuZXZlbnQuQmF0Y2hHZXRFdmVudHNSZXNwb25zZRJQChFMaXN0RXZlbnRzRm9yU29uZxIWLm11c2ljY2x1Yi5zb25nLlNvbmdJZBojLm11c2ljY2x1Yi5ldmVudC5MaXN0RXZlbnRzUmVzcG9uc2USUQoLQ3JlYXRlRXZlbnQSIy5tdXNpY2NsdWIuZXZlbnQuQ3JlYXRlRXZlbnRSZXF1ZXN0Gh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJPCgpDbG9uZUV2ZW50EiIubXVzaWNjbHViLmV2ZW50LkNsb25lRXZlbnRSZXF1ZXN0Gh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJRCgtVcGRhdGVFdmVudBIjLm11c2ljY2x1Yi5ldmVudC5VcGRhdGVFdmVudFJlcXVlc3QaHS5tdXNpY2NsdWIuZXZlbnQuRXZlbnREZXRhaWxzEkoKC0RlbGV0ZUV2ZW50EiMubXVzaWNjbHViLmV2ZW50LkRlbGV0ZUV2ZW50UmVxdWVzdBoWLmdvb2dsZS5wcm90b2J1Zi5FbXB0eRJTCgxTZXRUcmFja2xpc3QSJC5tdXNpY2NsdWIuZXZlbnQuU2V0VHJhY2tsaXN0UmVxdWVzdBodLm11c2ljY2x1Yi5ldmVudC5FdmVudERldGFpbHMSXwoSU3dhcFRyYWNrbGlzdFNvbmdzEioubXVzaWNjbHViLmV2ZW50LlN3YXBUcmFja2xpc3RTb25nc1JlcXVlc3QaHS5tdXNpY2NsdWIuZXZlbnQuRXZlbnREZXRhaWxzEl8KEk1vdmVUcmFja2xpc3RTb25ncxIqLm11c2ljY2x1Yi5ldmVudC5Nb3ZlVHJhY2tsaXN0U29uZ3NSZXF1ZXN0Gh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlscxJJCg9MaXN0RXZlbnRQZW9wbGUSGC5tdXNpY2NsdWIuZXZlbnQuRXZlbnRJZBocLm11c2ljY2x1Yi5ldmVudC5FdmVudFBlb3BsZRJHCg5MaXN0RXZlbnREYXRlcxIYLm11c2ljY2x1Yi5ldmVudC5FdmVudElkGhsubXVzaWNjbHViLmV2ZW50LkV2ZW50RGF0ZXMSUAoMQWRkRXZlbnREYXRlEiEubXVzaWNjbHViLmV2ZW50LkV2ZW50RGF0ZVJlcXVlc3QaHS5tdXNpY2NsdWIuZXZlbnQuRXZlbnREZXRhaWxzElMKD1JlbW92ZUV2ZW50RGF0ZRIhLm11c2ljY2x1Yi5ldmVudC5FdmVudERhdGVSZXF1ZXN0Gh0ubXVzaWNjbHViLmV2ZW50LkV2ZW50RGV0YWlsc0IcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z", [file_google_protobuf_empty, file_google_protobuf_timestamp, file_song, file_user, file_permissions]);
 
 /**
  * @generated from message musicclub.event.EventId
@@ -36,6 +37,38 @@ export type EventId = Message<"musicclub.event.EventId"> & {
 export const EventIdSchema: GenMessage<EventId> = /*@__PURE__*/
   messageDesc(file_event, 0);
 
+/**
+ * @generated from message musicclub.event.DeleteEventRequest
+ */
+export type DeleteEventRequest = Message<"musicclub.event.DeleteEventRequest"> & {
+  /**
+   * @generated from field: string id = 1;
+   */
+  id: string;
+
+  /**
+   * Succeed instead of returning NOT_FOUND when the event is already gone.
+   *
+   * @generated from field: bool allow_missing = 2;
+   */
+  allowMissing: boolean;
+
+  /**
+   * Remove the event and its history instead of hiding it. Also purges
+   * events that were deleted before.
+   *
+   * @generated from field: bool force = 3;
+   */
+  force: boolean;
+};
+
+/**
+ * Describes the message musicclub.event.DeleteEventRequest.
+ * Use `create(DeleteEventRequestSchema)` to create a new message.
+ */
+export const DeleteEventRequestSchema: GenMessage<DeleteEventRequest> = /*@__PURE__*/
+  messageDesc(file_event, 1);
+
 /**
  * @generated from message musicclub.event.ListEventsRequest
  */
@@ -54,6 +87,13 @@ export type ListEventsRequest = Message<"musicclub.event.ListEventsRequest"> & {
    * @generated from field: uint32 limit = 3;
    */
   limit: number;
+
+  /**
+   * Also return the first few tracklist items and the song count of each event.
+   *
+   * @generated from field: bool include_tracklist = 4;
+   */
+  includeTracklist: boolean;
 };
 
 /**
@@ -61,7 +101,7 @@ export type ListEventsRequest = Message<"musicclub.event.ListEventsRequest"> & {
  * Use `create(ListEventsRequestSchema)` to create a new message.
  */
 export const ListEventsRequestSchema: GenMessage<ListEventsRequest> = /*@__PURE__*/
-  messageDesc(file_event, 1);
+  messageDesc(file_event, 2);
 
 /**
  * @generated from message musicclub.event.ListEventsResponse
@@ -71,6 +111,13 @@ export type ListEventsResponse = Message<"musicclub.event.ListEventsResponse"> &
    * @generated from field: repeated musicclub.event.Event events = 1;
    */
   events: Event[];
+
+  /**
+   * Whether more events match than the requested limit.
+   *
+   * @generated from field: bool has_more = 2;
+   */
+  hasMore: boolean;
 };
 
 /**
@@ -78,7 +125,52 @@ export type ListEventsResponse = Message<"musicclub.event.ListEventsResponse"> &
  * Use `create(ListEventsResponseSchema)` to create a new message.
  */
 export const ListEventsResponseSchema: GenMessage<ListEventsResponse> = /*@__PURE__*/
-  messageDesc(file_event, 2);
+  messageDesc(file_event, 3);
+
+/**
+ * @generated from message musicclub.event.BatchGetEventsRequest
+ */
+export type BatchGetEventsRequest = Message<"musicclub.event.BatchGetEventsRequest"> & {
+  /**
+   * Every id must be a uuid. Duplicates are ignored; at most 100 distinct ids.
+   *
+   * @generated from field: repeated string ids = 1;
+   */
+  ids: string[];
+};
+
+/**
+ * Describes the message musicclub.event.BatchGetEventsRequest.
+ * Use `create(BatchGetEventsRequestSchema)` to create a new message.
+ */
+export const BatchGetEventsRequestSchema: GenMessage<BatchGetEventsRequest> = /*@__PURE__*/
+  messageDesc(file_event, 4);
+
+/**
+ * @generated from message musicclub.event.BatchGetEventsResponse
+ */
+export type BatchGetEventsResponse = Message<"musicclub.event.BatchGetEventsResponse"> & {
+  /**
+   * Found events, in the order their ids were first requested.
+   *
+   * @generated from field: repeated musicclub.event.Event events = 1;
+   */
+  events: Event[];
+
+  /**
+   * Requested ids with no (visible) event, in canonical lowercase form.
+   *
+   * @generated from field: repeated string missing_ids = 2;
+   */
+  missingIds: string[];
+};
+
+/**
+ * Describes the message musicclub.event.BatchGetEventsResponse.
+ * Use `create(BatchGetEventsResponseSchema)` to create a new message.
+ */
+export const BatchGetEventsResponseSchema: GenMessage<BatchGetEventsResponse> = /*@__PURE__*/
+  messageDesc(file_event, 5);
 
 /**
  * @generated from message musicclub.event.Event
@@ -115,6 +207,41 @@ export type Event = Message<"musicclub.event.Event"> & {
    * @generated from field: bool notify_hour_before = 6;
    */
   notifyHourBefore: boolean;
+
+  /**
+   * ID of the user who created the event. Empty for legacy rows.
+   *
+   * @generated from field: string created_by = 7;
+   */
+  createdBy: string;
+
+  /**
+   * All performance dates, sorted; start_at is the earliest of them.
+   * Only populated in EventDetails.
+   *
+   * @generated from field: repeated google.protobuf.Timestamp dates = 8;
+   */
+  dates: Timestamp[];
+
+  /**
+   * First tracklist items and total number of items. Only populated by
+   * ListEvents with include_tracklist.
+   *
+   * @generated from field: musicclub.event.Tracklist tracklist_preview = 9;
+   */
+  tracklistPreview?: Tracklist;
+
+  /**
+   * @generated from field: uint32 song_count = 10;
+   */
+  songCount: number;
+
+  /**
+   * New events are drafts; ListEvents shows members only published events.
+   *
+   * @generated from field: musicclub.event.EventStatus status = 11;
+   */
+  status: EventStatus;
 };
 
 /**
@@ -122,7 +249,7 @@ export type Event = Message<"musicclub.event.Event"> & {
  * Use `create(EventSchema)` to create a new message.
  */
 export const EventSchema: GenMessage<Event> = /*@__PURE__*/
-  messageDesc(file_event, 3);
+  messageDesc(file_event, 6);
 
 /**
  * @generated from message musicclub.event.EventDetails
@@ -147,6 +274,34 @@ export type EventDetails = Message<"musicclub.event.EventDetails"> & {
    * @generated from field: musicclub.permissions.PermissionSet permissions = 4;
    */
   permissions?: PermissionSet;
+
+  /**
+   * Number of tracklist items, catalog and custom alike.
+   *
+   * @generated from field: uint32 song_count = 5;
+   */
+  songCount: number;
+
+  /**
+   * Distinct people playing a tracklist song or registered for the event.
+   *
+   * @generated from field: uint32 participant_count = 6;
+   */
+  participantCount: number;
+
+  /**
+   * Sum of known song durations in the tracklist, in seconds.
+   *
+   * @generated from field: uint32 total_duration_seconds = 7;
+   */
+  totalDurationSeconds: number;
+
+  /**
+   * Tracklist items without a known duration, custom items included.
+   *
+   * @generated from field: uint32 missing_duration_count = 8;
+   */
+  missingDurationCount: number;
 };
 
 /**
@@ -154,7 +309,7 @@ export type EventDetails = Message<"musicclub.event.EventDetails"> & {
  * Use `create(EventDetailsSchema)` to create a new message.
  */
 export const EventDetailsSchema: GenMessage<EventDetails> = /*@__PURE__*/
-  messageDesc(file_event, 4);
+  messageDesc(file_event, 7);
 
 /**
  * @generated from message musicclub.event.Tracklist
@@ -171,7 +326,7 @@ export type Tracklist = Message<"musicclub.event.Tracklist"> & {
  * Use `create(TracklistSchema)` to create a new message.
  */
 export const TracklistSchema: GenMessage<Tracklist> = /*@__PURE__*/
-  messageDesc(file_event, 5);
+  messageDesc(file_event, 8);
 
 /**
  * @generated from message musicclub.event.TrackItem
@@ -200,6 +355,14 @@ export type TrackItem = Message<"musicclub.event.TrackItem"> & {
    * @generated from field: string custom_artist = 4;
    */
   customArtist: string;
+
+  /**
+   * Output only: catalog song referenced by song_id, without roles and
+   * per-user fields. Ignored in requests.
+   *
+   * @generated from field: musicclub.song.Song song = 5;
+   */
+  song?: Song;
 };
 
 /**
@@ -207,7 +370,7 @@ export type TrackItem = Message<"musicclub.event.TrackItem"> & {
  * Use `create(TrackItemSchema)` to create a new message.
  */
 export const TrackItemSchema: GenMessage<TrackItem> = /*@__PURE__*/
-  messageDesc(file_event, 6);
+  messageDesc(file_event, 9);
 
 /**
  * @generated from message musicclub.event.CreateEventRequest
@@ -242,6 +405,13 @@ export type CreateEventRequest = Message<"musicclub.event.CreateEventRequest"> &
    * @generated from field: musicclub.event.Tracklist tracklist = 6;
    */
   tracklist?: Tracklist;
+
+  /**
+   * Defaults to DRAFT. New events cannot start out cancelled.
+   *
+   * @generated from field: musicclub.event.EventStatus status = 7;
+   */
+  status: EventStatus;
 };
 
 /**
@@ -249,7 +419,41 @@ export type CreateEventRequest = Message<"musicclub.event.CreateEventRequest"> &
  * Use `create(CreateEventRequestSchema)` to create a new message.
  */
 export const CreateEventRequestSchema: GenMessage<CreateEventRequest> = /*@__PURE__*/
-  messageDesc(file_event, 7);
+  messageDesc(file_event, 10);
+
+/**
+ * @generated from message musicclub.event.CloneEventRequest
+ */
+export type CloneEventRequest = Message<"musicclub.event.CloneEventRequest"> & {
+  /**
+   * @generated from field: string source_event_id = 1;
+   */
+  sourceEventId: string;
+
+  /**
+   * @generated from field: string title = 2;
+   */
+  title: string;
+
+  /**
+   * @generated from field: google.protobuf.Timestamp start_at = 3;
+   */
+  startAt?: Timestamp;
+
+  /**
+   * Also register the source event's participants for the new event.
+   *
+   * @generated from field: bool copy_participants = 4;
+   */
+  copyParticipants: boolean;
+};
+
+/**
+ * Describes the message musicclub.event.CloneEventRequest.
+ * Use `create(CloneEventRequestSchema)` to create a new message.
+ */
+export const CloneEventRequestSchema: GenMessage<CloneEventRequest> = /*@__PURE__*/
+  messageDesc(file_event, 11);
 
 /**
  * @generated from message musicclub.event.UpdateEventRequest
@@ -284,6 +488,11 @@ export type UpdateEventRequest = Message<"musicclub.event.UpdateEventRequest"> &
    * @generated from field: bool notify_hour_before = 6;
    */
   notifyHourBefore: boolean;
+
+  /**
+   * @generated from field: musicclub.event.EventStatus status = 7;
+   */
+  status: EventStatus;
 };
 
 /**
@@ -291,7 +500,7 @@ export type UpdateEventRequest = Message<"musicclub.event.UpdateEventRequest"> &
  * Use `create(UpdateEventRequestSchema)` to create a new message.
  */
 export const UpdateEventRequestSchema: GenMessage<UpdateEventRequest> = /*@__PURE__*/
-  messageDesc(file_event, 8);
+  messageDesc(file_event, 12);
 
 /**
  * @generated from message musicclub.event.SetTracklistRequest
@@ -313,7 +522,159 @@ export type SetTracklistRequest = Message<"musicclub.event.SetTracklistRequest">
  * Use `create(SetTracklistRequestSchema)` to create a new message.
  */
 export const SetTracklistRequestSchema: GenMessage<SetTracklistRequest> = /*@__PURE__*/
-  messageDesc(file_event, 9);
+  messageDesc(file_event, 13);
+
+/**
+ * @generated from message musicclub.event.SwapTracklistSongsRequest
+ */
+export type SwapTracklistSongsRequest = Message<"musicclub.event.SwapTracklistSongsRequest"> & {
+  /**
+   * @generated from field: string event_id = 1;
+   */
+  eventId: string;
+
+  /**
+   * Both songs must appear exactly once in the tracklist.
+   *
+   * @generated from field: string first_song_id = 2;
+   */
+  firstSongId: string;
+
+  /**
+   * @generated from field: string second_song_id = 3;
+   */
+  secondSongId: string;
+};
+
+/**
+ * Describes the message musicclub.event.SwapTracklistSongsRequest.
+ * Use `create(SwapTracklistSongsRequestSchema)` to create a new message.
+ */
+export const SwapTracklistSongsRequestSchema: GenMessage<SwapTracklistSongsRequest> = /*@__PURE__*/
+  messageDesc(file_event, 14);
+
+/**
+ * @generated from message musicclub.event.MoveTracklistSongsRequest
+ */
+export type MoveTracklistSongsRequest = Message<"musicclub.event.MoveTracklistSongsRequest"> & {
+  /**
+   * @generated from field: string source_event_id = 1;
+   */
+  sourceEventId: string;
+
+  /**
+   * @generated from field: string target_event_id = 2;
+   */
+  targetEventId: string;
+
+  /**
+   * Each song must appear exactly once in the source tracklist. They are
+   * appended to the target in this order.
+   *
+   * @generated from field: repeated string song_ids = 3;
+   */
+  songIds: string[];
+};
+
+/**
+ * Describes the message musicclub.event.MoveTracklistSongsRequest.
+ * Use `create(MoveTracklistSongsRequestSchema)` to create a new message.
+ */
+export const MoveTracklistSongsRequestSchema: GenMessage<MoveTracklistSongsRequest> = /*@__PURE__*/
+  messageDesc(file_event, 15);
+
+/**
+ * @generated from message musicclub.event.EventDates
+ */
+export type EventDates = Message<"musicclub.event.EventDates"> & {
+  /**
+   * @generated from field: repeated google.protobuf.Timestamp dates = 1;
+   */
+  dates: Timestamp[];
+};
+
+/**
+ * Describes the message musicclub.event.EventDates.
+ * Use `create(EventDatesSchema)` to create a new message.
+ */
+export const EventDatesSchema: GenMessage<EventDates> = /*@__PURE__*/
+  messageDesc(file_event, 16);
+
+/**
+ * @generated from message musicclub.event.EventPeople
+ */
+export type EventPeople = Message<"musicclub.event.EventPeople"> & {
+  /**
+   * Ordered by display name.
+   *
+   * @generated from field: repeated musicclub.user.User people = 1;
+   */
+  people: User[];
+};
+
+/**
+ * Describes the message musicclub.event.EventPeople.
+ * Use `create(EventPeopleSchema)` to create a new message.
+ */
+export const EventPeopleSchema: GenMessage<EventPeople> = /*@__PURE__*/
+  messageDesc(file_event, 17);
+
+/**
+ * @generated from message musicclub.event.EventDateRequest
+ */
+export type EventDateRequest = Message<"musicclub.event.EventDateRequest"> & {
+  /**
+   * @generated from field: string event_id = 1;
+   */
+  eventId: string;
+
+  /**
+   * @generated from field: google.protobuf.Timestamp date = 2;
+   */
+  date?: Timestamp;
+};
+
+/**
+ * Describes the message musicclub.event.EventDateRequest.
+ * Use `create(EventDateRequestSchema)` to create a new message.
+ */
+export const EventDateRequestSchema: GenMessage<EventDateRequest> = /*@__PURE__*/
+  messageDesc(file_event, 18);
+
+/**
+ * @generated from enum musicclub.event.EventStatus
+ */
+export enum EventStatus {
+  /**
+   * Not set; keeps the current status on update.
+   *
+   * @generated from enum value: EVENT_STATUS_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * @generated from enum value: EVENT_STATUS_DRAFT = 1;
+   */
+  DRAFT = 1,
+
+  /**
+   * @generated from enum value: EVENT_STATUS_PUBLISHED = 2;
+   */
+  PUBLISHED = 2,
+
+  /**
+   * Final: a cancelled event cannot be reopened.
+   *
+   * @generated from enum value: EVENT_STATUS_CANCELLED = 3;
+   */
+  CANCELLED = 3,
+}
+
+/**
+ * Describes the enum musicclub.event.EventStatus.
+ */
+export const EventStatusSchema: GenEnum<EventStatus> = /*@__PURE__*/
+  enumDesc(file_event, 0);
 
 /**
  * Provides CRUD functionality for events and tracklists.
@@ -341,6 +702,26 @@ export const EventService: GenService<{
     input: typeof EventIdSchema;
     output: typeof EventDetailsSchema;
   },
+  /**
+   * Returns several events at once, in request order.
+   *
+   * @generated from rpc musicclub.event.EventService.BatchGetEvents
+   */
+  batchGetEvents: {
+    methodKind: "unary";
+    input: typeof BatchGetEventsRequestSchema;
+    output: typeof BatchGetEventsResponseSchema;
+  },
+  /**
+   * Lists the events whose tracklist includes a song, by start date.
+   *
+   * @generated from rpc musicclub.event.EventService.ListEventsForSong
+   */
+  listEventsForSong: {
+    methodKind: "unary";
+    input: typeof SongIdSchema;
+    output: typeof ListEventsResponseSchema;
+  },
   /**
    * Create events (requires permissions).
    *
@@ -351,6 +732,16 @@ export const EventService: GenService<{
     input: typeof CreateEventRequestSchema;
     output: typeof EventDetailsSchema;
   },
+  /**
+   * Create a draft event with the tracklist of an existing one (requires permissions).
+   *
+   * @generated from rpc musicclub.event.EventService.CloneEvent
+   */
+  cloneEvent: {
+    methodKind: "unary";
+    input: typeof CloneEventRequestSchema;
+    output: typeof EventDetailsSchema;
+  },
   /**
    * Update events (requires permissions).
    *
@@ -362,13 +753,13 @@ export const EventService: GenService<{
     output: typeof EventDetailsSchema;
   },
   /**
-   * Delete events (requires permissions).
+   * Hide events (requires permissions); admins can restore them.
    *
    * @generated from rpc musicclub.event.EventService.DeleteEvent
    */
   deleteEvent: {
     methodKind: "unary";
-    input: typeof EventIdSchema;
+    input: typeof DeleteEventRequestSchema;
     output: typeof EmptySchema;
   },
   /**
@@ -381,6 +772,66 @@ export const EventService: GenService<{
     input: typeof SetTracklistRequestSchema;
     output: typeof EventDetailsSchema;
   },
+  /**
+   * Swap the positions of two catalog songs in a tracklist.
+   *
+   * @generated from rpc musicclub.event.EventService.SwapTracklistSongs
+   */
+  swapTracklistSongs: {
+    methodKind: "unary";
+    input: typeof SwapTracklistSongsRequestSchema;
+    output: typeof EventDetailsSchema;
+  },
+  /**
+   * Move catalog songs from one event's tracklist to the end of another's.
+   *
+   * @generated from rpc musicclub.event.EventService.MoveTracklistSongs
+   */
+  moveTracklistSongs: {
+    methodKind: "unary";
+    input: typeof MoveTracklistSongsRequestSchema;
+    output: typeof EventDetailsSchema;
+  },
+  /**
+   * Lists everyone holding a role on any song of the tracklist, once each.
+   *
+   * @generated from rpc musicclub.event.EventService.ListEventPeople
+   */
+  listEventPeople: {
+    methodKind: "unary";
+    input: typeof EventIdSchema;
+    output: typeof EventPeopleSchema;
+  },
+  /**
+   * Returns all performance dates of an event, sorted.
+   *
+   * @generated from rpc musicclub.event.EventService.ListEventDates
+   */
+  listEventDates: {
+    methodKind: "unary";
+    input: typeof EventIdSchema;
+    output: typeof EventDatesSchema;
+  },
+  /**
+   * Add a performance date to a multi-night event.
+   *
+   * @generated from rpc musicclub.event.EventService.AddEventDate
+   */
+  addEventDate: {
+    methodKind: "unary";
+    input: typeof EventDateRequestSchema;
+    output: typeof EventDetailsSchema;
+  },
+  /**
+   * Remove a performance date from an event.
+   *
+   * @generated from rpc musicclub.event.EventService.RemoveEventDate
+   */
+  removeEventDate: {
+    methodKind: "unary";
+    input: typeof EventDateRequestSchema;
+    output: typeof EventDetailsSchema;
+  },
 }> = /*@__PURE__*/
   serviceDesc(file_event, 0);
 
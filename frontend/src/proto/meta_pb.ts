@@ -0,0 +1,144 @@
+// @generated by protoc-gen-es v2.10.2 with parameter "target=ts,import_extension=ts"
+// @generated from file meta.proto (package musicclub.meta, syntax proto3)
+/* eslint-disable */
+
+import type { GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
+import { fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
+import type { EmptySchema, Timestamp } from "@bufbuild/protobuf/wkt";
+import { file_google_protobuf_empty, file_google_protobuf_timestamp } from "@bufbuild/protobuf/wkt";
+import type { Message } from "@bufbuild/protobuf";
+
+/**
+ * Describes the file meta.proto.
+ */
+export const file_meta: GenFile = /*@__PURE__*/
+  fileDesc("CgptZXRhLnByb3RvEg5tdXNpY2NsdWIubWV0YSIcCgtQaW5nUmVxdWVzdBINCgVub25jZRgBIAEoCSJOCgxQaW5nUmVzcG9uc2USDQoFbm9uY2UYASABKAkSLwoLc2VydmVyX3RpbWUYAiABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wIv8BCgxTZXJ2ZXJDb25maWcSGQoRZGVmYXVsdF9wYWdlX3NpemUYASABKA0SFQoNbWF4X3BhZ2Vfc2l6ZRgCIAEoDRIgChhhY2Nlc3NfdG9rZW5fdHRsX3NlY29uZHMYAyABKAMSJgoeYWRtaW5fYWNjZXNzX3Rva2VuX3R0bF9zZWNvbmRzGAQgASgDEiEKGXJlZnJlc2hfdG9rZW5fdHRsX3NlY29uZHMYBSABKAMSFwoPbWluX2FwaV92ZXJzaW9uGAYgASgFEhsKE21heF9yb2xlc19wZXJfZXZlbnQYByABKAUSGgoSY29tcHJlc3NfcmVzcG9uc2VzGAggASgIMpkBCgtNZXRhU2VydmljZRJBCgRQaW5nEhsubXVzaWNjbHViLm1ldGEuUGluZ1JlcXVlc3QaHC5tdXNpY2NsdWIubWV0YS5QaW5nUmVzcG9uc2USRwoPR2V0U2VydmVyQ29uZmlnEhYuZ29vZ2xlLnByb3RvYnVmLkVtcHR5GhwubXVzaWNjbHViLm1ldGEuU2VydmVyQ29uZmlnQhxaGm11c2ljY2x1YmJvdC9iYWNrZW5kL3Byb3RvYgZwcm90bzM", [file_google_protobuf_empty, file_google_protobuf_timestamp]);
+
+/**
+ * @generated from message musicclub.meta.PingRequest
+ */
+export type PingRequest = Message<"musicclub.meta.PingRequest"> & {
+  /**
+   * @generated from field: string nonce = 1;
+   */
+  nonce: string;
+};
+
+/**
+ * Describes the message musicclub.meta.PingRequest.
+ * Use `create(PingRequestSchema)` to create a new message.
+ */
+export const PingRequestSchema: GenMessage<PingRequest> = /*@__PURE__*/
+  messageDesc(file_meta, 0);
+
+/**
+ * @generated from message musicclub.meta.PingResponse
+ */
+export type PingResponse = Message<"musicclub.meta.PingResponse"> & {
+  /**
+   * @generated from field: string nonce = 1;
+   */
+  nonce: string;
+
+  /**
+   * @generated from field: google.protobuf.Timestamp server_time = 2;
+   */
+  serverTime?: Timestamp;
+};
+
+/**
+ * Describes the message musicclub.meta.PingResponse.
+ * Use `create(PingResponseSchema)` to create a new message.
+ */
+export const PingResponseSchema: GenMessage<PingResponse> = /*@__PURE__*/
+  messageDesc(file_meta, 1);
+
+/**
+ * @generated from message musicclub.meta.ServerConfig
+ */
+export type ServerConfig = Message<"musicclub.meta.ServerConfig"> & {
+  /**
+   * page_size used when a list request leaves it unset.
+   *
+   * @generated from field: uint32 default_page_size = 1;
+   */
+  defaultPageSize: number;
+
+  /**
+   * Larger page_size values are clamped to this.
+   *
+   * @generated from field: uint32 max_page_size = 2;
+   */
+  maxPageSize: number;
+
+  /**
+   * @generated from field: int64 access_token_ttl_seconds = 3;
+   */
+  accessTokenTtlSeconds: bigint;
+
+  /**
+   * @generated from field: int64 admin_access_token_ttl_seconds = 4;
+   */
+  adminAccessTokenTtlSeconds: bigint;
+
+  /**
+   * @generated from field: int64 refresh_token_ttl_seconds = 5;
+   */
+  refreshTokenTtlSeconds: bigint;
+
+  /**
+   * Lowest x-api-version the server accepts; 0 when unchecked.
+   *
+   * @generated from field: int32 min_api_version = 6;
+   */
+  minApiVersion: number;
+
+  /**
+   * 0 means unlimited.
+   *
+   * @generated from field: int32 max_roles_per_event = 7;
+   */
+  maxRolesPerEvent: number;
+
+  /**
+   * @generated from field: bool compress_responses = 8;
+   */
+  compressResponses: boolean;
+};
+
+/**
+ * Describes the message musicclub.meta.ServerConfig.
+ * Use `create(ServerConfigSchema)` to create a new message.
+ */
+export const ServerConfigSchema: GenMessage<ServerConfig> = /*@__PURE__*/
+  messageDesc(file_meta, 2);
+
+/**
+ * Connection-level helpers that need neither authentication nor the database.
+ *
+ * @generated from service musicclub.meta.MetaService
+ */
+export const MetaService: GenService<{
+  /**
+   * Echoes the nonce back with the server time, for latency measurement.
+   *
+   * @generated from rpc musicclub.meta.MetaService.Ping
+   */
+  ping: {
+    methodKind: "unary";
+    input: typeof PingRequestSchema;
+    output: typeof PingResponseSchema;
+  },
+  /**
+   * Returns the non-sensitive server settings clients may tune themselves to.
+   *
+   * @generated from rpc musicclub.meta.MetaService.GetServerConfig
+   */
+  getServerConfig: {
+    methodKind: "unary";
+    input: typeof EmptySchema;
+    output: typeof ServerConfigSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_meta, 0);
+
@@ -10,7 +10,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file permissions.proto.
  */
 export const file_permissions: GenFile = /*@__PURE__*/
-  fileDesc("ChFwZXJtaXNzaW9ucy5wcm90bxIVbXVzaWNjbHViLnBlcm1pc3Npb25zIrUBCg1QZXJtaXNzaW9uU2V0EjQKBGpvaW4YASABKAsyJi5tdXNpY2NsdWIucGVybWlzc2lvbnMuSm9pblBlcm1pc3Npb25zEjUKBXNvbmdzGAIgASgLMiYubXVzaWNjbHViLnBlcm1pc3Npb25zLlNvbmdQZXJtaXNzaW9ucxI3CgZldmVudHMYAyABKAsyJy5tdXNpY2NsdWIucGVybWlzc2lvbnMuRXZlbnRQZXJtaXNzaW9ucyJRCg9Kb2luUGVybWlzc2lvbnMSHgoWZWRpdF9vd25fcGFydGljaXBhdGlvbhgBIAEoCBIeChZlZGl0X2FueV9wYXJ0aWNpcGF0aW9uGAIgASgIIkEKD1NvbmdQZXJtaXNzaW9ucxIWCg5lZGl0X293bl9zb25ncxgBIAEoCBIWCg5lZGl0X2FueV9zb25ncxgCIAEoCCJAChBFdmVudFBlcm1pc3Npb25zEhMKC2VkaXRfZXZlbnRzGAEgASgIEhcKD2VkaXRfdHJhY2tsaXN0cxgCIAEoCEIcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z");
+  fileDesc("ChFwZXJtaXNzaW9ucy5wcm90bxIVbXVzaWNjbHViLnBlcm1pc3Npb25zIsQBCg1QZXJtaXNzaW9uU2V0EjQKBGpvaW4YASABKAsyJi5tdXNpY2NsdWIucGVybWlzc2lvbnMuSm9pblBlcm1pc3Npb25zEjUKBXNvbmdzGAIgASgLMiYubXVzaWNjbHViLnBlcm1pc3Npb25zLlNvbmdQZXJtaXNzaW9ucxI3CgZldmVudHMYAyABKAsyJy5tdXNpY2NsdWIucGVybWlzc2lvbnMuRXZlbnRQZXJtaXNzaW9ucxINCgVhZG1pbhgEIAEoCCJRCg9Kb2luUGVybWlzc2lvbnMSHgoWZWRpdF9vd25fcGFydGljaXBhdGlvbhgBIAEoCBIeChZlZGl0X2FueV9wYXJ0aWNpcGF0aW9uGAIgASgIIkEKD1NvbmdQZXJtaXNzaW9ucxIWCg5lZGl0X293bl9zb25ncxgBIAEoCBIWCg5lZGl0X2FueV9zb25ncxgCIAEoCCJAChBFdmVudFBlcm1pc3Npb25zEhMKC2VkaXRfZXZlbnRzGAEgASgIEhcKD2VkaXRfdHJhY2tsaXN0cxgCIAEoCEIcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z");
 
 /**
  * Aggregated permissions for a user session.
@@ -32,6 +32,14 @@ export type PermissionSet = Message<"musicclub.permissions.PermissionSet"> & {
    * @generated from field: musicclub.permissions.EventPermissions events = 3;
    */
   events?: EventPermissions;
+
+  /**
+   * Club admin: sees song notes and manages other admins. Granted
+   * explicitly, not implied by the permissions above.
+   *
+   * @generated from field: bool admin = 4;
+   */
+  admin: boolean;
 };
 
 /**
@@ -4,8 +4,8 @@
 
 import type { GenEnum, GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
 import { enumDesc, fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
-import type { EmptySchema, Timestamp } from "@bufbuild/protobuf/wkt";
-import { file_google_protobuf_empty, file_google_protobuf_timestamp } from "@bufbuild/protobuf/wkt";
+import type { EmptySchema, FieldMask, Timestamp } from "@bufbuild/protobuf/wkt";
+import { file_google_protobuf_empty, file_google_protobuf_field_mask, file_google_protobuf_timestamp } from "@bufbuild/protobuf/wkt";
 import type { User } from "./user_pb.ts";
 import { file_user } from "./user_pb.ts";
 import type { PermissionSet } from "./permissions_pb.ts";
@@ -16,7 +16,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file song.proto.
  */
 export const file_song: GenFile = /*@__PURE__*/
-  fileDesc("Cgpzb25nLnByb3RvEg5tdXNpY2NsdWIuc29uZyJIChBMaXN0U29uZ3NSZXF1ZXN0Eg0KBXF1ZXJ5GAEgASgJEhIKCnBhZ2VfdG9rZW4YAiABKAkSEQoJcGFnZV9zaXplGAMgASgNIlEKEUxpc3RTb25nc1Jlc3BvbnNlEiMKBXNvbmdzGAEgAygLMhQubXVzaWNjbHViLnNvbmcuU29uZxIXCg9uZXh0X3BhZ2VfdG9rZW4YAiABKAkiFAoGU29uZ0lkEgoKAmlkGAEgASgJItABCgRTb25nEgoKAmlkGAEgASgJEg0KBXRpdGxlGAIgASgJEg4KBmFydGlzdBgDIAEoCRImCgRsaW5rGAQgASgLMhgubXVzaWNjbHViLnNvbmcuU29uZ0xpbmsSEwoLZGVzY3JpcHRpb24YBSABKAkSFwoPYXZhaWxhYmxlX3JvbGVzGAYgAygJEhYKDmVkaXRhYmxlX2J5X21lGAcgASgIEhgKEGFzc2lnbm1lbnRfY291bnQYCCABKAUSFQoNdGh1bWJuYWlsX3VybBgJIAEoCSKhAQoLU29uZ0RldGFpbHMSIgoEc29uZxgBIAEoCzIULm11c2ljY2x1Yi5zb25nLlNvbmcSMwoLYXNzaWdubWVudHMYAiADKAsyHi5tdXNpY2NsdWIuc29uZy5Sb2xlQXNzaWdubWVudBI5CgtwZXJtaXNzaW9ucxgDIAEoCzIkLm11c2ljY2x1Yi5wZXJtaXNzaW9ucy5QZXJtaXNzaW9uU2V0IkMKCFNvbmdMaW5rEioKBGtpbmQYASABKA4yHC5tdXNpY2NsdWIuc29uZy5Tb25nTGlua1R5cGUSCwoDdXJsGAIgASgJInEKDlJvbGVBc3NpZ25tZW50EgwKBHJvbGUYASABKAkSIgoEdXNlchgCIAEoCzIULm11c2ljY2x1Yi51c2VyLlVzZXISLQoJam9pbmVkX2F0GAMgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcCKfAQoRQ3JlYXRlU29uZ1JlcXVlc3QSDQoFdGl0bGUYASABKAkSDgoGYXJ0aXN0GAIgASgJEiYKBGxpbmsYAyABKAsyGC5tdXNpY2NsdWIuc29uZy5Tb25nTGluaxITCgtkZXNjcmlwdGlvbhgEIAEoCRIXCg9hdmFpbGFibGVfcm9sZXMYBSADKAkSFQoNdGh1bWJuYWlsX3VybBgGIAEoCSKrAQoRVXBkYXRlU29uZ1JlcXVlc3QSCgoCaWQYASABKAkSDQoFdGl0bGUYAiABKAkSDgoGYXJ0aXN0GAMgASgJEiYKBGxpbmsYBCABKAsyGC5tdXNpY2NsdWIuc29uZy5Tb25nTGluaxITCgtkZXNjcmlwdGlvbhgFIAEoCRIXCg9hdmFpbGFibGVfcm9sZXMYBiADKAkSFQoNdGh1bWJuYWlsX3VybBgHIAEoCSIwCg9Kb2luUm9sZVJlcXVlc3QSDwoHc29uZ19pZBgBIAEoCRIMCgRyb2xlGAIgASgJIjEKEExlYXZlUm9sZVJlcXVlc3QSDwoHc29uZ19pZBgBIAEoCRIMCgRyb2xlGAIgASgJKoYBCgxTb25nTGlua1R5cGUSGgoWU09OR19MSU5LX1RZUEVfVU5LTk9XThAAEhoKFlNPTkdfTElOS19UWVBFX1lPVVRVQkUQARIfChtTT05HX0xJTktfVFlQRV9ZQU5ERVhfTVVTSUMQAhIdChlTT05HX0xJTktfVFlQRV9TT1VORENMT1VEEAMyjwQKC1NvbmdTZXJ2aWNlElAKCUxpc3RTb25ncxIgLm11c2ljY2x1Yi5zb25nLkxpc3RTb25nc1JlcXVlc3QaIS5tdXNpY2NsdWIuc29uZy5MaXN0U29uZ3NSZXNwb25zZRI+CgdHZXRTb25nEhYubXVzaWNjbHViLnNvbmcuU29uZ0lkGhsubXVzaWNjbHViLnNvbmcuU29uZ0RldGFpbHMSTAoKQ3JlYXRlU29uZxIhLm11c2ljY2x1Yi5zb25nLkNyZWF0ZVNvbmdSZXF1ZXN0GhsubXVzaWNjbHViLnNvbmcuU29uZ0RldGFpbHMSTAoKVXBkYXRlU29uZxIhLm11c2ljY2x1Yi5zb25nLlVwZGF0ZVNvbmdSZXF1ZXN0GhsubXVzaWNjbHViLnNvbmcuU29uZ0RldGFpbHMSPAoKRGVsZXRlU29uZxIWLm11c2ljY2x1Yi5zb25nLlNvbmdJZBoWLmdvb2dsZS5wcm90b2J1Zi5FbXB0eRJICghKb2luUm9sZRIfLm11c2ljY2x1Yi5zb25nLkpvaW5Sb2xlUmVxdWVzdBobLm11c2ljY2x1Yi5zb25nLlNvbmdEZXRhaWxzEkoKCUxlYXZlUm9sZRIgLm11c2ljY2x1Yi5zb25nLkxlYXZlUm9sZVJlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlsc0IcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z", [file_google_protobuf_empty, file_google_protobuf_timestamp, file_user, file_permissions]);
+  fileDesc("Cgpzb25nLnByb3RvEg5tdXNpY2NsdWIuc29uZyKyAQoQTGlzdFNvbmdzUmVxdWVzdBINCgVxdWVyeRgBIAEoCRISCgpwYWdlX3Rva2VuGAIgASgJEhEKCXBhZ2Vfc2l6ZRgDIAEoDRIUCgxtaXNzaW5nX2xpbmsYBCABKAgSFgoOcGFydGljaXBhbnRfaWQYBSABKAkSCwoDdGFnGAYgASgJEi0KCXJlYWRfbWFzaxgHIAEoCzIaLmdvb2dsZS5wcm90b2J1Zi5GaWVsZE1hc2siKQoYU3RyZWFtU2VhcmNoU29uZ3NSZXF1ZXN0Eg0KBXF1ZXJ5GAEgASgJIlUKGkxpc3RTb25nc05vdEluRXZlbnRSZXF1ZXN0EhAKCGV2ZW50X2lkGAEgASgJEhIKCnBhZ2VfdG9rZW4YAiABKAkSEQoJcGFnZV9zaXplGAMgASgNIkAKF0xpc3RTb25nc0J5Vm90ZXNSZXF1ZXN0EhIKCnBhZ2VfdG9rZW4YASABKAkSEQoJcGFnZV9zaXplGAIgASgNIksKDkdldFNvbmdSZXF1ZXN0EgoKAmlkGAEgASgJEi0KCXJlYWRfbWFzaxgCIAEoCzIaLmdvb2dsZS5wcm90b2J1Zi5GaWVsZE1hc2siYwoRTGlzdFNvbmdzUmVzcG9uc2USIwoFc29uZ3MYASADKAsyFC5tdXNpY2NsdWIuc29uZy5Tb25nEhcKD25leHRfcGFnZV90b2tlbhgCIAEoCRIQCghoYXNfbW9yZRgDIAEoCCInChZMaXN0UmVjZW50U29uZ3NSZXF1ZXN0Eg0KBWxpbWl0GAEgASgNIhQKBlNvbmdJZBIKCgJpZBgBIAEoCSI2ChFEZWxldGVTb25nUmVxdWVzdBIKCgJpZBgBIAEoCRIVCg1hbGxvd19taXNzaW5nGAIgASgIIqECCgRTb25nEgoKAmlkGAEgASgJEg0KBXRpdGxlGAIgASgJEg4KBmFydGlzdBgDIAEoCRImCgRsaW5rGAQgASgLMhgubXVzaWNjbHViLnNvbmcuU29uZ0xpbmsSEwoLZGVzY3JpcHRpb24YBSABKAkSFwoPYXZhaWxhYmxlX3JvbGVzGAYgAygJEhYKDmVkaXRhYmxlX2J5X21lGAcgASgIEhgKEGFzc2lnbm1lbnRfY291bnQYCCABKAUSFQoNdGh1bWJuYWlsX3VybBgJIAEoCRISCgpjcmVhdGVkX2J5GAogASgJEhgKEGR1cmF0aW9uX3NlY29uZHMYCyABKAUSEgoKdm90ZV9jb3VudBgMIAEoBRINCgVub3RlcxgNIAEoCSKhAQoLU29uZ0RldGFpbHMSIgoEc29uZxgBIAEoCzIULm11c2ljY2x1Yi5zb25nLlNvbmcSMwoLYXNzaWdubWVudHMYAiADKAsyHi5tdXNpY2NsdWIuc29uZy5Sb2xlQXNzaWdubWVudBI5CgtwZXJtaXNzaW9ucxgDIAEoCzIkLm11c2ljY2x1Yi5wZXJtaXNzaW9ucy5QZXJtaXNzaW9uU2V0IkMKCFNvbmdMaW5rEioKBGtpbmQYASABKA4yHC5tdXNpY2NsdWIuc29uZy5Tb25nTGlua1R5cGUSCwoDdXJsGAIgASgJIqYBCg5Sb2xlQXNzaWdubWVudBIMCgRyb2xlGAEgASgJEiIKBHVzZXIYAiABKAsyFC5tdXNpY2NsdWIudXNlci5Vc2VyEi0KCWpvaW5lZF9hdBgDIAEoCzIaLmdvb2dsZS5wcm90b2J1Zi5UaW1lc3RhbXASMwoGc3RhdHVzGAQgASgOMiMubXVzaWNjbHViLnNvbmcuUGFydGljaXBhdGlvblN0YXR1cyLIAQoRQ3JlYXRlU29uZ1JlcXVlc3QSDQoFdGl0bGUYASABKAkSDgoGYXJ0aXN0GAIgASgJEiYKBGxpbmsYAyABKAsyGC5tdXNpY2NsdWIuc29uZy5Tb25nTGluaxITCgtkZXNjcmlwdGlvbhgEIAEoCRIXCg9hdmFpbGFibGVfcm9sZXMYBSADKAkSFQoNdGh1bWJuYWlsX3VybBgGIAEoCRIYChBkdXJhdGlvbl9zZWNvbmRzGAcgASgFEg0KBW5vdGVzGAggASgJIjQKDkZpZWxkVmlvbGF0aW9uEg0KBWZpZWxkGAEgASgJEhMKC2Rlc2NyaXB0aW9uGAIgASgJIkoKFFZhbGlkYXRlU29uZ1Jlc3BvbnNlEjIKCnZpb2xhdGlvbnMYASADKAsyHi5tdXNpY2NsdWIuc29uZy5GaWVsZFZpb2xhdGlvbiKFAgoRVXBkYXRlU29uZ1JlcXVlc3QSCgoCaWQYASABKAkSDQoFdGl0bGUYAiABKAkSDgoGYXJ0aXN0GAMgASgJEiYKBGxpbmsYBCABKAsyGC5tdXNpY2NsdWIuc29uZy5Tb25nTGluaxITCgtkZXNjcmlwdGlvbhgFIAEoCRIXCg9hdmFpbGFibGVfcm9sZXMYBiADKAkSFQoNdGh1bWJuYWlsX3VybBgHIAEoCRIYChBkdXJhdGlvbl9zZWNvbmRzGAggASgFEg0KBW5vdGVzGAkgASgJEi8KC3VwZGF0ZV9tYXNrGAogASgLMhouZ29vZ2xlLnByb3RvYnVmLkZpZWxkTWFzayJlCg9Kb2luUm9sZVJlcXVlc3QSDwoHc29uZ19pZBgBIAEoCRIMCgRyb2xlGAIgASgJEjMKBnN0YXR1cxgDIAEoDjIjLm11c2ljY2x1Yi5zb25nLlBhcnRpY2lwYXRpb25TdGF0dXMiMQoQTGVhdmVSb2xlUmVxdWVzdBIPCgdzb25nX2lkGAEgASgJEgwKBHJvbGUYAiABKAkiQwoRUm9sZUFzc2lnbm1lbnRLZXkSDwoHc29uZ19pZBgBIAEoCRIMCgRyb2xlGAIgASgJEg8KB3VzZXJfaWQYAyABKAkijgEKEVVwZGF0ZVJvbGVSZXF1ZXN0Ei4KA2tleRgBIAEoCzIhLm11c2ljY2x1Yi5zb25nLlJvbGVBc3NpZ25tZW50S2V5EhAKCG5ld19yb2xlGAIgASgJEjcKCm5ld19zdGF0dXMYAyABKA4yIy5tdXNpY2NsdWIuc29uZy5QYXJ0aWNpcGF0aW9uU3RhdHVzIqcBChlMaXN0UGFydGljaXBhdGlvbnNSZXF1ZXN0Eg8KB3VzZXJfaWQYASABKAkSDwoHc29uZ19pZBgCIAEoCRIMCgRyb2xlGAMgASgJEhIKCnBhZ2VfdG9rZW4YBCABKAkSEQoJcGFnZV9zaXplGAUgASgNEjMKBnN0YXR1cxgGIAEoDjIjLm11c2ljY2x1Yi5zb25nLlBhcnRpY2lwYXRpb25TdGF0dXMirQEKDVBhcnRpY2lwYXRpb24SDwoHc29uZ19pZBgBIAEoCRISCgpzb25nX3RpdGxlGAIgASgJEhMKC3NvbmdfYXJ0aXN0GAMgASgJEjIKCmFzc2lnbm1lbnQYBCABKAsyHi5tdXNpY2NsdWIuc29uZy5Sb2xlQXNzaWdubWVudBIuCgp1cGRhdGVkX2F0GAUgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcCJ+ChpMaXN0UGFydGljaXBhdGlvbnNSZXNwb25zZRI1Cg5wYXJ0aWNpcGF0aW9ucxgBIAMoCzIdLm11c2ljY2x1Yi5zb25nLlBhcnRpY2lwYXRpb24SFwoPbmV4dF9wYWdlX3Rva2VuGAIgASgJEhAKCGhhc19tb3JlGAMgASgIIkEKH0xpc3RSZWNlbnRQYXJ0aWNpcGF0aW9uc1JlcXVlc3QSDwoHdXNlcl9pZBgBIAEoCRINCgVsaW1pdBgCIAEoDSJyCh5MaXN0UGFydGljaXBhdGlvbnNTaW5jZVJlcXVlc3QSKQoFc2luY2UYASABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wEhIKCnBhZ2VfdG9rZW4YAiABKAkSEQoJcGFnZV9zaXplGAMgASgNIrsBCh9MaXN0UGFydGljaXBhdGlvbnNTaW5jZVJlc3BvbnNlEjUKDnBhcnRpY2lwYXRpb25zGAEgAygLMh0ubXVzaWNjbHViLnNvbmcuUGFydGljaXBhdGlvbhIXCg9uZXh0X3BhZ2VfdG9rZW4YAiABKAkSEAoIaGFzX21vcmUYAyABKAgSNgoIcmVtb3ZhbHMYBCADKAsyJC5tdXNpY2NsdWIuc29uZy5SZW1vdmVkUGFydGljaXBhdGlvbiJ2ChRSZW1vdmVkUGFydGljaXBhdGlvbhIPCgdzb25nX2lkGAEgASgJEgwKBHJvbGUYAiABKAkSDwoHdXNlcl9pZBgDIAEoCRIuCgpyZW1vdmVkX2F0GAQgASgLMhouZ29vZ2xlLnByb3RvYnVmLlRpbWVzdGFtcCJkChpSZXBsYWNlUGFydGljaXBhbnRzUmVxdWVzdBIPCgdzb25nX2lkGAEgASgJEjUKDHBhcnRpY2lwYW50cxgCIAMoCzIfLm11c2ljY2x1Yi5zb25nLlBhcnRpY2lwYW50U2xvdCIwCg9QYXJ0aWNpcGFudFNsb3QSDAoEcm9sZRgBIAEoCRIPCgd1c2VyX2lkGAIgASgJIiIKIENvdW50UGFydGljaXBhdGlvbnNCeVJvbGVSZXF1ZXN0IigKCVJvbGVDb3VudBIMCgRyb2xlGAEgASgJEg0KBWNvdW50GAIgASgNIk4KIUNvdW50UGFydGljaXBhdGlvbnNCeVJvbGVSZXNwb25zZRIpCgZjb3VudHMYASADKAsyGS5tdXNpY2NsdWIuc29uZy5Sb2xlQ291bnQiLgoOU29uZ1RhZ1JlcXVlc3QSDwoHc29uZ19pZBgBIAEoCRILCgN0YWcYAiABKAkiKQoIU29uZ1RhZ3MSDwoHc29uZ19pZBgBIAEoCRIMCgR0YWdzGAIgAygJIjUKFEJhdGNoVGFnU29uZ3NSZXF1ZXN0EgsKA3RhZxgBIAEoCRIQCghzb25nX2lkcxgCIAMoCSI6ChVCYXRjaFRhZ1NvbmdzUmVzcG9uc2USCwoDdGFnGAEgASgJEhQKDHRhZ2dlZF9jb3VudBgCIAEoBSIpChVDaGVja1NvbmdMaW5rc1JlcXVlc3QSEAoIc29uZ19pZHMYASADKAkinQEKCkxpbmtTdGF0dXMSDwoHc29uZ19pZBgBIAEoCRIqCgZoZWFsdGgYAiABKA4yGi5tdXNpY2NsdWIuc29uZy5MaW5rSGVhbHRoEhMKC2h0dHBfc3RhdHVzGAMgASgNEg0KBWVycm9yGAQgASgJEi4KCmNoZWNrZWRfYXQYBSABKAsyGi5nb29nbGUucHJvdG9idWYuVGltZXN0YW1wKoYBCgxTb25nTGlua1R5cGUSGgoWU09OR19MSU5LX1RZUEVfVU5LTk9XThAAEhoKFlNPTkdfTElOS19UWVBFX1lPVVRVQkUQARIfChtTT05HX0xJTktfVFlQRV9ZQU5ERVhfTVVTSUMQAhIdChlTT05HX0xJTktfVFlQRV9TT1VORENMT1VEEAMqgwEKE1BhcnRpY2lwYXRpb25TdGF0dXMSJAogUEFSVElDSVBBVElPTl9TVEFUVVNfVU5TUEVDSUZJRUQQABIiCh5QQVJUSUNJUEFUSU9OX1NUQVRVU19DT05GSVJNRUQQARIiCh5QQVJUSUNJUEFUSU9OX1NUQVRVU19URU5UQVRJVkUQAiqHAQoKTGlua0hlYWx0aBIXChNMSU5LX0hFQUxUSF9VTktOT1dOEAASEgoOTElOS19IRUFMVEhfT0sQARIWChJMSU5LX0hFQUxUSF9CUk9LRU4QAhIbChdMSU5LX0hFQUxUSF9VTlJFQUNIQUJMRRADEhcKE0xJTktfSEVBTFRIX01JU1NJTkcQBDLWEQoLU29uZ1NlcnZpY2USUAoJTGlzdFNvbmdzEiAubXVzaWNjbHViLnNvbmcuTGlzdFNvbmdzUmVxdWVzdBohLm11c2ljY2x1Yi5zb25nLkxpc3RTb25nc1Jlc3BvbnNlElUKEVN0cmVhbVNlYXJjaFNvbmdzEigubXVzaWNjbHViLnNvbmcuU3RyZWFtU2VhcmNoU29uZ3NSZXF1ZXN0GhQubXVzaWNjbHViLnNvbmcuU29uZzABElwKD0xpc3RSZWNlbnRTb25ncxImLm11c2ljY2x1Yi5zb25nLkxpc3RSZWNlbnRTb25nc1JlcXVlc3QaIS5tdXNpY2NsdWIuc29uZy5MaXN0U29uZ3NSZXNwb25zZRJkChNMaXN0U29uZ3NOb3RJbkV2ZW50EioubXVzaWNjbHViLnNvbmcuTGlzdFNvbmdzTm90SW5FdmVudFJlcXVlc3QaIS5tdXNpY2NsdWIuc29uZy5MaXN0U29uZ3NSZXNwb25zZRJeChBMaXN0U29uZ3NCeVZvdGVzEicubXVzaWNjbHViLnNvbmcuTGlzdFNvbmdzQnlWb3Rlc1JlcXVlc3QaIS5tdXNpY2NsdWIuc29uZy5MaXN0U29uZ3NSZXNwb25zZRJGCgdHZXRTb25nEh4ubXVzaWNjbHViLnNvbmcuR2V0U29uZ1JlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlscxJMCgpDcmVhdGVTb25nEiEubXVzaWNjbHViLnNvbmcuQ3JlYXRlU29uZ1JlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlscxJXCgxWYWxpZGF0ZVNvbmcSIS5tdXNpY2NsdWIuc29uZy5DcmVhdGVTb25nUmVxdWVzdBokLm11c2ljY2x1Yi5zb25nLlZhbGlkYXRlU29uZ1Jlc3BvbnNlEkwKClVwZGF0ZVNvbmcSIS5tdXNpY2NsdWIuc29uZy5VcGRhdGVTb25nUmVxdWVzdBobLm11c2ljY2x1Yi5zb25nLlNvbmdEZXRhaWxzEkcKCkRlbGV0ZVNvbmcSIS5tdXNpY2NsdWIuc29uZy5EZWxldGVTb25nUmVxdWVzdBoWLmdvb2dsZS5wcm90b2J1Zi5FbXB0eRJICghKb2luUm9sZRIfLm11c2ljY2x1Yi5zb25nLkpvaW5Sb2xlUmVxdWVzdBobLm11c2ljY2x1Yi5zb25nLlNvbmdEZXRhaWxzEkoKCUxlYXZlUm9sZRIgLm11c2ljY2x1Yi5zb25nLkxlYXZlUm9sZVJlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlscxJMCgpVcGRhdGVSb2xlEiEubXVzaWNjbHViLnNvbmcuVXBkYXRlUm9sZVJlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlscxJeChNSZXBsYWNlUGFydGljaXBhbnRzEioubXVzaWNjbHViLnNvbmcuUmVwbGFjZVBhcnRpY2lwYW50c1JlcXVlc3QaGy5tdXNpY2NsdWIuc29uZy5Tb25nRGV0YWlscxJrChJMaXN0UGFydGljaXBhdGlvbnMSKS5tdXNpY2NsdWIuc29uZy5MaXN0UGFydGljaXBhdGlvbnNSZXF1ZXN0GioubXVzaWNjbHViLnNvbmcuTGlzdFBhcnRpY2lwYXRpb25zUmVzcG9uc2USegoXTGlzdFBhcnRpY2lwYXRpb25zU2luY2USLi5tdXNpY2NsdWIuc29uZy5MaXN0UGFydGljaXBhdGlvbnNTaW5jZVJlcXVlc3QaLy5tdXNpY2NsdWIuc29uZy5MaXN0UGFydGljaXBhdGlvbnNTaW5jZVJlc3BvbnNlEncKGExpc3RSZWNlbnRQYXJ0aWNpcGF0aW9ucxIvLm11c2ljY2x1Yi5zb25nLkxpc3RSZWNlbnRQYXJ0aWNpcGF0aW9uc1JlcXVlc3QaKi5tdXNpY2NsdWIuc29uZy5MaXN0UGFydGljaXBhdGlvbnNSZXNwb25zZRKAAQoZQ291bnRQYXJ0aWNpcGF0aW9uc0J5Um9sZRIwLm11c2ljY2x1Yi5zb25nLkNvdW50UGFydGljaXBhdGlvbnNCeVJvbGVSZXF1ZXN0GjEubXVzaWNjbHViLnNvbmcuQ291bnRQYXJ0aWNpcGF0aW9uc0J5Um9sZVJlc3BvbnNlEk8KDkNoZWNrU29uZ0xpbmtzEiUubXVzaWNjbHViLnNvbmcuQ2hlY2tTb25nTGlua3NSZXF1ZXN0GhYuZ29vZ2xlLnByb3RvYnVmLkVtcHR5EkMKDUdldExpbmtTdGF0dXMSFi5tdXNpY2NsdWIuc29uZy5Tb25nSWQaGi5tdXNpY2NsdWIuc29uZy5MaW5rU3RhdHVzEkAKDExpc3RTb25nVGFncxIWLm11c2ljY2x1Yi5zb25nLlNvbmdJZBoYLm11c2ljY2x1Yi5zb25nLlNvbmdUYWdzEkYKCkFkZFNvbmdUYWcSHi5tdXNpY2NsdWIuc29uZy5Tb25nVGFnUmVxdWVzdBoYLm11c2ljY2x1Yi5zb25nLlNvbmdUYWdzEkkKDVJlbW92ZVNvbmdUYWcSHi5tdXNpY2NsdWIuc29uZy5Tb25nVGFnUmVxdWVzdBoYLm11c2ljY2x1Yi5zb25nLlNvbmdUYWdzEj8KCFZvdGVTb25nEhYubXVzaWNjbHViLnNvbmcuU29uZ0lkGhsubXVzaWNjbHViLnNvbmcuU29uZ0RldGFpbHMSQQoKVW52b3RlU29uZxIWLm11c2ljY2x1Yi5zb25nLlNvbmdJZBobLm11c2ljY2x1Yi5zb25nLlNvbmdEZXRhaWxzElwKDUJhdGNoVGFnU29uZ3MSJC5tdXNpY2NsdWIuc29uZy5CYXRjaFRhZ1NvbmdzUmVxdWVzdBolLm11c2ljY2x1Yi5zb25nLkJhdGNoVGFnU29uZ3NSZXNwb25zZUIcWhptdXNpY2NsdWJib3QvYmFja2VuZC9wcm90b2IGcHJvdG8z", [file_google_protobuf_empty, file_google_protobuf_field_mask, file_google_protobuf_timestamp, file_user, file_permissions]);
 
 /**
  * @generated from message musicclub.song.ListSongsRequest
@@ -40,6 +40,34 @@ export type ListSongsRequest = Message<"musicclub.song.ListSongsRequest"> & {
    * @generated from field: uint32 page_size = 3;
    */
   pageSize: number;
+
+  /**
+   * Only return songs without a streaming link, ordered by id.
+   *
+   * @generated from field: bool missing_link = 4;
+   */
+  missingLink: boolean;
+
+  /**
+   * Only return songs this user holds at least one role on.
+   *
+   * @generated from field: string participant_id = 5;
+   */
+  participantId: string;
+
+  /**
+   * Only return songs with this tag (case-insensitive).
+   *
+   * @generated from field: string tag = 6;
+   */
+  tag: string;
+
+  /**
+   * Song fields to return, e.g. "id" and "title"; empty returns every field.
+   *
+   * @generated from field: google.protobuf.FieldMask read_mask = 7;
+   */
+  readMask?: FieldMask;
 };
 
 /**
@@ -49,6 +77,103 @@ export type ListSongsRequest = Message<"musicclub.song.ListSongsRequest"> & {
 export const ListSongsRequestSchema: GenMessage<ListSongsRequest> = /*@__PURE__*/
   messageDesc(file_song, 0);
 
+/**
+ * @generated from message musicclub.song.StreamSearchSongsRequest
+ */
+export type StreamSearchSongsRequest = Message<"musicclub.song.StreamSearchSongsRequest"> & {
+  /**
+   * Substring filter by title or artist, matched like ListSongsRequest.query.
+   *
+   * @generated from field: string query = 1;
+   */
+  query: string;
+};
+
+/**
+ * Describes the message musicclub.song.StreamSearchSongsRequest.
+ * Use `create(StreamSearchSongsRequestSchema)` to create a new message.
+ */
+export const StreamSearchSongsRequestSchema: GenMessage<StreamSearchSongsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 1);
+
+/**
+ * @generated from message musicclub.song.ListSongsNotInEventRequest
+ */
+export type ListSongsNotInEventRequest = Message<"musicclub.song.ListSongsNotInEventRequest"> & {
+  /**
+   * @generated from field: string event_id = 1;
+   */
+  eventId: string;
+
+  /**
+   * Pagination cursor (opaque to client).
+   *
+   * @generated from field: string page_token = 2;
+   */
+  pageToken: string;
+
+  /**
+   * @generated from field: uint32 page_size = 3;
+   */
+  pageSize: number;
+};
+
+/**
+ * Describes the message musicclub.song.ListSongsNotInEventRequest.
+ * Use `create(ListSongsNotInEventRequestSchema)` to create a new message.
+ */
+export const ListSongsNotInEventRequestSchema: GenMessage<ListSongsNotInEventRequest> = /*@__PURE__*/
+  messageDesc(file_song, 2);
+
+/**
+ * @generated from message musicclub.song.ListSongsByVotesRequest
+ */
+export type ListSongsByVotesRequest = Message<"musicclub.song.ListSongsByVotesRequest"> & {
+  /**
+   * Pagination cursor (opaque to client).
+   *
+   * @generated from field: string page_token = 1;
+   */
+  pageToken: string;
+
+  /**
+   * @generated from field: uint32 page_size = 2;
+   */
+  pageSize: number;
+};
+
+/**
+ * Describes the message musicclub.song.ListSongsByVotesRequest.
+ * Use `create(ListSongsByVotesRequestSchema)` to create a new message.
+ */
+export const ListSongsByVotesRequestSchema: GenMessage<ListSongsByVotesRequest> = /*@__PURE__*/
+  messageDesc(file_song, 3);
+
+/**
+ * @generated from message musicclub.song.GetSongRequest
+ */
+export type GetSongRequest = Message<"musicclub.song.GetSongRequest"> & {
+  /**
+   * @generated from field: string id = 1;
+   */
+  id: string;
+
+  /**
+   * Song fields to return; empty returns every field. Assignments and
+   * permissions are always included.
+   *
+   * @generated from field: google.protobuf.FieldMask read_mask = 2;
+   */
+  readMask?: FieldMask;
+};
+
+/**
+ * Describes the message musicclub.song.GetSongRequest.
+ * Use `create(GetSongRequestSchema)` to create a new message.
+ */
+export const GetSongRequestSchema: GenMessage<GetSongRequest> = /*@__PURE__*/
+  messageDesc(file_song, 4);
+
 /**
  * @generated from message musicclub.song.ListSongsResponse
  */
@@ -62,6 +187,13 @@ export type ListSongsResponse = Message<"musicclub.song.ListSongsResponse"> & {
    * @generated from field: string next_page_token = 2;
    */
   nextPageToken: string;
+
+  /**
+   * Whether more songs exist after this page.
+   *
+   * @generated from field: bool has_more = 3;
+   */
+  hasMore: boolean;
 };
 
 /**
@@ -69,7 +201,26 @@ export type ListSongsResponse = Message<"musicclub.song.ListSongsResponse"> & {
  * Use `create(ListSongsResponseSchema)` to create a new message.
  */
 export const ListSongsResponseSchema: GenMessage<ListSongsResponse> = /*@__PURE__*/
-  messageDesc(file_song, 1);
+  messageDesc(file_song, 5);
+
+/**
+ * @generated from message musicclub.song.ListRecentSongsRequest
+ */
+export type ListRecentSongsRequest = Message<"musicclub.song.ListRecentSongsRequest"> & {
+  /**
+   * Number of songs to return (default 10, max 50).
+   *
+   * @generated from field: uint32 limit = 1;
+   */
+  limit: number;
+};
+
+/**
+ * Describes the message musicclub.song.ListRecentSongsRequest.
+ * Use `create(ListRecentSongsRequestSchema)` to create a new message.
+ */
+export const ListRecentSongsRequestSchema: GenMessage<ListRecentSongsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 6);
 
 /**
  * @generated from message musicclub.song.SongId
@@ -86,7 +237,31 @@ export type SongId = Message<"musicclub.song.SongId"> & {
  * Use `create(SongIdSchema)` to create a new message.
  */
 export const SongIdSchema: GenMessage<SongId> = /*@__PURE__*/
-  messageDesc(file_song, 2);
+  messageDesc(file_song, 7);
+
+/**
+ * @generated from message musicclub.song.DeleteSongRequest
+ */
+export type DeleteSongRequest = Message<"musicclub.song.DeleteSongRequest"> & {
+  /**
+   * @generated from field: string id = 1;
+   */
+  id: string;
+
+  /**
+   * Succeed instead of returning NOT_FOUND when the song is already gone.
+   *
+   * @generated from field: bool allow_missing = 2;
+   */
+  allowMissing: boolean;
+};
+
+/**
+ * Describes the message musicclub.song.DeleteSongRequest.
+ * Use `create(DeleteSongRequestSchema)` to create a new message.
+ */
+export const DeleteSongRequestSchema: GenMessage<DeleteSongRequest> = /*@__PURE__*/
+  messageDesc(file_song, 8);
 
 /**
  * @generated from message musicclub.song.Song
@@ -142,6 +317,34 @@ export type Song = Message<"musicclub.song.Song"> & {
    * @generated from field: string thumbnail_url = 9;
    */
   thumbnailUrl: string;
+
+  /**
+   * ID of the user who added the song. Empty for legacy rows.
+   *
+   * @generated from field: string created_by = 10;
+   */
+  createdBy: string;
+
+  /**
+   * Length of the song in seconds, 0 when unknown.
+   *
+   * @generated from field: int32 duration_seconds = 11;
+   */
+  durationSeconds: number;
+
+  /**
+   * Number of members who upvoted the song.
+   *
+   * @generated from field: int32 vote_count = 12;
+   */
+  voteCount: number;
+
+  /**
+   * Private organizer notes. Only returned to admins.
+   *
+   * @generated from field: string notes = 13;
+   */
+  notes: string;
 };
 
 /**
@@ -149,7 +352,7 @@ export type Song = Message<"musicclub.song.Song"> & {
  * Use `create(SongSchema)` to create a new message.
  */
 export const SongSchema: GenMessage<Song> = /*@__PURE__*/
-  messageDesc(file_song, 3);
+  messageDesc(file_song, 9);
 
 /**
  * @generated from message musicclub.song.SongDetails
@@ -176,7 +379,7 @@ export type SongDetails = Message<"musicclub.song.SongDetails"> & {
  * Use `create(SongDetailsSchema)` to create a new message.
  */
 export const SongDetailsSchema: GenMessage<SongDetails> = /*@__PURE__*/
-  messageDesc(file_song, 4);
+  messageDesc(file_song, 10);
 
 /**
  * @generated from message musicclub.song.SongLink
@@ -198,7 +401,7 @@ export type SongLink = Message<"musicclub.song.SongLink"> & {
  * Use `create(SongLinkSchema)` to create a new message.
  */
 export const SongLinkSchema: GenMessage<SongLink> = /*@__PURE__*/
-  messageDesc(file_song, 5);
+  messageDesc(file_song, 11);
 
 /**
  * @generated from message musicclub.song.RoleAssignment
@@ -218,6 +421,11 @@ export type RoleAssignment = Message<"musicclub.song.RoleAssignment"> & {
    * @generated from field: google.protobuf.Timestamp joined_at = 3;
    */
   joinedAt?: Timestamp;
+
+  /**
+   * @generated from field: musicclub.song.ParticipationStatus status = 4;
+   */
+  status: ParticipationStatus;
 };
 
 /**
@@ -225,7 +433,7 @@ export type RoleAssignment = Message<"musicclub.song.RoleAssignment"> & {
  * Use `create(RoleAssignmentSchema)` to create a new message.
  */
 export const RoleAssignmentSchema: GenMessage<RoleAssignment> = /*@__PURE__*/
-  messageDesc(file_song, 6);
+  messageDesc(file_song, 12);
 
 /**
  * @generated from message musicclub.song.CreateSongRequest
@@ -260,6 +468,20 @@ export type CreateSongRequest = Message<"musicclub.song.CreateSongRequest"> & {
    * @generated from field: string thumbnail_url = 6;
    */
   thumbnailUrl: string;
+
+  /**
+   * 0 leaves the duration unknown; negative values are rejected.
+   *
+   * @generated from field: int32 duration_seconds = 7;
+   */
+  durationSeconds: number;
+
+  /**
+   * Admins only.
+   *
+   * @generated from field: string notes = 8;
+   */
+  notes: string;
 };
 
 /**
@@ -267,7 +489,48 @@ export type CreateSongRequest = Message<"musicclub.song.CreateSongRequest"> & {
  * Use `create(CreateSongRequestSchema)` to create a new message.
  */
 export const CreateSongRequestSchema: GenMessage<CreateSongRequest> = /*@__PURE__*/
-  messageDesc(file_song, 7);
+  messageDesc(file_song, 13);
+
+/**
+ * @generated from message musicclub.song.FieldViolation
+ */
+export type FieldViolation = Message<"musicclub.song.FieldViolation"> & {
+  /**
+   * @generated from field: string field = 1;
+   */
+  field: string;
+
+  /**
+   * @generated from field: string description = 2;
+   */
+  description: string;
+};
+
+/**
+ * Describes the message musicclub.song.FieldViolation.
+ * Use `create(FieldViolationSchema)` to create a new message.
+ */
+export const FieldViolationSchema: GenMessage<FieldViolation> = /*@__PURE__*/
+  messageDesc(file_song, 14);
+
+/**
+ * @generated from message musicclub.song.ValidateSongResponse
+ */
+export type ValidateSongResponse = Message<"musicclub.song.ValidateSongResponse"> & {
+  /**
+   * Empty when the song could be created as is.
+   *
+   * @generated from field: repeated musicclub.song.FieldViolation violations = 1;
+   */
+  violations: FieldViolation[];
+};
+
+/**
+ * Describes the message musicclub.song.ValidateSongResponse.
+ * Use `create(ValidateSongResponseSchema)` to create a new message.
+ */
+export const ValidateSongResponseSchema: GenMessage<ValidateSongResponse> = /*@__PURE__*/
+  messageDesc(file_song, 15);
 
 /**
  * @generated from message musicclub.song.UpdateSongRequest
@@ -307,6 +570,27 @@ export type UpdateSongRequest = Message<"musicclub.song.UpdateSongRequest"> & {
    * @generated from field: string thumbnail_url = 7;
    */
   thumbnailUrl: string;
+
+  /**
+   * 0 clears the duration; negative values are rejected.
+   *
+   * @generated from field: int32 duration_seconds = 8;
+   */
+  durationSeconds: number;
+
+  /**
+   * Admins only; replaces the notes when update_mask lists "notes".
+   *
+   * @generated from field: string notes = 9;
+   */
+  notes: string;
+
+  /**
+   * Optional fields to overwrite. Only "notes" is recognised; notes are left untouched unless listed.
+   *
+   * @generated from field: google.protobuf.FieldMask update_mask = 10;
+   */
+  updateMask?: FieldMask;
 };
 
 /**
@@ -314,7 +598,7 @@ export type UpdateSongRequest = Message<"musicclub.song.UpdateSongRequest"> & {
  * Use `create(UpdateSongRequestSchema)` to create a new message.
  */
 export const UpdateSongRequestSchema: GenMessage<UpdateSongRequest> = /*@__PURE__*/
-  messageDesc(file_song, 8);
+  messageDesc(file_song, 16);
 
 /**
  * @generated from message musicclub.song.JoinRoleRequest
@@ -329,6 +613,13 @@ export type JoinRoleRequest = Message<"musicclub.song.JoinRoleRequest"> & {
    * @generated from field: string role = 2;
    */
   role: string;
+
+  /**
+   * Defaults to CONFIRMED. Joining a role already held updates its status.
+   *
+   * @generated from field: musicclub.song.ParticipationStatus status = 3;
+   */
+  status: ParticipationStatus;
 };
 
 /**
@@ -336,7 +627,7 @@ export type JoinRoleRequest = Message<"musicclub.song.JoinRoleRequest"> & {
  * Use `create(JoinRoleRequestSchema)` to create a new message.
  */
 export const JoinRoleRequestSchema: GenMessage<JoinRoleRequest> = /*@__PURE__*/
-  messageDesc(file_song, 9);
+  messageDesc(file_song, 17);
 
 /**
  * @generated from message musicclub.song.LeaveRoleRequest
@@ -358,82 +649,754 @@ export type LeaveRoleRequest = Message<"musicclub.song.LeaveRoleRequest"> & {
  * Use `create(LeaveRoleRequestSchema)` to create a new message.
  */
 export const LeaveRoleRequestSchema: GenMessage<LeaveRoleRequest> = /*@__PURE__*/
-  messageDesc(file_song, 10);
+  messageDesc(file_song, 18);
 
 /**
- * @generated from enum musicclub.song.SongLinkType
+ * Identifies a single existing role assignment.
+ *
+ * @generated from message musicclub.song.RoleAssignmentKey
  */
-export enum SongLinkType {
-  /**
-   * @generated from enum value: SONG_LINK_TYPE_UNKNOWN = 0;
-   */
-  UNKNOWN = 0,
-
+export type RoleAssignmentKey = Message<"musicclub.song.RoleAssignmentKey"> & {
   /**
-   * @generated from enum value: SONG_LINK_TYPE_YOUTUBE = 1;
+   * @generated from field: string song_id = 1;
    */
-  YOUTUBE = 1,
+  songId: string;
 
   /**
-   * @generated from enum value: SONG_LINK_TYPE_YANDEX_MUSIC = 2;
+   * @generated from field: string role = 2;
    */
-  YANDEX_MUSIC = 2,
+  role: string;
 
   /**
-   * @generated from enum value: SONG_LINK_TYPE_SOUNDCLOUD = 3;
+   * @generated from field: string user_id = 3;
    */
-  SOUNDCLOUD = 3,
-}
+  userId: string;
+};
 
 /**
- * Describes the enum musicclub.song.SongLinkType.
+ * Describes the message musicclub.song.RoleAssignmentKey.
+ * Use `create(RoleAssignmentKeySchema)` to create a new message.
  */
-export const SongLinkTypeSchema: GenEnum<SongLinkType> = /*@__PURE__*/
-  enumDesc(file_song, 0);
+export const RoleAssignmentKeySchema: GenMessage<RoleAssignmentKey> = /*@__PURE__*/
+  messageDesc(file_song, 19);
 
 /**
- * Provides CRUD functionality for songs
- *
- * @generated from service musicclub.song.SongService
+ * @generated from message musicclub.song.UpdateRoleRequest
  */
-export const SongService: GenService<{
+export type UpdateRoleRequest = Message<"musicclub.song.UpdateRoleRequest"> & {
   /**
-   * Returns a paginated list of songs.
+   * Assignment to update.
    *
-   * @generated from rpc musicclub.song.SongService.ListSongs
+   * @generated from field: musicclub.song.RoleAssignmentKey key = 1;
    */
-  listSongs: {
-    methodKind: "unary";
-    input: typeof ListSongsRequestSchema;
-    output: typeof ListSongsResponseSchema;
-  },
+  key?: RoleAssignmentKey;
+
   /**
-   * Returns a single song with full metadata and assignments.
+   * Role the assignment is moved to.
    *
-   * @generated from rpc musicclub.song.SongService.GetSong
+   * @generated from field: string new_role = 2;
    */
-  getSong: {
-    methodKind: "unary";
-    input: typeof SongIdSchema;
-    output: typeof SongDetailsSchema;
-  },
+  newRole: string;
+
   /**
-   * Create songs (requires permissions).
+   * New availability; UNSPECIFIED keeps the current one.
    *
-   * @generated from rpc musicclub.song.SongService.CreateSong
+   * @generated from field: musicclub.song.ParticipationStatus new_status = 3;
    */
-  createSong: {
-    methodKind: "unary";
-    input: typeof CreateSongRequestSchema;
-    output: typeof SongDetailsSchema;
-  },
+  newStatus: ParticipationStatus;
+};
+
+/**
+ * Describes the message musicclub.song.UpdateRoleRequest.
+ * Use `create(UpdateRoleRequestSchema)` to create a new message.
+ */
+export const UpdateRoleRequestSchema: GenMessage<UpdateRoleRequest> = /*@__PURE__*/
+  messageDesc(file_song, 20);
+
+/**
+ * @generated from message musicclub.song.ListParticipationsRequest
+ */
+export type ListParticipationsRequest = Message<"musicclub.song.ListParticipationsRequest"> & {
   /**
-   * Update songs (requires permissions).
+   * Optional filter by participant.
    *
-   * @generated from rpc musicclub.song.SongService.UpdateSong
+   * @generated from field: string user_id = 1;
    */
-  updateSong: {
-    methodKind: "unary";
+  userId: string;
+
+  /**
+   * Optional filter by song.
+   *
+   * @generated from field: string song_id = 2;
+   */
+  songId: string;
+
+  /**
+   * Optional case-insensitive filter by role, e.g. "drums".
+   *
+   * @generated from field: string role = 3;
+   */
+  role: string;
+
+  /**
+   * Pagination cursor (opaque to client).
+   *
+   * @generated from field: string page_token = 4;
+   */
+  pageToken: string;
+
+  /**
+   * @generated from field: uint32 page_size = 5;
+   */
+  pageSize: number;
+
+  /**
+   * Optional filter by availability.
+   *
+   * @generated from field: musicclub.song.ParticipationStatus status = 6;
+   */
+  status: ParticipationStatus;
+};
+
+/**
+ * Describes the message musicclub.song.ListParticipationsRequest.
+ * Use `create(ListParticipationsRequestSchema)` to create a new message.
+ */
+export const ListParticipationsRequestSchema: GenMessage<ListParticipationsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 21);
+
+/**
+ * @generated from message musicclub.song.Participation
+ */
+export type Participation = Message<"musicclub.song.Participation"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * @generated from field: string song_title = 2;
+   */
+  songTitle: string;
+
+  /**
+   * @generated from field: string song_artist = 3;
+   */
+  songArtist: string;
+
+  /**
+   * @generated from field: musicclub.song.RoleAssignment assignment = 4;
+   */
+  assignment?: RoleAssignment;
+
+  /**
+   * When the assignment was created or last moved to another role.
+   *
+   * @generated from field: google.protobuf.Timestamp updated_at = 5;
+   */
+  updatedAt?: Timestamp;
+};
+
+/**
+ * Describes the message musicclub.song.Participation.
+ * Use `create(ParticipationSchema)` to create a new message.
+ */
+export const ParticipationSchema: GenMessage<Participation> = /*@__PURE__*/
+  messageDesc(file_song, 22);
+
+/**
+ * @generated from message musicclub.song.ListParticipationsResponse
+ */
+export type ListParticipationsResponse = Message<"musicclub.song.ListParticipationsResponse"> & {
+  /**
+   * @generated from field: repeated musicclub.song.Participation participations = 1;
+   */
+  participations: Participation[];
+
+  /**
+   * @generated from field: string next_page_token = 2;
+   */
+  nextPageToken: string;
+
+  /**
+   * Whether more assignments exist after this page.
+   *
+   * @generated from field: bool has_more = 3;
+   */
+  hasMore: boolean;
+};
+
+/**
+ * Describes the message musicclub.song.ListParticipationsResponse.
+ * Use `create(ListParticipationsResponseSchema)` to create a new message.
+ */
+export const ListParticipationsResponseSchema: GenMessage<ListParticipationsResponse> = /*@__PURE__*/
+  messageDesc(file_song, 23);
+
+/**
+ * @generated from message musicclub.song.ListRecentParticipationsRequest
+ */
+export type ListRecentParticipationsRequest = Message<"musicclub.song.ListRecentParticipationsRequest"> & {
+  /**
+   * @generated from field: string user_id = 1;
+   */
+  userId: string;
+
+  /**
+   * Number of assignments to return (default 10, max 50).
+   *
+   * @generated from field: uint32 limit = 2;
+   */
+  limit: number;
+};
+
+/**
+ * Describes the message musicclub.song.ListRecentParticipationsRequest.
+ * Use `create(ListRecentParticipationsRequestSchema)` to create a new message.
+ */
+export const ListRecentParticipationsRequestSchema: GenMessage<ListRecentParticipationsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 24);
+
+/**
+ * @generated from message musicclub.song.ListParticipationsSinceRequest
+ */
+export type ListParticipationsSinceRequest = Message<"musicclub.song.ListParticipationsSinceRequest"> & {
+  /**
+   * Only assignments with updated_at strictly after this are returned.
+   *
+   * @generated from field: google.protobuf.Timestamp since = 1;
+   */
+  since?: Timestamp;
+
+  /**
+   * @generated from field: string page_token = 2;
+   */
+  pageToken: string;
+
+  /**
+   * @generated from field: uint32 page_size = 3;
+   */
+  pageSize: number;
+};
+
+/**
+ * Describes the message musicclub.song.ListParticipationsSinceRequest.
+ * Use `create(ListParticipationsSinceRequestSchema)` to create a new message.
+ */
+export const ListParticipationsSinceRequestSchema: GenMessage<ListParticipationsSinceRequest> = /*@__PURE__*/
+  messageDesc(file_song, 25);
+
+/**
+ * @generated from message musicclub.song.ListParticipationsSinceResponse
+ */
+export type ListParticipationsSinceResponse = Message<"musicclub.song.ListParticipationsSinceResponse"> & {
+  /**
+   * Ordered by updated_at.
+   *
+   * @generated from field: repeated musicclub.song.Participation participations = 1;
+   */
+  participations: Participation[];
+
+  /**
+   * @generated from field: string next_page_token = 2;
+   */
+  nextPageToken: string;
+
+  /**
+   * Whether more changes exist after this page.
+   *
+   * @generated from field: bool has_more = 3;
+   */
+  hasMore: boolean;
+
+  /**
+   * Assignments removed after the watermark, ordered by removed_at. A page
+   * holds the oldest page_size changes of either kind. A removal only cancels
+   * a participation with the same key that was updated before it.
+   *
+   * @generated from field: repeated musicclub.song.RemovedParticipation removals = 4;
+   */
+  removals: RemovedParticipation[];
+};
+
+/**
+ * Describes the message musicclub.song.ListParticipationsSinceResponse.
+ * Use `create(ListParticipationsSinceResponseSchema)` to create a new message.
+ */
+export const ListParticipationsSinceResponseSchema: GenMessage<ListParticipationsSinceResponse> = /*@__PURE__*/
+  messageDesc(file_song, 26);
+
+/**
+ * Tombstone of a role assignment that no longer exists.
+ *
+ * @generated from message musicclub.song.RemovedParticipation
+ */
+export type RemovedParticipation = Message<"musicclub.song.RemovedParticipation"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * @generated from field: string role = 2;
+   */
+  role: string;
+
+  /**
+   * @generated from field: string user_id = 3;
+   */
+  userId: string;
+
+  /**
+   * @generated from field: google.protobuf.Timestamp removed_at = 4;
+   */
+  removedAt?: Timestamp;
+};
+
+/**
+ * Describes the message musicclub.song.RemovedParticipation.
+ * Use `create(RemovedParticipationSchema)` to create a new message.
+ */
+export const RemovedParticipationSchema: GenMessage<RemovedParticipation> = /*@__PURE__*/
+  messageDesc(file_song, 27);
+
+/**
+ * @generated from message musicclub.song.ReplaceParticipantsRequest
+ */
+export type ReplaceParticipantsRequest = Message<"musicclub.song.ReplaceParticipantsRequest"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * Complete desired set of assignments; anything else is removed.
+   *
+   * @generated from field: repeated musicclub.song.ParticipantSlot participants = 2;
+   */
+  participants: ParticipantSlot[];
+};
+
+/**
+ * Describes the message musicclub.song.ReplaceParticipantsRequest.
+ * Use `create(ReplaceParticipantsRequestSchema)` to create a new message.
+ */
+export const ReplaceParticipantsRequestSchema: GenMessage<ReplaceParticipantsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 28);
+
+/**
+ * @generated from message musicclub.song.ParticipantSlot
+ */
+export type ParticipantSlot = Message<"musicclub.song.ParticipantSlot"> & {
+  /**
+   * @generated from field: string role = 1;
+   */
+  role: string;
+
+  /**
+   * @generated from field: string user_id = 2;
+   */
+  userId: string;
+};
+
+/**
+ * Describes the message musicclub.song.ParticipantSlot.
+ * Use `create(ParticipantSlotSchema)` to create a new message.
+ */
+export const ParticipantSlotSchema: GenMessage<ParticipantSlot> = /*@__PURE__*/
+  messageDesc(file_song, 29);
+
+/**
+ * @generated from message musicclub.song.CountParticipationsByRoleRequest
+ */
+export type CountParticipationsByRoleRequest = Message<"musicclub.song.CountParticipationsByRoleRequest"> & {
+};
+
+/**
+ * Describes the message musicclub.song.CountParticipationsByRoleRequest.
+ * Use `create(CountParticipationsByRoleRequestSchema)` to create a new message.
+ */
+export const CountParticipationsByRoleRequestSchema: GenMessage<CountParticipationsByRoleRequest> = /*@__PURE__*/
+  messageDesc(file_song, 30);
+
+/**
+ * @generated from message musicclub.song.RoleCount
+ */
+export type RoleCount = Message<"musicclub.song.RoleCount"> & {
+  /**
+   * @generated from field: string role = 1;
+   */
+  role: string;
+
+  /**
+   * @generated from field: uint32 count = 2;
+   */
+  count: number;
+};
+
+/**
+ * Describes the message musicclub.song.RoleCount.
+ * Use `create(RoleCountSchema)` to create a new message.
+ */
+export const RoleCountSchema: GenMessage<RoleCount> = /*@__PURE__*/
+  messageDesc(file_song, 31);
+
+/**
+ * @generated from message musicclub.song.CountParticipationsByRoleResponse
+ */
+export type CountParticipationsByRoleResponse = Message<"musicclub.song.CountParticipationsByRoleResponse"> & {
+  /**
+   * @generated from field: repeated musicclub.song.RoleCount counts = 1;
+   */
+  counts: RoleCount[];
+};
+
+/**
+ * Describes the message musicclub.song.CountParticipationsByRoleResponse.
+ * Use `create(CountParticipationsByRoleResponseSchema)` to create a new message.
+ */
+export const CountParticipationsByRoleResponseSchema: GenMessage<CountParticipationsByRoleResponse> = /*@__PURE__*/
+  messageDesc(file_song, 32);
+
+/**
+ * @generated from message musicclub.song.SongTagRequest
+ */
+export type SongTagRequest = Message<"musicclub.song.SongTagRequest"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * Normalized to lowercase.
+   *
+   * @generated from field: string tag = 2;
+   */
+  tag: string;
+};
+
+/**
+ * Describes the message musicclub.song.SongTagRequest.
+ * Use `create(SongTagRequestSchema)` to create a new message.
+ */
+export const SongTagRequestSchema: GenMessage<SongTagRequest> = /*@__PURE__*/
+  messageDesc(file_song, 33);
+
+/**
+ * @generated from message musicclub.song.SongTags
+ */
+export type SongTags = Message<"musicclub.song.SongTags"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * @generated from field: repeated string tags = 2;
+   */
+  tags: string[];
+};
+
+/**
+ * Describes the message musicclub.song.SongTags.
+ * Use `create(SongTagsSchema)` to create a new message.
+ */
+export const SongTagsSchema: GenMessage<SongTags> = /*@__PURE__*/
+  messageDesc(file_song, 34);
+
+/**
+ * @generated from message musicclub.song.BatchTagSongsRequest
+ */
+export type BatchTagSongsRequest = Message<"musicclub.song.BatchTagSongsRequest"> & {
+  /**
+   * @generated from field: string tag = 1;
+   */
+  tag: string;
+
+  /**
+   * @generated from field: repeated string song_ids = 2;
+   */
+  songIds: string[];
+};
+
+/**
+ * Describes the message musicclub.song.BatchTagSongsRequest.
+ * Use `create(BatchTagSongsRequestSchema)` to create a new message.
+ */
+export const BatchTagSongsRequestSchema: GenMessage<BatchTagSongsRequest> = /*@__PURE__*/
+  messageDesc(file_song, 35);
+
+/**
+ * @generated from message musicclub.song.BatchTagSongsResponse
+ */
+export type BatchTagSongsResponse = Message<"musicclub.song.BatchTagSongsResponse"> & {
+  /**
+   * Normalized tag that was applied.
+   *
+   * @generated from field: string tag = 1;
+   */
+  tag: string;
+
+  /**
+   * Songs that did not have the tag before this call.
+   *
+   * @generated from field: int32 tagged_count = 2;
+   */
+  taggedCount: number;
+};
+
+/**
+ * Describes the message musicclub.song.BatchTagSongsResponse.
+ * Use `create(BatchTagSongsResponseSchema)` to create a new message.
+ */
+export const BatchTagSongsResponseSchema: GenMessage<BatchTagSongsResponse> = /*@__PURE__*/
+  messageDesc(file_song, 36);
+
+/**
+ * @generated from message musicclub.song.CheckSongLinksRequest
+ */
+export type CheckSongLinksRequest = Message<"musicclub.song.CheckSongLinksRequest"> & {
+  /**
+   * @generated from field: repeated string song_ids = 1;
+   */
+  songIds: string[];
+};
+
+/**
+ * Describes the message musicclub.song.CheckSongLinksRequest.
+ * Use `create(CheckSongLinksRequestSchema)` to create a new message.
+ */
+export const CheckSongLinksRequestSchema: GenMessage<CheckSongLinksRequest> = /*@__PURE__*/
+  messageDesc(file_song, 37);
+
+/**
+ * @generated from message musicclub.song.LinkStatus
+ */
+export type LinkStatus = Message<"musicclub.song.LinkStatus"> & {
+  /**
+   * @generated from field: string song_id = 1;
+   */
+  songId: string;
+
+  /**
+   * UNKNOWN until the first check has finished.
+   *
+   * @generated from field: musicclub.song.LinkHealth health = 2;
+   */
+  health: LinkHealth;
+
+  /**
+   * @generated from field: uint32 http_status = 3;
+   */
+  httpStatus: number;
+
+  /**
+   * @generated from field: string error = 4;
+   */
+  error: string;
+
+  /**
+   * @generated from field: google.protobuf.Timestamp checked_at = 5;
+   */
+  checkedAt?: Timestamp;
+};
+
+/**
+ * Describes the message musicclub.song.LinkStatus.
+ * Use `create(LinkStatusSchema)` to create a new message.
+ */
+export const LinkStatusSchema: GenMessage<LinkStatus> = /*@__PURE__*/
+  messageDesc(file_song, 38);
+
+/**
+ * @generated from enum musicclub.song.SongLinkType
+ */
+export enum SongLinkType {
+  /**
+   * @generated from enum value: SONG_LINK_TYPE_UNKNOWN = 0;
+   */
+  UNKNOWN = 0,
+
+  /**
+   * @generated from enum value: SONG_LINK_TYPE_YOUTUBE = 1;
+   */
+  YOUTUBE = 1,
+
+  /**
+   * @generated from enum value: SONG_LINK_TYPE_YANDEX_MUSIC = 2;
+   */
+  YANDEX_MUSIC = 2,
+
+  /**
+   * @generated from enum value: SONG_LINK_TYPE_SOUNDCLOUD = 3;
+   */
+  SOUNDCLOUD = 3,
+}
+
+/**
+ * Describes the enum musicclub.song.SongLinkType.
+ */
+export const SongLinkTypeSchema: GenEnum<SongLinkType> = /*@__PURE__*/
+  enumDesc(file_song, 0);
+
+/**
+ * @generated from enum musicclub.song.ParticipationStatus
+ */
+export enum ParticipationStatus {
+  /**
+   * Not set; treated as CONFIRMED on writes and ignored as a list filter.
+   *
+   * @generated from enum value: PARTICIPATION_STATUS_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * @generated from enum value: PARTICIPATION_STATUS_CONFIRMED = 1;
+   */
+  CONFIRMED = 1,
+
+  /**
+   * @generated from enum value: PARTICIPATION_STATUS_TENTATIVE = 2;
+   */
+  TENTATIVE = 2,
+}
+
+/**
+ * Describes the enum musicclub.song.ParticipationStatus.
+ */
+export const ParticipationStatusSchema: GenEnum<ParticipationStatus> = /*@__PURE__*/
+  enumDesc(file_song, 1);
+
+/**
+ * @generated from enum musicclub.song.LinkHealth
+ */
+export enum LinkHealth {
+  /**
+   * @generated from enum value: LINK_HEALTH_UNKNOWN = 0;
+   */
+  UNKNOWN = 0,
+
+  /**
+   * @generated from enum value: LINK_HEALTH_OK = 1;
+   */
+  OK = 1,
+
+  /**
+   * @generated from enum value: LINK_HEALTH_BROKEN = 2;
+   */
+  BROKEN = 2,
+
+  /**
+   * @generated from enum value: LINK_HEALTH_UNREACHABLE = 3;
+   */
+  UNREACHABLE = 3,
+
+  /**
+   * @generated from enum value: LINK_HEALTH_MISSING = 4;
+   */
+  MISSING = 4,
+}
+
+/**
+ * Describes the enum musicclub.song.LinkHealth.
+ */
+export const LinkHealthSchema: GenEnum<LinkHealth> = /*@__PURE__*/
+  enumDesc(file_song, 2);
+
+/**
+ * Provides CRUD functionality for songs
+ *
+ * @generated from service musicclub.song.SongService
+ */
+export const SongService: GenService<{
+  /**
+   * Returns a paginated list of songs.
+   *
+   * @generated from rpc musicclub.song.SongService.ListSongs
+   */
+  listSongs: {
+    methodKind: "unary";
+    input: typeof ListSongsRequestSchema;
+    output: typeof ListSongsResponseSchema;
+  },
+  /**
+   * Streams every song matching a search, for "select all" without paging.
+   * Songs carry only catalog fields: no roles, counts or per-user fields.
+   *
+   * @generated from rpc musicclub.song.SongService.StreamSearchSongs
+   */
+  streamSearchSongs: {
+    methodKind: "server_streaming";
+    input: typeof StreamSearchSongsRequestSchema;
+    output: typeof SongSchema;
+  },
+  /**
+   * Returns the most recently added songs, newest first.
+   *
+   * @generated from rpc musicclub.song.SongService.ListRecentSongs
+   */
+  listRecentSongs: {
+    methodKind: "unary";
+    input: typeof ListRecentSongsRequestSchema;
+    output: typeof ListSongsResponseSchema;
+  },
+  /**
+   * Returns songs that are not yet in an event's tracklist.
+   *
+   * @generated from rpc musicclub.song.SongService.ListSongsNotInEvent
+   */
+  listSongsNotInEvent: {
+    methodKind: "unary";
+    input: typeof ListSongsNotInEventRequestSchema;
+    output: typeof ListSongsResponseSchema;
+  },
+  /**
+   * Returns songs ranked by vote count, most voted first.
+   *
+   * @generated from rpc musicclub.song.SongService.ListSongsByVotes
+   */
+  listSongsByVotes: {
+    methodKind: "unary";
+    input: typeof ListSongsByVotesRequestSchema;
+    output: typeof ListSongsResponseSchema;
+  },
+  /**
+   * Returns a single song with full metadata and assignments.
+   *
+   * @generated from rpc musicclub.song.SongService.GetSong
+   */
+  getSong: {
+    methodKind: "unary";
+    input: typeof GetSongRequestSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Create songs (requires permissions).
+   *
+   * @generated from rpc musicclub.song.SongService.CreateSong
+   */
+  createSong: {
+    methodKind: "unary";
+    input: typeof CreateSongRequestSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Runs the CreateSong checks without inserting anything.
+   *
+   * @generated from rpc musicclub.song.SongService.ValidateSong
+   */
+  validateSong: {
+    methodKind: "unary";
+    input: typeof CreateSongRequestSchema;
+    output: typeof ValidateSongResponseSchema;
+  },
+  /**
+   * Update songs (requires permissions).
+   *
+   * @generated from rpc musicclub.song.SongService.UpdateSong
+   */
+  updateSong: {
+    methodKind: "unary";
     input: typeof UpdateSongRequestSchema;
     output: typeof SongDetailsSchema;
   },
@@ -444,7 +1407,7 @@ export const SongService: GenService<{
    */
   deleteSong: {
     methodKind: "unary";
-    input: typeof SongIdSchema;
+    input: typeof DeleteSongRequestSchema;
     output: typeof EmptySchema;
   },
   /**
@@ -467,6 +1430,146 @@ export const SongService: GenService<{
     input: typeof LeaveRoleRequestSchema;
     output: typeof SongDetailsSchema;
   },
+  /**
+   * Move an existing role assignment to another role.
+   *
+   * @generated from rpc musicclub.song.SongService.UpdateRole
+   */
+  updateRole: {
+    methodKind: "unary";
+    input: typeof UpdateRoleRequestSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Replace all role assignments of a song in one call (admins only).
+   *
+   * @generated from rpc musicclub.song.SongService.ReplaceParticipants
+   */
+  replaceParticipants: {
+    methodKind: "unary";
+    input: typeof ReplaceParticipantsRequestSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Lists role assignments across songs together with song titles.
+   *
+   * @generated from rpc musicclub.song.SongService.ListParticipations
+   */
+  listParticipations: {
+    methodKind: "unary";
+    input: typeof ListParticipationsRequestSchema;
+    output: typeof ListParticipationsResponseSchema;
+  },
+  /**
+   * Lists role assignments created, changed or removed after a watermark, oldest first.
+   *
+   * @generated from rpc musicclub.song.SongService.ListParticipationsSince
+   */
+  listParticipationsSince: {
+    methodKind: "unary";
+    input: typeof ListParticipationsSinceRequestSchema;
+    output: typeof ListParticipationsSinceResponseSchema;
+  },
+  /**
+   * Returns a person's latest signups across songs, newest first.
+   *
+   * @generated from rpc musicclub.song.SongService.ListRecentParticipations
+   */
+  listRecentParticipations: {
+    methodKind: "unary";
+    input: typeof ListRecentParticipationsRequestSchema;
+    output: typeof ListParticipationsResponseSchema;
+  },
+  /**
+   * Counts role assignments per role across all songs, most common first.
+   *
+   * @generated from rpc musicclub.song.SongService.CountParticipationsByRole
+   */
+  countParticipationsByRole: {
+    methodKind: "unary";
+    input: typeof CountParticipationsByRoleRequestSchema;
+    output: typeof CountParticipationsByRoleResponseSchema;
+  },
+  /**
+   * Queues background health checks for song links.
+   *
+   * @generated from rpc musicclub.song.SongService.CheckSongLinks
+   */
+  checkSongLinks: {
+    methodKind: "unary";
+    input: typeof CheckSongLinksRequestSchema;
+    output: typeof EmptySchema;
+  },
+  /**
+   * Returns the latest link health check result for a song.
+   *
+   * @generated from rpc musicclub.song.SongService.GetLinkStatus
+   */
+  getLinkStatus: {
+    methodKind: "unary";
+    input: typeof SongIdSchema;
+    output: typeof LinkStatusSchema;
+  },
+  /**
+   * Lists the tags of a song.
+   *
+   * @generated from rpc musicclub.song.SongService.ListSongTags
+   */
+  listSongTags: {
+    methodKind: "unary";
+    input: typeof SongIdSchema;
+    output: typeof SongTagsSchema;
+  },
+  /**
+   * Adds a tag to a song (requires permissions).
+   *
+   * @generated from rpc musicclub.song.SongService.AddSongTag
+   */
+  addSongTag: {
+    methodKind: "unary";
+    input: typeof SongTagRequestSchema;
+    output: typeof SongTagsSchema;
+  },
+  /**
+   * Removes a tag from a song (requires permissions).
+   *
+   * @generated from rpc musicclub.song.SongService.RemoveSongTag
+   */
+  removeSongTag: {
+    methodKind: "unary";
+    input: typeof SongTagRequestSchema;
+    output: typeof SongTagsSchema;
+  },
+  /**
+   * Upvotes a song for the current user; voting twice has no effect.
+   *
+   * @generated from rpc musicclub.song.SongService.VoteSong
+   */
+  voteSong: {
+    methodKind: "unary";
+    input: typeof SongIdSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Withdraws the current user's vote.
+   *
+   * @generated from rpc musicclub.song.SongService.UnvoteSong
+   */
+  unvoteSong: {
+    methodKind: "unary";
+    input: typeof SongIdSchema;
+    output: typeof SongDetailsSchema;
+  },
+  /**
+   * Adds one tag to many songs in a single transaction (requires permissions).
+   *
+   * @generated from rpc musicclub.song.SongService.BatchTagSongs
+   */
+  batchTagSongs: {
+    methodKind: "unary";
+    input: typeof BatchTagSongsRequestSchema;
+    output: typeof BatchTagSongsResponseSchema;
+  },
 }> = /*@__PURE__*/
   serviceDesc(file_song, 0);
 
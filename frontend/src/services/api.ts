@@ -4,8 +4,8 @@ import {AuthService, TelegramWebAppAuthRequestSchema} from "../proto/auth_pb";
 import {create} from "@bufbuild/protobuf";
 
 import {clearTokenPair, transport} from "./config";
-import {SongService} from "../proto/song_pb";
-import {EventService} from "../proto/event_pb";
+import {ParticipationStatus, SongService} from "../proto/song_pb";
+import {EventService, EventStatus} from "../proto/event_pb";
 import type {Timestamp} from "@bufbuild/protobuf/wkt";
 import {type User, UserSchema} from "../proto/user_pb";
 
@@ -46,10 +46,30 @@ export function listSongs(query = "", pageToken = "", pageSize = 20) {
 	return songClient.listSongs({ query, pageToken, pageSize });
 }
 
+export function listSongsForUser(participantId: string, pageToken = "", pageSize = 20) {
+	return songClient.listSongs({ participantId, pageToken, pageSize });
+}
+
+export function listSongsByVotes(pageToken = "", pageSize = 20) {
+	return songClient.listSongsByVotes({ pageToken, pageSize });
+}
+
+export function listSongsNotInEvent(eventId: string, pageToken = "", pageSize = 20) {
+	return songClient.listSongsNotInEvent({ eventId, pageToken, pageSize });
+}
+
 export function getSong(id: string) {
 	return songClient.getSong({ id });
 }
 
+export function voteSong(id: string) {
+	return songClient.voteSong({ id });
+}
+
+export function unvoteSong(id: string) {
+	return songClient.unvoteSong({ id });
+}
+
 export function createSong(payload: {
 	title: string;
 	artist: string;
@@ -58,6 +78,7 @@ export function createSong(payload: {
 	linkKind: number;
 	roles: string[];
 	thumbnailUrl?: string;
+	durationSeconds?: number;
 }) {
 	return songClient.createSong(
 		{
@@ -67,10 +88,26 @@ export function createSong(payload: {
 			link: { url: payload.linkUrl, kind: payload.linkKind },
 			availableRoles: payload.roles,
 			thumbnailUrl: payload.thumbnailUrl ?? "",
+			durationSeconds: payload.durationSeconds ?? 0,
 		}
 	);
 }
 
+export function validateSong(payload: {
+	title: string;
+	artist: string;
+	linkUrl: string;
+	linkKind: number;
+	roles: string[];
+}) {
+	return songClient.validateSong({
+		title: payload.title,
+		artist: payload.artist,
+		link: { url: payload.linkUrl, kind: payload.linkKind },
+		availableRoles: payload.roles,
+	});
+}
+
 export function updateSong(payload: {
 	id: string;
 	title: string;
@@ -80,6 +117,9 @@ export function updateSong(payload: {
 	linkKind: number;
 	roles: string[];
 	thumbnailUrl?: string;
+	durationSeconds?: number;
+	// Admins only; notes are left untouched when omitted.
+	notes?: string;
 }) {
 	return songClient.updateSong(
 		{
@@ -90,6 +130,9 @@ export function updateSong(payload: {
 			link: { url: payload.linkUrl, kind: payload.linkKind },
 			availableRoles: payload.roles,
 			thumbnailUrl: payload.thumbnailUrl ?? "",
+			durationSeconds: payload.durationSeconds ?? 0,
+			notes: payload.notes ?? "",
+			updateMask: payload.notes === undefined ? undefined : { paths: ["notes"] },
 		},
 	);
 }
@@ -98,16 +141,20 @@ export function deleteSong(id: string) {
 	return songClient.deleteSong({ id });
 }
 
-export function joinSongRole(songId: string, role: string) {
-	return songClient.joinRole({ songId, role });
+export function joinSongRole(songId: string, role: string, status = ParticipationStatus.CONFIRMED) {
+	return songClient.joinRole({ songId, role, status });
 }
 
 export function leaveSongRole(songId: string, role: string) {
 	return songClient.leaveRole({ songId, role });
 }
 
-export function listEvents(from?: Timestamp, to?: Timestamp, limit = 50) {
-	return eventClient.listEvents({ from, to, limit });
+export function updateSongRole(songId: string, role: string, userId: string, newRole: string) {
+	return songClient.updateRole({ key: { songId, role, userId }, newRole });
+}
+
+export function listEvents(from?: Timestamp, to?: Timestamp, limit = 50, includeTracklist = false) {
+	return eventClient.listEvents({ from, to, limit, includeTracklist });
 }
 
 export function getEvent(id: string) {
@@ -121,6 +168,7 @@ export function createEvent(payload: {
 	notifyDayBefore?: boolean;
 	notifyHourBefore?: boolean;
 	tracklist?: { order: number; songId: string; customTitle: string; customArtist: string }[];
+	status?: EventStatus;
 }) {
 	return eventClient.createEvent(
 		{
@@ -132,6 +180,7 @@ export function createEvent(payload: {
 			tracklist: payload.tracklist
 				? { items: payload.tracklist.map((i) => ({ order: i.order, songId: i.songId, customTitle: i.customTitle, customArtist: i.customArtist })) }
 				: undefined,
+			status: payload.status ?? EventStatus.UNSPECIFIED,
 		},
 	);
 }
@@ -143,6 +192,7 @@ export function updateEvent(payload: {
 	location?: string;
 	notifyDayBefore?: boolean;
 	notifyHourBefore?: boolean;
+	status?: EventStatus;
 }) {
 	return eventClient.updateEvent(
 		{
@@ -152,10 +202,15 @@ export function updateEvent(payload: {
 			location: payload.location ?? "",
 			notifyDayBefore: payload.notifyDayBefore ?? false,
 			notifyHourBefore: payload.notifyHourBefore ?? false,
+			status: payload.status ?? EventStatus.UNSPECIFIED,
 		},
 	);
 }
 
+export function listEventsForSong(songId: string) {
+	return eventClient.listEventsForSong({ id: songId });
+}
+
 export function deleteEvent(id: string) {
 	return eventClient.deleteEvent({ id });
 }
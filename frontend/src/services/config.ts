@@ -15,7 +15,18 @@ const ACCESS_TOKEN_DEFAULT_MAX_AGE = 15 * 60; // 15 minutes
 const REFRESH_TOKEN_MAX_AGE = 7 * 24 * 60 * 60; // 7 days
 const REFRESH_SKEW_MS = 60_000; // refresh 1 minute before expiry
 
-const refreshTransport = createGrpcWebTransport({ baseUrl: BACKEND_URL });
+// Bump together with breaking API changes; the backend rejects versions below MIN_API_VERSION.
+const API_VERSION = "1";
+
+const apiVersionInterceptor: Interceptor = (next) => async (req) => {
+	req.header.set("x-api-version", API_VERSION);
+	return await next(req);
+};
+
+const refreshTransport = createGrpcWebTransport({
+	baseUrl: BACKEND_URL,
+	interceptors: [apiVersionInterceptor],
+});
 const refreshClient = createClient(AuthService, refreshTransport);
 
 let accessToken = readCookie(ACCESS_TOKEN_COOKIE) ?? "";
@@ -173,5 +184,5 @@ const authInterceptor: Interceptor = (next) => async (req) => {
 
 export const transport = createGrpcWebTransport({
 	baseUrl: BACKEND_URL,
-	interceptors: [authInterceptor],
+	interceptors: [apiVersionInterceptor, authInterceptor],
 });
@@ -0,0 +1,10 @@
+-- Multi-night events: every performance date lives in event_date,
+-- event.start_at is kept as the earliest one for backward compatibility.
+CREATE TABLE IF NOT EXISTS event_date (
+    event_id UUID NOT NULL REFERENCES event(id) ON DELETE CASCADE,
+    start_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (event_id, start_at)
+);
+INSERT INTO event_date (event_id, start_at)
+SELECT id, start_at FROM event WHERE start_at IS NOT NULL
+ON CONFLICT DO NOTHING;
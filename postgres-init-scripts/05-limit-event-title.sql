@@ -0,0 +1,10 @@
+-- Event titles are limited to 200 characters (see maxEventTitleLength in the backend).
+-- NOT VALID keeps existing rows untouched while enforcing the limit for new writes.
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_constraint
+    WHERE conname = 'event_title_length'
+        AND conrelid = 'event'::regclass
+) THEN ALTER TABLE event ADD CONSTRAINT event_title_length CHECK (char_length(title) <= 200) NOT VALID;
+END IF;
+END $$;
@@ -0,0 +1,12 @@
+-- Track when a role assignment last changed so clients can sync incrementally.
+-- Existing rows are backfilled from joined_at only when the column is first added.
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM information_schema.columns
+    WHERE table_name = 'song_role_assignment'
+        AND column_name = 'updated_at'
+) THEN ALTER TABLE song_role_assignment ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+UPDATE song_role_assignment SET updated_at = joined_at;
+END IF;
+END $$;
+CREATE INDEX IF NOT EXISTS idx_song_role_assignment_updated ON song_role_assignment (updated_at, id);
@@ -0,0 +1,8 @@
+-- Latest result of the background link health check per song.
+CREATE TABLE IF NOT EXISTS link_status (
+    song_id UUID PRIMARY KEY REFERENCES song(id) ON DELETE CASCADE,
+    status TEXT NOT NULL,
+    http_status INTEGER NOT NULL DEFAULT 0,
+    error TEXT NOT NULL DEFAULT '',
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+-- Optional song length, used for setlist timing.
+ALTER TABLE song ADD COLUMN IF NOT EXISTS duration_seconds INTEGER CHECK (duration_seconds > 0);
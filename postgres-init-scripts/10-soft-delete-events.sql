@@ -0,0 +1,2 @@
+-- Deleting an event only hides it; admins can restore it until it is force-deleted.
+ALTER TABLE event ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
@@ -0,0 +1,7 @@
+-- One upvote per user and song, used to pick songs for the next event.
+CREATE TABLE IF NOT EXISTS song_vote (
+    song_id UUID NOT NULL REFERENCES song(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES app_user(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (song_id, user_id)
+);
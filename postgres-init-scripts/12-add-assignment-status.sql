@@ -0,0 +1,3 @@
+-- Musicians can sign up tentatively before confirming a role.
+ALTER TABLE song_role_assignment ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'confirmed'
+    CONSTRAINT song_role_assignment_status CHECK (status IN ('confirmed', 'tentative'));
@@ -0,0 +1,2 @@
+-- Private organizer notes (key, tuning, cues); only admins can read or write them.
+ALTER TABLE song ADD COLUMN IF NOT EXISTS notes TEXT;
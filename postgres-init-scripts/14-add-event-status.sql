@@ -0,0 +1,5 @@
+-- Events start as drafts and are hidden from members until published.
+-- Events that already exist are published.
+ALTER TABLE event ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'published'
+    CONSTRAINT event_status CHECK (status IN ('draft', 'published', 'cancelled'));
+ALTER TABLE event ALTER COLUMN status SET DEFAULT 'draft';
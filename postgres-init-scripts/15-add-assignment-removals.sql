@@ -0,0 +1,19 @@
+-- Remember removed role assignments so incremental sync can report them.
+-- Every delete is logged, including cascades from songs, roles and users.
+CREATE TABLE IF NOT EXISTS song_role_assignment_removal (
+    id UUID PRIMARY KEY,
+    song_id UUID NOT NULL,
+    role TEXT NOT NULL,
+    user_id UUID NOT NULL,
+    removed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_song_role_assignment_removal_at ON song_role_assignment_removal (removed_at, id);
+CREATE OR REPLACE FUNCTION log_song_role_assignment_removal() RETURNS trigger AS $$ BEGIN
+INSERT INTO song_role_assignment_removal (id, song_id, role, user_id)
+VALUES (OLD.id, OLD.song_id, OLD.role, OLD.user_id) ON CONFLICT (id) DO NOTHING;
+RETURN OLD;
+END $$ LANGUAGE plpgsql;
+DROP TRIGGER IF EXISTS song_role_assignment_removal ON song_role_assignment;
+CREATE TRIGGER song_role_assignment_removal
+AFTER DELETE ON song_role_assignment
+FOR EACH ROW EXECUTE FUNCTION log_song_role_assignment_removal();
@@ -0,0 +1,12 @@
+-- Admins are recorded explicitly instead of being inferred from permission flags.
+CREATE TABLE IF NOT EXISTS app_admin (
+    user_id UUID PRIMARY KEY REFERENCES app_user(id) ON DELETE CASCADE,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+-- Users holding both flags were the admins before this table existed.
+INSERT INTO app_admin (user_id)
+SELECT user_id
+FROM user_permissions
+WHERE edit_any_songs
+    AND edit_events
+ON CONFLICT DO NOTHING;